@@ -0,0 +1,110 @@
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol};
+
+use crate::admin::require_compliance_officer;
+
+/// Errors that can occur when freezing accounts
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AccountFreezeError {
+    Unauthorized = 1,
+    NotFrozen = 2,
+}
+
+/// Storage keys for account freeze data
+#[contracttype]
+#[derive(Clone)]
+pub enum AccountFreezeDataKey {
+    FrozenAccount(Address),
+}
+
+/// Why and when an account was frozen
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AccountFreeze {
+    pub reason: Symbol,
+    pub frozen_at: u64,
+}
+
+/// Account freeze event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct AccountFreezeEvent {
+    pub account: Address,
+    pub reason: Symbol,
+    pub actor: Address,
+    pub timestamp: u64,
+}
+
+/// Freeze an account's new borrows and supplies (compliance officer only)
+///
+/// Meant for legal holds in permissioned deployments. The account can still
+/// repay and withdraw, and its positions can still be liquidated. Freezing
+/// again replaces the reason code.
+pub fn freeze_account(
+    env: &Env,
+    caller: Address,
+    account: Address,
+    reason: Symbol,
+) -> Result<(), AccountFreezeError> {
+    require_compliance_officer(env, &caller).map_err(|_| AccountFreezeError::Unauthorized)?;
+
+    let freeze = AccountFreeze {
+        reason: reason.clone(),
+        frozen_at: env.ledger().timestamp(),
+    };
+    env.storage().persistent().set(
+        &AccountFreezeDataKey::FrozenAccount(account.clone()),
+        &freeze,
+    );
+    emit_account_freeze_event(env, "account_frozen", account, reason, caller);
+    Ok(())
+}
+
+/// Lift an account freeze (compliance officer only)
+pub fn unfreeze_account(
+    env: &Env,
+    caller: Address,
+    account: Address,
+    reason: Symbol,
+) -> Result<(), AccountFreezeError> {
+    require_compliance_officer(env, &caller).map_err(|_| AccountFreezeError::Unauthorized)?;
+
+    let key = AccountFreezeDataKey::FrozenAccount(account.clone());
+    if !env.storage().persistent().has(&key) {
+        return Err(AccountFreezeError::NotFrozen);
+    }
+    env.storage().persistent().remove(&key);
+    emit_account_freeze_event(env, "account_unfrozen", account, reason, caller);
+    Ok(())
+}
+
+/// Get an account's freeze, if it is frozen
+pub fn get_account_freeze(env: &Env, account: &Address) -> Option<AccountFreeze> {
+    env.storage()
+        .persistent()
+        .get(&AccountFreezeDataKey::FrozenAccount(account.clone()))
+}
+
+/// Whether an account's new borrows and supplies are frozen
+pub(crate) fn is_account_frozen(env: &Env, account: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&AccountFreezeDataKey::FrozenAccount(account.clone()))
+}
+
+fn emit_account_freeze_event(
+    env: &Env,
+    name: &str,
+    account: Address,
+    reason: Symbol,
+    actor: Address,
+) {
+    let event = AccountFreezeEvent {
+        account,
+        reason,
+        actor,
+        timestamp: env.ledger().timestamp(),
+    };
+    crate::events::publish(env, (Symbol::new(env, name), event.account.clone()), event);
+}
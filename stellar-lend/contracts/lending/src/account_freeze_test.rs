@@ -0,0 +1,51 @@
+use super::*;
+use soroban_sdk::{testutils::Address as _, Address, Env, Symbol};
+
+#[test]
+fn test_frozen_account_can_only_unwind() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = LendingContractClient::new(&env, &env.register(LendingContract, ()));
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &InitConfig::new(&env, 1_000_000_000, 1000));
+    let officer = Address::generate(&env);
+    client.set_compliance_officer(&admin, &officer);
+
+    let asset = Address::generate(&env);
+    let collateral = Address::generate(&env);
+    client.set_p2p_market(
+        &admin,
+        &asset,
+        &P2PMarket {
+            pool_supply_rate_bps: 200,
+            p2p_cursor_bps: 5000,
+        },
+    );
+    let user = Address::generate(&env);
+    client.borrow(&user, &asset, &10_000, &collateral, &20_000);
+
+    let reason = Symbol::new(&env, "legal_hold");
+    assert_eq!(
+        client.try_freeze_account(&admin, &user, &reason),
+        Err(Ok(AccountFreezeError::Unauthorized))
+    );
+    client.freeze_account(&officer, &user, &reason);
+    assert_eq!(client.get_account_freeze(&user).unwrap().reason, reason);
+
+    assert_eq!(
+        client.try_borrow(&user, &asset, &1_000, &collateral, &2_000),
+        Err(Ok(BorrowError::AccountFrozen))
+    );
+    assert_eq!(
+        client.try_p2p_supply(&user, &asset, &1_000),
+        Err(Ok(P2PError::AccountFrozen))
+    );
+    client.repay(&user, &5_000);
+
+    client.unfreeze_account(&officer, &user, &Symbol::new(&env, "released"));
+    assert_eq!(
+        client.try_unfreeze_account(&officer, &user, &reason),
+        Err(Ok(AccountFreezeError::NotFrozen))
+    );
+    client.borrow(&user, &asset, &1_000, &collateral, &2_000);
+}
@@ -0,0 +1,166 @@
+use soroban_sdk::{contracterror, contracttype, Address, Env};
+
+/// Errors that can occur during admin operations
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AdminError {
+    Unauthorized = 1,
+    NotInitialized = 2,
+}
+
+/// Storage keys for admin data
+#[contracttype]
+#[derive(Clone)]
+pub enum AdminDataKey {
+    Admin,
+    RiskManager,
+    Guardian,
+    Treasurer,
+    ComplianceOfficer,
+}
+
+/// Get the admin address, if one has been set
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().persistent().get(&AdminDataKey::Admin)
+}
+
+/// Transfer the admin role (current admin only)
+///
+/// The first admin is set by `initialize`; before that there is no admin to
+/// transfer from and the call fails.
+pub fn set_admin(env: &Env, new_admin: Address) -> Result<(), AdminError> {
+    let current = get_admin(env).ok_or(AdminError::NotInitialized)?;
+    current.require_auth();
+    store_admin(env, &new_admin);
+    Ok(())
+}
+
+pub(crate) fn store_admin(env: &Env, admin: &Address) {
+    env.storage().persistent().set(&AdminDataKey::Admin, admin);
+}
+
+/// Require that `caller` is the admin and has authorized the invocation
+pub fn require_admin(env: &Env, caller: &Address) -> Result<(), AdminError> {
+    let admin = get_admin(env).ok_or(AdminError::NotInitialized)?;
+    if admin != *caller {
+        return Err(AdminError::Unauthorized);
+    }
+    caller.require_auth();
+    Ok(())
+}
+
+/// Get the risk manager address, if one has been set
+pub fn get_risk_manager(env: &Env) -> Option<Address> {
+    env.storage().persistent().get(&AdminDataKey::RiskManager)
+}
+
+/// Appoint the risk manager (admin only)
+pub fn set_risk_manager(env: &Env, caller: Address, manager: Address) -> Result<(), AdminError> {
+    require_admin(env, &caller)?;
+    store_risk_manager(env, &manager);
+    Ok(())
+}
+
+/// Require that `caller` is the risk manager or the admin and has authorized
+/// the invocation
+pub fn require_risk_manager(env: &Env, caller: &Address) -> Result<(), AdminError> {
+    if get_risk_manager(env).as_ref() == Some(caller) {
+        caller.require_auth();
+        return Ok(());
+    }
+    require_admin(env, caller)
+}
+
+/// Get the guardian address, if one has been set
+pub fn get_guardian(env: &Env) -> Option<Address> {
+    env.storage().persistent().get(&AdminDataKey::Guardian)
+}
+
+/// Appoint the guardian (admin only)
+///
+/// The guardian handles emergency actions such as proposing price overrides.
+pub fn set_guardian(env: &Env, caller: Address, guardian: Address) -> Result<(), AdminError> {
+    require_admin(env, &caller)?;
+    store_guardian(env, &guardian);
+    Ok(())
+}
+
+pub(crate) fn store_risk_manager(env: &Env, manager: &Address) {
+    env.storage()
+        .persistent()
+        .set(&AdminDataKey::RiskManager, manager);
+}
+
+pub(crate) fn store_guardian(env: &Env, guardian: &Address) {
+    env.storage()
+        .persistent()
+        .set(&AdminDataKey::Guardian, guardian);
+}
+
+/// Require that `caller` is the guardian and has authorized the invocation
+pub fn require_guardian(env: &Env, caller: &Address) -> Result<(), AdminError> {
+    if get_guardian(env).as_ref() != Some(caller) {
+        return Err(AdminError::Unauthorized);
+    }
+    caller.require_auth();
+    Ok(())
+}
+
+/// Get the treasurer address, if one has been set
+pub fn get_treasurer(env: &Env) -> Option<Address> {
+    env.storage().persistent().get(&AdminDataKey::Treasurer)
+}
+
+/// Appoint the treasurer (admin only)
+///
+/// The treasurer manages idle liquidity, such as deployments to yield strategies.
+pub fn set_treasurer(env: &Env, caller: Address, treasurer: Address) -> Result<(), AdminError> {
+    require_admin(env, &caller)?;
+    env.storage()
+        .persistent()
+        .set(&AdminDataKey::Treasurer, &treasurer);
+    Ok(())
+}
+
+/// Require that `caller` is the treasurer or the admin and has authorized the
+/// invocation
+pub fn require_treasurer(env: &Env, caller: &Address) -> Result<(), AdminError> {
+    if get_treasurer(env).as_ref() == Some(caller) {
+        caller.require_auth();
+        return Ok(());
+    }
+    require_admin(env, caller)
+}
+
+/// Get the compliance officer address, if one has been set
+pub fn get_compliance_officer(env: &Env) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&AdminDataKey::ComplianceOfficer)
+}
+
+/// Appoint the compliance officer (admin only)
+///
+/// The compliance officer places legal holds on individual accounts.
+pub fn set_compliance_officer(
+    env: &Env,
+    caller: Address,
+    officer: Address,
+) -> Result<(), AdminError> {
+    require_admin(env, &caller)?;
+    env.storage()
+        .persistent()
+        .set(&AdminDataKey::ComplianceOfficer, &officer);
+    Ok(())
+}
+
+/// Require that `caller` is the compliance officer and has authorized the
+/// invocation
+pub fn require_compliance_officer(env: &Env, caller: &Address) -> Result<(), AdminError> {
+    if get_compliance_officer(env).as_ref() != Some(caller) {
+        return Err(AdminError::Unauthorized);
+    }
+    caller.require_auth();
+    Ok(())
+}
@@ -0,0 +1,504 @@
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol, Vec};
+
+use crate::borrow::{
+    adjust_asset_debt, check_new_debt, get_total_collateral_value, get_total_debt,
+    get_total_user_debt, set_total_debt, validate_collateral_ratio, BorrowError, SECONDS_PER_YEAR,
+};
+use crate::math::{div_up, mul_div_down, mul_div_up};
+
+/// Errors that can occur with amortizing loans
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AmortizingError {
+    InvalidAmount = 1,
+    InvalidTerms = 2,
+    LoanNotFound = 3,
+    Unauthorized = 4,
+    LoanClosed = 5,
+    NotDue = 6,
+    AllowanceExhausted = 7,
+    InsufficientCollateral = 8,
+    TooManyPositions = 9,
+    ProtocolPaused = 10,
+    Overflow = 11,
+    BorrowCapExceeded = 12,
+    /// The account is under a compliance freeze
+    AccountFrozen = 13,
+    PriceStale = 14,
+    MarketFrozen = 15,
+    AssetBorrowsPaused = 16,
+    AssetDelisted = 17,
+    BorrowFrozen = 18,
+    AssetCollateralOnly = 19,
+    DebtCeilingReached = 20,
+    LiquidityBufferReached = 21,
+    RehypothecationDisabled = 22,
+    /// The borrow must go through the pool's `request_borrow`
+    LargeBorrowRequestRequired = 23,
+    /// The borrow is valued above the per-call maximum
+    BorrowSizeCapExceeded = 24,
+}
+
+/// Storage keys for amortizing loan data
+#[contracttype]
+#[derive(Clone)]
+pub enum AmortizingDataKey {
+    NextAmortizingLoanId,
+    AmortizingLoan(u64),
+    BorrowerAmortizingLoans(Address),
+}
+
+/// A pool loan repaid in equal periodic installments of principal and interest
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AmortizingLoan {
+    pub id: u64,
+    pub borrower: Address,
+    pub asset: Address,
+    pub principal: i128,
+    /// Annual rate in basis points, fixed at the pool rate at origination
+    pub rate_bps: i128,
+    /// Seconds between installments
+    pub interval: u64,
+    pub installments: u32,
+    /// Level payment per installment; the final one settles the remainder
+    pub installment_amount: i128,
+    pub start: u64,
+    pub paid_installments: u32,
+    pub outstanding_principal: i128,
+    /// Remaining amount keepers may pull on the borrower's behalf
+    pub allowance: i128,
+    pub repaid: bool,
+}
+
+/// One installment of an amortization schedule
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Installment {
+    /// 1-based installment number
+    pub number: u32,
+    pub due: u64,
+    pub amount: i128,
+    pub interest: i128,
+    pub principal: i128,
+    pub paid: bool,
+}
+
+/// Repayment standing of an amortizing loan
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AmortizingStatus {
+    Current,
+    /// At least one installment is unpaid past its grace period
+    Delinquent,
+    Repaid,
+}
+
+/// Amortizing loan event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct AmortizingEvent {
+    pub id: u64,
+    pub borrower: Address,
+    pub amount: i128,
+    pub outstanding_principal: i128,
+    pub timestamp: u64,
+}
+
+const MIN_INSTALLMENT_INTERVAL: u64 = 86400; // 1 day
+const MAX_INSTALLMENTS: u32 = 360;
+const MAX_AMORTIZING_TERM: u64 = 5 * SECONDS_PER_YEAR;
+const INSTALLMENT_GRACE_PERIOD: u64 = 3 * 86400; // 3 days
+const RATE_SCALE: i128 = 1_000_000_000_000;
+
+/// Open an amortizing loan against the borrower's collateral
+///
+/// The rate is the pool borrow rate at origination and stays fixed. The first
+/// installment is due one `interval` from now. The principal is drawn from
+/// the pool, so it faces the pool's borrow checks and counts toward its debt
+/// totals until repaid.
+pub fn open_amortizing_loan(
+    env: &Env,
+    borrower: Address,
+    asset: Address,
+    principal: i128,
+    interval: u64,
+    installments: u32,
+) -> Result<u64, AmortizingError> {
+    borrower.require_auth();
+
+    if principal <= 0 {
+        return Err(AmortizingError::InvalidAmount);
+    }
+    if interval < MIN_INSTALLMENT_INTERVAL
+        || !(1..=MAX_INSTALLMENTS).contains(&installments)
+        || interval.saturating_mul(installments as u64) > MAX_AMORTIZING_TERM
+    {
+        return Err(AmortizingError::InvalidTerms);
+    }
+    if !crate::position_limit::has_position_capacity(env, &borrower) {
+        return Err(AmortizingError::TooManyPositions);
+    }
+    let (new_total, new_asset_debt) =
+        check_new_debt(env, &borrower, &asset, principal).map_err(debt_error)?;
+
+    let total_debt = get_total_user_debt(env, &borrower)
+        .checked_add(principal)
+        .ok_or(AmortizingError::Overflow)?;
+    validate_collateral_ratio(
+        env,
+        &borrower,
+        get_total_collateral_value(env, &borrower),
+        total_debt,
+    )
+    .map_err(|_| AmortizingError::InsufficientCollateral)?;
+
+    let rate_bps = crate::rate_model::get_borrow_rate(env);
+    let id: u64 = env
+        .storage()
+        .persistent()
+        .get(&AmortizingDataKey::NextAmortizingLoanId)
+        .unwrap_or(1);
+    env.storage()
+        .persistent()
+        .set(&AmortizingDataKey::NextAmortizingLoanId, &(id + 1));
+
+    let loan = AmortizingLoan {
+        id,
+        borrower: borrower.clone(),
+        asset,
+        principal,
+        rate_bps,
+        interval,
+        installments,
+        installment_amount: level_installment(principal, rate_bps, interval, installments),
+        start: env.ledger().timestamp(),
+        paid_installments: 0,
+        outstanding_principal: principal,
+        allowance: 0,
+        repaid: false,
+    };
+    save_loan(env, &loan);
+    set_total_debt(env, new_total);
+    adjust_asset_debt(env, &loan.asset, principal);
+    crate::debt_ceiling::record_debt_growth(
+        env,
+        &loan.asset,
+        new_asset_debt - principal,
+        new_asset_debt,
+    );
+
+    let mut loans = get_borrower_amortizing_loans(env, &borrower);
+    loans.push_back(id);
+    save_borrower_loans(env, &borrower, &loans);
+
+    emit_amortizing_event(env, "amortizing_opened", &loan, principal);
+    Ok(id)
+}
+
+/// Pre-approve the amount keepers may pull for installments
+pub fn set_installment_allowance(
+    env: &Env,
+    borrower: Address,
+    loan_id: u64,
+    allowance: i128,
+) -> Result<(), AmortizingError> {
+    borrower.require_auth();
+
+    let mut loan = get_open_loan(env, loan_id)?;
+    if loan.borrower != borrower {
+        return Err(AmortizingError::Unauthorized);
+    }
+    if allowance < 0 {
+        return Err(AmortizingError::InvalidAmount);
+    }
+
+    loan.allowance = allowance;
+    save_loan(env, &loan);
+    Ok(())
+}
+
+/// Pay the next installment of a loan, whether or not it is due yet
+///
+/// # Returns
+/// The amount paid
+pub fn pay_installment(
+    env: &Env,
+    borrower: Address,
+    loan_id: u64,
+) -> Result<i128, AmortizingError> {
+    borrower.require_auth();
+
+    let mut loan = get_open_loan(env, loan_id)?;
+    if loan.borrower != borrower {
+        return Err(AmortizingError::Unauthorized);
+    }
+
+    let paid = settle_next(env, &mut loan);
+    save_loan(env, &loan);
+    emit_amortizing_event(env, "installment_paid", &loan, paid);
+    Ok(paid)
+}
+
+/// Pull the installments of a loan that are due
+///
+/// Permissionless: any keeper may call this. Missed installments are caught
+/// up in order while the borrower's allowance covers them in full.
+///
+/// # Returns
+/// The amount pulled
+pub fn execute_installments(env: &Env, loan_id: u64) -> Result<i128, AmortizingError> {
+    let mut loan = get_open_loan(env, loan_id)?;
+    if due_installments(env, &loan) <= loan.paid_installments {
+        return Err(AmortizingError::NotDue);
+    }
+
+    let mut pulled = 0;
+    while !loan.repaid && due_installments(env, &loan) > loan.paid_installments {
+        let (interest, principal) = next_split(&loan);
+        if interest + principal > loan.allowance {
+            break;
+        }
+        let paid = settle_next(env, &mut loan);
+        loan.allowance -= paid;
+        pulled += paid;
+    }
+    if pulled == 0 {
+        return Err(AmortizingError::AllowanceExhausted);
+    }
+
+    save_loan(env, &loan);
+    emit_amortizing_event(env, "installments_pulled", &loan, pulled);
+    Ok(pulled)
+}
+
+/// Get an amortizing loan by id
+pub fn get_amortizing_loan(env: &Env, loan_id: u64) -> Option<AmortizingLoan> {
+    env.storage()
+        .persistent()
+        .get(&AmortizingDataKey::AmortizingLoan(loan_id))
+}
+
+/// Get the ids of a borrower's open amortizing loans
+pub fn get_borrower_amortizing_loans(env: &Env, borrower: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&AmortizingDataKey::BorrowerAmortizingLoans(
+            borrower.clone(),
+        ))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Full installment schedule of a loan, paid installments included
+///
+/// Unpaid installments are projected from the outstanding principal, so the
+/// view stays exact as the loan is repaid.
+pub fn get_amortization_schedule(
+    env: &Env,
+    loan_id: u64,
+) -> Result<Vec<Installment>, AmortizingError> {
+    let loan = get_amortizing_loan(env, loan_id).ok_or(AmortizingError::LoanNotFound)?;
+
+    let mut schedule = Vec::new(env);
+    let mut projected = AmortizingLoan {
+        paid_installments: 0,
+        outstanding_principal: loan.principal,
+        ..loan.clone()
+    };
+    for number in 1..=loan.installments {
+        let (interest, principal) = next_split(&projected);
+        schedule.push_back(Installment {
+            number,
+            due: due_time(&loan, number),
+            amount: interest + principal,
+            interest,
+            principal,
+            paid: number <= loan.paid_installments,
+        });
+        projected.outstanding_principal -= principal;
+        projected.paid_installments = number;
+    }
+    Ok(schedule)
+}
+
+/// Repayment standing of a loan
+pub fn get_amortizing_status(env: &Env, loan_id: u64) -> Result<AmortizingStatus, AmortizingError> {
+    let loan = get_amortizing_loan(env, loan_id).ok_or(AmortizingError::LoanNotFound)?;
+    if loan.repaid {
+        return Ok(AmortizingStatus::Repaid);
+    }
+
+    let elapsed = env
+        .ledger()
+        .timestamp()
+        .saturating_sub(loan.start)
+        .saturating_sub(INSTALLMENT_GRACE_PERIOD);
+    let missed = ((elapsed / loan.interval).min(loan.installments as u64)) as u32;
+    if missed > loan.paid_installments {
+        Ok(AmortizingStatus::Delinquent)
+    } else {
+        Ok(AmortizingStatus::Current)
+    }
+}
+
+/// Outstanding amortizing principal owed by a borrower
+///
+/// Counted alongside core debt wherever collateral coverage is evaluated.
+pub fn get_amortizing_debt(env: &Env, borrower: &Address) -> i128 {
+    get_borrower_amortizing_loans(env, borrower)
+        .iter()
+        .filter_map(|id| get_amortizing_loan(env, id))
+        .fold(0i128, |total, loan| {
+            total.saturating_add(loan.outstanding_principal)
+        })
+}
+
+/// Level installment that amortizes `principal` over `installments` periods
+///
+/// Uses the annuity formula `P * r / (1 - (1 + r)^-n)`, rounded up so the
+/// final installment never exceeds the others by more than rounding.
+fn level_installment(principal: i128, rate_bps: i128, interval: u64, installments: u32) -> i128 {
+    let rate = period_rate(rate_bps, interval);
+    if rate == 0 {
+        return div_up(principal, installments as i128);
+    }
+
+    let mut growth = RATE_SCALE;
+    for _ in 0..installments {
+        growth = mul_div_down(growth, RATE_SCALE + rate, RATE_SCALE);
+    }
+    let factor = mul_div_up(rate, growth, growth - RATE_SCALE);
+    mul_div_up(principal, factor, RATE_SCALE)
+}
+
+/// Interest rate per installment period, scaled by `RATE_SCALE`
+fn period_rate(rate_bps: i128, interval: u64) -> i128 {
+    mul_div_down(
+        rate_bps.saturating_mul(interval as i128),
+        RATE_SCALE,
+        10000 * SECONDS_PER_YEAR as i128,
+    )
+}
+
+/// Interest and principal portions of the next installment
+fn next_split(loan: &AmortizingLoan) -> (i128, i128) {
+    let interest = mul_div_up(
+        loan.outstanding_principal,
+        loan.rate_bps.saturating_mul(loan.interval as i128),
+        10000 * SECONDS_PER_YEAR as i128,
+    );
+    let principal = if loan.paid_installments + 1 >= loan.installments {
+        loan.outstanding_principal
+    } else {
+        (loan.installment_amount - interest).clamp(0, loan.outstanding_principal)
+    };
+    (interest, principal)
+}
+
+/// Apply the next installment to a loan and return the amount paid
+fn settle_next(env: &Env, loan: &mut AmortizingLoan) -> i128 {
+    let (interest, principal) = next_split(loan);
+    loan.outstanding_principal -= principal;
+    loan.paid_installments += 1;
+    set_total_debt(env, get_total_debt(env).saturating_sub(principal).max(0));
+    adjust_asset_debt(env, &loan.asset, -principal);
+
+    if loan.outstanding_principal == 0 {
+        loan.repaid = true;
+        let mut loans = get_borrower_amortizing_loans(env, &loan.borrower);
+        if let Some(index) = loans.first_index_of(loan.id) {
+            loans.remove(index);
+        }
+        save_borrower_loans(env, &loan.borrower, &loans);
+    }
+    interest + principal
+}
+
+/// Map a failed debt check onto the amortizing error it surfaces as
+fn debt_error(error: BorrowError) -> AmortizingError {
+    match error {
+        BorrowError::ProtocolPaused => AmortizingError::ProtocolPaused,
+        BorrowError::AccountFrozen => AmortizingError::AccountFrozen,
+        BorrowError::BorrowCapExceeded => AmortizingError::BorrowCapExceeded,
+        BorrowError::PriceStale => AmortizingError::PriceStale,
+        BorrowError::MarketFrozen => AmortizingError::MarketFrozen,
+        BorrowError::AssetBorrowsPaused => AmortizingError::AssetBorrowsPaused,
+        BorrowError::AssetDelisted => AmortizingError::AssetDelisted,
+        BorrowError::BorrowFrozen => AmortizingError::BorrowFrozen,
+        BorrowError::AssetCollateralOnly => AmortizingError::AssetCollateralOnly,
+        BorrowError::DebtCeilingReached => AmortizingError::DebtCeilingReached,
+        BorrowError::LiquidityBufferReached => AmortizingError::LiquidityBufferReached,
+        BorrowError::RehypothecationDisabled => AmortizingError::RehypothecationDisabled,
+        BorrowError::LargeBorrowRequestRequired => AmortizingError::LargeBorrowRequestRequired,
+        BorrowError::BorrowSizeCapExceeded => AmortizingError::BorrowSizeCapExceeded,
+        BorrowError::Overflow => AmortizingError::Overflow,
+        BorrowError::TooManyPositions => AmortizingError::TooManyPositions,
+        BorrowError::InvalidAmount | BorrowError::BelowMinimumBorrow => {
+            AmortizingError::InvalidAmount
+        }
+        BorrowError::Unauthorized => AmortizingError::Unauthorized,
+        // Collateral-side and request errors are never returned by the debt check
+        BorrowError::InsufficientCollateral
+        | BorrowError::AssetNotSupported
+        | BorrowError::ExposureCapReached
+        | BorrowError::BelowMinimumCollateral
+        | BorrowError::CollateralFrozen
+        | BorrowError::AssetBorrowOnly => AmortizingError::InsufficientCollateral,
+        BorrowError::InvalidConfig
+        | BorrowError::NoPendingBorrow
+        | BorrowError::BorrowDelayActive
+        | BorrowError::PendingBorrowExists => AmortizingError::InvalidTerms,
+    }
+}
+
+/// Number of installments whose due time has passed
+fn due_installments(env: &Env, loan: &AmortizingLoan) -> u32 {
+    let elapsed = env.ledger().timestamp().saturating_sub(loan.start);
+    ((elapsed / loan.interval).min(loan.installments as u64)) as u32
+}
+
+fn due_time(loan: &AmortizingLoan, number: u32) -> u64 {
+    loan.start
+        .saturating_add(loan.interval.saturating_mul(number as u64))
+}
+
+fn get_open_loan(env: &Env, loan_id: u64) -> Result<AmortizingLoan, AmortizingError> {
+    let loan = get_amortizing_loan(env, loan_id).ok_or(AmortizingError::LoanNotFound)?;
+    if loan.repaid {
+        return Err(AmortizingError::LoanClosed);
+    }
+    Ok(loan)
+}
+
+fn save_loan(env: &Env, loan: &AmortizingLoan) {
+    env.storage()
+        .persistent()
+        .set(&AmortizingDataKey::AmortizingLoan(loan.id), loan);
+}
+
+fn save_borrower_loans(env: &Env, borrower: &Address, loans: &Vec<u64>) {
+    env.storage().persistent().set(
+        &AmortizingDataKey::BorrowerAmortizingLoans(borrower.clone()),
+        loans,
+    );
+}
+
+fn emit_amortizing_event(env: &Env, name: &str, loan: &AmortizingLoan, amount: i128) {
+    let event = AmortizingEvent {
+        id: loan.id,
+        borrower: loan.borrower.clone(),
+        amount,
+        outstanding_principal: loan.outstanding_principal,
+        timestamp: env.ledger().timestamp(),
+    };
+    crate::events::publish(
+        env,
+        (
+            Symbol::new(env, name),
+            event.borrower.clone(),
+            loan.asset.clone(),
+        ),
+        event,
+    );
+}
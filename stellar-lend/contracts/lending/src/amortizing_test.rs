@@ -0,0 +1,189 @@
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+
+const MONTH: u64 = 30 * 86400;
+
+fn setup(env: &Env) -> (LendingContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+
+    let contract_id = env.register(LendingContract, ());
+    let client = LendingContractClient::new(env, &contract_id);
+    client.initialize(
+        &Address::generate(env),
+        &InitConfig::new(env, 1_000_000_000, 1000),
+    );
+
+    let borrower = Address::generate(env);
+    let asset = Address::generate(env);
+    let collateral_asset = Address::generate(env);
+
+    // Borrower posts collateral in the core pool with a small core loan
+    client.borrow(&borrower, &asset, &10_000, &collateral_asset, &300_000);
+
+    (client, borrower, asset)
+}
+
+fn set_time(env: &Env, time: u64) {
+    env.ledger().with_mut(|li| li.timestamp = time);
+}
+
+#[test]
+fn test_schedule_has_level_installments() {
+    let env = Env::default();
+    let (client, borrower, asset) = setup(&env);
+
+    let loan_id = client.open_amortizing_loan(&borrower, &asset, &120_000, &MONTH, &12);
+    let loan = client.get_amortizing_loan(&loan_id).unwrap();
+    assert_eq!(loan.rate_bps, 500);
+    assert!(loan.installment_amount > 10_000);
+    assert_eq!(client.get_amortizing_debt(&borrower), 120_000);
+
+    let schedule = client.get_amortization_schedule(&loan_id);
+    assert_eq!(schedule.len(), 12);
+    let mut principal = 0;
+    for installment in schedule.iter() {
+        assert_eq!(installment.due, 1000 + MONTH * installment.number as u64);
+        assert!(!installment.paid);
+        if installment.number < 12 {
+            assert_eq!(installment.amount, loan.installment_amount);
+        }
+        principal += installment.principal;
+    }
+    assert_eq!(principal, 120_000);
+
+    // Interest falls and principal grows as the balance amortizes
+    let first = schedule.get(0).unwrap();
+    let last = schedule.get(11).unwrap();
+    assert!(first.interest > last.interest);
+    assert!(first.principal < last.principal);
+    assert!(last.amount.abs_diff(loan.installment_amount) <= 12);
+}
+
+#[test]
+fn test_keeper_pulls_due_installments_within_allowance() {
+    let env = Env::default();
+    let (client, borrower, asset) = setup(&env);
+
+    let loan_id = client.open_amortizing_loan(&borrower, &asset, &120_000, &MONTH, &12);
+    assert_eq!(
+        client.try_execute_installments(&loan_id),
+        Err(Ok(AmortizingError::NotDue))
+    );
+
+    set_time(&env, 1000 + 2 * MONTH);
+    assert_eq!(
+        client.try_execute_installments(&loan_id),
+        Err(Ok(AmortizingError::AllowanceExhausted))
+    );
+
+    let installment = client
+        .get_amortizing_loan(&loan_id)
+        .unwrap()
+        .installment_amount;
+    client.set_installment_allowance(&borrower, &loan_id, &(3 * installment));
+    assert_eq!(client.execute_installments(&loan_id), 2 * installment);
+
+    let loan = client.get_amortizing_loan(&loan_id).unwrap();
+    assert_eq!(loan.paid_installments, 2);
+    assert_eq!(loan.allowance, installment);
+    assert!(
+        client
+            .get_amortization_schedule(&loan_id)
+            .get(1)
+            .unwrap()
+            .paid
+    );
+    assert_eq!(
+        client.try_execute_installments(&loan_id),
+        Err(Ok(AmortizingError::NotDue))
+    );
+}
+
+#[test]
+fn test_missed_installment_marks_loan_delinquent() {
+    let env = Env::default();
+    let (client, borrower, asset) = setup(&env);
+
+    let loan_id = client.open_amortizing_loan(&borrower, &asset, &30_000, &MONTH, &3);
+
+    // Within the grace period the loan is still current
+    set_time(&env, 1000 + MONTH + 86400);
+    assert_eq!(
+        client.get_amortizing_status(&loan_id),
+        AmortizingStatus::Current
+    );
+
+    set_time(&env, 1000 + MONTH + 4 * 86400);
+    assert_eq!(
+        client.get_amortizing_status(&loan_id),
+        AmortizingStatus::Delinquent
+    );
+
+    client.pay_installment(&borrower, &loan_id);
+    assert_eq!(
+        client.get_amortizing_status(&loan_id),
+        AmortizingStatus::Current
+    );
+
+    client.pay_installment(&borrower, &loan_id);
+    client.pay_installment(&borrower, &loan_id);
+    assert_eq!(
+        client.get_amortizing_status(&loan_id),
+        AmortizingStatus::Repaid
+    );
+    assert_eq!(client.get_amortizing_debt(&borrower), 0);
+    assert_eq!(client.get_borrower_amortizing_loans(&borrower).len(), 0);
+    assert_eq!(
+        client.try_pay_installment(&borrower, &loan_id),
+        Err(Ok(AmortizingError::LoanClosed))
+    );
+}
+
+#[test]
+fn test_open_requires_collateral_and_valid_terms() {
+    let env = Env::default();
+    let (client, borrower, asset) = setup(&env);
+
+    // 300_000 collateral covers 200_000 of debt at 150%
+    assert_eq!(
+        client.try_open_amortizing_loan(&borrower, &asset, &195_000, &MONTH, &12),
+        Err(Ok(AmortizingError::InsufficientCollateral))
+    );
+    assert_eq!(
+        client.try_open_amortizing_loan(&borrower, &asset, &10_000, &3600, &12),
+        Err(Ok(AmortizingError::InvalidTerms))
+    );
+    assert_eq!(
+        client.try_open_amortizing_loan(&borrower, &asset, &10_000, &MONTH, &0),
+        Err(Ok(AmortizingError::InvalidTerms))
+    );
+}
+
+#[test]
+fn test_amortizing_principal_counts_toward_pool_debt() {
+    let env = Env::default();
+    let (client, borrower, asset) = setup(&env);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    client.set_asset_debt_ceiling(&admin, &asset, &70_000);
+
+    assert_eq!(
+        client.try_open_amortizing_loan(&borrower, &asset, &60_001, &MONTH, &2),
+        Err(Ok(AmortizingError::DebtCeilingReached))
+    );
+    let loan_id = client.open_amortizing_loan(&borrower, &asset, &60_000, &MONTH, &2);
+    assert_eq!(client.get_asset_debt(&asset), 70_000);
+
+    client.pay_installment(&borrower, &loan_id);
+    let outstanding = client
+        .get_amortizing_loan(&loan_id)
+        .unwrap()
+        .outstanding_principal;
+    assert_eq!(client.get_asset_debt(&asset), 10_000 + outstanding);
+    client.pay_installment(&borrower, &loan_id);
+    assert_eq!(client.get_asset_debt(&asset), 10_000);
+}
@@ -0,0 +1,50 @@
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+/// Standing approvals revocation event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ApprovalsRevokedEvent {
+    pub owner: Address,
+    /// Automation intents cancelled
+    pub intents: u32,
+    pub timestamp: u64,
+}
+
+/// Revoke every standing approval an owner has granted
+///
+/// Cancels the owner's automation intents, auto-repay instruction, soft
+/// liquidation band, repayment stream and session delegates in one call, as
+/// an escape hatch when a key or keeper integration is suspected to be
+/// compromised.
+pub fn revoke_all(env: &Env, owner: Address) {
+    owner.require_auth();
+
+    let intents = crate::automation::cancel_all_intents(env, &owner);
+    crate::auto_repay::remove_auto_repay(env, &owner);
+    crate::soft_liquidation::remove_band(env, &owner);
+    crate::repayment_stream::remove_stream(env, &owner);
+    crate::session_delegate::remove_all_delegates(env, &owner);
+
+    let event = ApprovalsRevokedEvent {
+        owner,
+        intents,
+        timestamp: env.ledger().timestamp(),
+    };
+    crate::events::publish(
+        env,
+        (Symbol::new(env, "approvals_revoked"), event.owner.clone()),
+        event,
+    );
+}
+
+/// Whether an approval expiring at `expiration_ledger` can still be granted
+///
+/// Like SEP-41 allowances, an approval is live through its expiration ledger.
+pub(crate) fn is_valid_expiration(env: &Env, expiration_ledger: u32) -> bool {
+    expiration_ledger >= env.ledger().sequence()
+}
+
+/// Whether an approval expiring at `expiration_ledger` is still in force
+pub(crate) fn is_live(env: &Env, expiration_ledger: u32) -> bool {
+    env.ledger().sequence() <= expiration_ledger
+}
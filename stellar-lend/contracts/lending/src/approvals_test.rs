@@ -0,0 +1,85 @@
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+
+const DAY: u64 = 86400;
+
+fn setup(env: &Env) -> (LendingContractClient<'_>, Address) {
+    env.mock_all_auths();
+    let client = LendingContractClient::new(env, &env.register(LendingContract, ()));
+    client.initialize(
+        &Address::generate(env),
+        &InitConfig::new(env, 1_000_000_000, 1000),
+    );
+    let user = Address::generate(env);
+    client.borrow(
+        &user,
+        &Address::generate(env),
+        &10_000,
+        &Address::generate(env),
+        &20_000,
+    );
+    (client, user)
+}
+
+#[test]
+fn test_approvals_void_after_expiration_ledger() {
+    let env = Env::default();
+    let (client, user) = setup(&env);
+    env.ledger().with_mut(|li| li.sequence_number = 100);
+    let expiration = env.ledger().sequence() + 5;
+    assert_eq!(
+        client.try_set_repayment_stream(&user, &1000, &DAY, &5000, &(expiration - 6)),
+        Err(Ok(StreamError::InvalidConfig))
+    );
+    client.set_repayment_stream(&user, &1000, &DAY, &5000, &expiration);
+
+    // Live through the expiration ledger itself
+    env.ledger().with_mut(|li| {
+        li.sequence_number += 5;
+        li.timestamp += DAY;
+    });
+    assert_eq!(client.execute_repayment_stream(&user), 1000);
+
+    env.ledger().with_mut(|li| {
+        li.sequence_number += 1;
+        li.timestamp += DAY;
+    });
+    assert_eq!(client.get_repayment_stream(&user), None);
+    assert_eq!(
+        client.try_execute_repayment_stream(&user),
+        Err(Ok(StreamError::NoStream))
+    );
+}
+
+#[test]
+fn test_revoke_all_clears_standing_approvals() {
+    let env = Env::default();
+    let (client, user) = setup(&env);
+    let expiration = env.ledger().sequence() + 1_000;
+    client.set_repayment_stream(&user, &1000, &DAY, &5000, &expiration);
+    client.enable_auto_repay(
+        &user,
+        &AutoRepayConfig {
+            trigger_health: 12_000,
+            max_repay: 1_000,
+            expiration_ledger: expiration,
+        },
+    );
+    client.enable_soft_liquidation(&user, &8_000_000, &4_000_000, &expiration);
+    client.register_intent(
+        &user,
+        &IntentKind::RepayIfHealthBelow(12_000),
+        &1_000,
+        &0,
+        &expiration,
+    );
+
+    client.revoke_all(&user);
+    assert_eq!(client.get_repayment_stream(&user), None);
+    assert_eq!(client.get_auto_repay_config(&user), None);
+    assert_eq!(client.get_soft_liquidation_band(&user), None);
+    assert_eq!(client.get_user_intents(&user).len(), 0);
+}
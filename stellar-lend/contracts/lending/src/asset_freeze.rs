@@ -0,0 +1,90 @@
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol};
+
+use crate::admin::require_risk_manager;
+
+/// Errors that can occur when freezing asset usage
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AssetFreezeError {
+    Unauthorized = 1,
+    InvalidConfig = 2,
+}
+
+/// Storage keys for asset freeze data
+#[contracttype]
+#[derive(Clone)]
+pub enum AssetFreezeDataKey {
+    /// Bitmask of frozen uses of an asset
+    AssetFreeze(Address),
+}
+
+/// Asset freeze event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct AssetFreezeEvent {
+    pub asset: Address,
+    pub flags: u32,
+    pub actor: Address,
+    pub timestamp: u64,
+}
+
+/// New collateral deposits of the asset
+pub const FREEZE_COLLATERAL: u32 = 1 << 0;
+/// New borrows of the asset
+pub const FREEZE_BORROW: u32 = 1 << 1;
+const ALL_FREEZE_FLAGS: u32 = (1 << 2) - 1;
+
+/// Set the bitmask of frozen uses of an asset (risk manager or admin)
+///
+/// Narrower than an operation pause: freezing the asset as collateral keeps
+/// it borrowable and leaves existing positions valid, and vice versa. Clear a
+/// bit to lift the freeze.
+pub fn set_asset_freeze(
+    env: &Env,
+    caller: Address,
+    asset: Address,
+    flags: u32,
+) -> Result<(), AssetFreezeError> {
+    require_risk_manager(env, &caller).map_err(|_| AssetFreezeError::Unauthorized)?;
+    if flags & !ALL_FREEZE_FLAGS != 0 {
+        return Err(AssetFreezeError::InvalidConfig);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&AssetFreezeDataKey::AssetFreeze(asset.clone()), &flags);
+    let event = AssetFreezeEvent {
+        asset,
+        flags,
+        actor: caller,
+        timestamp: env.ledger().timestamp(),
+    };
+    crate::events::publish(
+        env,
+        (
+            Symbol::new(env, "asset_freeze_updated"),
+            event.asset.clone(),
+        ),
+        event,
+    );
+    Ok(())
+}
+
+/// Get the bitmask of frozen uses of an asset
+pub fn get_asset_freeze(env: &Env, asset: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&AssetFreezeDataKey::AssetFreeze(asset.clone()))
+        .unwrap_or(0)
+}
+
+/// Whether new deposits of `asset` as collateral are frozen
+pub(crate) fn is_collateral_frozen(env: &Env, asset: &Address) -> bool {
+    get_asset_freeze(env, asset) & FREEZE_COLLATERAL != 0
+}
+
+/// Whether new borrows of `asset` are frozen
+pub(crate) fn is_borrow_frozen(env: &Env, asset: &Address) -> bool {
+    get_asset_freeze(env, asset) & FREEZE_BORROW != 0
+}
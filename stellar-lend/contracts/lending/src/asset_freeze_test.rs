@@ -0,0 +1,44 @@
+use super::*;
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+#[test]
+fn test_asset_freeze_flags() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = LendingContractClient::new(&env, &env.register(LendingContract, ()));
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &InitConfig::new(&env, 1_000_000_000, 1000));
+    let usdc = Address::generate(&env);
+    let xlm = Address::generate(&env);
+    let user = Address::generate(&env);
+    client.borrow(&user, &usdc, &10_000, &xlm, &20_000);
+
+    // Frozen as collateral, still borrowable against other collateral
+    client.set_asset_freeze(&admin, &xlm, &FREEZE_COLLATERAL);
+    assert_eq!(client.get_asset_freeze(&xlm), FREEZE_COLLATERAL);
+    assert_eq!(
+        client.try_borrow(&user, &usdc, &1000, &xlm, &2000),
+        Err(Ok(BorrowError::CollateralFrozen))
+    );
+    client.borrow(&Address::generate(&env), &xlm, &1000, &usdc, &2000);
+    // The existing position stays valid
+    client.repay(&user, &1000);
+    client.withdraw_collateral(&user, &1000);
+
+    client.set_asset_freeze(&admin, &xlm, &0);
+    client.set_asset_freeze(&admin, &usdc, &FREEZE_BORROW);
+    assert_eq!(
+        client.try_borrow(&user, &usdc, &1000, &xlm, &2000),
+        Err(Ok(BorrowError::BorrowFrozen))
+    );
+    client.borrow(&Address::generate(&env), &xlm, &1000, &usdc, &2000);
+
+    assert_eq!(
+        client.try_set_asset_freeze(&admin, &usdc, &4),
+        Err(Ok(AssetFreezeError::InvalidConfig))
+    );
+    assert_eq!(
+        client.try_set_asset_freeze(&Address::generate(&env), &usdc, &0),
+        Err(Ok(AssetFreezeError::Unauthorized))
+    );
+}
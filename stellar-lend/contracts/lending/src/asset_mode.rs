@@ -0,0 +1,86 @@
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol};
+
+use crate::admin::require_admin;
+
+/// Errors that can occur when designating asset modes
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AssetModeError {
+    Unauthorized = 1,
+}
+
+/// Storage keys for asset mode data
+#[contracttype]
+#[derive(Clone)]
+pub enum AssetModeDataKey {
+    AssetMode(Address),
+}
+
+/// How an asset may be used in the protocol
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AssetMode {
+    /// Collateral and borrowable
+    Standard,
+    /// Posted as collateral but never borrowed, e.g. long-tail governance tokens
+    CollateralOnly,
+    /// Borrowed but never counted as collateral, e.g. high-volatility synthetics
+    BorrowOnly,
+}
+
+/// Asset mode event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct AssetModeEvent {
+    pub asset: Address,
+    pub mode: AssetMode,
+    pub timestamp: u64,
+}
+
+/// Designate how an asset may be used (admin only)
+///
+/// Unlike a freeze this is part of the asset's listing: a collateral-only
+/// asset is rejected by every borrow path, and a borrow-only asset by every
+/// collateral deposit. Borrow-only collateral already deposited is valued at
+/// zero.
+pub fn set_asset_mode(
+    env: &Env,
+    caller: Address,
+    asset: Address,
+    mode: AssetMode,
+) -> Result<(), AssetModeError> {
+    require_admin(env, &caller).map_err(|_| AssetModeError::Unauthorized)?;
+    apply_asset_mode(env, asset, mode);
+    Ok(())
+}
+
+/// Store an asset's mode; callers check authorization
+pub(crate) fn apply_asset_mode(env: &Env, asset: Address, mode: AssetMode) {
+    env.storage()
+        .persistent()
+        .set(&AssetModeDataKey::AssetMode(asset.clone()), &mode);
+    let event = AssetModeEvent {
+        asset,
+        mode,
+        timestamp: env.ledger().timestamp(),
+    };
+    crate::events::publish(
+        env,
+        (Symbol::new(env, "asset_mode_set"), event.asset.clone()),
+        event,
+    );
+}
+
+/// Whether `asset` may back debt
+pub(crate) fn counts_as_collateral(env: &Env, asset: &Address) -> bool {
+    get_asset_mode(env, asset) != AssetMode::BorrowOnly
+}
+
+/// Get how an asset may be used (`Standard` unless designated)
+pub fn get_asset_mode(env: &Env, asset: &Address) -> AssetMode {
+    env.storage()
+        .persistent()
+        .get(&AssetModeDataKey::AssetMode(asset.clone()))
+        .unwrap_or(AssetMode::Standard)
+}
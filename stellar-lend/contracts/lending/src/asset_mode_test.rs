@@ -0,0 +1,84 @@
+use super::*;
+use soroban_sdk::{testutils::Address as _, Address, Env, Vec};
+
+#[test]
+fn test_collateral_only_asset_cannot_be_borrowed() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = LendingContractClient::new(&env, &env.register(LendingContract, ()));
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &InitConfig::new(&env, 1_000_000_000, 1000));
+    let gov = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    assert_eq!(client.get_asset_mode(&gov), AssetMode::Standard);
+
+    client.set_asset_mode(&admin, &gov, &AssetMode::CollateralOnly);
+    assert_eq!(
+        client.try_borrow(&Address::generate(&env), &gov, &1000, &usdc, &2000),
+        Err(Ok(BorrowError::AssetCollateralOnly))
+    );
+    // Still accepted as collateral
+    client.borrow(&Address::generate(&env), &usdc, &1000, &gov, &2000);
+
+    let mut changes = Vec::new(&env);
+    changes.push_back(ConfigChange::AssetMode(gov.clone(), AssetMode::Standard));
+    client.set_config(&admin, &changes);
+    client.borrow(&Address::generate(&env), &gov, &1000, &usdc, &2000);
+
+    assert_eq!(
+        client.try_set_asset_mode(&Address::generate(&env), &gov, &AssetMode::Standard),
+        Err(Ok(AssetModeError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_borrow_only_asset_is_not_collateral() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = LendingContractClient::new(&env, &env.register(LendingContract, ()));
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &InitConfig::new(&env, 1_000_000_000, 1000));
+    let synth = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    let user = Address::generate(&env);
+    client.borrow(&user, &usdc, &1000, &synth, &3000);
+    assert_eq!(client.get_user_summary(&user).total_collateral_value, 3000);
+
+    client.set_asset_mode(&admin, &synth, &AssetMode::BorrowOnly);
+    assert_eq!(
+        client.try_borrow(&Address::generate(&env), &usdc, &1000, &synth, &2000),
+        Err(Ok(BorrowError::AssetBorrowOnly))
+    );
+    client.borrow(&Address::generate(&env), &synth, &1000, &usdc, &2000);
+
+    // Collateral already deposited no longer backs the debt
+    let summary = client.get_user_summary(&user);
+    assert_eq!(summary.collateral_mode, AssetMode::BorrowOnly);
+    assert_eq!(summary.collateral.amount, 3000);
+    assert_eq!(summary.total_collateral_value, 0);
+    assert_eq!(summary.health_factor, 0);
+}
+
+#[test]
+fn test_committed_liquidation_check_ignores_borrow_only_collateral() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = LendingContractClient::new(&env, &env.register(LendingContract, ()));
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &InitConfig::new(&env, 1_000_000_000, 1000));
+    let synth = Address::generate(&env);
+    let user = Address::generate(&env);
+    let collection = Address::generate(&env);
+    client.approve_nft_collection(&admin, &collection, &5000);
+    client.set_nft_floor(&admin, &collection, &2);
+    client.escrow_nft(&user, &collection, &1);
+    client.borrow(&user, &Address::generate(&env), &1000, &synth, &3000);
+    client.set_committed_price_mode(&admin, &true);
+    assert_eq!(
+        client.try_start_nft_auction(&collection, &1),
+        Err(Ok(NftError::PositionHealthy))
+    );
+
+    client.set_asset_mode(&admin, &synth, &AssetMode::BorrowOnly);
+    client.start_nft_auction(&collection, &1);
+}
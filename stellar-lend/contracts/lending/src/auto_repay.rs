@@ -0,0 +1,185 @@
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol};
+
+use crate::admin::require_admin;
+use crate::automation::credit_keeper_tip;
+use crate::borrow::{get_health_factor, get_user_debt};
+use crate::p2p::{apply_withdrawal, get_p2p_supply};
+use crate::repay::{apply_borrower_repayment, RepayError};
+
+/// Errors that can occur during auto-repayment
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AutoRepayError {
+    Unauthorized = 1,
+    InvalidConfig = 2,
+    NotEnabled = 3,
+    /// Health is at or above the trigger, or repayments are paused
+    ConditionNotMet = 4,
+    /// No debt, or no supplied balance of the debt asset beyond the tip
+    NothingToRepay = 5,
+    ExecutionFailed = 6,
+}
+
+/// Storage keys for auto-repay data
+#[contracttype]
+#[derive(Clone)]
+pub enum AutoRepayDataKey {
+    AutoRepayConfig(Address),
+    AutoRepayTip,
+}
+
+/// A user's auto-repay instruction
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AutoRepayConfig {
+    /// Health factor below which repayment is triggered (basis points, 10000 = 1.0)
+    pub trigger_health: i128,
+    /// Most debt repaid per execution
+    pub max_repay: i128,
+    /// Last ledger the instruction can be executed in
+    pub expiration_ledger: u32,
+}
+
+/// Auto-repay event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct AutoRepayEvent {
+    pub user: Address,
+    pub keeper: Address,
+    pub asset: Address,
+    pub repaid: i128,
+    pub tip: i128,
+    pub health_factor: i128,
+    pub timestamp: u64,
+}
+
+/// Set the fixed tip paid per auto-repay execution (admin only)
+///
+/// The tip is drawn from the user's supplied balance of the debt asset
+/// alongside the repayment.
+pub fn set_auto_repay_tip(env: &Env, caller: Address, tip: i128) -> Result<(), AutoRepayError> {
+    require_admin(env, &caller).map_err(|_| AutoRepayError::Unauthorized)?;
+    if tip < 0 {
+        return Err(AutoRepayError::InvalidConfig);
+    }
+    env.storage()
+        .persistent()
+        .set(&AutoRepayDataKey::AutoRepayTip, &tip);
+    Ok(())
+}
+
+/// Get the fixed tip paid per auto-repay execution
+pub fn get_auto_repay_tip(env: &Env) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&AutoRepayDataKey::AutoRepayTip)
+        .unwrap_or(0)
+}
+
+/// Opt in to repaying debt from the supplied balance of the debt asset
+pub fn enable_auto_repay(
+    env: &Env,
+    user: Address,
+    config: AutoRepayConfig,
+) -> Result<(), AutoRepayError> {
+    user.require_auth();
+    if config.trigger_health <= 0
+        || config.max_repay <= 0
+        || !crate::approvals::is_valid_expiration(env, config.expiration_ledger)
+    {
+        return Err(AutoRepayError::InvalidConfig);
+    }
+    env.storage()
+        .persistent()
+        .set(&AutoRepayDataKey::AutoRepayConfig(user), &config);
+    Ok(())
+}
+
+/// Opt out of auto-repayment
+pub fn disable_auto_repay(env: &Env, user: Address) {
+    user.require_auth();
+    remove_auto_repay(env, &user);
+}
+
+/// Get a user's auto-repay instruction, if enabled and not expired
+pub fn get_auto_repay_config(env: &Env, user: &Address) -> Option<AutoRepayConfig> {
+    env.storage()
+        .persistent()
+        .get::<_, AutoRepayConfig>(&AutoRepayDataKey::AutoRepayConfig(user.clone()))
+        .filter(|config| crate::approvals::is_live(env, config.expiration_ledger))
+}
+
+pub(crate) fn remove_auto_repay(env: &Env, user: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&AutoRepayDataKey::AutoRepayConfig(user.clone()));
+}
+
+/// Repay a user's debt from their supplied balance once health falls below the trigger
+///
+/// Permissionless. Withdraws the repayment plus the fixed tip from the user's
+/// supply of the debt asset, repays with the proceeds (after any withdrawal
+/// fee) and credits the tip to the keeper. The instruction stays in place for
+/// later executions.
+///
+/// # Returns
+/// The debt repaid
+pub fn execute_auto_repay(
+    env: &Env,
+    keeper: Address,
+    user: Address,
+) -> Result<i128, AutoRepayError> {
+    keeper.require_auth();
+
+    let config = get_auto_repay_config(env, &user).ok_or(AutoRepayError::NotEnabled)?;
+    let health_factor = get_health_factor(env, &user);
+    if health_factor >= config.trigger_health {
+        return Err(AutoRepayError::ConditionNotMet);
+    }
+
+    let debt = get_user_debt(env, &user);
+    let owed = debt.borrowed_amount.saturating_add(debt.interest_accrued);
+    let supply = get_p2p_supply(env, &user, &debt.asset);
+    let tip = get_auto_repay_tip(env);
+    let available = supply
+        .on_pool
+        .saturating_add(supply.in_p2p)
+        .saturating_sub(tip);
+    let amount = owed.min(config.max_repay).min(available);
+    if amount <= 0 {
+        return Err(AutoRepayError::NothingToRepay);
+    }
+
+    let received = apply_withdrawal(env, user.clone(), debt.asset.clone(), amount + tip)
+        .map_err(|_| AutoRepayError::ExecutionFailed)?;
+    let repaid = received.saturating_sub(tip);
+    if repaid <= 0 {
+        return Err(AutoRepayError::NothingToRepay);
+    }
+    apply_borrower_repayment(env, user.clone(), repaid).map_err(|e| match e {
+        RepayError::PriceStale | RepayError::ProtocolPaused => AutoRepayError::ConditionNotMet,
+        _ => AutoRepayError::ExecutionFailed,
+    })?;
+    credit_keeper_tip(env, &keeper, tip);
+
+    let event = AutoRepayEvent {
+        user,
+        keeper,
+        asset: debt.asset,
+        repaid,
+        tip,
+        health_factor,
+        timestamp: env.ledger().timestamp(),
+    };
+    crate::events::publish(
+        env,
+        (
+            Symbol::new(env, "auto_repay_executed"),
+            event.user.clone(),
+            event.asset.clone(),
+        ),
+        event,
+    );
+    Ok(repaid)
+}
@@ -0,0 +1,70 @@
+use super::*;
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+#[test]
+fn test_auto_repay_draws_supply_below_trigger() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = LendingContractClient::new(&env, &env.register(LendingContract, ()));
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &InitConfig::new(&env, 1_000_000_000, 1000));
+
+    let usdc = Address::generate(&env);
+    let xlm = Address::generate(&env);
+    client.set_p2p_market(
+        &admin,
+        &usdc,
+        &P2PMarket {
+            pool_supply_rate_bps: 200,
+            p2p_cursor_bps: 5000,
+        },
+    );
+    client.set_asset_price(&admin, &usdc, &10_000_000);
+    client.set_asset_price(&admin, &xlm, &10_000_000);
+    client.p2p_supply(&Address::generate(&env), &usdc, &100_000);
+
+    let user = Address::generate(&env);
+    client.p2p_supply(&user, &usdc, &5_000);
+    client.borrow(&user, &usdc, &10_000, &xlm, &20_000);
+    client.set_auto_repay_tip(&admin, &10);
+
+    let keeper = Address::generate(&env);
+    assert_eq!(
+        client.try_execute_auto_repay(&keeper, &user),
+        Err(Ok(AutoRepayError::NotEnabled))
+    );
+    let trigger_health = client.get_health_factor(&user);
+    client.enable_auto_repay(
+        &user,
+        &AutoRepayConfig {
+            trigger_health,
+            max_repay: 3_000,
+            expiration_ledger: 1_000,
+        },
+    );
+    assert_eq!(
+        client.try_execute_auto_repay(&keeper, &user),
+        Err(Ok(AutoRepayError::ConditionNotMet))
+    );
+
+    // Health must double, i.e. half the debt be repaid, before the trigger clears
+    client.enable_auto_repay(
+        &user,
+        &AutoRepayConfig {
+            trigger_health: trigger_health * 2,
+            max_repay: 3_000,
+            expiration_ledger: 1_000,
+        },
+    );
+    assert_eq!(client.execute_auto_repay(&keeper, &user), 3_000);
+    assert_eq!(client.get_user_debt(&user).borrowed_amount, 7_000);
+    assert_eq!(client.get_p2p_supply(&user, &usdc).on_pool, 1_990);
+    assert_eq!(client.get_keeper_tips(&keeper), 10);
+
+    // The remaining supply caps the next repayment
+    assert_eq!(client.execute_auto_repay(&keeper, &user), 1_980);
+    assert_eq!(
+        client.try_execute_auto_repay(&keeper, &user),
+        Err(Ok(AutoRepayError::NothingToRepay))
+    );
+}
@@ -0,0 +1,305 @@
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol, Vec};
+
+use crate::borrow::get_health_factor;
+use crate::repay::{apply_borrower_repayment, RepayError};
+use crate::withdraw::{apply_withdrawal, WithdrawError};
+
+/// Errors that can occur in the automation registry
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AutomationError {
+    InvalidIntent = 1,
+    IntentNotFound = 2,
+    Unauthorized = 3,
+    IntentExpired = 4,
+    ConditionNotMet = 5,
+    TooManyIntents = 6,
+    ExecutionFailed = 7,
+}
+
+/// Storage keys for automation data
+#[contracttype]
+#[derive(Clone)]
+pub enum AutomationDataKey {
+    NextIntentId,
+    Intent(u64),
+    UserIntents(Address),
+    KeeperTips(Address),
+}
+
+/// The operation an intent performs and the condition that triggers it
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum IntentKind {
+    /// Repay `amount` once the health factor falls below the given value
+    /// (basis points, 10000 = 1.0)
+    RepayIfHealthBelow(i128),
+    /// Withdraw `amount` of collateral once the given timestamp is reached
+    WithdrawAfter(u64),
+}
+
+/// A pre-authorized operation any keeper may execute once
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Intent {
+    pub id: u64,
+    pub owner: Address,
+    pub kind: IntentKind,
+    /// Exact amount the operation moves
+    pub amount: i128,
+    /// Owed by the owner to the keeper that executes the intent
+    pub tip: i128,
+    /// Last ledger the intent can be executed in
+    pub expiration_ledger: u32,
+}
+
+/// Automation event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct IntentEvent {
+    pub id: u64,
+    pub owner: Address,
+    pub keeper: Option<Address>,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+const MAX_INTENTS_PER_USER: u32 = 8;
+
+/// Register an intent
+///
+/// Like SEP-41 allowances, the intent stays executable through
+/// `expiration_ledger`. Expired intents no longer count toward the owner's
+/// limit and are dropped when the next intent is registered.
+///
+/// # Returns
+/// The intent id
+pub fn register_intent(
+    env: &Env,
+    owner: Address,
+    kind: IntentKind,
+    amount: i128,
+    tip: i128,
+    expiration_ledger: u32,
+) -> Result<u64, AutomationError> {
+    owner.require_auth();
+
+    if amount <= 0 || tip < 0 || !crate::approvals::is_valid_expiration(env, expiration_ledger) {
+        return Err(AutomationError::InvalidIntent);
+    }
+    if let IntentKind::RepayIfHealthBelow(health) = kind {
+        if health <= 0 {
+            return Err(AutomationError::InvalidIntent);
+        }
+    }
+
+    let mut ids = remove_expired_intents(env, &owner);
+    if ids.len() >= MAX_INTENTS_PER_USER {
+        return Err(AutomationError::TooManyIntents);
+    }
+
+    let id: u64 = env
+        .storage()
+        .persistent()
+        .get(&AutomationDataKey::NextIntentId)
+        .unwrap_or(1);
+    env.storage()
+        .persistent()
+        .set(&AutomationDataKey::NextIntentId, &(id + 1));
+
+    let intent = Intent {
+        id,
+        owner: owner.clone(),
+        kind,
+        amount,
+        tip,
+        expiration_ledger,
+    };
+    env.storage()
+        .persistent()
+        .set(&AutomationDataKey::Intent(id), &intent);
+    ids.push_back(id);
+    save_user_intents(env, &owner, &ids);
+
+    emit_intent_event(env, "intent_registered", id, owner, None, amount);
+    Ok(id)
+}
+
+/// Cancel an intent
+pub fn cancel_intent(env: &Env, owner: Address, id: u64) -> Result<(), AutomationError> {
+    owner.require_auth();
+
+    let intent = get_intent(env, id).ok_or(AutomationError::IntentNotFound)?;
+    if intent.owner != owner {
+        return Err(AutomationError::Unauthorized);
+    }
+    remove_intent(env, &intent);
+
+    emit_intent_event(env, "intent_cancelled", id, owner, None, 0);
+    Ok(())
+}
+
+/// Execute an intent whose condition is met, earning its tip
+///
+/// The operation moves exactly the registered amount (repayments are capped at
+/// the outstanding debt); the intent is consumed on success.
+///
+/// # Returns
+/// The tip credited to the keeper
+pub fn execute_intent(env: &Env, keeper: Address, id: u64) -> Result<i128, AutomationError> {
+    keeper.require_auth();
+
+    let intent = get_intent(env, id).ok_or(AutomationError::IntentNotFound)?;
+    if !crate::approvals::is_live(env, intent.expiration_ledger) {
+        return Err(AutomationError::IntentExpired);
+    }
+
+    match intent.kind {
+        IntentKind::RepayIfHealthBelow(threshold) => {
+            if get_health_factor(env, &intent.owner) >= threshold {
+                return Err(AutomationError::ConditionNotMet);
+            }
+            apply_borrower_repayment(env, intent.owner.clone(), intent.amount).map_err(
+                |e| match e {
+                    RepayError::NoDebt | RepayError::PriceStale | RepayError::ProtocolPaused => {
+                        AutomationError::ConditionNotMet
+                    }
+                    _ => AutomationError::ExecutionFailed,
+                },
+            )?;
+        }
+        IntentKind::WithdrawAfter(time) => {
+            if env.ledger().timestamp() < time {
+                return Err(AutomationError::ConditionNotMet);
+            }
+            apply_withdrawal(env, intent.owner.clone(), intent.amount).map_err(|e| match e {
+                WithdrawError::InsufficientCollateral
+                | WithdrawError::PriceStale
+                | WithdrawError::ProtocolPaused
+                | WithdrawError::CooldownActive => AutomationError::ConditionNotMet,
+                _ => AutomationError::ExecutionFailed,
+            })?;
+        }
+    }
+
+    remove_intent(env, &intent);
+    credit_keeper_tip(env, &keeper, intent.tip);
+
+    emit_intent_event(
+        env,
+        "intent_executed",
+        id,
+        intent.owner,
+        Some(keeper),
+        intent.amount,
+    );
+    Ok(intent.tip)
+}
+
+/// Get an intent by id
+pub fn get_intent(env: &Env, id: u64) -> Option<Intent> {
+    env.storage()
+        .persistent()
+        .get(&AutomationDataKey::Intent(id))
+}
+
+/// Get the ids of a user's pending intents
+pub fn get_user_intents(env: &Env, owner: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&AutomationDataKey::UserIntents(owner.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Get the tips a keeper has earned
+pub fn get_keeper_tips(env: &Env, keeper: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&AutomationDataKey::KeeperTips(keeper.clone()))
+        .unwrap_or(0)
+}
+
+/// Credit a tip to a keeper's earnings
+pub(crate) fn credit_keeper_tip(env: &Env, keeper: &Address, tip: i128) {
+    let tips = get_keeper_tips(env, keeper).saturating_add(tip);
+    env.storage()
+        .persistent()
+        .set(&AutomationDataKey::KeeperTips(keeper.clone()), &tips);
+}
+
+/// Cancel all of an owner's intents
+///
+/// # Returns
+/// The number of intents cancelled
+pub(crate) fn cancel_all_intents(env: &Env, owner: &Address) -> u32 {
+    let ids = get_user_intents(env, owner);
+    for id in ids.iter() {
+        env.storage()
+            .persistent()
+            .remove(&AutomationDataKey::Intent(id));
+        emit_intent_event(env, "intent_cancelled", id, owner.clone(), None, 0);
+    }
+    env.storage()
+        .persistent()
+        .remove(&AutomationDataKey::UserIntents(owner.clone()));
+    ids.len()
+}
+
+/// Drop an owner's expired intents
+///
+/// # Returns
+/// The ids of the owner's remaining intents
+fn remove_expired_intents(env: &Env, owner: &Address) -> Vec<u64> {
+    let mut live = Vec::new(env);
+    for id in get_user_intents(env, owner).iter() {
+        match get_intent(env, id) {
+            Some(intent) if crate::approvals::is_live(env, intent.expiration_ledger) => {
+                live.push_back(id)
+            }
+            _ => {
+                env.storage()
+                    .persistent()
+                    .remove(&AutomationDataKey::Intent(id));
+                emit_intent_event(env, "intent_cancelled", id, owner.clone(), None, 0);
+            }
+        }
+    }
+    save_user_intents(env, owner, &live);
+    live
+}
+
+fn remove_intent(env: &Env, intent: &Intent) {
+    env.storage()
+        .persistent()
+        .remove(&AutomationDataKey::Intent(intent.id));
+    let mut ids = get_user_intents(env, &intent.owner);
+    if let Some(index) = ids.first_index_of(intent.id) {
+        ids.remove(index);
+    }
+    save_user_intents(env, &intent.owner, &ids);
+}
+
+fn save_user_intents(env: &Env, owner: &Address, ids: &Vec<u64>) {
+    env.storage()
+        .persistent()
+        .set(&AutomationDataKey::UserIntents(owner.clone()), ids);
+}
+
+fn emit_intent_event(
+    env: &Env,
+    name: &str,
+    id: u64,
+    owner: Address,
+    keeper: Option<Address>,
+    amount: i128,
+) {
+    let event = IntentEvent {
+        id,
+        owner,
+        keeper,
+        amount,
+        timestamp: env.ledger().timestamp(),
+    };
+    crate::events::publish(env, (Symbol::new(env, name), event.owner.clone()), event);
+}
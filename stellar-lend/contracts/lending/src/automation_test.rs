@@ -0,0 +1,155 @@
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, Env,
+};
+
+const DAY: u64 = 86400;
+
+fn setup(env: &Env) -> (LendingContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 0);
+
+    let contract_id = env.register(LendingContract, ());
+    let client = LendingContractClient::new(env, &contract_id);
+    client.initialize(
+        &Address::generate(env),
+        &InitConfig::new(env, 1_000_000_000, 1000),
+    );
+
+    let user = Address::generate(env);
+    let keeper = Address::generate(env);
+    let asset = Address::generate(env);
+    let collateral_asset = Address::generate(env);
+    // Health factor 1.1: 16,500 collateral against 10,000 debt
+    client.borrow(&user, &asset, &10_000, &collateral_asset, &16_500);
+    (client, user, keeper)
+}
+
+#[test]
+fn test_repay_intent_executes_below_health_threshold() {
+    let env = Env::default();
+    let (client, user, keeper) = setup(&env);
+
+    let id = client.register_intent(
+        &user,
+        &IntentKind::RepayIfHealthBelow(11_000),
+        &2000,
+        &50,
+        &(env.ledger().sequence() + 1_000),
+    );
+
+    let result = client.try_execute_intent(&keeper, &id);
+    assert_eq!(result, Err(Ok(AutomationError::ConditionNotMet)));
+
+    // Interest accrual pushes health below 1.1
+    env.ledger().with_mut(|li| li.timestamp = DAY);
+    assert!(client.get_health_factor(&user) < 11_000);
+
+    assert_eq!(client.execute_intent(&keeper, &id), 50);
+    assert_eq!(client.get_user_debt(&user).borrowed_amount, 8002);
+    assert_eq!(client.get_keeper_tips(&keeper), 50);
+    assert_eq!(client.get_intent(&id), None);
+    assert_eq!(client.get_user_intents(&user).len(), 0);
+}
+
+#[test]
+fn test_withdraw_intent_waits_for_timestamp() {
+    let env = Env::default();
+    let (client, user, keeper) = setup(&env);
+
+    client.repay(&user, &5000);
+    let id = client.register_intent(
+        &user,
+        &IntentKind::WithdrawAfter(7 * DAY),
+        &8000,
+        &10,
+        &(env.ledger().sequence() + 1_000),
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 6 * DAY);
+    let result = client.try_execute_intent(&keeper, &id);
+    assert_eq!(result, Err(Ok(AutomationError::ConditionNotMet)));
+
+    env.ledger().with_mut(|li| li.timestamp = 7 * DAY);
+    client.execute_intent(&keeper, &id);
+    assert_eq!(client.get_user_collateral(&user).amount, 8500);
+}
+
+#[test]
+fn test_intent_expires_and_cancels() {
+    let env = Env::default();
+    let (client, user, keeper) = setup(&env);
+
+    let id = client.register_intent(
+        &user,
+        &IntentKind::RepayIfHealthBelow(20_000),
+        &1000,
+        &0,
+        &(env.ledger().sequence() + 100),
+    );
+    // Still executable in its expiration ledger
+    env.ledger().with_mut(|li| li.sequence_number += 100);
+    assert_eq!(
+        client.get_intent(&id).unwrap().expiration_ledger,
+        env.ledger().sequence()
+    );
+    env.ledger().with_mut(|li| li.sequence_number += 1);
+    let result = client.try_execute_intent(&keeper, &id);
+    assert_eq!(result, Err(Ok(AutomationError::IntentExpired)));
+
+    let result = client.try_cancel_intent(&keeper, &id);
+    assert_eq!(result, Err(Ok(AutomationError::Unauthorized)));
+    client.cancel_intent(&user, &id);
+    assert_eq!(client.get_intent(&id), None);
+}
+
+#[test]
+fn test_intent_validation() {
+    let env = Env::default();
+    let (client, user, _keeper) = setup(&env);
+    env.ledger().with_mut(|li| li.sequence_number = 100);
+
+    let result = client.try_register_intent(&user, &IntentKind::WithdrawAfter(DAY), &1000, &0, &99);
+    assert_eq!(result, Err(Ok(AutomationError::InvalidIntent)));
+
+    let result =
+        client.try_register_intent(&user, &IntentKind::RepayIfHealthBelow(11_000), &0, &0, &200);
+    assert_eq!(result, Err(Ok(AutomationError::InvalidIntent)));
+}
+
+#[test]
+fn test_expired_intents_free_their_slots() {
+    let env = Env::default();
+    let (client, user, _keeper) = setup(&env);
+    let expiration = env.ledger().sequence() + 100;
+
+    for _ in 0..8 {
+        client.register_intent(
+            &user,
+            &IntentKind::RepayIfHealthBelow(11_000),
+            &1000,
+            &0,
+            &expiration,
+        );
+    }
+    let result = client.try_register_intent(
+        &user,
+        &IntentKind::RepayIfHealthBelow(11_000),
+        &1000,
+        &0,
+        &expiration,
+    );
+    assert_eq!(result, Err(Ok(AutomationError::TooManyIntents)));
+
+    env.ledger().with_mut(|li| li.sequence_number += 101);
+    let id = client.register_intent(
+        &user,
+        &IntentKind::RepayIfHealthBelow(11_000),
+        &1000,
+        &0,
+        &(env.ledger().sequence() + 100),
+    );
+    assert_eq!(client.get_user_intents(&user), vec![&env, id]);
+    assert_eq!(client.get_intent(&(id - 1)), None);
+}
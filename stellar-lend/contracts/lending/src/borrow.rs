@@ -1,5 +1,10 @@
 use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol};
 
+use crate::admin::{require_admin, require_risk_manager};
+use crate::asset_mode::AssetMode;
+use crate::history::PositionAction;
+use crate::math::{div_up, mul_div_down, mul_div_up};
+
 /// Errors that can occur during borrow operations
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -13,6 +18,32 @@ pub enum BorrowError {
     Unauthorized = 6,
     AssetNotSupported = 7,
     BelowMinimumBorrow = 8,
+    InvalidConfig = 9,
+    ExposureCapReached = 10,
+    TooManyPositions = 11,
+    BorrowCapExceeded = 12,
+    PriceStale = 13,
+    MarketFrozen = 14,
+    BelowMinimumCollateral = 15,
+    AssetBorrowsPaused = 16,
+    AssetDelisted = 17,
+    CollateralFrozen = 18,
+    BorrowFrozen = 19,
+    AssetCollateralOnly = 20,
+    AssetBorrowOnly = 21,
+    LiquidityBufferReached = 22,
+    /// Collateral of the asset may not be lent out and supply is exhausted
+    RehypothecationDisabled = 23,
+    /// The borrow is large enough to need `request_borrow` first
+    LargeBorrowRequestRequired = 24,
+    NoPendingBorrow = 25,
+    /// The pending borrow's delay has not elapsed yet
+    BorrowDelayActive = 26,
+    PendingBorrowExists = 27,
+    /// The borrow exceeds the per-call maximum; use `request_borrow` instead
+    BorrowSizeCapExceeded = 28,
+    /// The account is under a compliance freeze
+    AccountFrozen = 29,
 }
 
 /// Storage keys for borrow-related data
@@ -27,6 +58,19 @@ pub enum BorrowDataKey {
     CollateralRatio,
     MinBorrowAmount,
     Paused,
+    UserMetadata(Address),
+    BorrowIndex,
+    /// Pool rate the borrow index accrues at until its next checkpoint
+    PoolBorrowRate,
+    CollateralParams(Address),
+    /// Fungible collateral deposited across all users
+    TotalCollateral,
+    AssetDebtCeiling(Address),
+    AssetMinBorrow(Address),
+    /// Absolute borrow cap of an address, independent of its collateral
+    UserBorrowCap(Address),
+    /// Who last paused or unpaused the protocol, when and why
+    PauseInfo,
 }
 
 /// User debt position
@@ -37,6 +81,56 @@ pub struct DebtPosition {
     pub interest_accrued: i128,
     pub last_update: u64,
     pub asset: Address,
+    /// Origination time of the current loan, reset once it is fully repaid;
+    /// determines early-repayment rebate eligibility
+    pub opened_at: u64,
+    /// Borrow index of the position's asset at `last_update`
+    pub borrow_index: i128,
+}
+
+/// Per-user position metadata tracked alongside the debt position
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PositionMetadata {
+    /// Consecutive repayment cycles with accrued interest fully repaid
+    pub repay_streak: u32,
+    /// Start of the first repayment cycle (time of first borrow)
+    pub cycle_anchor: u64,
+    /// Index of the last cycle that counted towards the streak
+    pub last_on_time_cycle: u64,
+}
+
+/// Cumulative pool borrow index, scaled by `BORROW_INDEX_SCALE`
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BorrowIndex {
+    pub value: i128,
+    pub last_update: u64,
+}
+
+/// Per-asset collateral risk parameters
+///
+/// Borrowing and withdrawals are limited by the loan-to-value ratio, while
+/// liquidation only becomes possible past the looser liquidation threshold.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CollateralParams {
+    /// Maximum debt per unit of collateral at origination, in basis points
+    pub ltv_bps: i128,
+    /// Debt per unit of collateral at which a position becomes liquidatable,
+    /// in basis points
+    pub liquidation_threshold_bps: i128,
+}
+
+/// Minimum borrow size of an asset
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MinBorrowConfig {
+    /// Minimum amount in the asset's own units
+    pub min_amount: i128,
+    /// Minimum value at the asset's oracle price (amount * price / `PRICE_SCALE`);
+    /// zero to disable
+    pub min_value: i128,
 }
 
 /// User collateral position
@@ -47,6 +141,27 @@ pub struct CollateralPosition {
     pub asset: Address,
 }
 
+/// Latest pause state change
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PauseInfo {
+    pub paused: bool,
+    pub actor: Address,
+    /// Short incident code, e.g. `oracle` or `exploit`
+    pub reason: Symbol,
+    pub timestamp: u64,
+}
+
+/// Governed parameter change event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ParamUpdatedEvent {
+    pub param: Symbol,
+    pub old_value: i128,
+    pub new_value: i128,
+    pub timestamp: u64,
+}
+
 /// Borrow event data
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -58,9 +173,16 @@ pub struct BorrowEvent {
     pub timestamp: u64,
 }
 
-const COLLATERAL_RATIO_MIN: i128 = 15000; // 150% in basis points
-const INTEREST_RATE_PER_YEAR: i128 = 500; // 5% in basis points
-const SECONDS_PER_YEAR: u64 = 31536000;
+// Defaults for the governed protocol parameters
+pub(crate) const COLLATERAL_RATIO_MIN: i128 = 15000; // 150% in basis points
+pub(crate) const INTEREST_RATE_PER_YEAR: i128 = 500; // 5% in basis points
+const DEFAULT_MIN_BORROW_AMOUNT: i128 = 1000;
+/// Allowed range of the minimum collateral ratio, in basis points
+const COLLATERAL_RATIO_BOUNDS: (i128, i128) = (11000, 50000);
+/// Allowed range of the fixed pool interest rate, in basis points per year
+const INTEREST_RATE_BOUNDS: (i128, i128) = (0, 10000);
+pub(crate) const SECONDS_PER_YEAR: u64 = 31536000;
+pub(crate) const BORROW_INDEX_SCALE: i128 = 1_000_000_000;
 
 /// Borrow assets against deposited collateral
 ///
@@ -89,7 +211,78 @@ pub fn borrow(
     collateral_amount: i128,
 ) -> Result<(), BorrowError> {
     user.require_auth();
+    apply_borrow(
+        env,
+        user,
+        asset,
+        amount,
+        collateral_asset,
+        collateral_amount,
+        false,
+    )
+}
+
+/// Borrow against new collateral
+///
+/// `requested` borrows were recorded through a large-borrow request and skip
+/// the size checks. Callers are responsible for authorization.
+pub(crate) fn apply_borrow(
+    env: &Env,
+    user: Address,
+    asset: Address,
+    amount: i128,
+    collateral_asset: Address,
+    collateral_amount: i128,
+    requested: bool,
+) -> Result<(), BorrowError> {
+    let collateral_position = validate_borrow(
+        env,
+        &user,
+        &asset,
+        amount,
+        &collateral_asset,
+        collateral_amount,
+    )?;
 
+    let checked = if requested {
+        check_debt_limits(env, &user, &asset, amount)?
+    } else {
+        check_debt_increase(env, &user, &asset, amount)?
+    };
+    record_debt_increase(env, &user, &asset, amount, checked)?;
+    save_collateral_position(env, &user, &collateral_position);
+    crate::withdraw_cooldown::record_borrow(env, &user);
+    crate::history::record_action(
+        env,
+        &user,
+        PositionAction::DepositCollateral,
+        &collateral_asset,
+        collateral_amount,
+    );
+    crate::position_hooks::notify(
+        env,
+        &user,
+        Some(crate::position_hooks::HookEvent::Borrowed(amount)),
+    );
+
+    emit_borrow_event(env, user, asset, amount, collateral_amount);
+
+    Ok(())
+}
+
+/// Check a borrow against new collateral without writing state
+///
+/// Covers the collateral-side checks of `apply_borrow`; the debt-side checks
+/// are made by `check_debt_increase`. Returns the collateral position the
+/// borrow would leave.
+pub(crate) fn validate_borrow(
+    env: &Env,
+    user: &Address,
+    asset: &Address,
+    amount: i128,
+    collateral_asset: &Address,
+    collateral_amount: i128,
+) -> Result<CollateralPosition, BorrowError> {
     if is_paused(env) {
         return Err(BorrowError::ProtocolPaused);
     }
@@ -98,12 +291,142 @@ pub fn borrow(
         return Err(BorrowError::InvalidAmount);
     }
 
-    let min_borrow = get_min_borrow_amount(env);
+    if crate::oracle::is_price_stale(env, collateral_asset) {
+        return Err(BorrowError::PriceStale);
+    }
+    if crate::oracle::is_market_frozen(env, collateral_asset) {
+        return Err(BorrowError::MarketFrozen);
+    }
+    if crate::listing::is_winding_down(env, collateral_asset) {
+        return Err(BorrowError::AssetDelisted);
+    }
+    if crate::asset_freeze::is_collateral_frozen(env, collateral_asset) {
+        return Err(BorrowError::CollateralFrozen);
+    }
+    if !crate::asset_mode::counts_as_collateral(env, collateral_asset) {
+        return Err(BorrowError::AssetBorrowOnly);
+    }
+
+    let min_borrow = get_min_borrow_amount(env, asset);
     if amount < min_borrow {
         return Err(BorrowError::BelowMinimumBorrow);
     }
 
-    validate_collateral_ratio(collateral_amount, amount)?;
+    check_collateral_ratio(
+        crate::yield_collateral::to_underlying(env, collateral_asset, collateral_amount),
+        amount,
+        origination_ratio(env, user, collateral_asset),
+    )?;
+
+    let mut collateral_position = get_collateral_position(env, user);
+    collateral_position.amount = collateral_position
+        .amount
+        .checked_add(collateral_amount)
+        .ok_or(BorrowError::Overflow)?;
+    collateral_position.asset = collateral_asset.clone();
+    if collateral_position.amount < crate::dust::get_dust_config(env).min_collateral {
+        return Err(BorrowError::BelowMinimumCollateral);
+    }
+    if crate::collateral_tier::exceeds_exposure_cap(env, collateral_asset, collateral_amount) {
+        return Err(BorrowError::ExposureCapReached);
+    }
+    Ok(collateral_position)
+}
+
+/// Check newly borrowed principal against the position, asset and protocol
+/// limits without writing state
+///
+/// Every path that takes out pool debt goes through this check, including
+/// the large-borrow size rules. Returns the protocol's total debt after the
+/// borrow, the principal moved from the position's previous asset and the
+/// asset's resulting debt.
+pub(crate) fn check_debt_increase(
+    env: &Env,
+    user: &Address,
+    asset: &Address,
+    amount: i128,
+) -> Result<(i128, i128, i128), BorrowError> {
+    crate::large_borrow::check_borrow_size(env, asset, amount)?;
+    check_debt_limits(env, user, asset, amount)
+}
+
+/// `check_debt_increase` without the large-borrow size rules
+fn check_debt_limits(
+    env: &Env,
+    user: &Address,
+    asset: &Address,
+    amount: i128,
+) -> Result<(i128, i128, i128), BorrowError> {
+    if !crate::position_limit::has_core_position(env, user)
+        && !crate::position_limit::has_position_capacity(env, user)
+    {
+        return Err(BorrowError::TooManyPositions);
+    }
+
+    let debt_position = get_debt_position(env, user);
+    // A position is denominated in its latest borrowed asset, so existing
+    // principal moves with it
+    let moved = if debt_position.asset != *asset {
+        debt_position.borrowed_amount
+    } else {
+        0
+    };
+    let (new_total, new_asset_debt) = check_asset_limits(env, user, asset, amount, moved)?;
+    Ok((new_total, moved, new_asset_debt))
+}
+
+/// Check debt taken out outside the pool position without writing state
+///
+/// Fixed-term, amortizing and credit line borrows face the same pause,
+/// size, asset and ceiling checks as pool borrows; callers check position
+/// limits and collateral. Returns the protocol's total debt and the asset's
+/// debt after the borrow.
+pub(crate) fn check_new_debt(
+    env: &Env,
+    user: &Address,
+    asset: &Address,
+    amount: i128,
+) -> Result<(i128, i128), BorrowError> {
+    if is_paused(env) {
+        return Err(BorrowError::ProtocolPaused);
+    }
+    crate::large_borrow::check_borrow_size(env, asset, amount)?;
+    check_asset_limits(env, user, asset, amount, 0)
+}
+
+/// Account, asset and ceiling checks for `amount` newly borrowed in `asset`
+/// plus `moved` principal carried over from another asset
+fn check_asset_limits(
+    env: &Env,
+    user: &Address,
+    asset: &Address,
+    amount: i128,
+    moved: i128,
+) -> Result<(i128, i128), BorrowError> {
+    if crate::account_freeze::is_account_frozen(env, user) {
+        return Err(BorrowError::AccountFrozen);
+    }
+    if exceeds_user_borrow_cap(env, user, amount) {
+        return Err(BorrowError::BorrowCapExceeded);
+    }
+    if crate::oracle::is_price_stale(env, asset) {
+        return Err(BorrowError::PriceStale);
+    }
+    if crate::oracle::is_market_frozen(env, asset) {
+        return Err(BorrowError::MarketFrozen);
+    }
+    if crate::debt_ceiling::is_asset_borrow_paused(env, asset) {
+        return Err(BorrowError::AssetBorrowsPaused);
+    }
+    if crate::listing::is_winding_down(env, asset) {
+        return Err(BorrowError::AssetDelisted);
+    }
+    if crate::asset_freeze::is_borrow_frozen(env, asset) {
+        return Err(BorrowError::BorrowFrozen);
+    }
+    if crate::asset_mode::get_asset_mode(env, asset) == AssetMode::CollateralOnly {
+        return Err(BorrowError::AssetCollateralOnly);
+    }
 
     let total_debt = get_total_debt(env);
     let debt_ceiling = get_debt_ceiling(env);
@@ -115,8 +438,51 @@ pub fn borrow(
         return Err(BorrowError::DebtCeilingReached);
     }
 
-    let mut debt_position = get_debt_position(env, &user);
-    let accrued_interest = calculate_interest(env, &debt_position);
+    let new_asset_debt = get_asset_debt(env, asset)
+        .checked_add(amount)
+        .and_then(|debt| debt.checked_add(moved))
+        .ok_or(BorrowError::Overflow)?;
+    if get_asset_debt_ceiling(env, asset).is_some_and(|ceiling| new_asset_debt > ceiling) {
+        return Err(BorrowError::DebtCeilingReached);
+    }
+    if new_asset_debt > crate::debt_ceiling::get_effective_borrow_cap(env, asset) {
+        return Err(BorrowError::LiquidityBufferReached);
+    }
+    if crate::rehypothecation::exceeds_lendable(env, asset, new_asset_debt) {
+        return Err(BorrowError::RehypothecationDisabled);
+    }
+
+    Ok((new_total, new_asset_debt))
+}
+
+/// Add newly borrowed principal to a user's debt position
+///
+/// Enforces the asset's debt ceiling, the global ceiling and the per-user
+/// position limit, and accrues outstanding interest first. Callers are responsible for authorization and
+/// collateral checks.
+pub(crate) fn increase_debt(
+    env: &Env,
+    user: &Address,
+    asset: &Address,
+    amount: i128,
+) -> Result<(), BorrowError> {
+    let checked = check_debt_increase(env, user, asset, amount)?;
+    record_debt_increase(env, user, asset, amount, checked)
+}
+
+/// Write a debt increase that passed `check_debt_increase`
+fn record_debt_increase(
+    env: &Env,
+    user: &Address,
+    asset: &Address,
+    amount: i128,
+    (new_total, moved, new_asset_debt): (i128, i128, i128),
+) -> Result<(), BorrowError> {
+    let mut debt_position = get_debt_position(env, user);
+    let accrued_interest = calculate_interest(env, user, &debt_position);
+    if debt_position.borrowed_amount == 0 && debt_position.interest_accrued == 0 {
+        debt_position.opened_at = env.ledger().timestamp();
+    }
 
     debt_position.borrowed_amount = debt_position
         .borrowed_amount
@@ -127,35 +493,60 @@ pub fn borrow(
         .checked_add(accrued_interest)
         .ok_or(BorrowError::Overflow)?;
     debt_position.last_update = env.ledger().timestamp();
+    // Reserves re-rate at the utilization after the borrow
+    set_total_debt(env, new_total);
+    adjust_asset_debt(env, &debt_position.asset, -moved);
+    adjust_asset_debt(env, asset, amount + moved);
+    crate::debt_ceiling::record_debt_growth(
+        env,
+        asset,
+        new_asset_debt - amount - moved,
+        new_asset_debt,
+    );
     debt_position.asset = asset.clone();
+    debt_position.borrow_index = crate::reserve::checkpoint_borrow_index(env, asset);
 
-    let mut collateral_position = get_collateral_position(env, &user);
-    collateral_position.amount = collateral_position
-        .amount
-        .checked_add(collateral_amount)
-        .ok_or(BorrowError::Overflow)?;
-    collateral_position.asset = collateral_asset.clone();
+    crate::points::checkpoint(env, user);
+    crate::credit_score::record_borrow(env, user, amount);
+    update_borrow_index(env);
 
-    save_debt_position(env, &user, &debt_position);
-    save_collateral_position(env, &user, &collateral_position);
-    set_total_debt(env, new_total);
-
-    emit_borrow_event(env, user, asset, amount, collateral_amount);
+    if get_position_metadata(env, user).is_none() {
+        save_position_metadata(
+            env,
+            user,
+            &PositionMetadata {
+                repay_streak: 0,
+                cycle_anchor: env.ledger().timestamp(),
+                last_on_time_cycle: 0,
+            },
+        );
+    }
 
+    save_debt_position(env, user, &debt_position);
+    crate::deleverage::track_borrower(env, user);
+    crate::deleverage::update_crunch(env);
     Ok(())
 }
 
 /// Validate collateral ratio meets minimum requirements
-fn validate_collateral_ratio(collateral: i128, borrow: i128) -> Result<(), BorrowError> {
-    // To avoid overflow, check if collateral >= borrow * 1.5
-    // Which is: collateral * 10000 >= borrow * 15000
-    // Rearranged: collateral >= (borrow * 15000) / 10000
+pub(crate) fn validate_collateral_ratio(
+    env: &Env,
+    user: &Address,
+    collateral: i128,
+    borrow: i128,
+) -> Result<(), BorrowError> {
+    check_collateral_ratio(collateral, borrow, get_collateral_ratio(env, user))
+}
+
+fn check_collateral_ratio(collateral: i128, borrow: i128, ratio: i128) -> Result<(), BorrowError> {
+    // To avoid overflow, check if collateral >= borrow * ratio
+    // With the default 150% ratio: collateral * 10000 >= borrow * 15000
+    // Rearranged: collateral >= (borrow * 15000) / 10000, rounded up
 
-    let min_collateral = borrow
-        .checked_mul(COLLATERAL_RATIO_MIN)
-        .ok_or(BorrowError::Overflow)?
-        .checked_div(10000)
-        .ok_or(BorrowError::InvalidAmount)?;
+    let min_collateral = div_up(
+        borrow.checked_mul(ratio).ok_or(BorrowError::Overflow)?,
+        10000,
+    );
 
     if collateral < min_collateral {
         return Err(BorrowError::InsufficientCollateral);
@@ -164,8 +555,69 @@ fn validate_collateral_ratio(collateral: i128, borrow: i128) -> Result<(), Borro
     Ok(())
 }
 
+/// Minimum collateral ratio for borrowing and withdrawals, in basis points
+///
+/// Derived from the LTV of the user's collateral asset (the pool minimum when
+/// the asset has no parameters), adjusted by the user's credit score when
+/// score-based adjustment is enabled.
+pub(crate) fn get_collateral_ratio(env: &Env, user: &Address) -> i128 {
+    origination_ratio(env, user, &get_collateral_position(env, user).asset)
+}
+
+/// Collateral ratio below which a user's position is liquidatable, in basis points
+///
+/// Derived from the liquidation threshold of the user's collateral asset (the
+/// pool minimum when the asset has no parameters), with the same credit score
+/// adjustment as the origination ratio.
+pub(crate) fn get_liquidation_ratio(env: &Env, user: &Address) -> i128 {
+    liquidation_ratio(env, user, &get_collateral_position(env, user).asset)
+}
+
+fn liquidation_ratio(env: &Env, user: &Address, collateral_asset: &Address) -> i128 {
+    let base = get_collateral_params(env, collateral_asset)
+        .map(|params| ratio_from_bps(params.liquidation_threshold_bps))
+        .unwrap_or_else(|| get_min_collateral_ratio(env));
+    crate::credit_score::adjust_collateral_ratio(env, user, base)
+}
+
+fn origination_ratio(env: &Env, user: &Address, collateral_asset: &Address) -> i128 {
+    let base = get_collateral_params(env, collateral_asset)
+        .map(|params| ratio_from_bps(params.ltv_bps))
+        .unwrap_or_else(|| get_min_collateral_ratio(env));
+    crate::credit_score::adjust_collateral_ratio(env, user, base)
+}
+
+/// Convert a debt-to-collateral ratio into the collateral ratio it implies,
+/// rounded up in favor of the protocol
+fn ratio_from_bps(debt_bps: i128) -> i128 {
+    div_up(100_000_000, debt_bps)
+}
+
+/// Whether a user's position may be liquidated
+///
+/// In committed-price mode the position must also be unhealthy with its
+/// collateral valued at the prices observed at the previous accrual
+/// checkpoint.
+pub(crate) fn is_liquidatable(env: &Env, user: &Address) -> bool {
+    if get_health_factor(env, user) >= 10000 {
+        return false;
+    }
+    if !crate::oracle::get_committed_price_mode(env) {
+        return true;
+    }
+    let committed_value = fungible_collateral_value(env, &get_collateral_position(env, user))
+        .saturating_add(crate::nft::get_committed_nft_collateral_value(env, user))
+        .saturating_add(crate::rwa::get_rwa_collateral_value(env, user));
+    health_factor(env, user, committed_value) < 10000
+}
+
 /// Calculate accrued interest for a debt position
-fn calculate_interest(env: &Env, position: &DebtPosition) -> i128 {
+///
+/// Pool principal accrues by the growth of its asset's reserve borrow index
+/// since the position was last updated; principal matched peer-to-peer
+/// accrues at the matched rate. Any loyalty rebate the user has earned is
+/// applied to the accrued amount.
+pub(crate) fn calculate_interest(env: &Env, user: &Address, position: &DebtPosition) -> i128 {
     if position.borrowed_amount == 0 {
         return 0;
     }
@@ -173,15 +625,157 @@ fn calculate_interest(env: &Env, position: &DebtPosition) -> i128 {
     let current_time = env.ledger().timestamp();
     let time_elapsed = current_time.saturating_sub(position.last_update);
 
-    position
-        .borrowed_amount
-        .saturating_mul(INTEREST_RATE_PER_YEAR)
-        .saturating_mul(time_elapsed as i128)
-        .saturating_div(10000)
-        .saturating_div(SECONDS_PER_YEAR as i128)
+    let (matched, p2p_rate) = crate::p2p::get_matched_borrow(env, user, &position.asset);
+    let matched = matched.min(position.borrowed_amount);
+    let index = crate::reserve::get_reserve_data(env, &position.asset).borrow_index;
+    let pool_interest = mul_div_up(
+        position.borrowed_amount - matched,
+        index.saturating_sub(position.borrow_index).max(0),
+        position.borrow_index,
+    );
+    let interest = pool_interest.saturating_add(mul_div_up(
+        matched.saturating_mul(p2p_rate),
+        time_elapsed as i128,
+        10000 * SECONDS_PER_YEAR as i128,
+    ));
+
+    let rebate_bps = crate::loyalty::get_active_rebate_bps(env, user);
+    interest.saturating_sub(mul_div_down(interest, rebate_bps, 10000))
+}
+
+/// Current pool borrow index
+///
+/// Starts at `BORROW_INDEX_SCALE` and compounds at the pool borrow rate each
+/// time it is checkpointed. It is checkpointed with every reserve update, so
+/// it follows the same rate history as reserve indices without a premium.
+pub(crate) fn get_borrow_index(env: &Env) -> i128 {
+    accrue_borrow_index(env).value
+}
+
+/// Checkpoint the pool borrow index and accrue at the current rate from now
+pub(crate) fn update_borrow_index(env: &Env) -> i128 {
+    let index = accrue_borrow_index(env);
+    crate::rate_model::checkpoint_borrow_rate(env);
+    env.storage()
+        .persistent()
+        .set(&BorrowDataKey::BorrowIndex, &index);
+    env.storage().persistent().set(
+        &BorrowDataKey::PoolBorrowRate,
+        &crate::rate_model::get_borrow_rate(env),
+    );
+    index.value
+}
+
+/// Time of the latest pool accrual checkpoint (0 before the first)
+pub(crate) fn get_last_accrual_checkpoint(env: &Env) -> u64 {
+    env.storage()
+        .persistent()
+        .get::<_, BorrowIndex>(&BorrowDataKey::BorrowIndex)
+        .map(|index| index.last_update)
+        .unwrap_or(0)
+}
+
+fn accrue_borrow_index(env: &Env) -> BorrowIndex {
+    let now = env.ledger().timestamp();
+    let index = env
+        .storage()
+        .persistent()
+        .get(&BorrowDataKey::BorrowIndex)
+        .unwrap_or(BorrowIndex {
+            value: BORROW_INDEX_SCALE,
+            last_update: now,
+        });
+    accrue_index_to(env, index, now)
+}
+
+fn accrue_index_to(env: &Env, mut index: BorrowIndex, timestamp: u64) -> BorrowIndex {
+    let elapsed = timestamp.saturating_sub(index.last_update) as i128;
+    let rate = env
+        .storage()
+        .persistent()
+        .get(&BorrowDataKey::PoolBorrowRate)
+        .unwrap_or_else(|| crate::rate_model::get_borrow_rate(env));
+    let growth = mul_div_up(
+        index.value.saturating_mul(rate),
+        elapsed,
+        10000 * SECONDS_PER_YEAR as i128,
+    );
+    index.value = index.value.saturating_add(growth);
+    index.last_update = timestamp;
+    index
+}
+
+/// Value of all collateral backing a user's debt
+///
+/// Fungible collateral plus the collateral value of escrowed NFTs and
+/// restricted (RWA) collateral. Borrow-only assets count for nothing.
+pub(crate) fn get_total_collateral_value(env: &Env, user: &Address) -> i128 {
+    collateral_value(env, user, &get_collateral_position(env, user))
+}
+
+/// Value of a user's collateral were their fungible position `collateral`
+pub(crate) fn collateral_value(env: &Env, user: &Address, collateral: &CollateralPosition) -> i128 {
+    fungible_collateral_value(env, collateral)
+        .saturating_add(crate::nft::get_nft_collateral_value(env, user))
+        .saturating_add(crate::rwa::get_rwa_collateral_value(env, user))
+}
+
+/// Value of a fungible collateral position in its underlying
+fn fungible_collateral_value(env: &Env, collateral: &CollateralPosition) -> i128 {
+    if crate::asset_mode::counts_as_collateral(env, &collateral.asset) {
+        crate::yield_collateral::to_underlying(env, &collateral.asset, collateral.amount)
+    } else {
+        0
+    }
 }
 
-fn get_debt_position(env: &Env, user: &Address) -> DebtPosition {
+/// All debt owed by a user: pool principal and accrued interest plus
+/// fixed-term, amortizing and credit line debt
+pub(crate) fn get_total_user_debt(env: &Env, user: &Address) -> i128 {
+    let debt = get_user_debt(env, user);
+    debt.borrowed_amount
+        .saturating_add(debt.interest_accrued)
+        .saturating_add(crate::fixed_rate::get_fixed_debt(env, user))
+        .saturating_add(crate::amortizing::get_amortizing_debt(env, user))
+        .saturating_add(crate::credit_line::get_credit_debt(env, user))
+}
+
+/// Health factor in basis points (10000 = exactly at the liquidation threshold)
+///
+/// Returns `i128::MAX` for users without debt.
+pub(crate) fn get_health_factor(env: &Env, user: &Address) -> i128 {
+    health_factor(env, user, get_total_collateral_value(env, user))
+}
+
+fn health_factor(env: &Env, user: &Address, collateral_value: i128) -> i128 {
+    projected_health_factor(
+        env,
+        user,
+        &get_collateral_position(env, user).asset,
+        collateral_value,
+        get_total_user_debt(env, user),
+    )
+}
+
+/// Health factor a user would have with the given collateral and total debt
+pub(crate) fn projected_health_factor(
+    env: &Env,
+    user: &Address,
+    collateral_asset: &Address,
+    collateral_value: i128,
+    debt: i128,
+) -> i128 {
+    if debt == 0 {
+        return i128::MAX;
+    }
+    let required = mul_div_up(debt, liquidation_ratio(env, user, collateral_asset), 10000);
+    if required == 0 {
+        return i128::MAX;
+    }
+    mul_div_down(collateral_value, 10000, required)
+}
+
+pub(crate) fn get_debt_position(env: &Env, user: &Address) -> DebtPosition {
     env.storage()
         .persistent()
         .get(&BorrowDataKey::UserDebt(user.clone()))
@@ -190,16 +784,19 @@ fn get_debt_position(env: &Env, user: &Address) -> DebtPosition {
             interest_accrued: 0,
             last_update: env.ledger().timestamp(),
             asset: user.clone(), // Placeholder, will be replaced on first borrow
+            opened_at: env.ledger().timestamp(),
+            borrow_index: BORROW_INDEX_SCALE,
         })
 }
 
-fn save_debt_position(env: &Env, user: &Address, position: &DebtPosition) {
+pub(crate) fn save_debt_position(env: &Env, user: &Address, position: &DebtPosition) {
     env.storage()
         .persistent()
         .set(&BorrowDataKey::UserDebt(user.clone()), position);
+    crate::health_index::update_health_bucket(env, user);
 }
 
-fn get_collateral_position(env: &Env, user: &Address) -> CollateralPosition {
+pub(crate) fn get_collateral_position(env: &Env, user: &Address) -> CollateralPosition {
     env.storage()
         .persistent()
         .get(&BorrowDataKey::UserCollateral(user.clone()))
@@ -209,47 +806,344 @@ fn get_collateral_position(env: &Env, user: &Address) -> CollateralPosition {
         })
 }
 
-fn save_collateral_position(env: &Env, user: &Address, position: &CollateralPosition) {
+pub(crate) fn save_collateral_position(env: &Env, user: &Address, position: &CollateralPosition) {
+    let previous = get_collateral_position(env, user);
+    crate::collateral_tier::on_collateral_changed(env, &previous, position);
+    env.storage().persistent().set(
+        &BorrowDataKey::TotalCollateral,
+        &(get_total_collateral(env) - previous.amount + position.amount),
+    );
     env.storage()
         .persistent()
         .set(&BorrowDataKey::UserCollateral(user.clone()), position);
+    crate::health_index::update_health_bucket(env, user);
 }
 
-fn get_total_debt(env: &Env) -> i128 {
+pub(crate) fn get_total_debt(env: &Env) -> i128 {
     env.storage()
         .persistent()
         .get(&BorrowDataKey::TotalDebt)
         .unwrap_or(0)
 }
 
-fn set_total_debt(env: &Env, amount: i128) {
+pub(crate) fn set_total_debt(env: &Env, amount: i128) {
     env.storage()
         .persistent()
         .set(&BorrowDataKey::TotalDebt, &amount);
 }
 
-fn get_debt_ceiling(env: &Env) -> i128 {
+/// Outstanding principal borrowed in an asset
+pub fn get_asset_debt(env: &Env, asset: &Address) -> i128 {
+    crate::reserve::get_reserve_data(env, asset).total_borrowed
+}
+
+pub(crate) fn adjust_asset_debt(env: &Env, asset: &Address, delta: i128) {
+    if delta == 0 {
+        return;
+    }
+    crate::reserve::update_reserve(env, asset, 0, delta);
+}
+
+/// Set the maximum principal that may be borrowed in an asset (admin only)
+pub fn set_asset_debt_ceiling(
+    env: &Env,
+    caller: Address,
+    asset: Address,
+    ceiling: i128,
+) -> Result<(), BorrowError> {
+    require_admin(env, &caller).map_err(|_| BorrowError::Unauthorized)?;
+    apply_asset_debt_ceiling(env, asset, ceiling)
+}
+
+/// Validate and store an asset debt ceiling; callers check authorization
+pub(crate) fn apply_asset_debt_ceiling(
+    env: &Env,
+    asset: Address,
+    ceiling: i128,
+) -> Result<(), BorrowError> {
+    if ceiling < 0 {
+        return Err(BorrowError::InvalidConfig);
+    }
+    env.storage()
+        .persistent()
+        .set(&BorrowDataKey::AssetDebtCeiling(asset), &ceiling);
+    Ok(())
+}
+
+/// Get the debt ceiling of an asset, if set
+///
+/// Assets without a ceiling are only bound by the global ceiling.
+pub fn get_asset_debt_ceiling(env: &Env, asset: &Address) -> Option<i128> {
     env.storage()
         .persistent()
-        .get(&BorrowDataKey::DebtCeiling)
-        .unwrap_or(i128::MAX)
+        .get(&BorrowDataKey::AssetDebtCeiling(asset.clone()))
 }
 
-fn get_min_borrow_amount(env: &Env) -> i128 {
+pub(crate) fn get_total_collateral(env: &Env) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&BorrowDataKey::TotalCollateral)
+        .unwrap_or(0)
+}
+
+/// Debt ceiling in force: the dynamic ceiling when configured, otherwise the
+/// static ceiling
+pub(crate) fn get_debt_ceiling(env: &Env) -> i128 {
+    crate::debt_ceiling::get_dynamic_debt_ceiling(env).unwrap_or_else(|| {
+        env.storage()
+            .persistent()
+            .get(&BorrowDataKey::DebtCeiling)
+            .unwrap_or(i128::MAX)
+    })
+}
+
+/// Minimum amount of an asset a borrow may be for, or a position may be left at
+///
+/// The larger of the asset's unit minimum and the amount worth its value
+/// floor while a price is reported. Assets without their own minimum use the
+/// global minimum.
+pub(crate) fn get_min_borrow_amount(env: &Env, asset: &Address) -> i128 {
+    match get_asset_min_borrow(env, asset) {
+        Some(config) => {
+            let value_floor = if config.min_value > 0 {
+                crate::oracle::value_to_amount(env, asset, config.min_value).unwrap_or(0)
+            } else {
+                0
+            };
+            config.min_amount.max(value_floor)
+        }
+        None => get_default_min_borrow(env),
+    }
+}
+
+/// Validate and store the static global debt ceiling; callers check authorization
+pub(crate) fn apply_debt_ceiling(env: &Env, ceiling: i128) -> Result<(), BorrowError> {
+    if ceiling < 0 {
+        return Err(BorrowError::InvalidConfig);
+    }
+    env.storage()
+        .persistent()
+        .set(&BorrowDataKey::DebtCeiling, &ceiling);
+    Ok(())
+}
+
+/// Set the global minimum borrow amount (admin only)
+pub fn set_default_min_borrow(env: &Env, caller: Address, amount: i128) -> Result<(), BorrowError> {
+    require_admin(env, &caller).map_err(|_| BorrowError::Unauthorized)?;
+    apply_default_min_borrow(env, amount)
+}
+
+/// Validate and store the global minimum borrow amount; callers check authorization
+pub(crate) fn apply_default_min_borrow(env: &Env, amount: i128) -> Result<(), BorrowError> {
+    if amount < 0 {
+        return Err(BorrowError::InvalidConfig);
+    }
+    let old_value = get_default_min_borrow(env);
+    env.storage()
+        .persistent()
+        .set(&BorrowDataKey::MinBorrowAmount, &amount);
+    emit_param_updated(env, "min_borrow_amount", old_value, amount);
+    Ok(())
+}
+
+/// Global minimum borrow amount, used for assets without their own minimum
+pub fn get_default_min_borrow(env: &Env) -> i128 {
     env.storage()
         .persistent()
         .get(&BorrowDataKey::MinBorrowAmount)
-        .unwrap_or(1000)
+        .unwrap_or(DEFAULT_MIN_BORROW_AMOUNT)
+}
+
+/// Set the collateral ratio required of assets without risk parameters (admin only)
+///
+/// Must stay between 110% and 500%.
+pub fn set_min_collateral_ratio(
+    env: &Env,
+    caller: Address,
+    ratio_bps: i128,
+) -> Result<(), BorrowError> {
+    require_admin(env, &caller).map_err(|_| BorrowError::Unauthorized)?;
+    apply_min_collateral_ratio(env, ratio_bps)
+}
+
+/// Validate and store the minimum collateral ratio; callers check authorization
+pub(crate) fn apply_min_collateral_ratio(env: &Env, ratio_bps: i128) -> Result<(), BorrowError> {
+    if !(COLLATERAL_RATIO_BOUNDS.0..=COLLATERAL_RATIO_BOUNDS.1).contains(&ratio_bps) {
+        return Err(BorrowError::InvalidConfig);
+    }
+    let old_value = get_min_collateral_ratio(env);
+    env.storage()
+        .persistent()
+        .set(&BorrowDataKey::CollateralRatio, &ratio_bps);
+    emit_param_updated(env, "min_collateral_ratio", old_value, ratio_bps);
+    Ok(())
+}
+
+/// Collateral ratio required of assets without risk parameters, in basis points
+pub fn get_min_collateral_ratio(env: &Env) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&BorrowDataKey::CollateralRatio)
+        .unwrap_or(COLLATERAL_RATIO_MIN)
+}
+
+/// Set the pool interest rate used while no rate model is set (admin only)
+///
+/// Must stay between 0% and 100% per year. Interest accrues at the old rate
+/// up to the change.
+pub fn set_base_interest_rate(
+    env: &Env,
+    caller: Address,
+    rate_bps: i128,
+) -> Result<(), BorrowError> {
+    require_admin(env, &caller).map_err(|_| BorrowError::Unauthorized)?;
+    apply_base_interest_rate(env, rate_bps)
+}
+
+/// Validate and store the fixed pool interest rate; callers check authorization
+pub(crate) fn apply_base_interest_rate(env: &Env, rate_bps: i128) -> Result<(), BorrowError> {
+    if !(INTEREST_RATE_BOUNDS.0..=INTEREST_RATE_BOUNDS.1).contains(&rate_bps) {
+        return Err(BorrowError::InvalidConfig);
+    }
+    store_base_interest_rate(env, rate_bps)?;
+    crate::reserve::refresh_borrow_rates(env);
+    Ok(())
+}
+
+/// Validate and store the fixed pool interest rate without accruing first
+///
+/// Only for initialization, before any interest has accrued.
+pub(crate) fn store_base_interest_rate(env: &Env, rate_bps: i128) -> Result<(), BorrowError> {
+    if !(INTEREST_RATE_BOUNDS.0..=INTEREST_RATE_BOUNDS.1).contains(&rate_bps) {
+        return Err(BorrowError::InvalidConfig);
+    }
+    let old_value = get_base_interest_rate(env);
+    env.storage()
+        .persistent()
+        .set(&BorrowDataKey::InterestRate, &rate_bps);
+    emit_param_updated(env, "base_interest_rate", old_value, rate_bps);
+    Ok(())
 }
 
-fn is_paused(env: &Env) -> bool {
+/// Pool interest rate used while no rate model is set, in basis points per year
+pub fn get_base_interest_rate(env: &Env) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&BorrowDataKey::InterestRate)
+        .unwrap_or(INTEREST_RATE_PER_YEAR)
+}
+
+fn emit_param_updated(env: &Env, param: &str, old_value: i128, new_value: i128) {
+    let event = ParamUpdatedEvent {
+        param: Symbol::new(env, param),
+        old_value,
+        new_value,
+        timestamp: env.ledger().timestamp(),
+    };
+    crate::events::publish(env, (Symbol::new(env, "param_updated"),), event);
+}
+
+/// Set the minimum borrow size of an asset (admin only)
+pub fn set_asset_min_borrow(
+    env: &Env,
+    caller: Address,
+    asset: Address,
+    config: MinBorrowConfig,
+) -> Result<(), BorrowError> {
+    require_admin(env, &caller).map_err(|_| BorrowError::Unauthorized)?;
+    apply_asset_min_borrow(env, asset, config)
+}
+
+/// Validate and store an asset's minimum borrow size; callers check authorization
+pub(crate) fn apply_asset_min_borrow(
+    env: &Env,
+    asset: Address,
+    config: MinBorrowConfig,
+) -> Result<(), BorrowError> {
+    if config.min_amount < 0 || config.min_value < 0 {
+        return Err(BorrowError::InvalidConfig);
+    }
+    env.storage()
+        .persistent()
+        .set(&BorrowDataKey::AssetMinBorrow(asset), &config);
+    Ok(())
+}
+
+/// Get the minimum borrow size of an asset, if set
+pub fn get_asset_min_borrow(env: &Env, asset: &Address) -> Option<MinBorrowConfig> {
+    env.storage()
+        .persistent()
+        .get(&BorrowDataKey::AssetMinBorrow(asset.clone()))
+}
+
+/// Set or clear the absolute borrow cap of an address (risk manager only)
+///
+/// The cap applies on top of the collateral check: a borrow must satisfy both.
+pub fn set_user_borrow_cap(
+    env: &Env,
+    caller: Address,
+    user: Address,
+    cap: Option<i128>,
+) -> Result<(), BorrowError> {
+    require_risk_manager(env, &caller).map_err(|_| BorrowError::Unauthorized)?;
+
+    let key = BorrowDataKey::UserBorrowCap(user.clone());
+    match cap {
+        Some(cap) if cap < 0 => return Err(BorrowError::InvalidConfig),
+        Some(cap) => env.storage().persistent().set(&key, &cap),
+        None => env.storage().persistent().remove(&key),
+    }
+
+    crate::events::publish(env, (Symbol::new(env, "user_borrow_cap_set"), user), cap);
+    Ok(())
+}
+
+/// Get the absolute borrow cap of an address, if any
+pub fn get_user_borrow_cap(env: &Env, user: &Address) -> Option<i128> {
+    env.storage()
+        .persistent()
+        .get(&BorrowDataKey::UserBorrowCap(user.clone()))
+}
+
+/// Whether borrowing `amount` more would take a user's total debt over its cap
+///
+/// Covers pool, fixed-term and amortizing debt.
+pub(crate) fn exceeds_user_borrow_cap(env: &Env, user: &Address, amount: i128) -> bool {
+    get_user_borrow_cap(env, user)
+        .is_some_and(|cap| get_total_user_debt(env, user).saturating_add(amount) > cap)
+}
+
+pub(crate) fn get_position_metadata(env: &Env, user: &Address) -> Option<PositionMetadata> {
+    env.storage()
+        .persistent()
+        .get(&BorrowDataKey::UserMetadata(user.clone()))
+}
+
+pub(crate) fn save_position_metadata(env: &Env, user: &Address, metadata: &PositionMetadata) {
+    env.storage()
+        .persistent()
+        .set(&BorrowDataKey::UserMetadata(user.clone()), metadata);
+}
+
+/// Whether new borrows are paused, protocol-wide, by the borrow pause flag
+/// or by an emergency shutdown
+pub(crate) fn is_paused(env: &Env) -> bool {
     env.storage()
         .persistent()
         .get(&BorrowDataKey::Paused)
         .unwrap_or(false)
+        || crate::pause::is_operation_paused(env, crate::pause::PAUSE_BORROW)
+        || crate::shutdown::is_shutdown(env)
 }
 
-fn emit_borrow_event(env: &Env, user: Address, asset: Address, amount: i128, collateral: i128) {
+pub(crate) fn emit_borrow_event(
+    env: &Env,
+    user: Address,
+    asset: Address,
+    amount: i128,
+    collateral: i128,
+) {
+    crate::history::record_action(env, &user, PositionAction::Borrow, &asset, amount);
     let event = BorrowEvent {
         user,
         asset,
@@ -257,39 +1151,103 @@ fn emit_borrow_event(env: &Env, user: Address, asset: Address, amount: i128, col
         collateral,
         timestamp: env.ledger().timestamp(),
     };
-    env.events().publish((Symbol::new(env, "borrow"),), event);
+    crate::events::publish(
+        env,
+        (
+            Symbol::new(env, "borrow"),
+            event.user.clone(),
+            event.asset.clone(),
+        ),
+        event,
+    );
 }
 
-/// Initialize borrow settings (admin only)
-pub fn initialize_borrow_settings(
+/// Set the risk parameters of a collateral asset (admin only)
+///
+/// The liquidation threshold must be looser than the LTV so that positions
+/// opened at the maximum LTV are not immediately liquidatable.
+pub fn set_collateral_params(
     env: &Env,
-    debt_ceiling: i128,
-    min_borrow_amount: i128,
+    caller: Address,
+    asset: Address,
+    params: CollateralParams,
 ) -> Result<(), BorrowError> {
+    require_admin(env, &caller).map_err(|_| BorrowError::Unauthorized)?;
+    apply_collateral_params(env, asset, params)
+}
+
+/// Validate and store collateral risk parameters; callers check authorization
+pub(crate) fn apply_collateral_params(
+    env: &Env,
+    asset: Address,
+    params: CollateralParams,
+) -> Result<(), BorrowError> {
+    if !is_valid_collateral_params(&params) {
+        return Err(BorrowError::InvalidConfig);
+    }
     env.storage()
         .persistent()
-        .set(&BorrowDataKey::DebtCeiling, &debt_ceiling);
-    env.storage()
-        .persistent()
-        .set(&BorrowDataKey::MinBorrowAmount, &min_borrow_amount);
+        .set(&BorrowDataKey::CollateralParams(asset), &params);
+    Ok(())
+}
+
+/// Get the risk parameters of a collateral asset
+///
+/// Parameters set for the asset itself take precedence over the defaults of
+/// its collateral tier.
+pub fn get_collateral_params(env: &Env, asset: &Address) -> Option<CollateralParams> {
     env.storage()
         .persistent()
-        .set(&BorrowDataKey::Paused, &false);
-    Ok(())
+        .get(&BorrowDataKey::CollateralParams(asset.clone()))
+        .or_else(|| crate::collateral_tier::tier_params(env, asset))
+}
+
+pub(crate) fn is_valid_collateral_params(params: &CollateralParams) -> bool {
+    params.ltv_bps > 0
+        && params.liquidation_threshold_bps > params.ltv_bps
+        && params.liquidation_threshold_bps <= 10000
 }
 
 /// Set protocol pause state (admin only)
-pub fn set_paused(env: &Env, paused: bool) -> Result<(), BorrowError> {
+pub fn set_paused(
+    env: &Env,
+    caller: Address,
+    paused: bool,
+    reason: Symbol,
+) -> Result<(), BorrowError> {
+    require_admin(env, &caller).map_err(|_| BorrowError::Unauthorized)?;
+
     env.storage()
         .persistent()
         .set(&BorrowDataKey::Paused, &paused);
+    let info = PauseInfo {
+        paused,
+        actor: caller,
+        reason,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.storage()
+        .persistent()
+        .set(&BorrowDataKey::PauseInfo, &info);
+
+    let name = if paused {
+        "protocol_paused"
+    } else {
+        "protocol_unpaused"
+    };
+    crate::events::publish(env, (Symbol::new(env, name),), info);
     Ok(())
 }
 
+/// Get the latest pause state change, if the pause state was ever set
+pub fn get_pause_info(env: &Env) -> Option<PauseInfo> {
+    env.storage().persistent().get(&BorrowDataKey::PauseInfo)
+}
+
 /// Get user's debt position
 pub fn get_user_debt(env: &Env, user: &Address) -> DebtPosition {
     let mut position = get_debt_position(env, user);
-    let accrued = calculate_interest(env, &position);
+    let accrued = calculate_interest(env, user, &position);
     position.interest_accrued = position.interest_accrued.saturating_add(accrued);
     position
 }
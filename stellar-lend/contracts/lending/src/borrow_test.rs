@@ -1,5 +1,6 @@
 use super::*;
 use soroban_sdk::{
+    symbol_short,
     testutils::{Address as _, Ledger},
     Address, Env,
 };
@@ -16,7 +17,10 @@ fn test_borrow_success() {
     let asset = Address::generate(&env);
     let collateral_asset = Address::generate(&env);
 
-    client.initialize_borrow_settings(&1_000_000_000, &1000);
+    client.initialize(
+        &Address::generate(&env),
+        &InitConfig::new(&env, 1_000_000_000, 1000),
+    );
 
     client.borrow(&user, &asset, &10_000, &collateral_asset, &20_000);
 
@@ -40,7 +44,10 @@ fn test_borrow_insufficient_collateral() {
     let asset = Address::generate(&env);
     let collateral_asset = Address::generate(&env);
 
-    client.initialize_borrow_settings(&1_000_000_000, &1000);
+    client.initialize(
+        &Address::generate(&env),
+        &InitConfig::new(&env, 1_000_000_000, 1000),
+    );
 
     let result = client.try_borrow(&user, &asset, &10_000, &collateral_asset, &10_000);
     assert_eq!(result, Err(Ok(BorrowError::InsufficientCollateral)));
@@ -58,8 +65,13 @@ fn test_borrow_protocol_paused() {
     let asset = Address::generate(&env);
     let collateral_asset = Address::generate(&env);
 
-    client.initialize_borrow_settings(&1_000_000_000, &1000);
-    client.set_paused(&true);
+    client.initialize(
+        &Address::generate(&env),
+        &InitConfig::new(&env, 1_000_000_000, 1000),
+    );
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    client.set_paused(&admin, &true, &symbol_short!("exploit"));
 
     let result = client.try_borrow(&user, &asset, &10_000, &collateral_asset, &20_000);
     assert_eq!(result, Err(Ok(BorrowError::ProtocolPaused)));
@@ -77,7 +89,10 @@ fn test_borrow_invalid_amount() {
     let asset = Address::generate(&env);
     let collateral_asset = Address::generate(&env);
 
-    client.initialize_borrow_settings(&1_000_000_000, &1000);
+    client.initialize(
+        &Address::generate(&env),
+        &InitConfig::new(&env, 1_000_000_000, 1000),
+    );
 
     let result = client.try_borrow(&user, &asset, &0, &collateral_asset, &20_000);
     assert_eq!(result, Err(Ok(BorrowError::InvalidAmount)));
@@ -98,7 +113,10 @@ fn test_borrow_below_minimum() {
     let asset = Address::generate(&env);
     let collateral_asset = Address::generate(&env);
 
-    client.initialize_borrow_settings(&1_000_000_000, &5000);
+    client.initialize(
+        &Address::generate(&env),
+        &InitConfig::new(&env, 1_000_000_000, 5000),
+    );
 
     let result = client.try_borrow(&user, &asset, &1000, &collateral_asset, &2000);
     assert_eq!(result, Err(Ok(BorrowError::BelowMinimumBorrow)));
@@ -116,7 +134,10 @@ fn test_borrow_debt_ceiling() {
     let asset = Address::generate(&env);
     let collateral_asset = Address::generate(&env);
 
-    client.initialize_borrow_settings(&50_000, &1000);
+    client.initialize(
+        &Address::generate(&env),
+        &InitConfig::new(&env, 50_000, 1000),
+    );
 
     let result = client.try_borrow(&user, &asset, &100_000, &collateral_asset, &200_000);
     assert_eq!(result, Err(Ok(BorrowError::DebtCeilingReached)));
@@ -134,7 +155,10 @@ fn test_borrow_multiple_times() {
     let asset = Address::generate(&env);
     let collateral_asset = Address::generate(&env);
 
-    client.initialize_borrow_settings(&1_000_000_000, &1000);
+    client.initialize(
+        &Address::generate(&env),
+        &InitConfig::new(&env, 1_000_000_000, 1000),
+    );
 
     client.borrow(&user, &asset, &10_000, &collateral_asset, &20_000);
     client.borrow(&user, &asset, &5_000, &collateral_asset, &10_000);
@@ -162,7 +186,10 @@ fn test_borrow_interest_accrual() {
     let asset = Address::generate(&env);
     let collateral_asset = Address::generate(&env);
 
-    client.initialize_borrow_settings(&1_000_000_000, &1000);
+    client.initialize(
+        &Address::generate(&env),
+        &InitConfig::new(&env, 1_000_000_000, 1000),
+    );
     client.borrow(&user, &asset, &100_000, &collateral_asset, &200_000);
 
     env.ledger().with_mut(|li| {
@@ -186,7 +213,10 @@ fn test_collateral_ratio_validation() {
     let asset = Address::generate(&env);
     let collateral_asset = Address::generate(&env);
 
-    client.initialize_borrow_settings(&1_000_000_000, &1000);
+    client.initialize(
+        &Address::generate(&env),
+        &InitConfig::new(&env, 1_000_000_000, 1000),
+    );
 
     // Exactly 150% collateral - should succeed
     client.borrow(&user, &asset, &10_000, &collateral_asset, &15_000);
@@ -209,16 +239,54 @@ fn test_pause_unpause() {
     let asset = Address::generate(&env);
     let collateral_asset = Address::generate(&env);
 
-    client.initialize_borrow_settings(&1_000_000_000, &1000);
+    client.initialize(
+        &Address::generate(&env),
+        &InitConfig::new(&env, 1_000_000_000, 1000),
+    );
 
-    client.set_paused(&true);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    client.set_paused(&admin, &true, &symbol_short!("oracle"));
     let result = client.try_borrow(&user, &asset, &10_000, &collateral_asset, &20_000);
     assert_eq!(result, Err(Ok(BorrowError::ProtocolPaused)));
 
-    client.set_paused(&false);
+    client.set_paused(&admin, &false, &symbol_short!("resolved"));
     client.borrow(&user, &asset, &10_000, &collateral_asset, &20_000);
 }
 
+#[test]
+fn test_pause_info_records_actor_and_reason() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 500);
+
+    let client = LendingContractClient::new(&env, &env.register(LendingContract, ()));
+    client.initialize(
+        &Address::generate(&env),
+        &InitConfig::new(&env, 1_000_000_000, 1000),
+    );
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    assert_eq!(client.get_pause_info(), None);
+
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_set_paused(&stranger, &true, &symbol_short!("exploit")),
+        Err(Ok(BorrowError::Unauthorized))
+    );
+
+    client.set_paused(&admin, &true, &symbol_short!("exploit"));
+    assert_eq!(
+        client.get_pause_info(),
+        Some(PauseInfo {
+            paused: true,
+            actor: admin,
+            reason: symbol_short!("exploit"),
+            timestamp: 500,
+        })
+    );
+}
+
 #[test]
 fn test_overflow_protection() {
     let env = Env::default();
@@ -231,7 +299,10 @@ fn test_overflow_protection() {
     let asset = Address::generate(&env);
     let collateral_asset = Address::generate(&env);
 
-    client.initialize_borrow_settings(&i128::MAX, &1000);
+    client.initialize(
+        &Address::generate(&env),
+        &InitConfig::new(&env, i128::MAX, 1000),
+    );
 
     // First borrow with reasonable amount
     client.borrow(&user, &asset, &1_000_000, &collateral_asset, &2_000_000);
@@ -248,3 +319,336 @@ fn test_overflow_protection() {
     );
     assert_eq!(result, Err(Ok(BorrowError::Overflow)));
 }
+
+fn setup_collateral_params(env: &Env) -> (LendingContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    let client = LendingContractClient::new(env, &env.register(LendingContract, ()));
+    client.initialize(
+        &Address::generate(env),
+        &InitConfig::new(env, 1_000_000_000, 1000),
+    );
+
+    let admin = Address::generate(env);
+    client.set_admin(&admin);
+    let collateral_asset = Address::generate(env);
+    client.set_collateral_params(
+        &admin,
+        &collateral_asset,
+        &CollateralParams {
+            ltv_bps: 7500,
+            liquidation_threshold_bps: 8000,
+        },
+    );
+    (client, admin, collateral_asset)
+}
+
+#[test]
+fn test_borrow_limited_by_asset_ltv() {
+    let env = Env::default();
+    let (client, _, collateral_asset) = setup_collateral_params(&env);
+    let user = Address::generate(&env);
+    let asset = Address::generate(&env);
+
+    // 75% LTV requires 13,334 collateral per 10,000 borrowed
+    assert_eq!(
+        client.try_borrow(&user, &asset, &10_000, &collateral_asset, &13_333),
+        Err(Ok(BorrowError::InsufficientCollateral))
+    );
+    client.borrow(&user, &asset, &10_000, &collateral_asset, &13_334);
+    assert_eq!(client.get_collateral_ratio(&user), 13_334);
+    assert_eq!(client.get_liquidation_ratio(&user), 12_500);
+
+    // A maximum-LTV position stays healthy against the looser threshold
+    assert_eq!(client.get_health_factor(&user), 10_667);
+}
+
+#[test]
+fn test_withdraw_checked_against_ltv() {
+    let env = Env::default();
+    let (client, _, collateral_asset) = setup_collateral_params(&env);
+    let user = Address::generate(&env);
+
+    client.borrow(
+        &user,
+        &Address::generate(&env),
+        &10_000,
+        &collateral_asset,
+        &20_000,
+    );
+    assert_eq!(
+        client.try_withdraw_collateral(&user, &6667),
+        Err(Ok(WithdrawError::InsufficientCollateral))
+    );
+    assert_eq!(client.withdraw_collateral(&user, &6666), 13_334);
+}
+
+#[test]
+fn test_unconfigured_asset_uses_pool_minimum() {
+    let env = Env::default();
+    let (client, _, _) = setup_collateral_params(&env);
+    let user = Address::generate(&env);
+
+    client.borrow(
+        &user,
+        &Address::generate(&env),
+        &10_000,
+        &Address::generate(&env),
+        &15_000,
+    );
+    assert_eq!(client.get_collateral_ratio(&user), 15_000);
+    assert_eq!(client.get_liquidation_ratio(&user), 15_000);
+    assert_eq!(client.get_health_factor(&user), 10_000);
+}
+
+#[test]
+fn test_collateral_params_validation() {
+    let env = Env::default();
+    let (client, admin, collateral_asset) = setup_collateral_params(&env);
+
+    let params = client.get_collateral_params(&collateral_asset).unwrap();
+    assert_eq!(
+        client.try_set_collateral_params(&Address::generate(&env), &collateral_asset, &params),
+        Err(Ok(BorrowError::Unauthorized))
+    );
+    // The liquidation threshold must be looser than the LTV
+    assert_eq!(
+        client.try_set_collateral_params(
+            &admin,
+            &collateral_asset,
+            &CollateralParams {
+                ltv_bps: 8000,
+                liquidation_threshold_bps: 8000,
+            },
+        ),
+        Err(Ok(BorrowError::InvalidConfig))
+    );
+    assert_eq!(
+        client.try_set_collateral_params(
+            &admin,
+            &collateral_asset,
+            &CollateralParams {
+                ltv_bps: 9000,
+                liquidation_threshold_bps: 10_001,
+            },
+        ),
+        Err(Ok(BorrowError::InvalidConfig))
+    );
+}
+
+#[test]
+fn test_asset_debt_ceiling_enforced_per_asset() {
+    let env = Env::default();
+    let (client, admin, collateral_asset) = setup_collateral_params(&env);
+    let usdc = Address::generate(&env);
+    let xlm = Address::generate(&env);
+    client.set_asset_debt_ceiling(&admin, &usdc, &15_000);
+
+    client.borrow(
+        &Address::generate(&env),
+        &usdc,
+        &10_000,
+        &collateral_asset,
+        &20_000,
+    );
+    assert_eq!(
+        client.try_borrow(
+            &Address::generate(&env),
+            &usdc,
+            &5001,
+            &collateral_asset,
+            &20_000,
+        ),
+        Err(Ok(BorrowError::DebtCeilingReached))
+    );
+    // Other assets are unaffected
+    client.borrow(
+        &Address::generate(&env),
+        &xlm,
+        &10_000,
+        &collateral_asset,
+        &20_000,
+    );
+    assert_eq!(client.get_asset_debt(&usdc), 10_000);
+    assert_eq!(client.get_asset_debt(&xlm), 10_000);
+}
+
+#[test]
+fn test_global_ceiling_bounds_asset_aggregate() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = LendingContractClient::new(&env, &env.register(LendingContract, ()));
+    client.initialize(
+        &Address::generate(&env),
+        &InitConfig::new(&env, 15_000, 1000),
+    );
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    let usdc = Address::generate(&env);
+    client.set_asset_debt_ceiling(&admin, &usdc, &100_000);
+
+    client.borrow(
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &10_000,
+        &Address::generate(&env),
+        &20_000,
+    );
+    assert_eq!(
+        client.try_borrow(
+            &Address::generate(&env),
+            &usdc,
+            &5001,
+            &Address::generate(&env),
+            &20_000,
+        ),
+        Err(Ok(BorrowError::DebtCeilingReached))
+    );
+    assert_eq!(
+        client.try_set_asset_debt_ceiling(&Address::generate(&env), &usdc, &1),
+        Err(Ok(BorrowError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_asset_debt_tracks_repayment_and_rebalancing() {
+    let env = Env::default();
+    let (client, _, collateral_asset) = setup_collateral_params(&env);
+    let user = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    let xlm = Address::generate(&env);
+
+    client.borrow(&user, &usdc, &10_000, &collateral_asset, &20_000);
+    client.repay(&user, &4000);
+    assert_eq!(client.get_asset_debt(&usdc), 6000);
+
+    // Borrowing another asset re-denominates the position
+    client.borrow(&user, &xlm, &2000, &collateral_asset, &20_000);
+    assert_eq!(client.get_asset_debt(&usdc), 0);
+    assert_eq!(client.get_asset_debt(&xlm), 8000);
+}
+
+#[test]
+fn test_asset_min_borrow_overrides_global_minimum() {
+    let env = Env::default();
+    let (client, admin, collateral_asset) = setup_collateral_params(&env);
+    let btc = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    client.set_asset_min_borrow(
+        &admin,
+        &btc,
+        &MinBorrowConfig {
+            min_amount: 10,
+            min_value: 0,
+        },
+    );
+
+    // Small unit amounts are allowed for high-value assets
+    client.borrow(&Address::generate(&env), &btc, &10, &collateral_asset, &20);
+    assert_eq!(
+        client.try_borrow(
+            &Address::generate(&env),
+            &usdc,
+            &999,
+            &collateral_asset,
+            &2000,
+        ),
+        Err(Ok(BorrowError::BelowMinimumBorrow))
+    );
+
+    // The asset's minimum also bounds dust left by repayments
+    let user = Address::generate(&env);
+    client.borrow(&user, &btc, &100, &collateral_asset, &200);
+    assert_eq!(
+        client.try_repay(&user, &91),
+        Err(Ok(RepayError::DustRemaining))
+    );
+    client.repay(&user, &90);
+}
+
+#[test]
+fn test_user_borrow_cap_applies_on_top_of_collateral() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = LendingContractClient::new(&env, &env.register(LendingContract, ()));
+    client.initialize(
+        &Address::generate(&env),
+        &InitConfig::new(&env, 1_000_000_000, 1000),
+    );
+    let admin = Address::generate(&env);
+    let risk_manager = Address::generate(&env);
+    client.set_admin(&admin);
+    client.set_risk_manager(&admin, &risk_manager);
+
+    let user = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let collateral_asset = Address::generate(&env);
+    assert_eq!(
+        client.try_set_user_borrow_cap(&user, &user, &Some(50_000)),
+        Err(Ok(BorrowError::Unauthorized))
+    );
+    client.set_user_borrow_cap(&risk_manager, &user, &Some(50_000));
+    assert_eq!(client.get_user_borrow_cap(&user), Some(50_000));
+    assert_eq!(
+        client.try_set_user_borrow_cap(&risk_manager, &user, &Some(-1)),
+        Err(Ok(BorrowError::InvalidConfig))
+    );
+
+    // Well collateralized, but over the cap
+    client.borrow(&user, &asset, &40_000, &collateral_asset, &1_000_000);
+    assert_eq!(
+        client.try_borrow(&user, &asset, &20_000, &collateral_asset, &100_000),
+        Err(Ok(BorrowError::BorrowCapExceeded))
+    );
+    client.borrow(&user, &asset, &10_000, &collateral_asset, &100_000);
+
+    // Fixed-term loans count towards the same cap
+    let lender = Address::generate(&env);
+    let offer_id = client.post_offer(&lender, &asset, &10_000, &500, &86400);
+    assert_eq!(
+        client.try_take_offer(&user, &offer_id, &1000),
+        Err(Ok(FixedRateError::BorrowCapExceeded))
+    );
+
+    client.set_user_borrow_cap(&risk_manager, &user, &None);
+    assert_eq!(client.get_user_borrow_cap(&user), None);
+    client.take_offer(&user, &offer_id, &1000);
+}
+
+#[test]
+fn test_governed_protocol_parameters() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = LendingContractClient::new(&env, &env.register(LendingContract, ()));
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &InitConfig::new(&env, 1_000_000_000, 1000));
+    assert_eq!(client.get_min_collateral_ratio(), 15000);
+    assert_eq!(client.get_base_interest_rate(), 500);
+
+    // Require 200% collateral and charge 10% a year
+    client.set_min_collateral_ratio(&admin, &20000);
+    client.set_base_interest_rate(&admin, &1000);
+    client.set_default_min_borrow(&admin, &100);
+    let user = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let collateral_asset = Address::generate(&env);
+    assert_eq!(
+        client.try_borrow(&user, &asset, &500, &collateral_asset, &999),
+        Err(Ok(BorrowError::InsufficientCollateral))
+    );
+    client.borrow(&user, &asset, &500, &collateral_asset, &1000);
+    env.ledger().with_mut(|li| li.timestamp += 31_536_000);
+    assert_eq!(client.get_user_debt(&user).interest_accrued, 50);
+
+    assert_eq!(
+        client.try_set_min_collateral_ratio(&admin, &10000),
+        Err(Ok(BorrowError::InvalidConfig))
+    );
+    assert_eq!(
+        client.try_set_base_interest_rate(&admin, &10001),
+        Err(Ok(BorrowError::InvalidConfig))
+    );
+    assert_eq!(
+        client.try_set_base_interest_rate(&user, &600),
+        Err(Ok(BorrowError::Unauthorized))
+    );
+}
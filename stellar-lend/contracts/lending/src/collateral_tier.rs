@@ -0,0 +1,164 @@
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol};
+
+use crate::admin::require_admin;
+use crate::borrow::{is_valid_collateral_params, CollateralParams, CollateralPosition};
+
+/// Errors that can occur when managing collateral tiers
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum TierError {
+    Unauthorized = 1,
+    InvalidConfig = 2,
+    TierNotConfigured = 3,
+}
+
+/// Risk tier of a collateral asset
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum CollateralTier {
+    BlueChip,
+    Mid,
+    Experimental,
+}
+
+/// Storage keys for collateral tier data
+#[contracttype]
+#[derive(Clone)]
+pub enum TierDataKey {
+    TierConfig(CollateralTier),
+    AssetTier(Address),
+    /// Fungible collateral deposited across all assets of a tier
+    TierExposure(CollateralTier),
+}
+
+/// Defaults and limits shared by the assets of a tier
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TierConfig {
+    /// Risk parameters for assets without their own
+    pub params: CollateralParams,
+    /// Maximum collateral deposited across the tier's assets
+    pub exposure_cap: i128,
+}
+
+/// Tier assignment event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct AssetTierEvent {
+    pub asset: Address,
+    pub tier: CollateralTier,
+    pub timestamp: u64,
+}
+
+/// Configure a collateral tier (admin only)
+pub fn set_tier_config(
+    env: &Env,
+    caller: Address,
+    tier: CollateralTier,
+    config: TierConfig,
+) -> Result<(), TierError> {
+    require_admin(env, &caller).map_err(|_| TierError::Unauthorized)?;
+
+    if !is_valid_collateral_params(&config.params) || config.exposure_cap <= 0 {
+        return Err(TierError::InvalidConfig);
+    }
+    env.storage()
+        .persistent()
+        .set(&TierDataKey::TierConfig(tier), &config);
+    Ok(())
+}
+
+/// Place a collateral asset in a tier (admin only)
+///
+/// The asset inherits the tier's risk parameters unless it has its own, and
+/// counts towards the tier's exposure cap. Collateral already deposited in the
+/// asset is not moved between tiers; assign tiers when listing an asset.
+pub fn assign_asset_tier(
+    env: &Env,
+    caller: Address,
+    asset: Address,
+    tier: CollateralTier,
+) -> Result<(), TierError> {
+    require_admin(env, &caller).map_err(|_| TierError::Unauthorized)?;
+
+    if get_tier_config(env, tier).is_none() {
+        return Err(TierError::TierNotConfigured);
+    }
+    env.storage()
+        .persistent()
+        .set(&TierDataKey::AssetTier(asset.clone()), &tier);
+
+    let event = AssetTierEvent {
+        asset,
+        tier,
+        timestamp: env.ledger().timestamp(),
+    };
+    crate::events::publish(
+        env,
+        (Symbol::new(env, "asset_tier_assigned"), event.asset.clone()),
+        event,
+    );
+    Ok(())
+}
+
+/// Get a tier's configuration, if set
+pub fn get_tier_config(env: &Env, tier: CollateralTier) -> Option<TierConfig> {
+    env.storage()
+        .persistent()
+        .get(&TierDataKey::TierConfig(tier))
+}
+
+/// Get the tier of a collateral asset, if assigned
+pub fn get_asset_tier(env: &Env, asset: &Address) -> Option<CollateralTier> {
+    env.storage()
+        .persistent()
+        .get(&TierDataKey::AssetTier(asset.clone()))
+}
+
+/// Get the collateral deposited across a tier's assets
+pub fn get_tier_exposure(env: &Env, tier: CollateralTier) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&TierDataKey::TierExposure(tier))
+        .unwrap_or(0)
+}
+
+/// Default risk parameters of an asset's tier
+pub(crate) fn tier_params(env: &Env, asset: &Address) -> Option<CollateralParams> {
+    let tier = get_asset_tier(env, asset)?;
+    get_tier_config(env, tier).map(|config| config.params)
+}
+
+/// Whether depositing `amount` more of an asset would breach its tier's cap
+pub(crate) fn exceeds_exposure_cap(env: &Env, asset: &Address, amount: i128) -> bool {
+    let Some(tier) = get_asset_tier(env, asset) else {
+        return false;
+    };
+    match get_tier_config(env, tier) {
+        Some(config) => get_tier_exposure(env, tier).saturating_add(amount) > config.exposure_cap,
+        None => false,
+    }
+}
+
+/// Move tier exposure to follow a change in a user's fungible collateral
+pub(crate) fn on_collateral_changed(
+    env: &Env,
+    previous: &CollateralPosition,
+    current: &CollateralPosition,
+) {
+    adjust_exposure(env, &previous.asset, -previous.amount);
+    adjust_exposure(env, &current.asset, current.amount);
+}
+
+fn adjust_exposure(env: &Env, asset: &Address, delta: i128) {
+    if delta == 0 {
+        return;
+    }
+    if let Some(tier) = get_asset_tier(env, asset) {
+        let exposure = get_tier_exposure(env, tier).saturating_add(delta).max(0);
+        env.storage()
+            .persistent()
+            .set(&TierDataKey::TierExposure(tier), &exposure);
+    }
+}
@@ -0,0 +1,128 @@
+use super::*;
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+fn setup(env: &Env) -> (LendingContractClient<'_>, Address) {
+    env.mock_all_auths();
+    let client = LendingContractClient::new(env, &env.register(LendingContract, ()));
+    client.initialize(
+        &Address::generate(env),
+        &InitConfig::new(env, 1_000_000_000, 1000),
+    );
+
+    let admin = Address::generate(env);
+    client.set_admin(&admin);
+    client.set_tier_config(
+        &admin,
+        &CollateralTier::Experimental,
+        &TierConfig {
+            params: CollateralParams {
+                ltv_bps: 5000,
+                liquidation_threshold_bps: 6000,
+            },
+            exposure_cap: 50_000,
+        },
+    );
+    (client, admin)
+}
+
+#[test]
+fn test_listed_asset_inherits_tier_defaults() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let token = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    client.assign_asset_tier(&admin, &token, &CollateralTier::Experimental);
+    assert_eq!(
+        client.get_asset_tier(&token),
+        Some(CollateralTier::Experimental)
+    );
+    assert_eq!(client.get_collateral_params(&token).unwrap().ltv_bps, 5000);
+
+    // 50% LTV requires twice the borrowed amount
+    assert_eq!(
+        client.try_borrow(&user, &Address::generate(&env), &10_000, &token, &19_999),
+        Err(Ok(BorrowError::InsufficientCollateral))
+    );
+    client.borrow(&user, &Address::generate(&env), &10_000, &token, &20_000);
+    assert_eq!(client.get_liquidation_ratio(&user), 16_667);
+}
+
+#[test]
+fn test_asset_params_override_tier_defaults() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let token = Address::generate(&env);
+
+    client.assign_asset_tier(&admin, &token, &CollateralTier::Experimental);
+    let params = CollateralParams {
+        ltv_bps: 4000,
+        liquidation_threshold_bps: 4500,
+    };
+    client.set_collateral_params(&admin, &token, &params);
+    assert_eq!(client.get_collateral_params(&token), Some(params));
+}
+
+#[test]
+fn test_tier_exposure_cap_bounds_deposits() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let token_a = Address::generate(&env);
+    let token_b = Address::generate(&env);
+    client.assign_asset_tier(&admin, &token_a, &CollateralTier::Experimental);
+    client.assign_asset_tier(&admin, &token_b, &CollateralTier::Experimental);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    client.borrow(&alice, &Address::generate(&env), &10_000, &token_a, &30_000);
+
+    // The cap spans every asset in the tier
+    assert_eq!(
+        client.try_borrow(&bob, &Address::generate(&env), &10_000, &token_b, &20_001),
+        Err(Ok(BorrowError::ExposureCapReached))
+    );
+    client.borrow(&bob, &Address::generate(&env), &10_000, &token_b, &20_000);
+    assert_eq!(
+        client.get_tier_exposure(&CollateralTier::Experimental),
+        50_000
+    );
+
+    // Withdrawals free up capacity
+    client.withdraw_collateral(&alice, &5000);
+    assert_eq!(
+        client.get_tier_exposure(&CollateralTier::Experimental),
+        45_000
+    );
+}
+
+#[test]
+fn test_tier_config_validation() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let config = client
+        .get_tier_config(&CollateralTier::Experimental)
+        .unwrap();
+
+    assert_eq!(
+        client.try_set_tier_config(&Address::generate(&env), &CollateralTier::Mid, &config),
+        Err(Ok(TierError::Unauthorized))
+    );
+    let mut invalid = config.clone();
+    invalid.exposure_cap = 0;
+    assert_eq!(
+        client.try_set_tier_config(&admin, &CollateralTier::Mid, &invalid),
+        Err(Ok(TierError::InvalidConfig))
+    );
+    let mut invalid = config;
+    invalid.params.liquidation_threshold_bps = invalid.params.ltv_bps;
+    assert_eq!(
+        client.try_set_tier_config(&admin, &CollateralTier::Mid, &invalid),
+        Err(Ok(TierError::InvalidConfig))
+    );
+
+    // Assets can only join configured tiers
+    assert_eq!(
+        client.try_assign_asset_tier(&admin, &Address::generate(&env), &CollateralTier::BlueChip),
+        Err(Ok(TierError::TierNotConfigured))
+    );
+}
@@ -0,0 +1,291 @@
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol, Vec};
+
+use crate::admin::{
+    get_admin, get_guardian, get_risk_manager, require_admin, store_admin, store_guardian,
+    store_risk_manager,
+};
+use crate::asset_mode::{apply_asset_mode, AssetMode};
+use crate::borrow::{
+    apply_asset_debt_ceiling, apply_asset_min_borrow, apply_base_interest_rate,
+    apply_collateral_params, apply_debt_ceiling, apply_default_min_borrow,
+    apply_min_collateral_ratio, get_base_interest_rate, get_debt_ceiling, get_default_min_borrow,
+    get_min_collateral_ratio, get_pause_info, store_base_interest_rate, CollateralParams,
+    MinBorrowConfig, INTEREST_RATE_PER_YEAR,
+};
+use crate::oracle::{apply_oracle_feed_config, OracleFeedConfig};
+use crate::rate_model::{
+    apply_borrow_rate_bounds, apply_borrow_rate_model, get_borrow_rate, get_borrow_rate_bounds,
+    get_borrow_rate_model, is_valid_rate_model, store_borrow_rate_model, BorrowRateBounds,
+    BorrowRateModel,
+};
+
+/// Errors that can occur when applying batch configuration changes
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ConfigError {
+    Unauthorized = 1,
+    InvalidConfig = 2,
+    AlreadyInitialized = 3,
+}
+
+/// Storage keys for protocol configuration state
+#[contracttype]
+#[derive(Clone)]
+pub enum ConfigDataKey {
+    Initialized,
+}
+
+/// An asset listed at initialization
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct InitAsset {
+    pub asset: Address,
+    pub collateral_params: CollateralParams,
+    /// Maximum principal that may be borrowed in the asset
+    pub debt_ceiling: i128,
+    pub oracle_feed: OracleFeedConfig,
+}
+
+/// Full initial protocol configuration
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct InitConfig {
+    /// Maximum total debt allowed in the protocol
+    pub debt_ceiling: i128,
+    /// Minimum borrow for assets without their own minimum
+    pub min_borrow_amount: i128,
+    pub risk_manager: Option<Address>,
+    pub guardian: Option<Address>,
+    /// Borrow rate model; one without slopes sets the base interest rate
+    /// instead, so that it stays governable
+    pub borrow_rate_model: BorrowRateModel,
+    pub assets: Vec<InitAsset>,
+}
+
+impl InitConfig {
+    /// Config with the given caps and defaults for everything else: no
+    /// roles besides the admin, the default 5% base interest rate and no
+    /// listed assets
+    pub fn new(env: &Env, debt_ceiling: i128, min_borrow_amount: i128) -> Self {
+        InitConfig {
+            debt_ceiling,
+            min_borrow_amount,
+            risk_manager: None,
+            guardian: None,
+            borrow_rate_model: BorrowRateModel {
+                base_rate_bps: INTEREST_RATE_PER_YEAR,
+                slope_bps: 0,
+                optimal_utilization_bps: 10000,
+                jump_slope_bps: 0,
+            },
+            assets: Vec::new(env),
+        }
+    }
+}
+
+/// One parameter update applied by `set_config`
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConfigChange {
+    DebtCeiling(i128),
+    MinBorrowAmount(i128),
+    MinCollateralRatio(i128),
+    BaseInterestRate(i128),
+    AssetDebtCeiling(Address, i128),
+    AssetMinBorrow(Address, MinBorrowConfig),
+    CollateralParams(Address, CollateralParams),
+    AssetMode(Address, AssetMode),
+    BorrowRateModel(BorrowRateModel),
+    BorrowRateBounds(BorrowRateBounds),
+    RiskManager(Address),
+    Guardian(Address),
+}
+
+/// Batch configuration event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ConfigUpdatedEvent {
+    pub admin: Address,
+    pub changes: u32,
+    pub timestamp: u64,
+}
+
+/// Protocol-wide configuration in force
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProtocolConfig {
+    pub admin: Option<Address>,
+    pub risk_manager: Option<Address>,
+    pub guardian: Option<Address>,
+    /// Global debt ceiling in force (dynamic when configured)
+    pub debt_ceiling: i128,
+    /// Minimum borrow for assets without their own minimum
+    pub min_borrow_amount: i128,
+    /// Collateral ratio for assets without risk parameters, in basis points
+    pub min_collateral_ratio_bps: i128,
+    /// Borrow rate model; a flat model at the base interest rate when unset
+    pub borrow_rate_model: BorrowRateModel,
+    /// Borrow rate bounds; unbounded when unset
+    pub borrow_rate_bounds: BorrowRateBounds,
+    /// Current pool borrow rate, in basis points per year
+    pub borrow_rate_bps: i128,
+    /// Protocol-wide borrow pause flag
+    pub paused: bool,
+    /// Paused operations as a bitmask of `PAUSE_*` flags
+    pub paused_operations: u32,
+    pub shutdown: bool,
+    pub initialized: bool,
+}
+
+/// Get the protocol-wide configuration in one struct
+pub fn get_config(env: &Env) -> ProtocolConfig {
+    let borrow_rate_model = get_borrow_rate_model(env).unwrap_or(BorrowRateModel {
+        base_rate_bps: get_base_interest_rate(env),
+        slope_bps: 0,
+        optimal_utilization_bps: 10000,
+        jump_slope_bps: 0,
+    });
+    let borrow_rate_bounds = get_borrow_rate_bounds(env).unwrap_or(BorrowRateBounds {
+        floor_bps: 0,
+        cap_bps: i128::MAX,
+    });
+
+    ProtocolConfig {
+        admin: get_admin(env),
+        risk_manager: get_risk_manager(env),
+        guardian: get_guardian(env),
+        debt_ceiling: get_debt_ceiling(env),
+        min_borrow_amount: get_default_min_borrow(env),
+        min_collateral_ratio_bps: get_min_collateral_ratio(env),
+        borrow_rate_model,
+        borrow_rate_bounds,
+        borrow_rate_bps: get_borrow_rate(env),
+        paused: get_pause_info(env).is_some_and(|info| info.paused),
+        paused_operations: crate::pause::get_paused_operations(env),
+        shutdown: crate::shutdown::is_shutdown(env),
+        initialized: is_initialized(env),
+    }
+}
+
+/// Initialize the protocol with a full configuration
+///
+/// Rejected once the protocol is initialized or an admin has been claimed.
+/// Every part of the config is validated like its individual setter; if any
+/// part is invalid the call fails and nothing is stored.
+pub fn initialize(env: &Env, admin: Address, config: InitConfig) -> Result<(), ConfigError> {
+    admin.require_auth();
+    if is_initialized(env) || get_admin(env).is_some() {
+        return Err(ConfigError::AlreadyInitialized);
+    }
+
+    // Nothing has accrued yet, so rates are stored without an index checkpoint
+    let model = config.borrow_rate_model;
+    let rate_stored = if model.slope_bps == 0 && model.jump_slope_bps == 0 {
+        store_base_interest_rate(env, model.base_rate_bps).is_ok()
+    } else {
+        let valid = is_valid_rate_model(&model);
+        if valid {
+            store_borrow_rate_model(env, &model);
+        }
+        valid
+    };
+    let mut valid = rate_stored
+        && apply_debt_ceiling(env, config.debt_ceiling).is_ok()
+        && apply_default_min_borrow(env, config.min_borrow_amount).is_ok();
+    let mut listed: Vec<Address> = Vec::new(env);
+    for asset in config.assets.iter() {
+        valid = valid
+            && !listed.contains(&asset.asset)
+            && apply_collateral_params(env, asset.asset.clone(), asset.collateral_params).is_ok()
+            && apply_asset_debt_ceiling(env, asset.asset.clone(), asset.debt_ceiling).is_ok()
+            && apply_oracle_feed_config(env, asset.asset.clone(), asset.oracle_feed).is_ok();
+        listed.push_back(asset.asset);
+    }
+    if !valid {
+        return Err(ConfigError::InvalidConfig);
+    }
+
+    store_admin(env, &admin);
+    if let Some(manager) = config.risk_manager {
+        store_risk_manager(env, &manager);
+    }
+    if let Some(guardian) = config.guardian {
+        store_guardian(env, &guardian);
+    }
+    env.storage()
+        .persistent()
+        .set(&ConfigDataKey::Initialized, &true);
+    crate::version::record_version(env);
+
+    crate::events::publish(env, (Symbol::new(env, "initialized"),), admin);
+    Ok(())
+}
+
+/// Whether `initialize` has completed
+pub fn is_initialized(env: &Env) -> bool {
+    env.storage()
+        .persistent()
+        .get(&ConfigDataKey::Initialized)
+        .unwrap_or(false)
+}
+
+/// Apply several configuration changes atomically (admin only)
+///
+/// Changes are validated like their individual setters and applied in order.
+/// If any change is invalid the call fails and none of them take effect.
+pub fn set_config(
+    env: &Env,
+    caller: Address,
+    changes: Vec<ConfigChange>,
+) -> Result<(), ConfigError> {
+    require_admin(env, &caller).map_err(|_| ConfigError::Unauthorized)?;
+    if changes.is_empty() {
+        return Err(ConfigError::InvalidConfig);
+    }
+
+    for change in changes.iter() {
+        let applied = match change {
+            ConfigChange::DebtCeiling(ceiling) => apply_debt_ceiling(env, ceiling).is_ok(),
+            ConfigChange::MinBorrowAmount(amount) => apply_default_min_borrow(env, amount).is_ok(),
+            ConfigChange::MinCollateralRatio(ratio) => {
+                apply_min_collateral_ratio(env, ratio).is_ok()
+            }
+            ConfigChange::BaseInterestRate(rate) => apply_base_interest_rate(env, rate).is_ok(),
+            ConfigChange::AssetDebtCeiling(asset, ceiling) => {
+                apply_asset_debt_ceiling(env, asset, ceiling).is_ok()
+            }
+            ConfigChange::AssetMinBorrow(asset, config) => {
+                apply_asset_min_borrow(env, asset, config).is_ok()
+            }
+            ConfigChange::CollateralParams(asset, params) => {
+                apply_collateral_params(env, asset, params).is_ok()
+            }
+            ConfigChange::BorrowRateModel(model) => apply_borrow_rate_model(env, model).is_ok(),
+            ConfigChange::BorrowRateBounds(bounds) => apply_borrow_rate_bounds(env, bounds).is_ok(),
+            ConfigChange::AssetMode(asset, mode) => {
+                apply_asset_mode(env, asset, mode);
+                true
+            }
+            ConfigChange::RiskManager(manager) => {
+                store_risk_manager(env, &manager);
+                true
+            }
+            ConfigChange::Guardian(guardian) => {
+                store_guardian(env, &guardian);
+                true
+            }
+        };
+        if !applied {
+            return Err(ConfigError::InvalidConfig);
+        }
+    }
+
+    let event = ConfigUpdatedEvent {
+        admin: caller,
+        changes: changes.len(),
+        timestamp: env.ledger().timestamp(),
+    };
+    crate::events::publish(env, (Symbol::new(env, "config_updated"),), event);
+    Ok(())
+}
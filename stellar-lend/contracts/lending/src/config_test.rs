@@ -0,0 +1,213 @@
+use super::*;
+use soroban_sdk::{testutils::Address as _, Address, Env, Symbol, Vec};
+
+#[test]
+fn test_config_defaults() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = LendingContractClient::new(&env, &env.register(LendingContract, ()));
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &InitConfig::new(&env, 1_000_000, 500));
+
+    let config = client.get_config();
+    assert!(config.initialized);
+    assert_eq!(config.admin, Some(admin));
+    assert_eq!(config.guardian, None);
+    assert_eq!(config.debt_ceiling, 1_000_000);
+    assert_eq!(config.min_borrow_amount, 500);
+    assert_eq!(config.min_collateral_ratio_bps, 15000);
+    assert_eq!(config.borrow_rate_bps, 500);
+    assert_eq!(config.borrow_rate_model.base_rate_bps, 500);
+    assert_eq!(config.borrow_rate_bounds.cap_bps, i128::MAX);
+    assert!(!config.paused && !config.shutdown);
+    assert_eq!(config.paused_operations, 0);
+}
+
+#[test]
+fn test_config_reflects_updates() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = LendingContractClient::new(&env, &env.register(LendingContract, ()));
+    client.initialize(
+        &Address::generate(&env),
+        &InitConfig::new(&env, 1_000_000, 500),
+    );
+    let admin = Address::generate(&env);
+    let guardian = Address::generate(&env);
+    client.set_admin(&admin);
+    client.set_guardian(&admin, &guardian);
+
+    let model = BorrowRateModel {
+        base_rate_bps: 200,
+        slope_bps: 400,
+        optimal_utilization_bps: 8000,
+        jump_slope_bps: 6000,
+    };
+    client.set_borrow_rate_model(&admin, &model);
+    client.set_paused(&admin, &true, &Symbol::new(&env, "oracle"));
+    client.set_paused_operations(&admin, &(PAUSE_SUPPLY | PAUSE_REPAY));
+
+    let config = client.get_config();
+    assert_eq!(config.admin, Some(admin));
+    assert_eq!(config.guardian, Some(guardian));
+    assert_eq!(config.borrow_rate_model, model);
+    assert_eq!(config.borrow_rate_bps, 200);
+    assert!(config.paused);
+    assert_eq!(config.paused_operations, PAUSE_SUPPLY | PAUSE_REPAY);
+}
+
+fn setup_admin(env: &Env) -> (LendingContractClient<'_>, Address) {
+    env.mock_all_auths();
+    let client = LendingContractClient::new(env, &env.register(LendingContract, ()));
+    client.initialize(
+        &Address::generate(env),
+        &InitConfig::new(env, 1_000_000, 500),
+    );
+    let admin = Address::generate(env);
+    client.set_admin(&admin);
+    (client, admin)
+}
+
+#[test]
+fn test_set_config_applies_batch() {
+    let env = Env::default();
+    let (client, admin) = setup_admin(&env);
+    let asset = Address::generate(&env);
+    let manager = Address::generate(&env);
+    let bounds = BorrowRateBounds {
+        floor_bps: 100,
+        cap_bps: 2000,
+    };
+
+    client.set_config(
+        &admin,
+        &Vec::from_array(
+            &env,
+            [
+                ConfigChange::DebtCeiling(2_000_000),
+                ConfigChange::MinBorrowAmount(100),
+                ConfigChange::AssetDebtCeiling(asset.clone(), 50_000),
+                ConfigChange::BorrowRateBounds(bounds.clone()),
+                ConfigChange::RiskManager(manager.clone()),
+            ],
+        ),
+    );
+
+    let config = client.get_config();
+    assert_eq!(config.debt_ceiling, 2_000_000);
+    assert_eq!(config.min_borrow_amount, 100);
+    assert_eq!(config.borrow_rate_bounds, bounds);
+    assert_eq!(config.risk_manager, Some(manager));
+    assert_eq!(client.get_asset_debt_ceiling(&asset), Some(50_000));
+}
+
+#[test]
+fn test_set_config_is_all_or_nothing() {
+    let env = Env::default();
+    let (client, admin) = setup_admin(&env);
+    let changes = Vec::from_array(
+        &env,
+        [
+            ConfigChange::DebtCeiling(2_000_000),
+            ConfigChange::CollateralParams(
+                Address::generate(&env),
+                CollateralParams {
+                    ltv_bps: 9000,
+                    liquidation_threshold_bps: 8000,
+                },
+            ),
+        ],
+    );
+
+    assert_eq!(
+        client.try_set_config(&admin, &changes),
+        Err(Ok(ConfigError::InvalidConfig))
+    );
+    assert_eq!(client.get_config().debt_ceiling, 1_000_000);
+
+    let outsider = Address::generate(&env);
+    assert_eq!(
+        client.try_set_config(&outsider, &changes),
+        Err(Ok(ConfigError::Unauthorized))
+    );
+}
+
+fn init_asset(env: &Env, ltv_bps: i128) -> InitAsset {
+    InitAsset {
+        asset: Address::generate(env),
+        collateral_params: CollateralParams {
+            ltv_bps,
+            liquidation_threshold_bps: 8000,
+        },
+        debt_ceiling: 100_000,
+        oracle_feed: OracleFeedConfig {
+            feed: Address::generate(env),
+            quote: Symbol::new(env, "USD"),
+            decimals: 7,
+            heartbeat: 3600,
+            max_deviation_bps: 0,
+        },
+    }
+}
+
+#[test]
+fn test_initialize_lists_assets_and_roles() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = LendingContractClient::new(&env, &env.register(LendingContract, ()));
+    let admin = Address::generate(&env);
+    let guardian = Address::generate(&env);
+    let asset = init_asset(&env, 7000);
+
+    let mut config = InitConfig::new(&env, 1_000_000, 500);
+    config.guardian = Some(guardian.clone());
+    config.assets.push_back(asset.clone());
+    client.initialize(&admin, &config);
+
+    assert_eq!(client.get_config().guardian, Some(guardian));
+    assert_eq!(
+        client.get_collateral_params(&asset.asset),
+        Some(asset.collateral_params)
+    );
+    assert_eq!(client.get_asset_debt_ceiling(&asset.asset), Some(100_000));
+    assert_eq!(
+        client.get_oracle_feed_config(&asset.asset),
+        Some(asset.oracle_feed)
+    );
+
+    assert_eq!(
+        client.try_initialize(&admin, &config),
+        Err(Ok(ConfigError::AlreadyInitialized))
+    );
+}
+
+#[test]
+fn test_invalid_initialization_stores_nothing() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = LendingContractClient::new(&env, &env.register(LendingContract, ()));
+    let admin = Address::generate(&env);
+
+    let mut config = InitConfig::new(&env, 1_000_000, 500);
+    config.assets.push_back(init_asset(&env, 7000));
+    // LTV above the liquidation threshold
+    config.assets.push_back(init_asset(&env, 9000));
+    assert_eq!(
+        client.try_initialize(&admin, &config),
+        Err(Ok(ConfigError::InvalidConfig))
+    );
+    let state = client.get_config();
+    assert!(!state.initialized);
+    assert_eq!(state.admin, None);
+    assert_eq!(
+        client.get_collateral_params(&config.assets.get(0).unwrap().asset),
+        None
+    );
+
+    // The admin role cannot be claimed outside initialization
+    assert_eq!(
+        client.try_set_admin(&admin),
+        Err(Ok(AdminError::NotInitialized))
+    );
+    assert_eq!(client.get_admin(), None);
+}
@@ -0,0 +1,438 @@
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol};
+
+use crate::admin::require_risk_manager;
+use crate::borrow::{
+    adjust_asset_debt, check_new_debt, get_collateral_ratio, get_total_collateral_value,
+    get_total_debt, get_total_user_debt, set_total_debt, BorrowError, SECONDS_PER_YEAR,
+};
+use crate::math::{div_up, mul_div_up};
+
+/// Errors that can occur during credit line operations
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum CreditLineError {
+    Unauthorized = 1,
+    InvalidConfig = 2,
+    NoCreditLine = 3,
+    CreditLineInactive = 4,
+    LimitExceeded = 5,
+    InsufficientCollateral = 6,
+    InvalidAmount = 7,
+    NoDebt = 8,
+    ProtocolPaused = 9,
+    Overflow = 10,
+    /// The account is under a compliance freeze
+    AccountFrozen = 11,
+    BorrowCapExceeded = 12,
+    PriceStale = 13,
+    MarketFrozen = 14,
+    AssetBorrowsPaused = 15,
+    AssetDelisted = 16,
+    BorrowFrozen = 17,
+    AssetCollateralOnly = 18,
+    DebtCeilingReached = 19,
+    LiquidityBufferReached = 20,
+    RehypothecationDisabled = 21,
+    /// The draw must go through the pool's `request_borrow`
+    LargeBorrowRequestRequired = 22,
+    /// The draw is valued above the per-call maximum
+    BorrowSizeCapExceeded = 23,
+}
+
+/// Storage keys for credit line data
+#[contracttype]
+#[derive(Clone)]
+pub enum CreditLineDataKey {
+    UserCreditLine(Address),
+    TotalCreditDrawn,
+    CreditLosses,
+}
+
+/// An underwritten credit line, tracked separately from the pooled book
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreditLine {
+    /// Asset the line is drawn in
+    pub asset: Address,
+    /// Maximum principal that may be drawn
+    pub limit: i128,
+    /// Collateral required per unit drawn, in basis points (0 = unsecured)
+    pub collateral_bps: i128,
+    /// Annual interest rate in basis points
+    pub rate_bps: i128,
+    pub drawn: i128,
+    pub interest_accrued: i128,
+    pub last_update: u64,
+    /// Inactive lines accept repayments but no new draws
+    pub active: bool,
+}
+
+/// Credit line event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct CreditLineEvent {
+    pub borrower: Address,
+    pub asset: Address,
+    pub amount: i128,
+    pub drawn: i128,
+    pub timestamp: u64,
+}
+
+const MAX_CREDIT_COLLATERAL_BPS: i128 = 15000; // Never stricter than the pool
+const MAX_CREDIT_RATE_BPS: i128 = 10000; // 100% APR
+
+/// Open or update a credit line for a vetted borrower (risk manager only)
+///
+/// Updating a line keeps its drawn balance; lowering the limit below the
+/// drawn balance only blocks further draws. A line's asset can only change
+/// while nothing is drawn.
+pub fn open_credit_line(
+    env: &Env,
+    caller: Address,
+    borrower: Address,
+    asset: Address,
+    limit: i128,
+    collateral_bps: i128,
+    rate_bps: i128,
+) -> Result<(), CreditLineError> {
+    require_risk_manager(env, &caller).map_err(|_| CreditLineError::Unauthorized)?;
+
+    if limit <= 0
+        || !(0..=MAX_CREDIT_COLLATERAL_BPS).contains(&collateral_bps)
+        || !(0..=MAX_CREDIT_RATE_BPS).contains(&rate_bps)
+    {
+        return Err(CreditLineError::InvalidConfig);
+    }
+
+    let mut line = match get_stored_line(env, &borrower) {
+        Some(line) if line.asset != asset && line.drawn + line.interest_accrued > 0 => {
+            return Err(CreditLineError::InvalidConfig);
+        }
+        Some(line) => accrue(env, line),
+        None => CreditLine {
+            asset: asset.clone(),
+            limit,
+            collateral_bps,
+            rate_bps,
+            drawn: 0,
+            interest_accrued: 0,
+            last_update: env.ledger().timestamp(),
+            active: true,
+        },
+    };
+    line.asset = asset;
+    line.limit = limit;
+    line.collateral_bps = collateral_bps;
+    line.rate_bps = rate_bps;
+    line.active = true;
+    save_line(env, &borrower, &line);
+
+    emit_credit_event(
+        env,
+        "credit_line_opened",
+        borrower,
+        line.asset,
+        limit,
+        line.drawn,
+    );
+    Ok(())
+}
+
+/// Stop further draws on a credit line (risk manager only)
+pub fn revoke_credit_line(
+    env: &Env,
+    caller: Address,
+    borrower: Address,
+) -> Result<(), CreditLineError> {
+    require_risk_manager(env, &caller).map_err(|_| CreditLineError::Unauthorized)?;
+
+    let mut line = get_stored_line(env, &borrower).ok_or(CreditLineError::NoCreditLine)?;
+    line.active = false;
+    save_line(env, &borrower, &line);
+
+    emit_credit_event(
+        env,
+        "credit_line_revoked",
+        borrower,
+        line.asset,
+        0,
+        line.drawn,
+    );
+    Ok(())
+}
+
+/// Draw on a credit line
+///
+/// Draws face the same pause, asset and ceiling checks as pool borrows of the
+/// line's asset, and drawn principal counts toward the pool's total and asset
+/// debt. The borrower's collateral must cover their other debt at the pool
+/// ratio plus the line's own collateral requirement on the drawn balance.
+pub fn draw_credit(env: &Env, borrower: Address, amount: i128) -> Result<(), CreditLineError> {
+    borrower.require_auth();
+
+    if amount <= 0 {
+        return Err(CreditLineError::InvalidAmount);
+    }
+    let mut line = accrue(
+        env,
+        get_stored_line(env, &borrower).ok_or(CreditLineError::NoCreditLine)?,
+    );
+    if !line.active {
+        return Err(CreditLineError::CreditLineInactive);
+    }
+    let (new_total, new_asset_debt) =
+        check_new_debt(env, &borrower, &line.asset, amount).map_err(debt_error)?;
+
+    let drawn = line
+        .drawn
+        .checked_add(amount)
+        .ok_or(CreditLineError::Overflow)?;
+    if drawn > line.limit {
+        return Err(CreditLineError::LimitExceeded);
+    }
+
+    // Round the requirement up so the protocol never under-collateralizes
+    let required = get_total_user_debt(env, &borrower)
+        .checked_sub(line.drawn + line.interest_accrued)
+        .and_then(|other| other.checked_mul(get_collateral_ratio(env, &borrower)))
+        .and_then(|pool| {
+            drawn
+                .checked_mul(line.collateral_bps)
+                .and_then(|credit| pool.checked_add(credit))
+        })
+        .map(|required| div_up(required, 10000))
+        .ok_or(CreditLineError::Overflow)?;
+    if get_total_collateral_value(env, &borrower) < required {
+        return Err(CreditLineError::InsufficientCollateral);
+    }
+
+    line.drawn = drawn;
+    save_line(env, &borrower, &line);
+    adjust_total_drawn(env, amount);
+    set_total_debt(env, new_total);
+    adjust_asset_debt(env, &line.asset, amount);
+    crate::debt_ceiling::record_debt_growth(
+        env,
+        &line.asset,
+        new_asset_debt - amount,
+        new_asset_debt,
+    );
+
+    emit_credit_event(env, "credit_drawn", borrower, line.asset, amount, drawn);
+    Ok(())
+}
+
+/// Repay a credit line, interest first
+///
+/// # Returns
+/// The remaining balance (drawn principal plus interest)
+pub fn repay_credit(env: &Env, borrower: Address, amount: i128) -> Result<i128, CreditLineError> {
+    borrower.require_auth();
+
+    if amount <= 0 {
+        return Err(CreditLineError::InvalidAmount);
+    }
+    let mut line = accrue(
+        env,
+        get_stored_line(env, &borrower).ok_or(CreditLineError::NoCreditLine)?,
+    );
+    let owed = line
+        .drawn
+        .checked_add(line.interest_accrued)
+        .ok_or(CreditLineError::Overflow)?;
+    if owed == 0 {
+        return Err(CreditLineError::NoDebt);
+    }
+
+    let payment = amount.min(owed);
+    let interest_paid = payment.min(line.interest_accrued);
+    let principal_paid = payment - interest_paid;
+    line.interest_accrued -= interest_paid;
+    line.drawn -= principal_paid;
+    save_line(env, &borrower, &line);
+    adjust_total_drawn(env, -principal_paid);
+    release_debt(env, &line.asset, principal_paid);
+
+    emit_credit_event(
+        env,
+        "credit_repaid",
+        borrower,
+        line.asset,
+        payment,
+        line.drawn,
+    );
+    Ok(line.drawn + line.interest_accrued)
+}
+
+/// Write off a defaulted credit line (risk manager only)
+///
+/// The outstanding balance is recorded as a credit line loss and the line is
+/// closed.
+///
+/// # Returns
+/// The amount written off
+pub fn write_off_credit_line(
+    env: &Env,
+    caller: Address,
+    borrower: Address,
+) -> Result<i128, CreditLineError> {
+    require_risk_manager(env, &caller).map_err(|_| CreditLineError::Unauthorized)?;
+
+    let line = accrue(
+        env,
+        get_stored_line(env, &borrower).ok_or(CreditLineError::NoCreditLine)?,
+    );
+    let loss = line
+        .drawn
+        .checked_add(line.interest_accrued)
+        .ok_or(CreditLineError::Overflow)?;
+
+    env.storage()
+        .persistent()
+        .remove(&CreditLineDataKey::UserCreditLine(borrower.clone()));
+    adjust_total_drawn(env, -line.drawn);
+    release_debt(env, &line.asset, line.drawn);
+    let losses = get_credit_line_losses(env).saturating_add(loss);
+    env.storage()
+        .persistent()
+        .set(&CreditLineDataKey::CreditLosses, &losses);
+
+    emit_credit_event(env, "credit_written_off", borrower, line.asset, loss, 0);
+    Ok(loss)
+}
+
+/// Get a borrower's credit line, including interest accrued since the last update
+pub fn get_credit_line(env: &Env, borrower: &Address) -> Option<CreditLine> {
+    get_stored_line(env, borrower).map(|line| accrue(env, line))
+}
+
+/// Drawn principal plus interest a borrower owes on their credit line
+///
+/// Counted alongside core debt wherever collateral coverage is evaluated.
+pub fn get_credit_debt(env: &Env, borrower: &Address) -> i128 {
+    get_credit_line(env, borrower)
+        .map(|line| line.drawn.saturating_add(line.interest_accrued))
+        .unwrap_or(0)
+}
+
+/// Total principal drawn across all credit lines
+pub fn get_credit_line_total_drawn(env: &Env) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&CreditLineDataKey::TotalCreditDrawn)
+        .unwrap_or(0)
+}
+
+/// Cumulative balances written off on defaulted credit lines
+pub fn get_credit_line_losses(env: &Env) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&CreditLineDataKey::CreditLosses)
+        .unwrap_or(0)
+}
+
+/// Accrue interest on the drawn balance, rounded up in favor of the protocol
+fn accrue(env: &Env, mut line: CreditLine) -> CreditLine {
+    let now = env.ledger().timestamp();
+    let elapsed = now.saturating_sub(line.last_update) as i128;
+    let interest = mul_div_up(
+        line.drawn.saturating_mul(line.rate_bps),
+        elapsed,
+        10000 * SECONDS_PER_YEAR as i128,
+    );
+    line.interest_accrued = line.interest_accrued.saturating_add(interest);
+    line.last_update = now;
+    line
+}
+
+fn get_stored_line(env: &Env, borrower: &Address) -> Option<CreditLine> {
+    env.storage()
+        .persistent()
+        .get(&CreditLineDataKey::UserCreditLine(borrower.clone()))
+}
+
+fn save_line(env: &Env, borrower: &Address, line: &CreditLine) {
+    env.storage()
+        .persistent()
+        .set(&CreditLineDataKey::UserCreditLine(borrower.clone()), line);
+}
+
+/// Remove repaid or written-off principal from the pool's debt totals
+fn release_debt(env: &Env, asset: &Address, principal: i128) {
+    set_total_debt(env, get_total_debt(env).saturating_sub(principal).max(0));
+    adjust_asset_debt(env, asset, -principal);
+}
+
+fn adjust_total_drawn(env: &Env, delta: i128) {
+    let total = get_credit_line_total_drawn(env)
+        .saturating_add(delta)
+        .max(0);
+    env.storage()
+        .persistent()
+        .set(&CreditLineDataKey::TotalCreditDrawn, &total);
+}
+
+/// Map a failed debt check onto the credit line error it surfaces as
+fn debt_error(error: BorrowError) -> CreditLineError {
+    match error {
+        BorrowError::ProtocolPaused => CreditLineError::ProtocolPaused,
+        BorrowError::AccountFrozen => CreditLineError::AccountFrozen,
+        BorrowError::BorrowCapExceeded => CreditLineError::BorrowCapExceeded,
+        BorrowError::PriceStale => CreditLineError::PriceStale,
+        BorrowError::MarketFrozen => CreditLineError::MarketFrozen,
+        BorrowError::AssetBorrowsPaused => CreditLineError::AssetBorrowsPaused,
+        BorrowError::AssetDelisted => CreditLineError::AssetDelisted,
+        BorrowError::BorrowFrozen => CreditLineError::BorrowFrozen,
+        BorrowError::AssetCollateralOnly => CreditLineError::AssetCollateralOnly,
+        BorrowError::DebtCeilingReached => CreditLineError::DebtCeilingReached,
+        BorrowError::LiquidityBufferReached => CreditLineError::LiquidityBufferReached,
+        BorrowError::RehypothecationDisabled => CreditLineError::RehypothecationDisabled,
+        BorrowError::LargeBorrowRequestRequired => CreditLineError::LargeBorrowRequestRequired,
+        BorrowError::BorrowSizeCapExceeded => CreditLineError::BorrowSizeCapExceeded,
+        BorrowError::Overflow => CreditLineError::Overflow,
+        BorrowError::InvalidAmount | BorrowError::BelowMinimumBorrow => {
+            CreditLineError::InvalidAmount
+        }
+        BorrowError::Unauthorized => CreditLineError::Unauthorized,
+        // Collateral-side, position and request errors are never returned by
+        // the debt check
+        BorrowError::InsufficientCollateral
+        | BorrowError::AssetNotSupported
+        | BorrowError::ExposureCapReached
+        | BorrowError::BelowMinimumCollateral
+        | BorrowError::CollateralFrozen
+        | BorrowError::AssetBorrowOnly => CreditLineError::InsufficientCollateral,
+        BorrowError::InvalidConfig
+        | BorrowError::TooManyPositions
+        | BorrowError::NoPendingBorrow
+        | BorrowError::BorrowDelayActive
+        | BorrowError::PendingBorrowExists => CreditLineError::InvalidConfig,
+    }
+}
+
+fn emit_credit_event(
+    env: &Env,
+    name: &str,
+    borrower: Address,
+    asset: Address,
+    amount: i128,
+    drawn: i128,
+) {
+    let event = CreditLineEvent {
+        borrower,
+        asset,
+        amount,
+        drawn,
+        timestamp: env.ledger().timestamp(),
+    };
+    crate::events::publish(
+        env,
+        (
+            Symbol::new(env, name),
+            event.borrower.clone(),
+            event.asset.clone(),
+        ),
+        event,
+    );
+}
@@ -0,0 +1,161 @@
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+
+const YEAR: u64 = 31536000;
+
+fn setup(env: &Env) -> (LendingContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 0);
+
+    let contract_id = env.register(LendingContract, ());
+    let client = LendingContractClient::new(env, &contract_id);
+    client.initialize(
+        &Address::generate(env),
+        &InitConfig::new(env, 1_000_000_000, 1000),
+    );
+
+    let admin = Address::generate(env);
+    let risk_manager = Address::generate(env);
+    client.set_admin(&admin);
+    client.set_risk_manager(&admin, &risk_manager);
+    (client, admin, risk_manager)
+}
+
+#[test]
+fn test_unsecured_credit_line() {
+    let env = Env::default();
+    let (client, _admin, risk_manager) = setup(&env);
+    let borrower = Address::generate(&env);
+    let asset = Address::generate(&env);
+
+    client.open_credit_line(&risk_manager, &borrower, &asset, &50_000, &0, &1000);
+    client.draw_credit(&borrower, &50_000);
+
+    let result = client.try_draw_credit(&borrower, &1);
+    assert_eq!(result, Err(Ok(CreditLineError::LimitExceeded)));
+
+    // Credit line debt is tracked on the line, not the pool position
+    assert_eq!(client.get_user_debt(&borrower).borrowed_amount, 0);
+    assert_eq!(client.get_credit_line_total_drawn(), 50_000);
+}
+
+#[test]
+fn test_partially_secured_line_requires_collateral() {
+    let env = Env::default();
+    let (client, _admin, risk_manager) = setup(&env);
+    let borrower = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let collateral_asset = Address::generate(&env);
+
+    // 30,000 collateral; 15,000 backs the 10,000 pool loan at 150%
+    client.borrow(&borrower, &asset, &10_000, &collateral_asset, &30_000);
+    client.open_credit_line(&risk_manager, &borrower, &asset, &100_000, &5000, &1000);
+
+    // The remaining 15,000 covers 30,000 drawn at 50%
+    let result = client.try_draw_credit(&borrower, &30_001);
+    assert_eq!(result, Err(Ok(CreditLineError::InsufficientCollateral)));
+    client.draw_credit(&borrower, &30_000);
+}
+
+#[test]
+fn test_repay_credit_interest_first() {
+    let env = Env::default();
+    let (client, _admin, risk_manager) = setup(&env);
+    let borrower = Address::generate(&env);
+    let asset = Address::generate(&env);
+
+    client.open_credit_line(&risk_manager, &borrower, &asset, &10_000, &0, &1000);
+    client.draw_credit(&borrower, &10_000);
+
+    env.ledger().with_mut(|li| li.timestamp = YEAR);
+    assert_eq!(
+        client.get_credit_line(&borrower).unwrap().interest_accrued,
+        1000
+    );
+
+    assert_eq!(client.repay_credit(&borrower, &1500), 9500);
+    let line = client.get_credit_line(&borrower).unwrap();
+    assert_eq!(line.interest_accrued, 0);
+    assert_eq!(line.drawn, 9500);
+}
+
+#[test]
+fn test_revoke_and_write_off() {
+    let env = Env::default();
+    let (client, _admin, risk_manager) = setup(&env);
+    let borrower = Address::generate(&env);
+    let asset = Address::generate(&env);
+
+    client.open_credit_line(&risk_manager, &borrower, &asset, &10_000, &0, &1000);
+    client.draw_credit(&borrower, &4000);
+
+    client.revoke_credit_line(&risk_manager, &borrower);
+    let result = client.try_draw_credit(&borrower, &1000);
+    assert_eq!(result, Err(Ok(CreditLineError::CreditLineInactive)));
+
+    env.ledger().with_mut(|li| li.timestamp = YEAR);
+    assert_eq!(client.write_off_credit_line(&risk_manager, &borrower), 4400);
+    assert_eq!(client.get_credit_line_losses(), 4400);
+    assert_eq!(client.get_credit_line_total_drawn(), 0);
+    assert_eq!(client.get_credit_line(&borrower), None);
+}
+
+#[test]
+fn test_only_risk_manager_or_admin_underwrites() {
+    let env = Env::default();
+    let (client, admin, _risk_manager) = setup(&env);
+    let borrower = Address::generate(&env);
+    let asset = Address::generate(&env);
+
+    let result = client.try_open_credit_line(&borrower, &borrower, &asset, &10_000, &0, &1000);
+    assert_eq!(result, Err(Ok(CreditLineError::Unauthorized)));
+
+    let result = client.try_open_credit_line(&admin, &borrower, &asset, &10_000, &15_001, &1000);
+    assert_eq!(result, Err(Ok(CreditLineError::InvalidConfig)));
+
+    client.open_credit_line(&admin, &borrower, &asset, &10_000, &0, &1000);
+}
+
+#[test]
+fn test_draw_credit_applies_pool_borrow_checks() {
+    let env = Env::default();
+    let (client, admin, risk_manager) = setup(&env);
+    let borrower = Address::generate(&env);
+    let asset = Address::generate(&env);
+    client.open_credit_line(&risk_manager, &borrower, &asset, &50_000, &0, &1000);
+
+    client.set_asset_freeze(&admin, &asset, &FREEZE_BORROW);
+    let result = client.try_draw_credit(&borrower, &1000);
+    assert_eq!(result, Err(Ok(CreditLineError::BorrowFrozen)));
+    client.set_asset_freeze(&admin, &asset, &0);
+
+    client.set_max_borrow_per_call(&admin, &20_000);
+    let result = client.try_draw_credit(&borrower, &20_001);
+    assert_eq!(result, Err(Ok(CreditLineError::BorrowSizeCapExceeded)));
+    client.draw_credit(&borrower, &20_000);
+}
+
+#[test]
+fn test_drawn_credit_counts_toward_debt_totals() {
+    let env = Env::default();
+    let (client, _admin, risk_manager) = setup(&env);
+    let borrower = Address::generate(&env);
+    let asset = Address::generate(&env);
+
+    client.open_credit_line(&risk_manager, &borrower, &asset, &50_000, &0, &1000);
+    client.draw_credit(&borrower, &20_000);
+    assert_eq!(client.get_asset_debt(&asset), 20_000);
+
+    // The per-user cap counts the drawn balance
+    client.set_user_borrow_cap(&risk_manager, &borrower, &Some(25_000));
+    let result = client.try_draw_credit(&borrower, &5_001);
+    assert_eq!(result, Err(Ok(CreditLineError::BorrowCapExceeded)));
+
+    client.repay_credit(&borrower, &5_000);
+    assert_eq!(client.get_asset_debt(&asset), 15_000);
+    client.write_off_credit_line(&risk_manager, &borrower);
+    assert_eq!(client.get_asset_debt(&asset), 0);
+}
@@ -0,0 +1,150 @@
+use soroban_sdk::{contracterror, contracttype, Address, Env};
+
+use crate::admin::require_admin;
+use crate::borrow::DebtPosition;
+
+/// Errors that can occur during credit score configuration
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum CreditScoreError {
+    Unauthorized = 1,
+    InvalidConfig = 2,
+}
+
+/// Storage keys for credit score data
+#[contracttype]
+#[derive(Clone)]
+pub enum CreditScoreDataKey {
+    CreditScoreConfig,
+    CreditHistory(Address),
+}
+
+/// Controls whether scores adjust the collateral ratio
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreditScoreConfig {
+    pub enabled: bool,
+    /// Largest change to the collateral ratio at the best or worst score,
+    /// in basis points
+    pub max_ratio_adjustment_bps: i128,
+}
+
+/// A user's borrowing and repayment record
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreditHistory {
+    pub loan_count: u32,
+    pub borrow_volume: i128,
+    pub on_time_repayments: u32,
+    pub liquidations: u32,
+    /// Time of the last repayment counted as on time
+    pub last_on_time: u64,
+}
+
+pub const MAX_CREDIT_SCORE: u32 = 1000;
+const NEUTRAL_CREDIT_SCORE: i128 = 500;
+const BASE_SCORE: i128 = 300;
+const VOLUME_UNIT: i128 = 100_000;
+const MAX_RATIO_ADJUSTMENT_BPS: i128 = 2500; // Collateral ratio stays within 125%-175%
+const ON_TIME_INTERVAL: u64 = 86400; // At most one on-time repayment counted per day
+
+/// Configure score-based collateral ratio adjustment (admin only)
+pub fn set_credit_score_config(
+    env: &Env,
+    caller: Address,
+    config: CreditScoreConfig,
+) -> Result<(), CreditScoreError> {
+    require_admin(env, &caller).map_err(|_| CreditScoreError::Unauthorized)?;
+
+    if !(0..=MAX_RATIO_ADJUSTMENT_BPS).contains(&config.max_ratio_adjustment_bps) {
+        return Err(CreditScoreError::InvalidConfig);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&CreditScoreDataKey::CreditScoreConfig, &config);
+    Ok(())
+}
+
+/// Get the credit score configuration, if set
+pub fn get_credit_score_config(env: &Env) -> Option<CreditScoreConfig> {
+    env.storage()
+        .persistent()
+        .get(&CreditScoreDataKey::CreditScoreConfig)
+}
+
+/// Get a user's credit history
+pub fn get_credit_history(env: &Env, user: &Address) -> CreditHistory {
+    env.storage()
+        .persistent()
+        .get(&CreditScoreDataKey::CreditHistory(user.clone()))
+        .unwrap_or(CreditHistory {
+            loan_count: 0,
+            borrow_volume: 0,
+            on_time_repayments: 0,
+            liquidations: 0,
+            last_on_time: 0,
+        })
+}
+
+/// Credit score between 0 and 1000 derived from the user's history
+///
+/// Starts at 300 and gains up to 100 for loan count, 100 for borrowed volume
+/// and 500 for on-time repayments. Each liquidation costs 200.
+pub fn get_credit_score(env: &Env, user: &Address) -> u32 {
+    let history = get_credit_history(env, user);
+    let score = BASE_SCORE
+        + history.loan_count.min(10) as i128 * 10
+        + (history.borrow_volume / VOLUME_UNIT).min(10) * 10
+        + history.on_time_repayments.min(25) as i128 * 20
+        - history.liquidations as i128 * 200;
+    score.clamp(0, MAX_CREDIT_SCORE as i128) as u32
+}
+
+/// Adjust a collateral ratio by the user's score
+///
+/// Scores above 500 lower the ratio and scores below raise it, linearly up to
+/// the configured maximum adjustment.
+pub(crate) fn adjust_collateral_ratio(env: &Env, user: &Address, ratio_bps: i128) -> i128 {
+    let config = match get_credit_score_config(env) {
+        Some(config) if config.enabled => config,
+        _ => return ratio_bps,
+    };
+    let deviation = get_credit_score(env, user) as i128 - NEUTRAL_CREDIT_SCORE;
+    ratio_bps - deviation * config.max_ratio_adjustment_bps / NEUTRAL_CREDIT_SCORE
+}
+
+pub(crate) fn record_borrow(env: &Env, user: &Address, amount: i128) {
+    let mut history = get_credit_history(env, user);
+    history.loan_count = history.loan_count.saturating_add(1);
+    history.borrow_volume = history.borrow_volume.saturating_add(amount);
+    save_history(env, user, &history);
+}
+
+/// Count a repayment that leaves no accrued interest outstanding as on time
+pub(crate) fn record_repayment(env: &Env, user: &Address, position: &DebtPosition) {
+    if position.interest_accrued != 0 {
+        return;
+    }
+    let mut history = get_credit_history(env, user);
+    let now = env.ledger().timestamp();
+    if history.on_time_repayments > 0 && now < history.last_on_time + ON_TIME_INTERVAL {
+        return;
+    }
+    history.on_time_repayments = history.on_time_repayments.saturating_add(1);
+    history.last_on_time = now;
+    save_history(env, user, &history);
+}
+
+pub(crate) fn record_liquidation(env: &Env, user: &Address) {
+    let mut history = get_credit_history(env, user);
+    history.liquidations = history.liquidations.saturating_add(1);
+    save_history(env, user, &history);
+}
+
+fn save_history(env: &Env, user: &Address, history: &CreditHistory) {
+    env.storage()
+        .persistent()
+        .set(&CreditScoreDataKey::CreditHistory(user.clone()), history);
+}
@@ -0,0 +1,132 @@
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+
+const DAY: u64 = 86400;
+
+fn setup(env: &Env) -> (LendingContractClient<'_>, Address) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 0);
+
+    let contract_id = env.register(LendingContract, ());
+    let client = LendingContractClient::new(env, &contract_id);
+    client.initialize(
+        &Address::generate(env),
+        &InitConfig::new(env, 1_000_000_000, 1000),
+    );
+
+    let admin = Address::generate(env);
+    client.set_admin(&admin);
+    (client, admin)
+}
+
+#[test]
+fn test_score_grows_with_history() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let user = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let collateral_asset = Address::generate(&env);
+
+    assert_eq!(client.get_credit_score(&user), 300);
+
+    client.borrow(&user, &asset, &200_000, &collateral_asset, &400_000);
+    // 300 base + 10 for one loan + 20 for 200,000 of volume
+    assert_eq!(client.get_credit_score(&user), 330);
+
+    client.repay(&user, &1000);
+    let history = client.get_credit_history(&user);
+    assert_eq!(history.loan_count, 1);
+    assert_eq!(history.borrow_volume, 200_000);
+    assert_eq!(history.on_time_repayments, 1);
+    assert_eq!(client.get_credit_score(&user), 350);
+}
+
+#[test]
+fn test_one_on_time_repayment_counted_per_day() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let user = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let collateral_asset = Address::generate(&env);
+
+    client.borrow(&user, &asset, &100_000, &collateral_asset, &200_000);
+    client.repay(&user, &1000);
+    client.repay(&user, &1000);
+    assert_eq!(client.get_credit_history(&user).on_time_repayments, 1);
+
+    env.ledger().with_mut(|li| li.timestamp = DAY);
+    client.repay(&user, &1000);
+    assert_eq!(client.get_credit_history(&user).on_time_repayments, 2);
+}
+
+#[test]
+fn test_score_adjusts_collateral_ratio_when_enabled() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let user = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let collateral_asset = Address::generate(&env);
+
+    assert_eq!(client.get_collateral_ratio(&user), 15000);
+
+    client.set_credit_score_config(
+        &admin,
+        &CreditScoreConfig {
+            enabled: true,
+            max_ratio_adjustment_bps: 2500,
+        },
+    );
+    // Score 300 is below neutral: ratio rises by 200/500 of 2,500
+    assert_eq!(client.get_collateral_ratio(&user), 16000);
+
+    let result = client.try_borrow(&user, &asset, &10_000, &collateral_asset, &15_000);
+    assert_eq!(result, Err(Ok(BorrowError::InsufficientCollateral)));
+    client.borrow(&user, &asset, &10_000, &collateral_asset, &16_000);
+}
+
+#[test]
+fn test_liquidation_lowers_score() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let user = Address::generate(&env);
+    let bidder = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let collection = Address::generate(&env);
+
+    client.approve_nft_collection(&admin, &collection, &5000);
+    client.set_nft_floor(&admin, &collection, &100_000);
+    client.escrow_nft(&user, &collection, &1);
+    client.nft_borrow(&user, &asset, &30_000);
+    client.set_nft_floor(&admin, &collection, &60_000);
+    client.start_nft_auction(&collection, &1);
+    client.bid_nft_auction(&bidder, &collection, &1, &40_000);
+
+    env.ledger().with_mut(|li| li.timestamp = DAY);
+    client.settle_nft_auction(&collection, &1);
+
+    let history = client.get_credit_history(&user);
+    assert_eq!(history.liquidations, 1);
+    // Liquidation proceeds do not count as an on-time repayment
+    assert_eq!(history.on_time_repayments, 0);
+    assert_eq!(client.get_credit_score(&user), 300 + 10 - 200);
+}
+
+#[test]
+fn test_config_bounds() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let user = Address::generate(&env);
+    let config = CreditScoreConfig {
+        enabled: true,
+        max_ratio_adjustment_bps: 2501,
+    };
+
+    let result = client.try_set_credit_score_config(&admin, &config);
+    assert_eq!(result, Err(Ok(CreditScoreError::InvalidConfig)));
+
+    let result = client.try_set_credit_score_config(&user, &config);
+    assert_eq!(result, Err(Ok(CreditScoreError::Unauthorized)));
+}
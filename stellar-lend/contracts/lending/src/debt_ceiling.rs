@@ -0,0 +1,316 @@
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol};
+
+use crate::admin::{require_admin, require_risk_manager};
+use crate::borrow::get_total_collateral;
+use crate::math::mul_div_up;
+
+/// Errors that can occur when configuring the dynamic debt ceiling
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum CeilingError {
+    Unauthorized = 1,
+    InvalidConfig = 2,
+}
+
+/// Storage keys for dynamic debt ceiling data
+#[contracttype]
+#[derive(Clone)]
+pub enum CeilingDataKey {
+    DynamicCeilingConfig,
+    /// Confidence in current collateral prices, in basis points
+    OracleConfidence,
+    DebtGrowthGuard(Address),
+    /// Start and opening debt of an asset's current growth window
+    DebtGrowthWindow(Address),
+    AssetBorrowsPaused(Address),
+    /// Share of an asset's supply that must stay un-borrowed, in basis points
+    LiquidityBuffer(Address),
+}
+
+/// Parameters of the collateral-responsive debt ceiling
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DynamicCeilingConfig {
+    /// Share of total collateral that may be borrowed, in basis points
+    pub collateral_factor_bps: i128,
+    /// Lowest ceiling regardless of collateral or confidence
+    pub floor: i128,
+    /// Highest ceiling regardless of collateral
+    pub cap: i128,
+}
+
+/// Circuit breaker on the growth rate of an asset's debt
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DebtGrowthGuard {
+    /// Growth over the window's opening debt that trips the breaker, in
+    /// basis points
+    pub max_growth_bps: i128,
+    /// Length of a measurement window in seconds
+    pub window: u64,
+    /// Growth always allowed per window, so small markets are not tripped by
+    /// ordinary borrows
+    pub min_growth: i128,
+}
+
+/// Debt measured over the current growth window
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DebtGrowthWindow {
+    pub start: u64,
+    pub opening_debt: i128,
+}
+
+/// Debt growth breaker event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct DebtGrowthBreakerEvent {
+    pub asset: Address,
+    pub opening_debt: i128,
+    pub debt: i128,
+    pub timestamp: u64,
+}
+
+/// Oracle confidence update event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct OracleConfidenceEvent {
+    pub confidence_bps: i128,
+    pub debt_ceiling: i128,
+    pub timestamp: u64,
+}
+
+const MAX_LIQUIDITY_BUFFER_BPS: i128 = 5000; // 50%
+
+/// Configure the dynamic debt ceiling (risk manager or admin)
+///
+/// Once configured, the dynamic ceiling replaces the static ceiling set by
+/// `initialize`.
+pub fn set_dynamic_ceiling_config(
+    env: &Env,
+    caller: Address,
+    config: DynamicCeilingConfig,
+) -> Result<(), CeilingError> {
+    require_risk_manager(env, &caller).map_err(|_| CeilingError::Unauthorized)?;
+
+    if !(1..=10000).contains(&config.collateral_factor_bps)
+        || config.floor < 0
+        || config.cap < config.floor
+    {
+        return Err(CeilingError::InvalidConfig);
+    }
+    env.storage()
+        .persistent()
+        .set(&CeilingDataKey::DynamicCeilingConfig, &config);
+    Ok(())
+}
+
+/// Report the oracle's confidence in collateral prices (risk manager or admin)
+///
+/// The collateral-derived ceiling shrinks in proportion to the confidence,
+/// down to the configured floor.
+pub fn set_oracle_confidence(
+    env: &Env,
+    caller: Address,
+    confidence_bps: i128,
+) -> Result<(), CeilingError> {
+    require_risk_manager(env, &caller).map_err(|_| CeilingError::Unauthorized)?;
+
+    if !(0..=10000).contains(&confidence_bps) {
+        return Err(CeilingError::InvalidConfig);
+    }
+    env.storage()
+        .persistent()
+        .set(&CeilingDataKey::OracleConfidence, &confidence_bps);
+
+    let event = OracleConfidenceEvent {
+        confidence_bps,
+        debt_ceiling: crate::borrow::get_debt_ceiling(env),
+        timestamp: env.ledger().timestamp(),
+    };
+    crate::events::publish(env, (Symbol::new(env, "oracle_confidence_updated"),), event);
+    Ok(())
+}
+
+/// Get the dynamic debt ceiling configuration, if set
+pub fn get_dynamic_ceiling_config(env: &Env) -> Option<DynamicCeilingConfig> {
+    env.storage()
+        .persistent()
+        .get(&CeilingDataKey::DynamicCeilingConfig)
+}
+
+/// Get the reported oracle confidence in basis points (full confidence by default)
+pub fn get_oracle_confidence(env: &Env) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&CeilingDataKey::OracleConfidence)
+        .unwrap_or(10000)
+}
+
+/// Debt ceiling derived from total collateral and oracle confidence
+///
+/// `None` while the dynamic ceiling is not configured.
+pub(crate) fn get_dynamic_debt_ceiling(env: &Env) -> Option<i128> {
+    let config = get_dynamic_ceiling_config(env)?;
+    let ceiling = get_total_collateral(env).saturating_mul(config.collateral_factor_bps) / 10000;
+    let ceiling = ceiling.saturating_mul(get_oracle_confidence(env)) / 10000;
+    Some(ceiling.clamp(config.floor, config.cap))
+}
+
+/// Configure the debt growth breaker of an asset (risk manager or admin)
+///
+/// When the asset's outstanding debt grows by more than the allowance within
+/// one window, new borrows of the asset are paused until the admin resumes
+/// them. The borrow that trips the breaker completes.
+pub fn set_debt_growth_guard(
+    env: &Env,
+    caller: Address,
+    asset: Address,
+    guard: DebtGrowthGuard,
+) -> Result<(), CeilingError> {
+    require_risk_manager(env, &caller).map_err(|_| CeilingError::Unauthorized)?;
+
+    if !(1..=100_000).contains(&guard.max_growth_bps) || guard.window == 0 || guard.min_growth < 0 {
+        return Err(CeilingError::InvalidConfig);
+    }
+    env.storage()
+        .persistent()
+        .set(&CeilingDataKey::DebtGrowthGuard(asset.clone()), &guard);
+    env.storage()
+        .persistent()
+        .remove(&CeilingDataKey::DebtGrowthWindow(asset));
+    Ok(())
+}
+
+/// Get the debt growth breaker of an asset, if configured
+pub fn get_debt_growth_guard(env: &Env, asset: &Address) -> Option<DebtGrowthGuard> {
+    env.storage()
+        .persistent()
+        .get(&CeilingDataKey::DebtGrowthGuard(asset.clone()))
+}
+
+/// Require a share of an asset's supply to stay un-borrowed (risk manager or admin)
+///
+/// Borrows that would lend out the buffer are rejected, so routine
+/// withdrawals and liquidation payouts always find cash. Zero removes it.
+pub fn set_liquidity_buffer(
+    env: &Env,
+    caller: Address,
+    asset: Address,
+    buffer_bps: i128,
+) -> Result<(), CeilingError> {
+    require_risk_manager(env, &caller).map_err(|_| CeilingError::Unauthorized)?;
+    if !(0..=MAX_LIQUIDITY_BUFFER_BPS).contains(&buffer_bps) {
+        return Err(CeilingError::InvalidConfig);
+    }
+    env.storage()
+        .persistent()
+        .set(&CeilingDataKey::LiquidityBuffer(asset), &buffer_bps);
+    Ok(())
+}
+
+/// Get the share of an asset's supply that must stay un-borrowed, in basis points
+pub fn get_liquidity_buffer(env: &Env, asset: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&CeilingDataKey::LiquidityBuffer(asset.clone()))
+        .unwrap_or(0)
+}
+
+/// Supply of an asset held back by its liquidity buffer
+pub(crate) fn get_buffer_amount(env: &Env, asset: &Address) -> i128 {
+    mul_div_up(
+        crate::p2p::get_asset_supplied(env, asset),
+        get_liquidity_buffer(env, asset),
+        10000,
+    )
+}
+
+/// Most principal that may be outstanding in an asset
+///
+/// The lower of the asset's debt ceiling and, with a liquidity buffer, its
+/// supply outside the buffer; `i128::MAX` when neither applies.
+pub fn get_effective_borrow_cap(env: &Env, asset: &Address) -> i128 {
+    let ceiling = crate::borrow::get_asset_debt_ceiling(env, asset).unwrap_or(i128::MAX);
+    if get_liquidity_buffer(env, asset) == 0 {
+        return ceiling;
+    }
+    let lendable =
+        crate::p2p::get_asset_supplied(env, asset).saturating_sub(get_buffer_amount(env, asset));
+    ceiling.min(lendable)
+}
+
+/// Whether new borrows of an asset are paused by the debt growth breaker
+pub fn is_asset_borrow_paused(env: &Env, asset: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&CeilingDataKey::AssetBorrowsPaused(asset.clone()))
+        .unwrap_or(false)
+}
+
+/// Resume borrows of an asset paused by the debt growth breaker (admin only)
+///
+/// A new growth window starts from the current debt.
+pub fn resume_asset_borrows(
+    env: &Env,
+    caller: Address,
+    asset: Address,
+) -> Result<(), CeilingError> {
+    require_admin(env, &caller).map_err(|_| CeilingError::Unauthorized)?;
+
+    env.storage()
+        .persistent()
+        .remove(&CeilingDataKey::AssetBorrowsPaused(asset.clone()));
+    env.storage()
+        .persistent()
+        .remove(&CeilingDataKey::DebtGrowthWindow(asset.clone()));
+    crate::events::publish(
+        env,
+        (Symbol::new(env, "asset_borrows_resumed"), asset.clone()),
+        asset,
+    );
+    Ok(())
+}
+
+/// Record a change in an asset's debt and trip the breaker on excess growth
+pub(crate) fn record_debt_growth(env: &Env, asset: &Address, debt_before: i128, debt_after: i128) {
+    let guard = match get_debt_growth_guard(env, asset) {
+        Some(guard) => guard,
+        None => return,
+    };
+    let now = env.ledger().timestamp();
+    let key = CeilingDataKey::DebtGrowthWindow(asset.clone());
+    let window = match env.storage().persistent().get::<_, DebtGrowthWindow>(&key) {
+        Some(window) if now < window.start.saturating_add(guard.window) => window,
+        _ => {
+            let window = DebtGrowthWindow {
+                start: now,
+                opening_debt: debt_before,
+            };
+            env.storage().persistent().set(&key, &window);
+            window
+        }
+    };
+
+    let allowance =
+        (window.opening_debt.saturating_mul(guard.max_growth_bps) / 10000).max(guard.min_growth);
+    if debt_after.saturating_sub(window.opening_debt) <= allowance {
+        return;
+    }
+    env.storage()
+        .persistent()
+        .set(&CeilingDataKey::AssetBorrowsPaused(asset.clone()), &true);
+
+    let event = DebtGrowthBreakerEvent {
+        asset: asset.clone(),
+        opening_debt: window.opening_debt,
+        debt: debt_after,
+        timestamp: now,
+    };
+    crate::events::publish(
+        env,
+        (Symbol::new(env, "debt_growth_breaker"), event.asset.clone()),
+        event,
+    );
+}
@@ -0,0 +1,197 @@
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+
+fn setup(env: &Env) -> (LendingContractClient<'_>, Address) {
+    env.mock_all_auths();
+    let client = LendingContractClient::new(env, &env.register(LendingContract, ()));
+    client.initialize(
+        &Address::generate(env),
+        &InitConfig::new(env, 1_000_000, 1000),
+    );
+
+    let admin = Address::generate(env);
+    client.set_admin(&admin);
+    let manager = Address::generate(env);
+    client.set_risk_manager(&admin, &manager);
+    client.set_dynamic_ceiling_config(
+        &manager,
+        &DynamicCeilingConfig {
+            collateral_factor_bps: 5000,
+            floor: 10_000,
+            cap: 100_000,
+        },
+    );
+    (client, manager)
+}
+
+fn borrow(env: &Env, client: &LendingContractClient<'_>, amount: i128, collateral: i128) {
+    client.borrow(
+        &Address::generate(env),
+        &Address::generate(env),
+        &amount,
+        &Address::generate(env),
+        &collateral,
+    );
+}
+
+#[test]
+fn test_ceiling_follows_total_collateral() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+
+    // Without collateral the floor applies
+    assert_eq!(client.get_debt_ceiling(), 10_000);
+    borrow(&env, &client, 10_000, 60_000);
+    assert_eq!(client.get_debt_ceiling(), 30_000);
+
+    // A borrow is checked before its own collateral counts towards the ceiling
+    assert_eq!(
+        client.try_borrow(
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &30_000,
+            &Address::generate(&env),
+            &45_000,
+        ),
+        Err(Ok(BorrowError::DebtCeilingReached))
+    );
+    borrow(&env, &client, 20_000, 60_000);
+    assert_eq!(client.get_debt_ceiling(), 60_000);
+}
+
+#[test]
+fn test_ceiling_bounded_by_cap() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+
+    borrow(&env, &client, 10_000, 500_000);
+    assert_eq!(client.get_debt_ceiling(), 100_000);
+}
+
+#[test]
+fn test_low_oracle_confidence_shrinks_ceiling() {
+    let env = Env::default();
+    let (client, manager) = setup(&env);
+
+    borrow(&env, &client, 10_000, 100_000);
+    assert_eq!(client.get_debt_ceiling(), 50_000);
+
+    client.set_oracle_confidence(&manager, &5000);
+    assert_eq!(client.get_oracle_confidence(), 5000);
+    assert_eq!(client.get_debt_ceiling(), 25_000);
+
+    // Never below the floor
+    client.set_oracle_confidence(&manager, &0);
+    assert_eq!(client.get_debt_ceiling(), 10_000);
+}
+
+#[test]
+fn test_dynamic_ceiling_config_validation() {
+    let env = Env::default();
+    let (client, manager) = setup(&env);
+    let config = client.get_dynamic_ceiling_config().unwrap();
+
+    assert_eq!(
+        client.try_set_dynamic_ceiling_config(&Address::generate(&env), &config),
+        Err(Ok(CeilingError::Unauthorized))
+    );
+    let mut invalid = config;
+    invalid.cap = 9999;
+    assert_eq!(
+        client.try_set_dynamic_ceiling_config(&manager, &invalid),
+        Err(Ok(CeilingError::InvalidConfig))
+    );
+    assert_eq!(
+        client.try_set_oracle_confidence(&manager, &10_001),
+        Err(Ok(CeilingError::InvalidConfig))
+    );
+}
+
+#[test]
+fn test_debt_growth_breaker_pauses_asset_borrows() {
+    let env = Env::default();
+    let (client, manager) = setup(&env);
+    let asset = Address::generate(&env);
+    client.set_debt_growth_guard(
+        &manager,
+        &asset,
+        &DebtGrowthGuard {
+            max_growth_bps: 5000,
+            window: 3600,
+            min_growth: 10_000,
+        },
+    );
+    let borrow_asset = |amount: i128| {
+        client.try_borrow(
+            &Address::generate(&env),
+            &asset,
+            &amount,
+            &Address::generate(&env),
+            &100_000,
+        )
+    };
+
+    assert_eq!(borrow_asset(10_000), Ok(Ok(())));
+
+    // The next window opens at 10,000: growth up to 10,000 is allowed, and
+    // the borrow crossing it completes but trips the breaker
+    env.ledger().with_mut(|li| li.timestamp += 3600);
+    assert_eq!(borrow_asset(6000), Ok(Ok(())));
+    assert!(!client.is_asset_borrow_paused(&asset));
+    assert_eq!(borrow_asset(5000), Ok(Ok(())));
+    assert!(client.is_asset_borrow_paused(&asset));
+    assert_eq!(borrow_asset(1000), Err(Ok(BorrowError::AssetBorrowsPaused)));
+
+    assert_eq!(
+        client.try_resume_asset_borrows(&manager, &asset),
+        Err(Ok(CeilingError::Unauthorized))
+    );
+    client.resume_asset_borrows(&client.get_admin().unwrap(), &asset);
+    assert_eq!(borrow_asset(1000), Ok(Ok(())));
+}
+
+#[test]
+fn test_liquidity_buffer_caps_borrows() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = LendingContractClient::new(&env, &env.register(LendingContract, ()));
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &InitConfig::new(&env, 1_000_000_000, 1000));
+    let asset = Address::generate(&env);
+    client.set_p2p_market(
+        &admin,
+        &asset,
+        &P2PMarket {
+            pool_supply_rate_bps: 200,
+            p2p_cursor_bps: 5000,
+        },
+    );
+    client.p2p_supply(&Address::generate(&env), &asset, &100_000);
+    assert_eq!(client.get_effective_borrow_cap(&asset), i128::MAX);
+
+    client.set_liquidity_buffer(&admin, &asset, &2000);
+    assert_eq!(client.get_effective_borrow_cap(&asset), 80_000);
+    client.set_asset_debt_ceiling(&admin, &asset, &70_000);
+    assert_eq!(client.get_effective_borrow_cap(&asset), 70_000);
+    client.set_asset_debt_ceiling(&admin, &asset, &1_000_000);
+
+    let user = Address::generate(&env);
+    let collateral = Address::generate(&env);
+    client.borrow(&user, &asset, &80_000, &collateral, &200_000);
+    assert_eq!(
+        client.try_borrow(&user, &asset, &1000, &collateral, &2000),
+        Err(Ok(BorrowError::LiquidityBufferReached))
+    );
+    let liquidity = client.get_liquidity(&asset);
+    assert_eq!(liquidity.available_cash, 20_000);
+    assert_eq!(liquidity.buffer_locked, 20_000);
+    assert_eq!(liquidity.withdrawable, 20_000);
+
+    assert_eq!(
+        client.try_set_liquidity_buffer(&admin, &asset, &5001),
+        Err(Ok(CeilingError::InvalidConfig))
+    );
+}
@@ -0,0 +1,291 @@
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol, Vec};
+
+use crate::admin::require_admin;
+use crate::borrow::{
+    get_collateral_position, get_debt_position, get_total_debt, save_collateral_position,
+};
+use crate::history::PositionAction;
+use crate::insurance::credit_protocol_fee;
+use crate::math::div_up;
+use crate::repay::{apply_repayment, ensure_no_dust};
+
+/// Errors that can occur during automated deleveraging
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum DeleverageError {
+    Unauthorized = 1,
+    InvalidConfig = 2,
+    NotConfigured = 3,
+    NoLiquidityCrunch = 4,
+    CrunchNotSustained = 5,
+    NotLargestBorrower = 6,
+    InvalidAmount = 7,
+    InsufficientCollateral = 8,
+    Overflow = 9,
+    DustRemaining = 10,
+    PriceStale = 11,
+    MarketFrozen = 12,
+    ProtocolPaused = 13,
+}
+
+/// Storage keys for deleveraging data
+#[contracttype]
+#[derive(Clone)]
+pub enum DeleverageDataKey {
+    DeleverageConfig,
+    /// When utilization last rose above the critical threshold
+    CrunchStart,
+    /// Largest borrowers by principal, in descending order
+    LargestBorrowers,
+}
+
+/// Deleveraging parameters
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeleverageConfig {
+    /// Utilization above which a liquidity crunch starts, in basis points
+    pub critical_utilization_bps: i128,
+    /// Utilization deleveraging restores, in basis points
+    pub target_utilization_bps: i128,
+    /// How long utilization must stay critical before deleveraging (seconds)
+    pub min_crunch_duration: u64,
+    /// Maximum share of a position's debt closed per action, in basis points
+    pub max_close_bps: i128,
+    /// Collateral penalty on the closed amount, in basis points
+    pub penalty_bps: i128,
+}
+
+/// Deleverage event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct DeleverageEvent {
+    pub user: Address,
+    pub keeper: Address,
+    pub debt_asset: Address,
+    pub collateral_asset: Address,
+    pub repaid: i128,
+    pub collateral_seized: i128,
+    pub utilization_bps: i128,
+    pub timestamp: u64,
+}
+
+const MAX_TRACKED_BORROWERS: u32 = 10;
+const MAX_DELEVERAGE_PENALTY_BPS: i128 = 500; // 5%
+const MAX_DELEVERAGE_CLOSE_BPS: i128 = 5000; // 50%
+
+/// Configure automated deleveraging (admin only)
+pub fn set_deleverage_config(
+    env: &Env,
+    caller: Address,
+    config: DeleverageConfig,
+) -> Result<(), DeleverageError> {
+    require_admin(env, &caller).map_err(|_| DeleverageError::Unauthorized)?;
+
+    if config.target_utilization_bps <= 0
+        || config.target_utilization_bps >= config.critical_utilization_bps
+        || config.critical_utilization_bps > 10000
+        || !(1..=MAX_DELEVERAGE_CLOSE_BPS).contains(&config.max_close_bps)
+        || !(0..=MAX_DELEVERAGE_PENALTY_BPS).contains(&config.penalty_bps)
+    {
+        return Err(DeleverageError::InvalidConfig);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&DeleverageDataKey::DeleverageConfig, &config);
+    update_crunch(env);
+    Ok(())
+}
+
+/// Partially close one of the largest positions during a sustained liquidity crunch
+///
+/// Permissionless. The repaid debt is settled from the borrower's collateral,
+/// which is additionally charged the deleveraging penalty (credited to the
+/// insurance fund). The amount is capped by the per-action close factor and
+/// by what is needed to bring utilization back to the target.
+///
+/// # Returns
+/// The collateral seized, including the penalty
+pub fn deleverage(
+    env: &Env,
+    keeper: Address,
+    user: Address,
+    amount: i128,
+) -> Result<i128, DeleverageError> {
+    keeper.require_auth();
+
+    let config = get_deleverage_config(env).ok_or(DeleverageError::NotConfigured)?;
+    if amount <= 0 {
+        return Err(DeleverageError::InvalidAmount);
+    }
+    if crate::pause::is_operation_paused(env, crate::pause::PAUSE_LIQUIDATE) {
+        return Err(DeleverageError::ProtocolPaused);
+    }
+    let utilization = get_utilization(env);
+    if utilization <= config.critical_utilization_bps {
+        return Err(DeleverageError::NoLiquidityCrunch);
+    }
+    let crunch_start = get_crunch_start(env).ok_or(DeleverageError::CrunchNotSustained)?;
+    if env.ledger().timestamp() < crunch_start.saturating_add(config.min_crunch_duration) {
+        return Err(DeleverageError::CrunchNotSustained);
+    }
+    if !get_largest_borrowers(env).contains(&user) {
+        return Err(DeleverageError::NotLargestBorrower);
+    }
+    let debt_asset = get_debt_position(env, &user).asset;
+    if crate::oracle::is_liquidation_frozen(env, &debt_asset) {
+        return Err(DeleverageError::PriceStale);
+    }
+    if crate::oracle::is_market_frozen(env, &debt_asset) {
+        return Err(DeleverageError::MarketFrozen);
+    }
+
+    let debt = get_debt_position(env, &user).borrowed_amount;
+    let max_close = debt.saturating_mul(config.max_close_bps) / 10000;
+    let excess = get_total_debt(env).saturating_sub(
+        crate::p2p::get_supplied_total(env).saturating_mul(config.target_utilization_bps) / 10000,
+    );
+    if amount > max_close || amount > excess {
+        return Err(DeleverageError::InvalidAmount);
+    }
+    ensure_no_dust(env, &user, amount).map_err(|_| DeleverageError::DustRemaining)?;
+
+    // Round the penalty up in favor of the protocol
+    let penalty = amount
+        .checked_mul(config.penalty_bps)
+        .map(|penalty| div_up(penalty, 10000))
+        .ok_or(DeleverageError::Overflow)?;
+    let seized = amount + penalty;
+    let mut collateral = get_collateral_position(env, &user);
+    if collateral.amount < seized {
+        return Err(DeleverageError::InsufficientCollateral);
+    }
+
+    apply_repayment(env, user.clone(), amount).map_err(|_| DeleverageError::InvalidAmount)?;
+    crate::points::checkpoint(env, &user);
+    collateral.amount -= seized;
+    save_collateral_position(env, &user, &collateral);
+    crate::history::record_action(
+        env,
+        &user,
+        PositionAction::CollateralSeized,
+        &collateral.asset,
+        seized,
+    );
+    credit_protocol_fee(env, &collateral.asset, penalty);
+    crate::position_hooks::notify_liquidation(env, &user, amount, seized);
+
+    let event = DeleverageEvent {
+        user,
+        keeper,
+        debt_asset,
+        collateral_asset: collateral.asset,
+        repaid: amount,
+        collateral_seized: seized,
+        utilization_bps: get_utilization(env),
+        timestamp: env.ledger().timestamp(),
+    };
+    crate::events::publish(
+        env,
+        (
+            Symbol::new(env, "position_deleveraged"),
+            event.user.clone(),
+            event.debt_asset.clone(),
+            event.collateral_asset.clone(),
+        ),
+        event,
+    );
+    Ok(seized)
+}
+
+/// Record whether utilization is above the critical threshold (permissionless)
+///
+/// Utilization is also checkpointed by every borrow, repayment, supply and
+/// withdrawal; this lets keepers start the crunch clock without one.
+///
+/// # Returns
+/// The current utilization in basis points
+pub fn poke_utilization(env: &Env) -> i128 {
+    update_crunch(env);
+    get_utilization(env)
+}
+
+/// Get the deleveraging configuration, if set
+pub fn get_deleverage_config(env: &Env) -> Option<DeleverageConfig> {
+    env.storage()
+        .persistent()
+        .get(&DeleverageDataKey::DeleverageConfig)
+}
+
+/// Pool utilization in basis points: outstanding principal over net supplied liquidity
+///
+/// Zero while no supplier liquidity is tracked.
+pub fn get_utilization(env: &Env) -> i128 {
+    let supplied = crate::p2p::get_supplied_total(env);
+    if supplied <= 0 {
+        return 0;
+    }
+    get_total_debt(env).saturating_mul(10000) / supplied
+}
+
+/// When the current liquidity crunch started, if utilization is critical
+pub fn get_crunch_start(env: &Env) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DeleverageDataKey::CrunchStart)
+}
+
+/// The largest borrowers by principal, in descending order
+pub fn get_largest_borrowers(env: &Env) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DeleverageDataKey::LargestBorrowers)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Start or clear the crunch clock according to current utilization
+pub(crate) fn update_crunch(env: &Env) {
+    let critical = match get_deleverage_config(env) {
+        Some(config) => get_utilization(env) > config.critical_utilization_bps,
+        None => false,
+    };
+    match (critical, get_crunch_start(env)) {
+        (true, None) => env
+            .storage()
+            .persistent()
+            .set(&DeleverageDataKey::CrunchStart, &env.ledger().timestamp()),
+        (false, Some(_)) => env
+            .storage()
+            .persistent()
+            .remove(&DeleverageDataKey::CrunchStart),
+        _ => {}
+    }
+}
+
+/// Re-rank a borrower after their principal changed
+pub(crate) fn track_borrower(env: &Env, user: &Address) {
+    let mut borrowers = get_largest_borrowers(env);
+    if let Some(index) = borrowers.first_index_of(user) {
+        borrowers.remove(index);
+    }
+
+    let principal = get_debt_position(env, user).borrowed_amount;
+    if principal > 0 {
+        let position = borrowers
+            .iter()
+            .position(|other| get_debt_position(env, &other).borrowed_amount < principal)
+            .map(|i| i as u32)
+            .unwrap_or(borrowers.len());
+        if position < MAX_TRACKED_BORROWERS {
+            borrowers.insert(position, user.clone());
+        }
+        while borrowers.len() > MAX_TRACKED_BORROWERS {
+            borrowers.pop_back();
+        }
+    }
+
+    env.storage()
+        .persistent()
+        .set(&DeleverageDataKey::LargestBorrowers, &borrowers);
+}
@@ -0,0 +1,190 @@
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, Env,
+};
+
+const DAY: u64 = 86400;
+
+fn setup(env: &Env) -> (LendingContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 0);
+
+    let contract_id = env.register(LendingContract, ());
+    let client = LendingContractClient::new(env, &contract_id);
+    client.initialize(
+        &Address::generate(env),
+        &InitConfig::new(env, 1_000_000_000, 1000),
+    );
+
+    let admin = Address::generate(env);
+    client.set_admin(&admin);
+    let asset = Address::generate(env);
+    client.set_p2p_market(
+        &admin,
+        &asset,
+        &P2PMarket {
+            pool_supply_rate_bps: 200,
+            p2p_cursor_bps: 5000,
+        },
+    );
+    client.p2p_supply(&Address::generate(env), &asset, &100_000);
+
+    client.set_deleverage_config(
+        &admin,
+        &DeleverageConfig {
+            critical_utilization_bps: 9000,
+            target_utilization_bps: 8000,
+            min_crunch_duration: DAY,
+            max_close_bps: 2500,
+            penalty_bps: 200,
+        },
+    );
+    (client, admin, asset)
+}
+
+fn borrow(env: &Env, client: &LendingContractClient<'_>, asset: &Address, amount: i128) -> Address {
+    let user = Address::generate(env);
+    client.borrow(
+        &user,
+        asset,
+        &amount,
+        &Address::generate(env),
+        &(amount * 2),
+    );
+    user
+}
+
+#[test]
+fn test_deleverage_after_sustained_crunch() {
+    let env = Env::default();
+    let (client, _, asset) = setup(&env);
+    let keeper = Address::generate(&env);
+
+    let whale = borrow(&env, &client, &asset, 60_000);
+    borrow(&env, &client, &asset, 32_000);
+    assert_eq!(client.get_utilization(), 9200);
+    assert_eq!(client.get_crunch_start(), Some(0));
+
+    assert_eq!(
+        client.try_deleverage(&keeper, &whale, &12_000),
+        Err(Ok(DeleverageError::CrunchNotSustained))
+    );
+
+    // After a day, 12,000 brings principal back to the 80% target
+    env.ledger().with_mut(|li| li.timestamp = DAY);
+    assert_eq!(client.deleverage(&keeper, &whale, &12_000), 12_240);
+
+    let collateral = client.get_user_collateral(&whale);
+    assert_eq!(collateral.amount, 120_000 - 12_240);
+    assert_eq!(client.get_insurance_fund(&collateral.asset), 240);
+    assert_eq!(client.get_utilization(), 8000);
+    assert_eq!(client.get_crunch_start(), None);
+}
+
+#[test]
+fn test_deleverage_limited_to_close_factor_and_excess() {
+    let env = Env::default();
+    let (client, _, asset) = setup(&env);
+    let keeper = Address::generate(&env);
+
+    let whale = borrow(&env, &client, &asset, 40_000);
+    let other = borrow(&env, &client, &asset, 56_000);
+    env.ledger().with_mut(|li| li.timestamp = DAY);
+
+    // 25% of 40,000
+    assert_eq!(
+        client.try_deleverage(&keeper, &whale, &10_001),
+        Err(Ok(DeleverageError::InvalidAmount))
+    );
+    // 96,000 outstanding against an 80,000 target
+    assert_eq!(
+        client.try_deleverage(&keeper, &other, &16_001),
+        Err(Ok(DeleverageError::InvalidAmount))
+    );
+    assert_eq!(client.deleverage(&keeper, &other, &14_000), 14_280);
+}
+
+#[test]
+fn test_only_largest_borrowers_are_deleveraged() {
+    let env = Env::default();
+    let (client, _, asset) = setup(&env);
+    let keeper = Address::generate(&env);
+
+    let smallest = borrow(&env, &client, &asset, 1000);
+    let large = borrow(&env, &client, &asset, 9000);
+    for _ in 0..10 {
+        borrow(&env, &client, &asset, 8100);
+    }
+    let largest = client.get_largest_borrowers();
+    assert_eq!(largest.len(), 10);
+    assert_eq!(largest.get(0), Some(large));
+    assert!(!largest.contains(&smallest));
+
+    env.ledger().with_mut(|li| li.timestamp = DAY);
+    assert_eq!(
+        client.try_deleverage(&keeper, &smallest, &100),
+        Err(Ok(DeleverageError::NotLargestBorrower))
+    );
+}
+
+#[test]
+fn test_crunch_clock_resets_when_utilization_recovers() {
+    let env = Env::default();
+    let (client, _, asset) = setup(&env);
+    let keeper = Address::generate(&env);
+
+    let whale = borrow(&env, &client, &asset, 60_000);
+    assert_eq!(
+        client.try_deleverage(&keeper, &whale, &1000),
+        Err(Ok(DeleverageError::NoLiquidityCrunch))
+    );
+
+    let other = borrow(&env, &client, &asset, 32_000);
+    assert_eq!(client.get_crunch_start(), Some(0));
+    assert_eq!(
+        client.get_largest_borrowers(),
+        vec![&env, whale.clone(), other.clone()]
+    );
+
+    // Repayment lowers utilization and stops the clock
+    env.ledger().with_mut(|li| li.timestamp = DAY / 2);
+    client.repay(&other, &10_000);
+    assert_eq!(client.get_crunch_start(), None);
+
+    client.borrow(&other, &asset, &9000, &Address::generate(&env), &18_000);
+    assert_eq!(client.get_crunch_start(), Some(DAY / 2));
+
+    env.ledger().with_mut(|li| li.timestamp = DAY);
+    assert_eq!(
+        client.try_deleverage(&keeper, &whale, &1000),
+        Err(Ok(DeleverageError::CrunchNotSustained))
+    );
+    assert!(client.poke_utilization() > 9000);
+}
+
+#[test]
+fn test_deleverage_config_validation() {
+    let env = Env::default();
+    let (client, admin, _) = setup(&env);
+    let outsider = Address::generate(&env);
+
+    let config = client.get_deleverage_config().unwrap();
+    assert_eq!(
+        client.try_set_deleverage_config(&outsider, &config),
+        Err(Ok(DeleverageError::Unauthorized))
+    );
+
+    let mut invalid = config.clone();
+    invalid.target_utilization_bps = 9000;
+    assert_eq!(
+        client.try_set_deleverage_config(&admin, &invalid),
+        Err(Ok(DeleverageError::InvalidConfig))
+    );
+    let mut invalid = config;
+    invalid.penalty_bps = 501;
+    assert_eq!(
+        client.try_set_deleverage_config(&admin, &invalid),
+        Err(Ok(DeleverageError::InvalidConfig))
+    );
+}
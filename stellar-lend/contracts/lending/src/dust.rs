@@ -0,0 +1,148 @@
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol};
+
+use crate::admin::require_admin;
+use crate::borrow::{get_collateral_position, get_user_debt, save_collateral_position};
+use crate::history::PositionAction;
+use crate::repay::apply_repayment;
+
+/// Errors that can occur during dust-position cleanup
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum DustError {
+    Unauthorized = 1,
+    InvalidConfig = 2,
+    NotDust = 3,
+    Overflow = 4,
+}
+
+/// Storage keys for dust-position data
+#[contracttype]
+#[derive(Clone)]
+pub enum DustDataKey {
+    DustConfig,
+}
+
+/// Minimum position sizes
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DustConfig {
+    /// Minimum collateral a pool position may be opened or topped up to
+    pub min_collateral: i128,
+    /// Positions whose collateral and debt are both below this may be closed
+    /// by anyone (0 = cleanup disabled)
+    pub dust_threshold: i128,
+}
+
+/// Dust cleanup event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct DustCleanedEvent {
+    pub user: Address,
+    pub collateral_asset: Address,
+    /// Collateral absorbed into the insurance fund
+    pub collateral: i128,
+    /// Debt written off against it
+    pub debt: i128,
+    pub timestamp: u64,
+}
+
+/// Configure minimum position sizes (admin only)
+pub fn set_dust_config(env: &Env, caller: Address, config: DustConfig) -> Result<(), DustError> {
+    require_admin(env, &caller).map_err(|_| DustError::Unauthorized)?;
+
+    if config.min_collateral < 0
+        || config.dust_threshold < 0
+        || (config.min_collateral > 0 && config.dust_threshold > config.min_collateral)
+    {
+        return Err(DustError::InvalidConfig);
+    }
+    env.storage()
+        .persistent()
+        .set(&DustDataKey::DustConfig, &config);
+    Ok(())
+}
+
+/// Get the minimum position sizes
+pub fn get_dust_config(env: &Env) -> DustConfig {
+    env.storage()
+        .persistent()
+        .get(&DustDataKey::DustConfig)
+        .unwrap_or(DustConfig {
+            min_collateral: 0,
+            dust_threshold: 0,
+        })
+}
+
+/// Whether a user's pool position is below the dust threshold
+///
+/// Only plain pool positions qualify: escrowed NFTs, restricted collateral and
+/// fixed-term or amortizing debt keep a position open.
+pub fn is_dust_position(env: &Env, user: &Address) -> bool {
+    let threshold = get_dust_config(env).dust_threshold;
+    let collateral = get_collateral_position(env, user).amount;
+    let debt = get_user_debt(env, user);
+    let owed = debt.borrowed_amount.saturating_add(debt.interest_accrued);
+    threshold > 0
+        && (collateral > 0 || owed > 0)
+        && collateral < threshold
+        && owed < threshold
+        && crate::nft::get_user_nfts(env, user).is_empty()
+        && crate::rwa::get_rwa_collateral_value(env, user) == 0
+        && crate::fixed_rate::get_fixed_debt(env, user) == 0
+        && crate::amortizing::get_amortizing_debt(env, user) == 0
+}
+
+/// Close a dust position (permissionless)
+///
+/// The position's collateral is absorbed into the insurance fund of the
+/// collateral asset and its debt is written off against it.
+///
+/// # Returns
+/// The collateral absorbed and the debt written off
+pub fn clean_dust(env: &Env, user: Address) -> Result<(i128, i128), DustError> {
+    if !is_dust_position(env, &user) {
+        return Err(DustError::NotDust);
+    }
+
+    let mut position = get_collateral_position(env, &user);
+    let collateral = position.amount;
+    let owed = get_user_debt(env, &user);
+    let debt = if owed.borrowed_amount > 0 || owed.interest_accrued > 0 {
+        let (_, interest_paid, principal_paid) =
+            apply_repayment(env, user.clone(), i128::MAX).map_err(|_| DustError::Overflow)?;
+        interest_paid + principal_paid
+    } else {
+        0
+    };
+
+    crate::points::checkpoint(env, &user);
+    position.amount = 0;
+    save_collateral_position(env, &user, &position);
+    crate::history::record_action(
+        env,
+        &user,
+        PositionAction::CollateralSeized,
+        &position.asset,
+        collateral,
+    );
+    crate::insurance::credit_insurance_fund(env, &position.asset, collateral);
+
+    let event = DustCleanedEvent {
+        user,
+        collateral_asset: position.asset,
+        collateral,
+        debt,
+        timestamp: env.ledger().timestamp(),
+    };
+    crate::events::publish(
+        env,
+        (
+            Symbol::new(env, "dust_cleaned"),
+            event.user.clone(),
+            event.collateral_asset.clone(),
+        ),
+        event,
+    );
+    Ok((collateral, debt))
+}
@@ -0,0 +1,124 @@
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+
+fn setup(env: &Env) -> (LendingContractClient<'_>, Address) {
+    env.mock_all_auths();
+
+    let contract_id = env.register(LendingContract, ());
+    let client = LendingContractClient::new(env, &contract_id);
+    client.initialize(
+        &Address::generate(env),
+        &InitConfig::new(env, 1_000_000_000, 1000),
+    );
+
+    let admin = Address::generate(env);
+    client.set_admin(&admin);
+    client.set_dust_config(
+        &admin,
+        &DustConfig {
+            min_collateral: 5000,
+            dust_threshold: 2000,
+        },
+    );
+    (client, admin)
+}
+
+#[test]
+fn test_minimum_collateral_at_origination() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    let user = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let collateral_asset = Address::generate(&env);
+
+    assert_eq!(
+        client.try_borrow(&user, &asset, &1000, &collateral_asset, &4999),
+        Err(Ok(BorrowError::BelowMinimumCollateral))
+    );
+    client.borrow(&user, &asset, &1000, &collateral_asset, &5000);
+    assert!(!client.is_dust_position(&user));
+}
+
+#[test]
+fn test_clean_dust_absorbs_position_into_reserves() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let user = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let collateral_asset = Address::generate(&env);
+
+    // Opened before the minimum was raised, then left below the dust threshold
+    client.set_dust_config(
+        &admin,
+        &DustConfig {
+            min_collateral: 0,
+            dust_threshold: 2000,
+        },
+    );
+    client.borrow(&user, &asset, &1000, &collateral_asset, &1500);
+    assert!(client.is_dust_position(&user));
+
+    // Anyone may close it
+    assert_eq!(client.clean_dust(&user), (1500, 1000));
+    assert_eq!(client.get_user_collateral(&user).amount, 0);
+    assert_eq!(client.get_user_debt(&user).borrowed_amount, 0);
+    assert_eq!(client.get_insurance_fund(&collateral_asset), 1500);
+
+    assert_eq!(client.try_clean_dust(&user), Err(Ok(DustError::NotDust)));
+}
+
+#[test]
+fn test_clean_dust_settles_points_on_debt_free_collateral() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let user = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let collateral_asset = Address::generate(&env);
+
+    client.set_dust_config(
+        &admin,
+        &DustConfig {
+            min_collateral: 0,
+            dust_threshold: 2000,
+        },
+    );
+    client.set_points_config(
+        &admin,
+        &PointsConfig {
+            active: true,
+            supply_rate: 10,
+            borrow_rate: 0,
+        },
+    );
+    client.borrow(&user, &asset, &1000, &collateral_asset, &1500);
+    client.repay(&user, &1000);
+
+    env.ledger().with_mut(|li| li.timestamp += 100 * 86400);
+    let earned = client.get_points(&user);
+    assert!(earned > 0);
+    client.clean_dust(&user);
+    assert_eq!(client.get_points(&user), earned);
+}
+
+#[test]
+fn test_dust_config_validation() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let stranger = Address::generate(&env);
+
+    let config = DustConfig {
+        min_collateral: 1000,
+        dust_threshold: 1001,
+    };
+    assert_eq!(
+        client.try_set_dust_config(&admin, &config),
+        Err(Ok(DustError::InvalidConfig))
+    );
+    assert_eq!(
+        client.try_set_dust_config(&stranger, &client.get_dust_config()),
+        Err(Ok(DustError::Unauthorized))
+    );
+}
@@ -0,0 +1,142 @@
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol};
+
+use crate::admin::require_admin;
+use crate::borrow::{calculate_interest, get_debt_position, save_debt_position};
+use crate::math::mul_div_down;
+
+/// Errors that can occur during early-repayment rebate configuration
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum EarlyRepayError {
+    Unauthorized = 1,
+    InvalidConfig = 2,
+}
+
+/// Storage keys for early-repayment data
+#[contracttype]
+#[derive(Clone)]
+pub enum EarlyRepayDataKey {
+    EarlyRepayConfig,
+}
+
+/// Early-repayment rebate configuration for variable-rate pool loans
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct EarlyRepayConfig {
+    /// Seconds after origination within which a full repayment earns the rebate
+    pub window: u64,
+    /// Rebate in basis points of the interest outstanding at repayment
+    pub rebate_bps: i128,
+}
+
+/// Early-repayment rebate event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct EarlyRepayRebateEvent {
+    pub user: Address,
+    pub asset: Address,
+    pub rebate: i128,
+    pub timestamp: u64,
+}
+
+const MAX_EARLY_REPAY_REBATE_BPS: i128 = 1000; // 10% of outstanding interest
+
+/// Configure the early-repayment rebate (admin only)
+pub fn set_early_repay_config(
+    env: &Env,
+    caller: Address,
+    config: EarlyRepayConfig,
+) -> Result<(), EarlyRepayError> {
+    require_admin(env, &caller).map_err(|_| EarlyRepayError::Unauthorized)?;
+
+    if config.window == 0 || !(0..=MAX_EARLY_REPAY_REBATE_BPS).contains(&config.rebate_bps) {
+        return Err(EarlyRepayError::InvalidConfig);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&EarlyRepayDataKey::EarlyRepayConfig, &config);
+    Ok(())
+}
+
+/// Get the early-repayment rebate configuration, if set
+pub fn get_early_repay_config(env: &Env) -> Option<EarlyRepayConfig> {
+    env.storage()
+        .persistent()
+        .get(&EarlyRepayDataKey::EarlyRepayConfig)
+}
+
+/// Whether a full repayment now would earn the rebate
+///
+/// Eligibility follows the origination time recorded on the debt position, so
+/// topping up an open loan does not restart the window.
+pub fn is_early_repay_eligible(env: &Env, user: &Address) -> bool {
+    let config = match get_early_repay_config(env) {
+        Some(config) if config.rebate_bps > 0 => config,
+        _ => return false,
+    };
+    let position = get_debt_position(env, user);
+    position.borrowed_amount > 0
+        && env.ledger().timestamp() <= position.opened_at.saturating_add(config.window)
+}
+
+/// Forgive part of the outstanding interest when a payment closes an eligible loan
+///
+/// Called ahead of a borrower repayment. The rebate is only granted when
+/// `amount` covers the debt net of the rebate, so partial repayments are
+/// unaffected. Returns the interest forgiven.
+pub(crate) fn apply_closing_rebate(env: &Env, user: &Address, amount: i128) -> i128 {
+    let rebate = closing_rebate(env, user, amount);
+    if rebate == 0 {
+        return 0;
+    }
+
+    let mut position = get_debt_position(env, user);
+    let interest = position
+        .interest_accrued
+        .saturating_add(calculate_interest(env, user, &position));
+    position.interest_accrued = interest - rebate;
+    position.last_update = env.ledger().timestamp();
+    position.borrow_index = crate::reserve::checkpoint_borrow_index(env, &position.asset);
+    save_debt_position(env, user, &position);
+
+    let event = EarlyRepayRebateEvent {
+        user: user.clone(),
+        asset: position.asset,
+        rebate,
+        timestamp: env.ledger().timestamp(),
+    };
+    crate::events::publish(
+        env,
+        (
+            Symbol::new(env, "early_repay_rebate"),
+            event.user.clone(),
+            event.asset.clone(),
+        ),
+        event,
+    );
+    rebate
+}
+
+/// Interest a borrower payment of `amount` would have forgiven, without
+/// writing state
+pub(crate) fn closing_rebate(env: &Env, user: &Address, amount: i128) -> i128 {
+    if !is_early_repay_eligible(env, user) {
+        return 0;
+    }
+    let rebate_bps = get_early_repay_config(env)
+        .map(|config| config.rebate_bps)
+        .unwrap_or(0);
+
+    let position = get_debt_position(env, user);
+    let interest = position
+        .interest_accrued
+        .saturating_add(calculate_interest(env, user, &position));
+    let rebate = mul_div_down(interest, rebate_bps, 10000);
+    let owed = position.borrowed_amount.saturating_add(interest);
+    if rebate == 0 || amount < owed - rebate {
+        return 0;
+    }
+    rebate
+}
@@ -0,0 +1,119 @@
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+
+const DAY: u64 = 86400;
+
+fn setup(env: &Env) -> (LendingContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 0);
+
+    let contract_id = env.register(LendingContract, ());
+    let client = LendingContractClient::new(env, &contract_id);
+    client.initialize(
+        &Address::generate(env),
+        &InitConfig::new(env, 1_000_000_000, 1000),
+    );
+
+    let admin = Address::generate(env);
+    client.set_admin(&admin);
+    client.set_early_repay_config(
+        &admin,
+        &EarlyRepayConfig {
+            window: 7 * DAY,
+            rebate_bps: 1000,
+        },
+    );
+
+    let user = Address::generate(env);
+    let asset = Address::generate(env);
+    let collateral_asset = Address::generate(env);
+    client.borrow(&user, &asset, &1_000_000, &collateral_asset, &2_000_000);
+
+    (client, admin, user)
+}
+
+fn owed(client: &LendingContractClient, user: &Address) -> i128 {
+    let debt = client.get_user_debt(user);
+    debt.borrowed_amount + debt.interest_accrued
+}
+
+#[test]
+fn test_full_repay_within_window_earns_rebate() {
+    let env = Env::default();
+    let (client, _, user) = setup(&env);
+
+    env.ledger().with_mut(|li| li.timestamp = 5 * DAY);
+    assert!(client.is_early_repay_eligible(&user));
+    let interest = client.get_user_debt(&user).interest_accrued;
+    let rebate = interest / 10;
+    assert!(rebate > 0);
+
+    // Paying the debt net of the rebate closes the loan
+    let (remaining, interest_paid, _) = client.repay(&user, &(1_000_000 + interest - rebate));
+    assert_eq!(remaining, 0);
+    assert_eq!(interest_paid, interest - rebate);
+}
+
+#[test]
+fn test_no_rebate_after_window_or_on_partial_repay() {
+    let env = Env::default();
+    let (client, _, user) = setup(&env);
+
+    env.ledger().with_mut(|li| li.timestamp = 3 * DAY);
+    let before = owed(&client, &user);
+    let (remaining, _, _) = client.repay(&user, &100_000);
+    assert_eq!(remaining, before - 100_000);
+
+    env.ledger().with_mut(|li| li.timestamp = 8 * DAY);
+    assert!(!client.is_early_repay_eligible(&user));
+    let total = owed(&client, &user);
+    let (remaining, interest_paid, principal_paid) = client.repay(&user, &total);
+    assert_eq!(remaining, 0);
+    assert_eq!(interest_paid + principal_paid, total);
+}
+
+#[test]
+fn test_window_restarts_for_new_loan() {
+    let env = Env::default();
+    let (client, _, user) = setup(&env);
+
+    env.ledger().with_mut(|li| li.timestamp = 10 * DAY);
+    client.repay(&user, &owed(&client, &user));
+    assert_eq!(client.get_user_debt(&user).borrowed_amount, 0);
+
+    let asset = Address::generate(&env);
+    let collateral_asset = Address::generate(&env);
+    client.borrow(&user, &asset, &500_000, &collateral_asset, &1_000_000);
+    assert_eq!(client.get_user_debt(&user).opened_at, 10 * DAY);
+    assert!(client.is_early_repay_eligible(&user));
+}
+
+#[test]
+fn test_config_validation() {
+    let env = Env::default();
+    let (client, admin, _) = setup(&env);
+
+    let excessive = EarlyRepayConfig {
+        window: DAY,
+        rebate_bps: 1001,
+    };
+    assert_eq!(
+        client.try_set_early_repay_config(&admin, &excessive),
+        Err(Ok(EarlyRepayError::InvalidConfig))
+    );
+    let no_window = EarlyRepayConfig {
+        window: 0,
+        rebate_bps: 500,
+    };
+    assert_eq!(
+        client.try_set_early_repay_config(&admin, &no_window),
+        Err(Ok(EarlyRepayError::InvalidConfig))
+    );
+    assert_eq!(
+        client.try_set_early_repay_config(&Address::generate(&env), &no_window),
+        Err(Ok(EarlyRepayError::Unauthorized))
+    );
+}
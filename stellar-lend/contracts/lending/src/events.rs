@@ -0,0 +1,40 @@
+use soroban_sdk::{contracttype, Env, IntoVal, Val, Vec};
+
+/// Storage keys for event sequencing
+#[contracttype]
+#[derive(Clone)]
+pub enum EventDataKey {
+    /// Sequence number of the last event published by this contract
+    EventSequence,
+}
+
+/// Publish an event with the next sequence number appended as its last topic
+///
+/// Sequence numbers start at 1 and increase by one per event, so indexers can
+/// detect gaps and drop duplicates when replaying ledgers.
+///
+/// Topics start with the event name, followed by the account and then the
+/// asset the event concerns, where it has them, so subscribers can filter on
+/// a single account or market without decoding payloads.
+pub(crate) fn publish<T, D>(env: &Env, topics: T, data: D)
+where
+    T: IntoVal<Env, Vec<Val>>,
+    D: IntoVal<Env, Val>,
+{
+    let sequence = get_event_sequence(env) + 1;
+    env.storage()
+        .instance()
+        .set(&EventDataKey::EventSequence, &sequence);
+
+    let mut topics: Vec<Val> = topics.into_val(env);
+    topics.push_back(sequence.into_val(env));
+    env.events().publish(topics, data);
+}
+
+/// Sequence number of the last published event (0 before any event)
+pub fn get_event_sequence(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&EventDataKey::EventSequence)
+        .unwrap_or(0)
+}
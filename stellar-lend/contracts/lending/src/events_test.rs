@@ -0,0 +1,152 @@
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    Address, Env, Symbol, TryFromVal, Val, Vec,
+};
+
+fn setup(env: &Env) -> (LendingContractClient<'_>, Address) {
+    env.mock_all_auths();
+    let client = LendingContractClient::new(env, &env.register(LendingContract, ()));
+    client.initialize(
+        &Address::generate(env),
+        &InitConfig::new(env, 1_000_000_000, 1000),
+    );
+    let admin = Address::generate(env);
+    client.set_admin(&admin);
+    (client, admin)
+}
+
+/// Topics of the last event published under `name`
+fn topics_of(env: &Env, name: &str) -> Vec<Val> {
+    let (_, topics, _) = env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| {
+            Symbol::try_from_val(env, &topics.get(0).unwrap()) == Ok(Symbol::new(env, name))
+        })
+        .last()
+        .unwrap();
+    topics
+}
+
+fn assert_topics(env: &Env, topics: &Vec<Val>, expected: &[&Address]) {
+    // Name, the expected addresses, then the sequence number
+    assert_eq!(topics.len() as usize, expected.len() + 2);
+    for (i, address) in expected.iter().enumerate() {
+        assert_eq!(
+            Address::try_from_val(env, &topics.get(i as u32 + 1).unwrap()).as_ref(),
+            Ok(*address)
+        );
+    }
+}
+
+#[test]
+fn test_events_carry_consecutive_sequence_numbers() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = LendingContractClient::new(&env, &env.register(LendingContract, ()));
+    client.initialize(
+        &Address::generate(&env),
+        &InitConfig::new(&env, 1_000_000_000, 1000),
+    );
+    // Initialization emits the first events
+    let initial = client.get_event_sequence();
+    assert!(initial > 0);
+
+    let user = Address::generate(&env);
+    client.borrow(
+        &user,
+        &Address::generate(&env),
+        &10_000,
+        &Address::generate(&env),
+        &20_000,
+    );
+    let borrow_events = env.events().all();
+    client.repay(&user, &5000);
+    let repay_events = env.events().all();
+
+    let mut expected = initial + 1;
+    for events in [borrow_events, repay_events] {
+        for (_, topics, _) in events.iter() {
+            let last = topics.get(topics.len() - 1).unwrap();
+            assert_eq!(u64::try_from_val(&env, &last).unwrap(), expected);
+            expected += 1;
+        }
+    }
+    assert!(expected > initial + 2);
+    assert_eq!(client.get_event_sequence(), expected - 1);
+}
+
+#[test]
+fn test_events_carry_account_and_asset_topics() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+
+    let user = Address::generate(&env);
+    let asset = Address::generate(&env);
+    client.borrow(&user, &asset, &10_000, &Address::generate(&env), &20_000);
+    assert_topics(&env, &topics_of(&env, "borrow"), &[&user, &asset]);
+}
+
+#[test]
+fn test_credit_line_events_carry_line_asset() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let risk_manager = Address::generate(&env);
+    client.set_risk_manager(&admin, &risk_manager);
+
+    let borrower = Address::generate(&env);
+    let asset = Address::generate(&env);
+    client.open_credit_line(&risk_manager, &borrower, &asset, &50_000, &0, &1000);
+    assert_topics(
+        &env,
+        &topics_of(&env, "credit_line_opened"),
+        &[&borrower, &asset],
+    );
+    client.draw_credit(&borrower, &10_000);
+    assert_topics(&env, &topics_of(&env, "credit_drawn"), &[&borrower, &asset]);
+    client.write_off_credit_line(&risk_manager, &borrower);
+    assert_topics(
+        &env,
+        &topics_of(&env, "credit_written_off"),
+        &[&borrower, &asset],
+    );
+}
+
+#[test]
+fn test_deleverage_event_carries_debt_and_collateral_assets() {
+    let env = Env::default();
+    env.ledger().with_mut(|li| li.timestamp = 0);
+    let (client, admin) = setup(&env);
+    let asset = Address::generate(&env);
+    client.set_p2p_market(
+        &admin,
+        &asset,
+        &P2PMarket {
+            pool_supply_rate_bps: 200,
+            p2p_cursor_bps: 5000,
+        },
+    );
+    client.p2p_supply(&Address::generate(&env), &asset, &100_000);
+    client.set_deleverage_config(
+        &admin,
+        &DeleverageConfig {
+            critical_utilization_bps: 9000,
+            target_utilization_bps: 8000,
+            min_crunch_duration: 0,
+            max_close_bps: 2500,
+            penalty_bps: 200,
+        },
+    );
+
+    let whale = Address::generate(&env);
+    let collateral_asset = Address::generate(&env);
+    client.borrow(&whale, &asset, &92_000, &collateral_asset, &184_000);
+    client.deleverage(&Address::generate(&env), &whale, &12_000);
+    assert_topics(
+        &env,
+        &topics_of(&env, "position_deleveraged"),
+        &[&whale, &asset, &collateral_asset],
+    );
+}
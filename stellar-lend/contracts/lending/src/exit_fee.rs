@@ -0,0 +1,212 @@
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol};
+
+use crate::admin::require_admin;
+use crate::math::mul_div_down;
+
+/// Errors that can occur when configuring withdrawal fees
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ExitFeeError {
+    Unauthorized = 1,
+    InvalidConfig = 2,
+}
+
+/// Storage keys for withdrawal fee data
+#[contracttype]
+#[derive(Clone)]
+pub enum ExitFeeDataKey {
+    WithdrawalFeeConfig(Address),
+    /// Cumulative fees and strategy yield credited per unit of an asset's
+    /// supply, scaled by `EXIT_FEE_INDEX_SCALE`
+    ExitFeeIndex(Address),
+    /// Fee index a supplier's balance was last credited at
+    ExitFeeCheckpoint(Address, Address),
+}
+
+/// Utilization-dependent withdrawal fee of an asset
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExitFeeConfig {
+    /// Utilization above which withdrawals are charged, in basis points
+    pub kink_utilization_bps: i128,
+    /// Fee at full utilization, in basis points; scales linearly from the kink
+    pub max_fee_bps: i128,
+}
+
+/// Withdrawal fee event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ExitFeeEvent {
+    pub supplier: Address,
+    pub asset: Address,
+    pub amount: i128,
+    pub fee: i128,
+    pub utilization_bps: i128,
+    pub timestamp: u64,
+}
+
+const EXIT_FEE_INDEX_SCALE: i128 = 1_000_000_000_000;
+const MAX_EXIT_FEE_BPS: i128 = 100; // 1%
+
+/// Set an asset's withdrawal fee schedule (admin only)
+///
+/// Withdrawals pay nothing while the market's utilization is at or below the
+/// kink; above it the fee rises linearly to `max_fee_bps` (at most 1%) at full
+/// utilization. Fees stay in the market and accrue to the remaining suppliers.
+pub fn set_exit_fee_config(
+    env: &Env,
+    caller: Address,
+    asset: Address,
+    config: ExitFeeConfig,
+) -> Result<(), ExitFeeError> {
+    require_admin(env, &caller).map_err(|_| ExitFeeError::Unauthorized)?;
+    if !(0..10000).contains(&config.kink_utilization_bps)
+        || !(0..=MAX_EXIT_FEE_BPS).contains(&config.max_fee_bps)
+    {
+        return Err(ExitFeeError::InvalidConfig);
+    }
+    env.storage()
+        .persistent()
+        .set(&ExitFeeDataKey::WithdrawalFeeConfig(asset), &config);
+    Ok(())
+}
+
+/// Get an asset's withdrawal fee schedule, if set
+pub fn get_exit_fee_config(env: &Env, asset: &Address) -> Option<ExitFeeConfig> {
+    env.storage()
+        .persistent()
+        .get(&ExitFeeDataKey::WithdrawalFeeConfig(asset.clone()))
+}
+
+/// Current withdrawal fee of an asset, in basis points
+pub fn get_exit_fee_bps(env: &Env, asset: &Address) -> i128 {
+    let Some(config) = get_exit_fee_config(env, asset) else {
+        return 0;
+    };
+    let utilization = get_asset_utilization(env, asset).min(10000);
+    if utilization <= config.kink_utilization_bps {
+        return 0;
+    }
+    mul_div_down(
+        config.max_fee_bps,
+        utilization - config.kink_utilization_bps,
+        10000 - config.kink_utilization_bps,
+    )
+}
+
+/// Charge the withdrawal fee on `amount` and share it among remaining suppliers
+///
+/// Nothing is charged when no other supply would remain to receive it.
+///
+/// # Returns
+/// The fee, which the caller keeps in the market
+pub(crate) fn charge_exit_fee(
+    env: &Env,
+    supplier: &Address,
+    asset: &Address,
+    amount: i128,
+) -> i128 {
+    let fee_bps = get_exit_fee_bps(env, asset);
+    let remaining = crate::p2p::get_asset_supplied(env, asset).saturating_sub(amount);
+    if fee_bps == 0 || remaining <= 0 {
+        return 0;
+    }
+    let fee = mul_div_down(amount, fee_bps, 10000);
+    if fee == 0 {
+        return 0;
+    }
+
+    distribute_to_suppliers(env, asset, fee, remaining);
+
+    let event = ExitFeeEvent {
+        supplier: supplier.clone(),
+        asset: asset.clone(),
+        amount,
+        fee,
+        utilization_bps: get_asset_utilization(env, asset),
+        timestamp: env.ledger().timestamp(),
+    };
+    crate::events::publish(
+        env,
+        (
+            Symbol::new(env, "exit_fee_charged"),
+            event.supplier.clone(),
+            event.asset.clone(),
+        ),
+        event,
+    );
+    fee
+}
+
+/// Credit `amount` to the holders of `supply` units of an asset's supply, pro rata
+///
+/// Each balance picks up its share the next time it is touched. A negative
+/// amount writes balances down, rounded against suppliers.
+pub(crate) fn distribute_to_suppliers(env: &Env, asset: &Address, amount: i128, supply: i128) {
+    if amount == 0 || supply <= 0 {
+        return;
+    }
+    let index = get_exit_fee_index(env, asset).saturating_add(mul_div_down(
+        amount,
+        EXIT_FEE_INDEX_SCALE,
+        supply,
+    ));
+    env.storage()
+        .persistent()
+        .set(&ExitFeeDataKey::ExitFeeIndex(asset.clone()), &index);
+}
+
+/// Fees and yield earned by a supplier's balance since it was last credited
+///
+/// Negative when loss write-downs outweigh the earnings.
+pub(crate) fn get_fee_share(env: &Env, supplier: &Address, asset: &Address, balance: i128) -> i128 {
+    let earned =
+        get_exit_fee_index(env, asset).saturating_sub(get_checkpoint(env, supplier, asset));
+    if earned == 0 || balance <= 0 {
+        return 0;
+    }
+    mul_div_down(balance, earned, EXIT_FEE_INDEX_SCALE)
+}
+
+/// Record that a supplier's balance has been credited its share
+pub(crate) fn checkpoint_fee_share(env: &Env, supplier: &Address, asset: &Address) {
+    let index = get_exit_fee_index(env, asset);
+    if index != get_checkpoint(env, supplier, asset) {
+        env.storage().persistent().set(
+            &ExitFeeDataKey::ExitFeeCheckpoint(supplier.clone(), asset.clone()),
+            &index,
+        );
+    }
+}
+
+/// Utilization of one asset's market in basis points
+///
+/// Cash deployed to a yield strategy counts as used.
+fn get_asset_utilization(env: &Env, asset: &Address) -> i128 {
+    let supplied = crate::p2p::get_asset_supplied(env, asset);
+    if supplied <= 0 {
+        return 0;
+    }
+    crate::borrow::get_asset_debt(env, asset)
+        .saturating_add(crate::strategy::get_strategy_deployed(env, asset))
+        .saturating_mul(10000)
+        / supplied
+}
+
+fn get_exit_fee_index(env: &Env, asset: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&ExitFeeDataKey::ExitFeeIndex(asset.clone()))
+        .unwrap_or(0)
+}
+
+fn get_checkpoint(env: &Env, supplier: &Address, asset: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&ExitFeeDataKey::ExitFeeCheckpoint(
+            supplier.clone(),
+            asset.clone(),
+        ))
+        .unwrap_or(0)
+}
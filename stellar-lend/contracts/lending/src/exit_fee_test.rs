@@ -0,0 +1,65 @@
+use super::*;
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+#[test]
+fn test_exit_fee_scales_with_utilization_and_pays_suppliers() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = LendingContractClient::new(&env, &env.register(LendingContract, ()));
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &InitConfig::new(&env, 1_000_000_000, 1000));
+    let asset = Address::generate(&env);
+    client.set_p2p_market(
+        &admin,
+        &asset,
+        &P2PMarket {
+            pool_supply_rate_bps: 0,
+            p2p_cursor_bps: 0,
+        },
+    );
+    client.set_exit_fee_config(
+        &admin,
+        &asset,
+        &ExitFeeConfig {
+            kink_utilization_bps: 5000,
+            max_fee_bps: 100,
+        },
+    );
+
+    let leaving = Address::generate(&env);
+    let staying = Address::generate(&env);
+    client.p2p_supply(&leaving, &asset, &100_000);
+    client.p2p_supply(&staying, &asset, &100_000);
+    client.borrow(
+        &Address::generate(&env),
+        &asset,
+        &50_000,
+        &Address::generate(&env),
+        &100_000,
+    );
+    assert_eq!(client.get_exit_fee_bps(&asset), 0);
+    assert_eq!(client.p2p_withdraw(&leaving, &asset, &10_000), 10_000);
+
+    // 50_000 of 190_000 is still below the kink; 150_000 of 190_000 is 78.9%
+    client.borrow(
+        &Address::generate(&env),
+        &asset,
+        &100_000,
+        &Address::generate(&env),
+        &200_000,
+    );
+    assert_eq!(client.get_exit_fee_bps(&asset), 57);
+    assert_eq!(client.p2p_withdraw(&leaving, &asset, &10_000), 9943);
+
+    // The 57 fee is shared by the 180_000 remaining: half of it each
+    assert_eq!(client.get_p2p_supply(&staying, &asset).on_pool, 100_031);
+    assert_eq!(client.get_p2p_supply(&leaving, &asset).on_pool, 80_025);
+    assert_eq!(client.get_market_size(&asset).supplied, 180_057);
+
+    let mut config = client.get_exit_fee_config(&asset).unwrap();
+    config.max_fee_bps = 101;
+    assert_eq!(
+        client.try_set_exit_fee_config(&admin, &asset, &config),
+        Err(Ok(ExitFeeError::InvalidConfig))
+    );
+}
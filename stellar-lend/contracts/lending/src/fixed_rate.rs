@@ -0,0 +1,633 @@
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol, Vec};
+
+use crate::admin::require_admin;
+use crate::borrow::{
+    adjust_asset_debt, check_new_debt, get_total_collateral_value, get_total_debt,
+    get_total_user_debt, set_total_debt, validate_collateral_ratio, BorrowError, SECONDS_PER_YEAR,
+};
+use crate::insurance::credit_protocol_fee;
+use crate::math::{div_up, mul_div_up};
+
+/// Errors that can occur in the fixed-rate market
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum FixedRateError {
+    InvalidAmount = 1,
+    InvalidRate = 2,
+    InvalidDuration = 3,
+    OfferNotFound = 4,
+    OfferInactive = 5,
+    Unauthorized = 6,
+    InsufficientCollateral = 7,
+    LoanNotFound = 8,
+    LoanClosed = 9,
+    SelfMatch = 10,
+    Overflow = 11,
+    TooManyPositions = 12,
+    InvalidPenalty = 13,
+    InvalidConfig = 14,
+    BorrowCapExceeded = 15,
+    /// The account is under a compliance freeze
+    AccountFrozen = 16,
+    ProtocolPaused = 17,
+    PriceStale = 18,
+    MarketFrozen = 19,
+    AssetBorrowsPaused = 20,
+    AssetDelisted = 21,
+    BorrowFrozen = 22,
+    AssetCollateralOnly = 23,
+    DebtCeilingReached = 24,
+    LiquidityBufferReached = 25,
+    RehypothecationDisabled = 26,
+    /// The borrow must go through the pool's `request_borrow`
+    LargeBorrowRequestRequired = 27,
+    /// The borrow is valued above the per-call maximum
+    BorrowSizeCapExceeded = 28,
+}
+
+/// Storage keys for fixed-rate market data
+#[contracttype]
+#[derive(Clone)]
+pub enum FixedRateDataKey {
+    NextOfferId,
+    LoanOffer(u64),
+    NextFixedLoanId,
+    FixedLoan(u64),
+    BorrowerFixedLoans(Address),
+    OverduePenaltyRate,
+}
+
+/// A lender's standing offer to lend at a fixed rate for a fixed term
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LoanOffer {
+    pub id: u64,
+    pub lender: Address,
+    pub asset: Address,
+    /// Amount still available to borrowers
+    pub amount: i128,
+    /// Annual fixed rate in basis points
+    pub rate_bps: i128,
+    /// Loan term in seconds
+    pub duration: u64,
+    pub active: bool,
+    /// Fee charged on loans taken from this offer if repaid early
+    pub prepayment_penalty: PrepaymentPenalty,
+}
+
+/// Fee for repaying a fixed-term loan before maturity
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PrepaymentPenalty {
+    /// Fee on the principal when repaid at origination, in basis points;
+    /// decays linearly to zero at maturity (zero for no penalty)
+    pub penalty_bps: i128,
+    /// Credit the fee to the protocol reserve instead of the loan holder
+    pub to_reserve: bool,
+}
+
+/// A matched fixed-rate, fixed-term loan
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FixedLoan {
+    pub id: u64,
+    pub offer_id: u64,
+    /// Originating lender
+    pub lender: Address,
+    /// Current owner of the repayment claim
+    pub holder: Address,
+    pub borrower: Address,
+    pub asset: Address,
+    pub principal: i128,
+    /// Interest owed at maturity, fixed at origination
+    pub interest: i128,
+    pub start: u64,
+    pub maturity: u64,
+    pub repaid: bool,
+    /// Prepayment terms of the offer at origination
+    pub prepayment_penalty: PrepaymentPenalty,
+    /// Penalty interest paid for repaying after maturity, kept apart from
+    /// the fixed interest
+    pub penalty_interest: i128,
+}
+
+/// Fixed-rate market event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct FixedRateEvent {
+    pub id: u64,
+    pub lender: Address,
+    pub borrower: Option<Address>,
+    pub amount: i128,
+    pub rate_bps: i128,
+    pub timestamp: u64,
+}
+
+const MAX_FIXED_RATE_BPS: i128 = 10000; // 100% APR
+const MIN_LOAN_DURATION: u64 = 86400; // 1 day
+const MAX_LOAN_DURATION: u64 = 5 * SECONDS_PER_YEAR;
+const MAX_PREPAYMENT_PENALTY_BPS: i128 = 300; // 3%
+
+/// Penalty interest event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct PenaltyInterestEvent {
+    pub loan_id: u64,
+    pub borrower: Address,
+    pub asset: Address,
+    pub penalty_interest: i128,
+    /// Seconds the loan was overdue
+    pub overdue_for: u64,
+    pub timestamp: u64,
+}
+
+/// Post an offer to lend `amount` of `asset` at a fixed rate for `duration` seconds
+pub fn post_offer(
+    env: &Env,
+    lender: Address,
+    asset: Address,
+    amount: i128,
+    rate_bps: i128,
+    duration: u64,
+) -> Result<u64, FixedRateError> {
+    lender.require_auth();
+
+    if amount <= 0 {
+        return Err(FixedRateError::InvalidAmount);
+    }
+    if !(0..=MAX_FIXED_RATE_BPS).contains(&rate_bps) {
+        return Err(FixedRateError::InvalidRate);
+    }
+    if !(MIN_LOAN_DURATION..=MAX_LOAN_DURATION).contains(&duration) {
+        return Err(FixedRateError::InvalidDuration);
+    }
+    if crate::account_freeze::is_account_frozen(env, &lender) {
+        return Err(FixedRateError::AccountFrozen);
+    }
+
+    let id = next_id(env, &FixedRateDataKey::NextOfferId);
+    let offer = LoanOffer {
+        id,
+        lender: lender.clone(),
+        asset,
+        amount,
+        rate_bps,
+        duration,
+        active: true,
+        prepayment_penalty: PrepaymentPenalty {
+            penalty_bps: 0,
+            to_reserve: false,
+        },
+    };
+    save_offer(env, &offer);
+
+    emit_fixed_rate_event(env, "offer_posted", id, lender, None, amount, rate_bps);
+    Ok(id)
+}
+
+/// Cancel the unfilled remainder of an offer
+pub fn cancel_offer(env: &Env, lender: Address, offer_id: u64) -> Result<(), FixedRateError> {
+    lender.require_auth();
+
+    let mut offer = get_offer(env, offer_id).ok_or(FixedRateError::OfferNotFound)?;
+    if offer.lender != lender {
+        return Err(FixedRateError::Unauthorized);
+    }
+    if !offer.active {
+        return Err(FixedRateError::OfferInactive);
+    }
+
+    offer.active = false;
+    save_offer(env, &offer);
+
+    emit_fixed_rate_event(
+        env,
+        "offer_cancelled",
+        offer_id,
+        lender,
+        None,
+        offer.amount,
+        offer.rate_bps,
+    );
+    Ok(())
+}
+
+/// Take (part of) an offer against the borrower's collateral in the core pool
+///
+/// The loan faces the same pause, asset and ceiling checks as a pool borrow,
+/// and the borrower's collateral value must cover all of their debt at their
+/// collateral ratio. Its principal counts toward the pool's total and asset
+/// debt until it is repaid.
+pub fn take_offer(
+    env: &Env,
+    borrower: Address,
+    offer_id: u64,
+    amount: i128,
+) -> Result<u64, FixedRateError> {
+    borrower.require_auth();
+
+    if amount <= 0 {
+        return Err(FixedRateError::InvalidAmount);
+    }
+    let mut offer = get_offer(env, offer_id).ok_or(FixedRateError::OfferNotFound)?;
+    if !offer.active {
+        return Err(FixedRateError::OfferInactive);
+    }
+    if offer.lender == borrower {
+        return Err(FixedRateError::SelfMatch);
+    }
+    if amount > offer.amount {
+        return Err(FixedRateError::InvalidAmount);
+    }
+    if !crate::position_limit::has_position_capacity(env, &borrower) {
+        return Err(FixedRateError::TooManyPositions);
+    }
+    let (new_total, new_asset_debt) =
+        check_new_debt(env, &borrower, &offer.asset, amount).map_err(debt_error)?;
+
+    let total_debt = get_total_user_debt(env, &borrower)
+        .checked_add(amount)
+        .ok_or(FixedRateError::Overflow)?;
+    validate_collateral_ratio(
+        env,
+        &borrower,
+        get_total_collateral_value(env, &borrower),
+        total_debt,
+    )
+    .map_err(|_| FixedRateError::InsufficientCollateral)?;
+
+    let interest = fixed_interest(amount, offer.rate_bps, offer.duration)?;
+    let now = env.ledger().timestamp();
+    let id = next_id(env, &FixedRateDataKey::NextFixedLoanId);
+    let loan = FixedLoan {
+        id,
+        offer_id,
+        lender: offer.lender.clone(),
+        holder: offer.lender.clone(),
+        borrower: borrower.clone(),
+        asset: offer.asset.clone(),
+        principal: amount,
+        interest,
+        start: now,
+        maturity: now.saturating_add(offer.duration),
+        repaid: false,
+        prepayment_penalty: offer.prepayment_penalty.clone(),
+        penalty_interest: 0,
+    };
+    save_loan(env, &loan);
+    set_total_debt(env, new_total);
+    adjust_asset_debt(env, &loan.asset, amount);
+    crate::debt_ceiling::record_debt_growth(
+        env,
+        &loan.asset,
+        new_asset_debt - amount,
+        new_asset_debt,
+    );
+    crate::receivables::add_holding(env, &loan.holder, id);
+
+    let mut loans = get_borrower_fixed_loans(env, &borrower);
+    loans.push_back(id);
+    env.storage().persistent().set(
+        &FixedRateDataKey::BorrowerFixedLoans(borrower.clone()),
+        &loans,
+    );
+
+    offer.amount -= amount;
+    if offer.amount == 0 {
+        offer.active = false;
+    }
+    save_offer(env, &offer);
+
+    emit_fixed_rate_event(
+        env,
+        "offer_taken",
+        id,
+        offer.lender,
+        Some(borrower),
+        amount,
+        offer.rate_bps,
+    );
+    Ok(id)
+}
+
+/// Set the prepayment penalty of an open offer (lender only)
+///
+/// Applies to loans taken from the offer afterwards; existing loans keep the
+/// terms they were originated with.
+pub fn set_prepayment_penalty(
+    env: &Env,
+    lender: Address,
+    offer_id: u64,
+    penalty: PrepaymentPenalty,
+) -> Result<(), FixedRateError> {
+    lender.require_auth();
+
+    let mut offer = get_offer(env, offer_id).ok_or(FixedRateError::OfferNotFound)?;
+    if offer.lender != lender {
+        return Err(FixedRateError::Unauthorized);
+    }
+    if !offer.active {
+        return Err(FixedRateError::OfferInactive);
+    }
+    if !(0..=MAX_PREPAYMENT_PENALTY_BPS).contains(&penalty.penalty_bps) {
+        return Err(FixedRateError::InvalidPenalty);
+    }
+
+    offer.prepayment_penalty = penalty;
+    save_offer(env, &offer);
+    Ok(())
+}
+
+/// Repay a fixed-term loan in full (principal plus the fixed interest)
+///
+/// The repayment is routed to the loan's current claim holder. Loans repaid
+/// before maturity also pay their prepayment penalty, if any, to the holder
+/// or the protocol reserve; loans repaid after maturity pay penalty interest
+/// to the holder.
+///
+/// # Returns
+/// The total amount repaid, including any prepayment penalty or penalty
+/// interest
+pub fn repay_fixed_loan(
+    env: &Env,
+    borrower: Address,
+    loan_id: u64,
+) -> Result<i128, FixedRateError> {
+    borrower.require_auth();
+
+    let mut loan = get_fixed_loan(env, loan_id).ok_or(FixedRateError::LoanNotFound)?;
+    if loan.borrower != borrower {
+        return Err(FixedRateError::Unauthorized);
+    }
+    if loan.repaid {
+        return Err(FixedRateError::LoanClosed);
+    }
+
+    let penalty = prepayment_fee(env, &loan);
+    let penalty_interest = overdue_interest(env, &loan);
+    let total = loan
+        .principal
+        .checked_add(loan.interest)
+        .and_then(|owed| owed.checked_add(penalty))
+        .and_then(|owed| owed.checked_add(penalty_interest))
+        .ok_or(FixedRateError::Overflow)?;
+    loan.repaid = true;
+    loan.penalty_interest = penalty_interest;
+    save_loan(env, &loan);
+    set_total_debt(
+        env,
+        get_total_debt(env).saturating_sub(loan.principal).max(0),
+    );
+    adjust_asset_debt(env, &loan.asset, -loan.principal);
+    crate::receivables::on_loan_repaid(env, &loan);
+    if loan.prepayment_penalty.to_reserve {
+        credit_protocol_fee(env, &loan.asset, penalty);
+    } else {
+        crate::receivables::credit_proceeds(env, &loan.holder, penalty);
+    }
+    if penalty_interest > 0 {
+        crate::receivables::credit_proceeds(env, &loan.holder, penalty_interest);
+        let event = PenaltyInterestEvent {
+            loan_id,
+            borrower: borrower.clone(),
+            asset: loan.asset.clone(),
+            penalty_interest,
+            overdue_for: env.ledger().timestamp() - loan.maturity,
+            timestamp: env.ledger().timestamp(),
+        };
+        crate::events::publish(
+            env,
+            (
+                Symbol::new(env, "fixed_loan_penalty_interest"),
+                event.borrower.clone(),
+                event.asset.clone(),
+            ),
+            event,
+        );
+    }
+
+    let mut loans = get_borrower_fixed_loans(env, &borrower);
+    if let Some(index) = loans.first_index_of(loan_id) {
+        loans.remove(index);
+    }
+    env.storage().persistent().set(
+        &FixedRateDataKey::BorrowerFixedLoans(borrower.clone()),
+        &loans,
+    );
+
+    emit_fixed_rate_event(
+        env,
+        "fixed_loan_repaid",
+        loan_id,
+        loan.holder,
+        Some(borrower),
+        total,
+        0,
+    );
+    Ok(total)
+}
+
+/// Get an offer by id
+pub fn get_offer(env: &Env, offer_id: u64) -> Option<LoanOffer> {
+    env.storage()
+        .persistent()
+        .get(&FixedRateDataKey::LoanOffer(offer_id))
+}
+
+/// Get a fixed-term loan by id
+pub fn get_fixed_loan(env: &Env, loan_id: u64) -> Option<FixedLoan> {
+    env.storage()
+        .persistent()
+        .get(&FixedRateDataKey::FixedLoan(loan_id))
+}
+
+/// Get the ids of a borrower's open fixed-term loans
+pub fn get_borrower_fixed_loans(env: &Env, borrower: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&FixedRateDataKey::BorrowerFixedLoans(borrower.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Outstanding fixed-term principal owed by a borrower, plus penalty
+/// interest accrued on overdue loans
+///
+/// Counted alongside core debt wherever the borrower's collateral coverage is
+/// evaluated, so liquidation of the core position also protects fixed lenders.
+pub fn get_fixed_debt(env: &Env, borrower: &Address) -> i128 {
+    get_borrower_fixed_loans(env, borrower)
+        .iter()
+        .filter_map(|id| get_fixed_loan(env, id))
+        .fold(0i128, |acc, loan| {
+            acc.saturating_add(loan.principal)
+                .saturating_add(overdue_interest(env, &loan))
+        })
+}
+
+/// Set the annual penalty rate charged on fixed-term debt past maturity (admin only)
+pub fn set_overdue_penalty_rate(
+    env: &Env,
+    caller: Address,
+    rate_bps: i128,
+) -> Result<(), FixedRateError> {
+    require_admin(env, &caller).map_err(|_| FixedRateError::Unauthorized)?;
+
+    if !(0..=MAX_FIXED_RATE_BPS).contains(&rate_bps) {
+        return Err(FixedRateError::InvalidConfig);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&FixedRateDataKey::OverduePenaltyRate, &rate_bps);
+    Ok(())
+}
+
+/// Annual penalty rate on overdue fixed-term debt in basis points (0 if unset)
+pub fn get_overdue_penalty_rate(env: &Env) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&FixedRateDataKey::OverduePenaltyRate)
+        .unwrap_or(0)
+}
+
+/// Penalty interest accrued on a loan since maturity
+///
+/// Accrues on principal plus the fixed interest at the current penalty rate,
+/// separately from the fixed interest, until the loan is repaid.
+pub fn get_overdue_interest(env: &Env, loan_id: u64) -> i128 {
+    get_fixed_loan(env, loan_id)
+        .map(|loan| overdue_interest(env, &loan))
+        .unwrap_or(0)
+}
+
+fn overdue_interest(env: &Env, loan: &FixedLoan) -> i128 {
+    let now = env.ledger().timestamp();
+    if loan.repaid || now <= loan.maturity {
+        return 0;
+    }
+    mul_div_up(
+        loan.principal
+            .saturating_add(loan.interest)
+            .saturating_mul(get_overdue_penalty_rate(env)),
+        (now - loan.maturity) as i128,
+        10000 * SECONDS_PER_YEAR as i128,
+    )
+}
+
+/// Prepayment penalty owed if a loan were repaid now
+///
+/// Decays linearly from the full penalty at origination to zero at
+/// maturity, rounded up.
+pub fn get_prepayment_fee(env: &Env, loan_id: u64) -> i128 {
+    get_fixed_loan(env, loan_id)
+        .map(|loan| prepayment_fee(env, &loan))
+        .unwrap_or(0)
+}
+
+fn prepayment_fee(env: &Env, loan: &FixedLoan) -> i128 {
+    let now = env.ledger().timestamp();
+    if loan.repaid || now >= loan.maturity {
+        return 0;
+    }
+    let remaining = (loan.maturity - now) as i128;
+    let term = (loan.maturity - loan.start) as i128;
+    mul_div_up(
+        loan.principal
+            .saturating_mul(loan.prepayment_penalty.penalty_bps),
+        remaining,
+        10000 * term,
+    )
+}
+
+/// Interest for the full term, rounded up in favor of the lender
+fn fixed_interest(principal: i128, rate_bps: i128, duration: u64) -> Result<i128, FixedRateError> {
+    principal
+        .checked_mul(rate_bps)
+        .and_then(|v| v.checked_mul(duration as i128))
+        .map(|numerator| div_up(numerator, 10000 * SECONDS_PER_YEAR as i128))
+        .ok_or(FixedRateError::Overflow)
+}
+
+/// Map a failed debt check onto the fixed-rate error it surfaces as
+fn debt_error(error: BorrowError) -> FixedRateError {
+    match error {
+        BorrowError::ProtocolPaused => FixedRateError::ProtocolPaused,
+        BorrowError::AccountFrozen => FixedRateError::AccountFrozen,
+        BorrowError::BorrowCapExceeded => FixedRateError::BorrowCapExceeded,
+        BorrowError::PriceStale => FixedRateError::PriceStale,
+        BorrowError::MarketFrozen => FixedRateError::MarketFrozen,
+        BorrowError::AssetBorrowsPaused => FixedRateError::AssetBorrowsPaused,
+        BorrowError::AssetDelisted => FixedRateError::AssetDelisted,
+        BorrowError::BorrowFrozen => FixedRateError::BorrowFrozen,
+        BorrowError::AssetCollateralOnly => FixedRateError::AssetCollateralOnly,
+        BorrowError::DebtCeilingReached => FixedRateError::DebtCeilingReached,
+        BorrowError::LiquidityBufferReached => FixedRateError::LiquidityBufferReached,
+        BorrowError::RehypothecationDisabled => FixedRateError::RehypothecationDisabled,
+        BorrowError::LargeBorrowRequestRequired => FixedRateError::LargeBorrowRequestRequired,
+        BorrowError::BorrowSizeCapExceeded => FixedRateError::BorrowSizeCapExceeded,
+        BorrowError::Overflow => FixedRateError::Overflow,
+        BorrowError::TooManyPositions => FixedRateError::TooManyPositions,
+        BorrowError::InvalidAmount | BorrowError::BelowMinimumBorrow => {
+            FixedRateError::InvalidAmount
+        }
+        BorrowError::Unauthorized => FixedRateError::Unauthorized,
+        // Collateral-side and request errors are never returned by the debt check
+        BorrowError::InsufficientCollateral
+        | BorrowError::AssetNotSupported
+        | BorrowError::ExposureCapReached
+        | BorrowError::BelowMinimumCollateral
+        | BorrowError::CollateralFrozen
+        | BorrowError::AssetBorrowOnly => FixedRateError::InsufficientCollateral,
+        BorrowError::InvalidConfig
+        | BorrowError::NoPendingBorrow
+        | BorrowError::BorrowDelayActive
+        | BorrowError::PendingBorrowExists => FixedRateError::InvalidConfig,
+    }
+}
+
+fn next_id(env: &Env, key: &FixedRateDataKey) -> u64 {
+    let id: u64 = env.storage().persistent().get(key).unwrap_or(1);
+    env.storage().persistent().set(key, &(id + 1));
+    id
+}
+
+fn save_offer(env: &Env, offer: &LoanOffer) {
+    env.storage()
+        .persistent()
+        .set(&FixedRateDataKey::LoanOffer(offer.id), offer);
+}
+
+pub(crate) fn save_loan(env: &Env, loan: &FixedLoan) {
+    env.storage()
+        .persistent()
+        .set(&FixedRateDataKey::FixedLoan(loan.id), loan);
+}
+
+fn emit_fixed_rate_event(
+    env: &Env,
+    name: &str,
+    id: u64,
+    lender: Address,
+    borrower: Option<Address>,
+    amount: i128,
+    rate_bps: i128,
+) {
+    let event = FixedRateEvent {
+        id,
+        lender,
+        borrower,
+        amount,
+        rate_bps,
+        timestamp: env.ledger().timestamp(),
+    };
+    crate::events::publish(
+        env,
+        (
+            Symbol::new(env, name),
+            event.lender.clone(),
+            event.borrower.clone(),
+        ),
+        event,
+    );
+}
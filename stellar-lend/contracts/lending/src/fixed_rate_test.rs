@@ -0,0 +1,278 @@
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env, Symbol,
+};
+
+const YEAR: u64 = 31536000;
+
+fn setup(env: &Env) -> (LendingContractClient<'_>, Address, Address, Address) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+
+    let contract_id = env.register(LendingContract, ());
+    let client = LendingContractClient::new(env, &contract_id);
+    client.initialize(
+        &Address::generate(env),
+        &InitConfig::new(env, 1_000_000_000, 1000),
+    );
+
+    let lender = Address::generate(env);
+    let borrower = Address::generate(env);
+    let asset = Address::generate(env);
+    let collateral_asset = Address::generate(env);
+
+    // Borrower posts collateral in the core pool with a small core loan
+    client.borrow(&borrower, &asset, &10_000, &collateral_asset, &150_000);
+
+    (client, lender, borrower, asset)
+}
+
+#[test]
+fn test_take_offer_creates_fixed_loan() {
+    let env = Env::default();
+    let (client, lender, borrower, asset) = setup(&env);
+
+    let offer_id = client.post_offer(&lender, &asset, &100_000, &800, &YEAR);
+    let loan_id = client.take_offer(&borrower, &offer_id, &60_000);
+
+    let loan = client.get_fixed_loan(&loan_id).unwrap();
+    assert_eq!(loan.principal, 60_000);
+    assert_eq!(loan.interest, 4800); // 8% for one year
+    assert_eq!(loan.maturity, 1000 + YEAR);
+    assert_eq!(client.get_fixed_debt(&borrower), 60_000);
+
+    let offer = client.get_offer(&offer_id).unwrap();
+    assert_eq!(offer.amount, 40_000);
+    assert!(offer.active);
+}
+
+#[test]
+fn test_take_offer_requires_collateral_coverage() {
+    let env = Env::default();
+    let (client, lender, borrower, asset) = setup(&env);
+
+    let offer_id = client.post_offer(&lender, &asset, &200_000, &800, &YEAR);
+
+    // 150,000 collateral covers at most 100,000 debt, 10,000 already borrowed
+    let result = client.try_take_offer(&borrower, &offer_id, &90_001);
+    assert_eq!(result, Err(Ok(FixedRateError::InsufficientCollateral)));
+
+    client.take_offer(&borrower, &offer_id, &90_000);
+}
+
+#[test]
+fn test_fully_filled_offer_becomes_inactive() {
+    let env = Env::default();
+    let (client, lender, borrower, asset) = setup(&env);
+
+    let offer_id = client.post_offer(&lender, &asset, &50_000, &500, &YEAR);
+    client.take_offer(&borrower, &offer_id, &50_000);
+    assert!(!client.get_offer(&offer_id).unwrap().active);
+
+    let result = client.try_take_offer(&borrower, &offer_id, &1000);
+    assert_eq!(result, Err(Ok(FixedRateError::OfferInactive)));
+}
+
+#[test]
+fn test_repay_fixed_loan() {
+    let env = Env::default();
+    let (client, lender, borrower, asset) = setup(&env);
+
+    let offer_id = client.post_offer(&lender, &asset, &50_000, &1000, &YEAR);
+    let loan_id = client.take_offer(&borrower, &offer_id, &50_000);
+
+    assert_eq!(client.repay_fixed_loan(&borrower, &loan_id), 55_000);
+    assert!(client.get_fixed_loan(&loan_id).unwrap().repaid);
+    assert_eq!(client.get_fixed_debt(&borrower), 0);
+
+    let result = client.try_repay_fixed_loan(&borrower, &loan_id);
+    assert_eq!(result, Err(Ok(FixedRateError::LoanClosed)));
+}
+
+#[test]
+fn test_fixed_principal_counts_toward_pool_debt() {
+    let env = Env::default();
+    let (client, lender, borrower, asset) = setup(&env);
+    let before = client.get_asset_debt(&asset);
+    let offer_id = client.post_offer(&lender, &asset, &100_000, &800, &YEAR);
+
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    client.set_asset_debt_ceiling(&admin, &asset, &(before + 50_000));
+    let result = client.try_take_offer(&borrower, &offer_id, &50_001);
+    assert_eq!(result, Err(Ok(FixedRateError::DebtCeilingReached)));
+
+    let loan_id = client.take_offer(&borrower, &offer_id, &50_000);
+    assert_eq!(client.get_asset_debt(&asset), before + 50_000);
+    // The ceiling is used up by the first loan
+    let result = client.try_take_offer(&borrower, &offer_id, &1);
+    assert_eq!(result, Err(Ok(FixedRateError::DebtCeilingReached)));
+
+    client.repay_fixed_loan(&borrower, &loan_id);
+    assert_eq!(client.get_asset_debt(&asset), before);
+}
+
+#[test]
+fn test_offer_validation_and_cancel() {
+    let env = Env::default();
+    let (client, lender, borrower, asset) = setup(&env);
+
+    let result = client.try_post_offer(&lender, &asset, &1000, &10_001, &YEAR);
+    assert_eq!(result, Err(Ok(FixedRateError::InvalidRate)));
+
+    let result = client.try_post_offer(&lender, &asset, &1000, &500, &60);
+    assert_eq!(result, Err(Ok(FixedRateError::InvalidDuration)));
+
+    let offer_id = client.post_offer(&lender, &asset, &1000, &500, &YEAR);
+    let result = client.try_cancel_offer(&borrower, &offer_id);
+    assert_eq!(result, Err(Ok(FixedRateError::Unauthorized)));
+
+    client.cancel_offer(&lender, &offer_id);
+    let result = client.try_take_offer(&borrower, &offer_id, &1000);
+    assert_eq!(result, Err(Ok(FixedRateError::OfferInactive)));
+
+    let own_offer = client.post_offer(&borrower, &asset, &1000, &500, &YEAR);
+    let result = client.try_take_offer(&borrower, &own_offer, &1000);
+    assert_eq!(result, Err(Ok(FixedRateError::SelfMatch)));
+}
+
+#[test]
+fn test_prepayment_penalty_decays_to_holder() {
+    let env = Env::default();
+    let (client, lender, borrower, asset) = setup(&env);
+
+    let offer_id = client.post_offer(&lender, &asset, &50_000, &1000, &YEAR);
+    client.set_prepayment_penalty(
+        &lender,
+        &offer_id,
+        &PrepaymentPenalty {
+            penalty_bps: 200,
+            to_reserve: false,
+        },
+    );
+    let loan_id = client.take_offer(&borrower, &offer_id, &50_000);
+    assert_eq!(client.get_prepayment_fee(&loan_id), 1000);
+
+    // A quarter into the term, three quarters of the 2% remain
+    env.ledger().with_mut(|li| li.timestamp = 1000 + YEAR / 4);
+    assert_eq!(client.get_prepayment_fee(&loan_id), 750);
+    assert_eq!(client.repay_fixed_loan(&borrower, &loan_id), 55_750);
+    assert_eq!(client.get_receivable_proceeds(&lender), 55_750);
+}
+
+#[test]
+fn test_prepayment_penalty_to_reserve_and_validation() {
+    let env = Env::default();
+    let (client, lender, borrower, asset) = setup(&env);
+
+    let offer_id = client.post_offer(&lender, &asset, &50_000, &1000, &YEAR);
+    let penalty = PrepaymentPenalty {
+        penalty_bps: 301,
+        to_reserve: true,
+    };
+    assert_eq!(
+        client.try_set_prepayment_penalty(&lender, &offer_id, &penalty),
+        Err(Ok(FixedRateError::InvalidPenalty))
+    );
+    let penalty = PrepaymentPenalty {
+        penalty_bps: 300,
+        to_reserve: true,
+    };
+    assert_eq!(
+        client.try_set_prepayment_penalty(&borrower, &offer_id, &penalty),
+        Err(Ok(FixedRateError::Unauthorized))
+    );
+    client.set_prepayment_penalty(&lender, &offer_id, &penalty);
+    let loan_id = client.take_offer(&borrower, &offer_id, &10_000);
+
+    assert_eq!(client.repay_fixed_loan(&borrower, &loan_id), 11_300);
+    assert_eq!(client.get_insurance_fund(&asset), 300);
+    assert_eq!(client.get_receivable_proceeds(&lender), 11_000);
+
+    // No penalty at maturity
+    let loan_id = client.take_offer(&borrower, &offer_id, &10_000);
+    env.ledger().with_mut(|li| li.timestamp = 1000 + 2 * YEAR);
+    assert_eq!(client.repay_fixed_loan(&borrower, &loan_id), 11_000);
+}
+
+#[test]
+fn test_overdue_debt_accrues_penalty_interest() {
+    let env = Env::default();
+    let (client, lender, borrower, asset) = setup(&env);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    client.set_overdue_penalty_rate(&admin, &2000);
+
+    let offer_id = client.post_offer(&lender, &asset, &50_000, &1000, &YEAR);
+    let loan_id = client.take_offer(&borrower, &offer_id, &50_000);
+
+    env.ledger().with_mut(|li| li.timestamp = 1000 + YEAR);
+    assert_eq!(client.get_overdue_interest(&loan_id), 0);
+
+    // Half a year overdue at 20% on the 55_000 owed at maturity
+    env.ledger()
+        .with_mut(|li| li.timestamp = 1000 + YEAR + YEAR / 2);
+    assert_eq!(client.get_overdue_interest(&loan_id), 5500);
+    assert_eq!(client.get_fixed_debt(&borrower), 55_500);
+
+    assert_eq!(client.repay_fixed_loan(&borrower, &loan_id), 60_500);
+    let loan = client.get_fixed_loan(&loan_id).unwrap();
+    assert_eq!(loan.interest, 5000);
+    assert_eq!(loan.penalty_interest, 5500);
+    assert_eq!(client.get_overdue_interest(&loan_id), 0);
+    assert_eq!(client.get_receivable_proceeds(&lender), 60_500);
+}
+
+#[test]
+fn test_overdue_penalty_rate_validation() {
+    let env = Env::default();
+    let (client, lender, _, _) = setup(&env);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+
+    assert_eq!(client.get_overdue_penalty_rate(), 0);
+    assert_eq!(
+        client.try_set_overdue_penalty_rate(&admin, &10001),
+        Err(Ok(FixedRateError::InvalidConfig))
+    );
+    assert_eq!(
+        client.try_set_overdue_penalty_rate(&lender, &1000),
+        Err(Ok(FixedRateError::Unauthorized))
+    );
+    client.set_overdue_penalty_rate(&admin, &1000);
+    assert_eq!(client.get_overdue_penalty_rate(), 1000);
+}
+
+#[test]
+fn test_take_offer_applies_pool_borrow_checks() {
+    let env = Env::default();
+    let (client, lender, borrower, asset) = setup(&env);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    let offer_id = client.post_offer(&lender, &asset, &200_000, &800, &YEAR);
+
+    client.set_asset_freeze(&admin, &asset, &FREEZE_BORROW);
+    let result = client.try_take_offer(&borrower, &offer_id, &1000);
+    assert_eq!(result, Err(Ok(FixedRateError::BorrowFrozen)));
+    client.set_asset_freeze(&admin, &asset, &0);
+
+    client.set_paused(&admin, &true, &Symbol::new(&env, "incident"));
+    let result = client.try_take_offer(&borrower, &offer_id, &1000);
+    assert_eq!(result, Err(Ok(FixedRateError::ProtocolPaused)));
+    client.set_paused(&admin, &false, &Symbol::new(&env, "resolved"));
+
+    // A 50% LTV on the collateral asset caps total debt at 75,000
+    let collateral_asset = client.get_user_collateral(&borrower).asset;
+    client.set_collateral_params(
+        &admin,
+        &collateral_asset,
+        &CollateralParams {
+            ltv_bps: 5000,
+            liquidation_threshold_bps: 6000,
+        },
+    );
+    let result = client.try_take_offer(&borrower, &offer_id, &65_001);
+    assert_eq!(result, Err(Ok(FixedRateError::InsufficientCollateral)));
+    client.take_offer(&borrower, &offer_id, &65_000);
+}
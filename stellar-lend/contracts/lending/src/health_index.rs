@@ -0,0 +1,162 @@
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::borrow::{get_health_factor, get_total_user_debt};
+
+/// Storage keys for the health-bucket index
+///
+/// Each bucket stores one entry per member, so re-filing a borrower touches a
+/// fixed number of entries however large the bucket grows.
+#[contracttype]
+#[derive(Clone)]
+pub enum HealthIndexDataKey {
+    /// Borrower at a position of a bucket, filed at their last touch
+    HealthBucketMember(u32, u32),
+    HealthBucketSize(u32),
+    UserHealthBucket(Address),
+}
+
+/// Where a borrower is filed in the health-bucket index
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct HealthBucketSlot {
+    pub bucket: u32,
+    pub index: u32,
+}
+
+const HEALTH_BUCKET_PAGE_SIZE: u32 = 50;
+
+/// Health factor below 1.0: liquidatable
+pub const HEALTH_BUCKET_LIQUIDATABLE: u32 = 0;
+/// Health factor from 1.0 to below 1.1
+pub const HEALTH_BUCKET_CRITICAL: u32 = 1;
+/// Health factor from 1.1 to below 1.3
+pub const HEALTH_BUCKET_AT_RISK: u32 = 2;
+/// Health factor of 1.3 or more
+pub const HEALTH_BUCKET_HEALTHY: u32 = 3;
+
+/// Bucket of a health factor in basis points
+fn bucket_of(health_factor: i128) -> u32 {
+    match health_factor {
+        hf if hf < 10000 => HEALTH_BUCKET_LIQUIDATABLE,
+        hf if hf < 11000 => HEALTH_BUCKET_CRITICAL,
+        hf if hf < 13000 => HEALTH_BUCKET_AT_RISK,
+        _ => HEALTH_BUCKET_HEALTHY,
+    }
+}
+
+/// Re-file a borrower under the bucket of their current health factor
+///
+/// Called whenever a position is touched; borrowers without debt leave the
+/// index. Buckets reflect health at the last touch.
+pub(crate) fn update_health_bucket(env: &Env, user: &Address) {
+    let previous = get_slot(env, user);
+    let current = if get_total_user_debt(env, user) > 0 {
+        Some(bucket_of(get_health_factor(env, user)))
+    } else {
+        None
+    };
+    if previous.as_ref().map(|slot| slot.bucket) == current {
+        return;
+    }
+
+    if let Some(slot) = previous {
+        remove_member(env, &slot);
+    }
+    let user_key = HealthIndexDataKey::UserHealthBucket(user.clone());
+    match current {
+        Some(bucket) => {
+            let index = get_health_bucket_size(env, bucket);
+            set_member(env, bucket, index, user);
+            set_bucket_size(env, bucket, index + 1);
+            env.storage()
+                .persistent()
+                .set(&user_key, &HealthBucketSlot { bucket, index });
+        }
+        None => env.storage().persistent().remove(&user_key),
+    }
+}
+
+/// Re-file a borrower whose health drifted since their last touch (permissionless)
+///
+/// # Returns
+/// The borrower's bucket, or `None` without debt
+pub fn refresh_health_bucket(env: &Env, user: Address) -> Option<u32> {
+    update_health_bucket(env, &user);
+    get_user_health_bucket(env, &user)
+}
+
+/// Get a page of the borrowers filed under a health bucket
+///
+/// Returns up to `HEALTH_BUCKET_PAGE_SIZE` borrowers from position `cursor`;
+/// members are not kept in any particular order.
+pub fn get_health_bucket(env: &Env, bucket: u32, cursor: u32) -> Vec<Address> {
+    let end =
+        get_health_bucket_size(env, bucket).min(cursor.saturating_add(HEALTH_BUCKET_PAGE_SIZE));
+    let mut members = Vec::new(env);
+    for index in cursor..end {
+        if let Some(member) = env
+            .storage()
+            .persistent()
+            .get(&HealthIndexDataKey::HealthBucketMember(bucket, index))
+        {
+            members.push_back(member);
+        }
+    }
+    members
+}
+
+/// Get the number of borrowers filed under a health bucket
+pub fn get_health_bucket_size(env: &Env, bucket: u32) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&HealthIndexDataKey::HealthBucketSize(bucket))
+        .unwrap_or(0)
+}
+
+/// Get the health bucket a borrower is filed under, if any
+pub fn get_user_health_bucket(env: &Env, user: &Address) -> Option<u32> {
+    get_slot(env, user).map(|slot| slot.bucket)
+}
+
+fn get_slot(env: &Env, user: &Address) -> Option<HealthBucketSlot> {
+    env.storage()
+        .persistent()
+        .get(&HealthIndexDataKey::UserHealthBucket(user.clone()))
+}
+
+/// Remove a member by moving the bucket's last member into its position
+fn remove_member(env: &Env, slot: &HealthBucketSlot) {
+    let last = get_health_bucket_size(env, slot.bucket).saturating_sub(1);
+    if slot.index != last {
+        let moved: Option<Address> = env
+            .storage()
+            .persistent()
+            .get(&HealthIndexDataKey::HealthBucketMember(slot.bucket, last));
+        if let Some(moved) = moved {
+            set_member(env, slot.bucket, slot.index, &moved);
+            env.storage().persistent().set(
+                &HealthIndexDataKey::UserHealthBucket(moved),
+                &HealthBucketSlot {
+                    bucket: slot.bucket,
+                    index: slot.index,
+                },
+            );
+        }
+    }
+    env.storage()
+        .persistent()
+        .remove(&HealthIndexDataKey::HealthBucketMember(slot.bucket, last));
+    set_bucket_size(env, slot.bucket, last);
+}
+
+fn set_member(env: &Env, bucket: u32, index: u32, user: &Address) {
+    env.storage()
+        .persistent()
+        .set(&HealthIndexDataKey::HealthBucketMember(bucket, index), user);
+}
+
+fn set_bucket_size(env: &Env, bucket: u32, size: u32) {
+    env.storage()
+        .persistent()
+        .set(&HealthIndexDataKey::HealthBucketSize(bucket), &size);
+}
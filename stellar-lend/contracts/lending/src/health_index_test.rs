@@ -0,0 +1,124 @@
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+
+fn setup(env: &Env) -> LendingContractClient<'_> {
+    env.mock_all_auths();
+    let client = LendingContractClient::new(env, &env.register(LendingContract, ()));
+    client.initialize(
+        &Address::generate(env),
+        &InitConfig::new(env, 1_000_000_000, 1000),
+    );
+    client
+}
+
+#[test]
+fn test_borrowers_move_between_buckets_on_touch() {
+    let env = Env::default();
+    let client = setup(&env);
+    let user = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let collateral = Address::generate(&env);
+
+    client.borrow(&user, &asset, &10_000, &collateral, &20_000);
+    assert_eq!(
+        client.get_user_health_bucket(&user),
+        Some(HEALTH_BUCKET_HEALTHY)
+    );
+    assert_eq!(
+        client.get_health_bucket(&HEALTH_BUCKET_HEALTHY, &0),
+        Vec::from_array(&env, [user.clone()])
+    );
+
+    // Exactly at the liquidation threshold
+    client.withdraw_collateral(&user, &5000);
+    assert_eq!(
+        client.get_user_health_bucket(&user),
+        Some(HEALTH_BUCKET_CRITICAL)
+    );
+    assert!(client
+        .get_health_bucket(&HEALTH_BUCKET_HEALTHY, &0)
+        .is_empty());
+
+    client.repay(&user, &10_000);
+    assert_eq!(client.get_user_health_bucket(&user), None);
+    assert!(client
+        .get_health_bucket(&HEALTH_BUCKET_CRITICAL, &0)
+        .is_empty());
+}
+
+#[test]
+fn test_refresh_files_drifted_positions() {
+    let env = Env::default();
+    let client = setup(&env);
+    let user = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let collateral = Address::generate(&env);
+
+    client.borrow(&user, &asset, &10_000, &collateral, &15_000);
+    assert_eq!(
+        client.get_user_health_bucket(&user),
+        Some(HEALTH_BUCKET_CRITICAL)
+    );
+
+    // Accrued interest pushes the position under water without a touch
+    env.ledger().with_mut(|li| li.timestamp += 86400);
+    assert_eq!(
+        client.get_user_health_bucket(&user),
+        Some(HEALTH_BUCKET_CRITICAL)
+    );
+    assert_eq!(
+        client.refresh_health_bucket(&user),
+        Some(HEALTH_BUCKET_LIQUIDATABLE)
+    );
+    assert_eq!(
+        client.get_health_bucket(&HEALTH_BUCKET_LIQUIDATABLE, &0),
+        Vec::from_array(&env, [user])
+    );
+}
+
+#[test]
+fn test_bucket_pages_stay_compact() {
+    let env = Env::default();
+    let client = setup(&env);
+    let asset = Address::generate(&env);
+    let collateral = Address::generate(&env);
+
+    let mut users = Vec::new(&env);
+    for _ in 0..52 {
+        let user = Address::generate(&env);
+        client.borrow(&user, &asset, &10_000, &collateral, &20_000);
+        users.push_back(user);
+    }
+    assert_eq!(client.get_health_bucket_size(&HEALTH_BUCKET_HEALTHY), 52);
+    assert_eq!(
+        client.get_health_bucket(&HEALTH_BUCKET_HEALTHY, &0).len(),
+        50
+    );
+    assert_eq!(
+        client.get_health_bucket(&HEALTH_BUCKET_HEALTHY, &50).len(),
+        2
+    );
+
+    // Leaving the bucket moves its last member into the freed position
+    let leaving = users.get(0).unwrap();
+    client.repay(&leaving, &10_000);
+    assert_eq!(client.get_health_bucket_size(&HEALTH_BUCKET_HEALTHY), 51);
+    let first = client.get_health_bucket(&HEALTH_BUCKET_HEALTHY, &0);
+    assert_eq!(first.get(0), users.get(51));
+    assert!(!first.contains(&leaving));
+    assert_eq!(
+        client.get_health_bucket(&HEALTH_BUCKET_HEALTHY, &50),
+        Vec::from_array(&env, [users.get(50).unwrap()])
+    );
+
+    // The moved borrower can still leave cleanly
+    client.repay(&users.get(51).unwrap(), &10_000);
+    assert_eq!(client.get_health_bucket_size(&HEALTH_BUCKET_HEALTHY), 50);
+    assert_eq!(
+        client.get_health_bucket(&HEALTH_BUCKET_HEALTHY, &0).get(0),
+        users.get(50)
+    );
+}
@@ -0,0 +1,121 @@
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+/// Number of actions retained per user; older entries are overwritten
+pub const POSITION_HISTORY_SIZE: u64 = 20;
+/// Maximum entries returned by one history query
+const HISTORY_PAGE_SIZE: u64 = 10;
+
+/// Storage keys for opt-in position history
+#[contracttype]
+#[derive(Clone)]
+pub enum HistoryDataKey {
+    PositionHistoryEnabled(Address),
+    /// Number of actions ever recorded for a user
+    RecordedActionCount(Address),
+    /// Ring-buffer slot of a user's history
+    PositionHistoryEntry(Address, u64),
+}
+
+/// Position-changing actions that are recorded
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PositionAction {
+    Borrow,
+    DepositCollateral,
+    Repay,
+    WithdrawCollateral,
+    /// Collateral taken by deleveraging or dust cleanup
+    CollateralSeized,
+}
+
+/// One recorded action
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PositionHistoryEntry {
+    /// Position of the entry in the user's history, starting at 0
+    pub index: u64,
+    pub action: PositionAction,
+    pub asset: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+/// Start or stop recording a user's position history
+///
+/// Disabling keeps the retained entries queryable.
+pub fn set_position_history_enabled(env: &Env, user: Address, enabled: bool) {
+    user.require_auth();
+    env.storage()
+        .persistent()
+        .set(&HistoryDataKey::PositionHistoryEnabled(user), &enabled);
+}
+
+/// Check whether a user records position history
+pub fn is_position_history_enabled(env: &Env, user: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&HistoryDataKey::PositionHistoryEnabled(user.clone()))
+        .unwrap_or(false)
+}
+
+/// Append an action to a user's history if they opted in
+pub(crate) fn record_action(
+    env: &Env,
+    user: &Address,
+    action: PositionAction,
+    asset: &Address,
+    amount: i128,
+) {
+    if amount <= 0 || !is_position_history_enabled(env, user) {
+        return;
+    }
+
+    let index = history_count(env, user);
+    let entry = PositionHistoryEntry {
+        index,
+        action,
+        asset: asset.clone(),
+        amount,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.storage().persistent().set(
+        &HistoryDataKey::PositionHistoryEntry(user.clone(), index % POSITION_HISTORY_SIZE),
+        &entry,
+    );
+    env.storage().persistent().set(
+        &HistoryDataKey::RecordedActionCount(user.clone()),
+        &(index + 1),
+    );
+}
+
+/// Get up to 10 history entries, oldest first, starting at `cursor`
+///
+/// Entries that have been overwritten are skipped. Pass the last returned
+/// `index + 1` as the next cursor; an empty result marks the end.
+pub fn get_position_history(env: &Env, user: &Address, cursor: u64) -> Vec<PositionHistoryEntry> {
+    let count = history_count(env, user);
+    let start = cursor.max(count.saturating_sub(POSITION_HISTORY_SIZE));
+    let end = count.min(start.saturating_add(HISTORY_PAGE_SIZE));
+
+    let mut entries = Vec::new(env);
+    for index in start..end {
+        if let Some(entry) = env
+            .storage()
+            .persistent()
+            .get(&HistoryDataKey::PositionHistoryEntry(
+                user.clone(),
+                index % POSITION_HISTORY_SIZE,
+            ))
+        {
+            entries.push_back(entry);
+        }
+    }
+    entries
+}
+
+fn history_count(env: &Env, user: &Address) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&HistoryDataKey::RecordedActionCount(user.clone()))
+        .unwrap_or(0)
+}
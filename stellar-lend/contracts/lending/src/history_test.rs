@@ -0,0 +1,83 @@
+use super::*;
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+fn setup(env: &Env) -> LendingContractClient<'_> {
+    env.mock_all_auths();
+    let client = LendingContractClient::new(env, &env.register(LendingContract, ()));
+    client.initialize(
+        &Address::generate(env),
+        &InitConfig::new(env, 1_000_000_000, 1000),
+    );
+    client
+}
+
+#[test]
+fn test_history_records_actions_after_opt_in() {
+    let env = Env::default();
+    let client = setup(&env);
+    let user = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let collateral = Address::generate(&env);
+
+    client.borrow(&user, &asset, &10_000, &collateral, &30_000);
+    assert!(client.get_position_history(&user, &0).is_empty());
+
+    client.set_position_history_enabled(&user, &true);
+    client.borrow(&user, &asset, &5000, &collateral, &10_000);
+    client.repay(&user, &2000);
+    client.withdraw_collateral(&user, &3000);
+
+    let history = client.get_position_history(&user, &0);
+    assert_eq!(history.len(), 4);
+    let actions = [
+        (
+            PositionAction::DepositCollateral,
+            collateral.clone(),
+            10_000,
+        ),
+        (PositionAction::Borrow, asset.clone(), 5000),
+        (PositionAction::Repay, asset, 2000),
+        (PositionAction::WithdrawCollateral, collateral, 3000),
+    ];
+    for (i, (action, asset, amount)) in actions.into_iter().enumerate() {
+        let entry = history.get(i as u32).unwrap();
+        assert_eq!(entry.index, i as u64);
+        assert_eq!(
+            (entry.action, entry.asset, entry.amount),
+            (action, asset, amount)
+        );
+    }
+
+    client.set_position_history_enabled(&user, &false);
+    client.repay(&user, &1000);
+    assert_eq!(client.get_position_history(&user, &0).len(), 4);
+}
+
+#[test]
+fn test_history_is_a_bounded_ring_buffer() {
+    let env = Env::default();
+    let client = setup(&env);
+    let user = Address::generate(&env);
+
+    client.set_position_history_enabled(&user, &true);
+    client.borrow(
+        &user,
+        &Address::generate(&env),
+        &100_000,
+        &Address::generate(&env),
+        &200_000,
+    );
+    for _ in 0..22 {
+        client.repay(&user, &100);
+    }
+
+    // 24 actions recorded; the oldest 4 have been overwritten
+    let first_page = client.get_position_history(&user, &0);
+    assert_eq!(first_page.len(), 10);
+    assert_eq!(first_page.get(0).unwrap().index, 4);
+
+    let second_page = client.get_position_history(&user, &14);
+    assert_eq!(second_page.len(), 10);
+    assert_eq!(second_page.get(9).unwrap().index, 23);
+    assert!(client.get_position_history(&user, &24).is_empty());
+}
@@ -0,0 +1,118 @@
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+/// Storage keys for insurance fund data
+#[contracttype]
+#[derive(Clone)]
+pub enum InsuranceDataKey {
+    InsuranceFund(Address),
+    FeeAccrual(Address),
+    /// Assets that have accrued protocol fees, in order of first accrual
+    FeeAssets,
+}
+
+/// Protocol fees accrued in one asset
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FeeAccrual {
+    pub asset: Address,
+    /// Fees credited to the insurance fund since the first accrual
+    pub total_accrued: i128,
+    pub first_accrual: u64,
+    pub last_accrual: u64,
+}
+
+/// Get the insurance fund balance held for an asset
+pub fn get_insurance_fund(env: &Env, asset: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&InsuranceDataKey::InsuranceFund(asset.clone()))
+        .unwrap_or(0)
+}
+
+/// Credit an amount to the insurance fund of an asset
+pub fn credit_insurance_fund(env: &Env, asset: &Address, amount: i128) {
+    if amount <= 0 {
+        return;
+    }
+    let balance = get_insurance_fund(env, asset).saturating_add(amount);
+    env.storage()
+        .persistent()
+        .set(&InsuranceDataKey::InsuranceFund(asset.clone()), &balance);
+}
+
+/// Draw up to `amount` from the insurance fund of an asset
+///
+/// # Returns
+/// The amount drawn, limited by the fund's balance
+pub(crate) fn debit_insurance_fund(env: &Env, asset: &Address, amount: i128) -> i128 {
+    let balance = get_insurance_fund(env, asset);
+    let drawn = amount.clamp(0, balance.max(0));
+    if drawn > 0 {
+        env.storage().persistent().set(
+            &InsuranceDataKey::InsuranceFund(asset.clone()),
+            &(balance - drawn),
+        );
+    }
+    drawn
+}
+
+/// Credit a protocol fee to the insurance fund and record its accrual
+///
+/// Penalties and fees are revenue; absorbed collateral is not and goes
+/// through [`credit_insurance_fund`] directly.
+pub(crate) fn credit_protocol_fee(env: &Env, asset: &Address, amount: i128) {
+    if amount <= 0 {
+        return;
+    }
+    credit_insurance_fund(env, asset, amount);
+
+    let now = env.ledger().timestamp();
+    let accrual = match get_fee_accrual(env, asset) {
+        Some(mut accrual) => {
+            accrual.total_accrued = accrual.total_accrued.saturating_add(amount);
+            accrual.last_accrual = now;
+            accrual
+        }
+        None => {
+            let mut assets = get_fee_assets(env);
+            assets.push_back(asset.clone());
+            env.storage()
+                .persistent()
+                .set(&InsuranceDataKey::FeeAssets, &assets);
+            FeeAccrual {
+                asset: asset.clone(),
+                total_accrued: amount,
+                first_accrual: now,
+                last_accrual: now,
+            }
+        }
+    };
+    env.storage()
+        .persistent()
+        .set(&InsuranceDataKey::FeeAccrual(asset.clone()), &accrual);
+}
+
+/// Get the protocol fees accrued in an asset, if any
+pub fn get_fee_accrual(env: &Env, asset: &Address) -> Option<FeeAccrual> {
+    env.storage()
+        .persistent()
+        .get(&InsuranceDataKey::FeeAccrual(asset.clone()))
+}
+
+/// Get the fee accruals of every asset that has accrued protocol fees
+pub fn get_fee_accruals(env: &Env) -> Vec<FeeAccrual> {
+    let mut accruals = Vec::new(env);
+    for asset in get_fee_assets(env).iter() {
+        if let Some(accrual) = get_fee_accrual(env, &asset) {
+            accruals.push_back(accrual);
+        }
+    }
+    accruals
+}
+
+fn get_fee_assets(env: &Env) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&InsuranceDataKey::FeeAssets)
+        .unwrap_or(Vec::new(env))
+}
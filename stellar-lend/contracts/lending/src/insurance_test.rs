@@ -0,0 +1,80 @@
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+
+#[test]
+fn test_fee_accruals_track_amount_and_time() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    let client = LendingContractClient::new(&env, &env.register(LendingContract, ()));
+    let admin = Address::generate(&env);
+    let reward_asset = Address::generate(&env);
+    client.initialize(&admin, &InitConfig::new(&env, 1_000_000_000, 1000));
+    client.set_vesting_config(
+        &admin,
+        &VestingConfig {
+            enabled: true,
+            reward_asset: reward_asset.clone(),
+            duration: 1000,
+            early_exit_penalty_bps: 2000,
+        },
+    );
+    assert_eq!(client.get_fee_accrual(&reward_asset), None);
+
+    // Both exits forfeit 20% of a fully locked 1000 reward
+    for timestamp in [1000, 4000] {
+        env.ledger().with_mut(|li| li.timestamp = timestamp);
+        let user = Address::generate(&env);
+        client.distribute_reward(&admin, &user, &1000);
+        client.exit_vesting(&user);
+    }
+
+    let accrual = FeeAccrual {
+        asset: reward_asset,
+        total_accrued: 400,
+        first_accrual: 1000,
+        last_accrual: 4000,
+    };
+    assert_eq!(
+        client.get_fee_accrual(&accrual.asset),
+        Some(accrual.clone())
+    );
+    assert_eq!(client.get_fee_accruals(), Vec::from_array(&env, [accrual]));
+}
+
+#[test]
+fn test_absorbed_collateral_is_not_a_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = LendingContractClient::new(&env, &env.register(LendingContract, ()));
+    client.initialize(
+        &Address::generate(&env),
+        &InitConfig::new(&env, 1_000_000_000, 1000),
+    );
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    client.set_dust_config(
+        &admin,
+        &DustConfig {
+            min_collateral: 0,
+            dust_threshold: 2000,
+        },
+    );
+
+    let user = Address::generate(&env);
+    let collateral_asset = Address::generate(&env);
+    client.borrow(
+        &user,
+        &Address::generate(&env),
+        &1000,
+        &collateral_asset,
+        &1500,
+    );
+    client.clean_dust(&user);
+
+    assert_eq!(client.get_insurance_fund(&collateral_asset), 1500);
+    assert!(client.get_fee_accruals().is_empty());
+}
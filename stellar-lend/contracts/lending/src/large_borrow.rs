@@ -0,0 +1,243 @@
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+use crate::admin::{get_guardian, require_admin};
+use crate::borrow::{apply_borrow, BorrowError};
+
+/// Storage keys for large-borrow data
+#[contracttype]
+#[derive(Clone)]
+pub enum LargeBorrowDataKey {
+    LargeBorrowConfig,
+    PendingBorrow(Address),
+    /// Most value a single direct borrow may take, scaled by `PRICE_SCALE`
+    MaxBorrowPerCall,
+}
+
+/// When a borrow must be requested ahead of its execution
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LargeBorrowConfig {
+    /// Smallest borrow value that needs a request, scaled by `PRICE_SCALE`;
+    /// unpriced assets are valued 1:1
+    pub threshold: i128,
+    /// Ledgers that must pass between the request and the execution
+    pub delay_ledgers: u32,
+}
+
+/// A requested borrow awaiting its delay
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PendingBorrow {
+    pub asset: Address,
+    pub amount: i128,
+    pub collateral_asset: Address,
+    pub collateral_amount: i128,
+    pub requested_ledger: u32,
+    /// First ledger the borrow can be executed in
+    pub executable_ledger: u32,
+}
+
+/// Large-borrow event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct LargeBorrowEvent {
+    pub user: Address,
+    pub asset: Address,
+    pub amount: i128,
+    pub executable_ledger: u32,
+    pub timestamp: u64,
+}
+
+/// Require borrows from `threshold` up to go through a request (admin only)
+///
+/// Such borrows are recorded by [`request_borrow`] and can only be executed
+/// `delay_ledgers` later, giving monitoring and the guardian a window to
+/// cancel exploit-scale drains.
+pub fn set_large_borrow_config(
+    env: &Env,
+    caller: Address,
+    config: LargeBorrowConfig,
+) -> Result<(), BorrowError> {
+    require_admin(env, &caller).map_err(|_| BorrowError::Unauthorized)?;
+
+    if config.threshold <= 0 || config.delay_ledgers == 0 {
+        return Err(BorrowError::InvalidConfig);
+    }
+    env.storage()
+        .persistent()
+        .set(&LargeBorrowDataKey::LargeBorrowConfig, &config);
+    Ok(())
+}
+
+/// Stop requiring requests for large borrows (admin only)
+pub fn clear_large_borrow_config(env: &Env, caller: Address) -> Result<(), BorrowError> {
+    require_admin(env, &caller).map_err(|_| BorrowError::Unauthorized)?;
+    env.storage()
+        .persistent()
+        .remove(&LargeBorrowDataKey::LargeBorrowConfig);
+    Ok(())
+}
+
+/// Get the large-borrow threshold and delay, if set
+pub fn get_large_borrow_config(env: &Env) -> Option<LargeBorrowConfig> {
+    env.storage()
+        .persistent()
+        .get(&LargeBorrowDataKey::LargeBorrowConfig)
+}
+
+/// Cap the value a single direct borrow may take (admin only)
+///
+/// Independent of the per-asset ceilings, it keeps one compromised account
+/// or bug from drawing a whole ceiling in one call. The cap covers every call
+/// that takes out debt; borrows executed through [`execute_borrow`] are
+/// exempt. A `max_value` of zero removes the cap.
+pub fn set_max_borrow_per_call(
+    env: &Env,
+    caller: Address,
+    max_value: i128,
+) -> Result<(), BorrowError> {
+    require_admin(env, &caller).map_err(|_| BorrowError::Unauthorized)?;
+
+    let key = LargeBorrowDataKey::MaxBorrowPerCall;
+    match max_value {
+        0 => env.storage().persistent().remove(&key),
+        value if value > 0 => env.storage().persistent().set(&key, &value),
+        _ => return Err(BorrowError::InvalidConfig),
+    }
+    Ok(())
+}
+
+/// Get the most value a single direct borrow may take, if capped
+pub fn get_max_borrow_per_call(env: &Env) -> Option<i128> {
+    env.storage()
+        .persistent()
+        .get(&LargeBorrowDataKey::MaxBorrowPerCall)
+}
+
+/// Record a large borrow for execution after the configured delay
+///
+/// Nothing is checked or moved until [`execute_borrow`]; a user has at most
+/// one pending borrow.
+///
+/// # Returns
+/// The first ledger the borrow can be executed in
+pub fn request_borrow(
+    env: &Env,
+    user: Address,
+    asset: Address,
+    amount: i128,
+    collateral_asset: Address,
+    collateral_amount: i128,
+) -> Result<u32, BorrowError> {
+    user.require_auth();
+    if amount <= 0 || collateral_amount <= 0 {
+        return Err(BorrowError::InvalidAmount);
+    }
+    let config = get_large_borrow_config(env).ok_or(BorrowError::InvalidConfig)?;
+    let key = LargeBorrowDataKey::PendingBorrow(user.clone());
+    if env.storage().persistent().has(&key) {
+        return Err(BorrowError::PendingBorrowExists);
+    }
+
+    let requested_ledger = env.ledger().sequence();
+    let pending = PendingBorrow {
+        asset,
+        amount,
+        collateral_asset,
+        collateral_amount,
+        requested_ledger,
+        executable_ledger: requested_ledger.saturating_add(config.delay_ledgers),
+    };
+    env.storage().persistent().set(&key, &pending);
+    emit_large_borrow_event(env, "large_borrow_requested", user, &pending);
+    Ok(pending.executable_ledger)
+}
+
+/// Execute a requested borrow once its delay has passed
+///
+/// The borrow is validated as a regular borrow at execution time.
+pub fn execute_borrow(env: &Env, user: Address) -> Result<(), BorrowError> {
+    user.require_auth();
+    let pending = get_pending_borrow(env, &user).ok_or(BorrowError::NoPendingBorrow)?;
+    if env.ledger().sequence() < pending.executable_ledger {
+        return Err(BorrowError::BorrowDelayActive);
+    }
+
+    env.storage()
+        .persistent()
+        .remove(&LargeBorrowDataKey::PendingBorrow(user.clone()));
+    apply_borrow(
+        env,
+        user.clone(),
+        pending.asset.clone(),
+        pending.amount,
+        pending.collateral_asset.clone(),
+        pending.collateral_amount,
+        true,
+    )?;
+    emit_large_borrow_event(env, "large_borrow_executed", user, &pending);
+    Ok(())
+}
+
+/// Cancel a user's pending borrow (the user or the guardian)
+pub fn cancel_borrow_request(env: &Env, caller: Address, user: Address) -> Result<(), BorrowError> {
+    if caller != user && get_guardian(env).as_ref() != Some(&caller) {
+        return Err(BorrowError::Unauthorized);
+    }
+    caller.require_auth();
+    let pending = get_pending_borrow(env, &user).ok_or(BorrowError::NoPendingBorrow)?;
+
+    env.storage()
+        .persistent()
+        .remove(&LargeBorrowDataKey::PendingBorrow(user.clone()));
+    emit_large_borrow_event(env, "large_borrow_cancelled", user, &pending);
+    Ok(())
+}
+
+/// Get a user's pending borrow, if any
+pub fn get_pending_borrow(env: &Env, user: &Address) -> Option<PendingBorrow> {
+    env.storage()
+        .persistent()
+        .get(&LargeBorrowDataKey::PendingBorrow(user.clone()))
+}
+
+/// Reject direct borrows valued at or above the large-borrow threshold or
+/// above the per-call maximum
+///
+/// Applies to every path that takes out debt; only [`execute_borrow`] skips it.
+pub(crate) fn check_borrow_size(
+    env: &Env,
+    asset: &Address,
+    amount: i128,
+) -> Result<(), BorrowError> {
+    if get_large_borrow_config(env).is_some_and(|config| {
+        crate::oracle::amount_to_value(env, asset, amount) >= config.threshold
+    }) {
+        return Err(BorrowError::LargeBorrowRequestRequired);
+    }
+    if get_max_borrow_per_call(env)
+        .is_some_and(|max| crate::oracle::amount_to_value(env, asset, amount) > max)
+    {
+        return Err(BorrowError::BorrowSizeCapExceeded);
+    }
+    Ok(())
+}
+
+fn emit_large_borrow_event(env: &Env, name: &str, user: Address, pending: &PendingBorrow) {
+    let event = LargeBorrowEvent {
+        user,
+        asset: pending.asset.clone(),
+        amount: pending.amount,
+        executable_ledger: pending.executable_ledger,
+        timestamp: env.ledger().timestamp(),
+    };
+    crate::events::publish(
+        env,
+        (
+            Symbol::new(env, name),
+            event.user.clone(),
+            event.asset.clone(),
+        ),
+        event,
+    );
+}
@@ -0,0 +1,137 @@
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+
+fn setup(env: &Env) -> (LendingContractClient<'_>, Address, Address, Address) {
+    env.mock_all_auths();
+    let client = LendingContractClient::new(env, &env.register(LendingContract, ()));
+    let admin = Address::generate(env);
+    client.initialize(&admin, &InitConfig::new(env, 1_000_000_000, 1000));
+    client.set_large_borrow_config(
+        &admin,
+        &LargeBorrowConfig {
+            threshold: 50_000,
+            delay_ledgers: 10,
+        },
+    );
+    (
+        client,
+        admin,
+        Address::generate(env),
+        Address::generate(env),
+    )
+}
+
+#[test]
+fn test_large_borrow_executes_after_delay() {
+    let env = Env::default();
+    let (client, _, asset, collateral) = setup(&env);
+    let user = Address::generate(&env);
+
+    // Smaller borrows go through directly
+    client.borrow(&user, &asset, &10_000, &collateral, &20_000);
+    assert_eq!(
+        client.try_borrow(&user, &asset, &50_000, &collateral, &100_000),
+        Err(Ok(BorrowError::LargeBorrowRequestRequired))
+    );
+
+    let executable = client.request_borrow(&user, &asset, &50_000, &collateral, &100_000);
+    assert_eq!(executable, env.ledger().sequence() + 10);
+    assert_eq!(
+        client.try_request_borrow(&user, &asset, &50_000, &collateral, &100_000),
+        Err(Ok(BorrowError::PendingBorrowExists))
+    );
+    env.ledger().with_mut(|li| li.sequence_number += 9);
+    assert_eq!(
+        client.try_execute_borrow(&user),
+        Err(Ok(BorrowError::BorrowDelayActive))
+    );
+
+    env.ledger().with_mut(|li| li.sequence_number += 1);
+    client.execute_borrow(&user);
+    assert_eq!(client.get_user_debt(&user).borrowed_amount, 60_000);
+    assert_eq!(client.get_pending_borrow(&user), None);
+}
+
+#[test]
+fn test_guardian_cancels_pending_borrow() {
+    let env = Env::default();
+    let (client, admin, asset, collateral) = setup(&env);
+    let guardian = Address::generate(&env);
+    client.set_guardian(&admin, &guardian);
+    let user = Address::generate(&env);
+    client.request_borrow(&user, &asset, &80_000, &collateral, &160_000);
+
+    assert_eq!(
+        client.try_cancel_borrow_request(&Address::generate(&env), &user),
+        Err(Ok(BorrowError::Unauthorized))
+    );
+    client.cancel_borrow_request(&guardian, &user);
+    env.ledger().with_mut(|li| li.sequence_number += 10);
+    assert_eq!(
+        client.try_execute_borrow(&user),
+        Err(Ok(BorrowError::NoPendingBorrow))
+    );
+
+    // Priced assets are compared by value
+    client.set_asset_price(&admin, &asset, &5_000_000);
+    client.borrow(&user, &asset, &80_000, &collateral, &160_000);
+}
+
+#[test]
+fn test_per_call_borrow_cap() {
+    let env = Env::default();
+    let (client, admin, asset, collateral) = setup(&env);
+    client.set_max_borrow_per_call(&admin, &20_000);
+    let user = Address::generate(&env);
+
+    assert_eq!(
+        client.try_borrow(&user, &asset, &20_001, &collateral, &40_002),
+        Err(Ok(BorrowError::BorrowSizeCapExceeded))
+    );
+    client.borrow(&user, &asset, &20_000, &collateral, &40_000);
+
+    // Borrows through the delayed path are exempt
+    client.request_borrow(&user, &asset, &50_000, &collateral, &100_000);
+    env.ledger().with_mut(|li| li.sequence_number += 10);
+    client.execute_borrow(&user);
+
+    client.set_max_borrow_per_call(&admin, &0);
+    assert_eq!(client.get_max_borrow_per_call(), None);
+    client.borrow(&user, &asset, &30_000, &collateral, &60_000);
+}
+
+#[test]
+fn test_large_nft_borrow_requires_request() {
+    let env = Env::default();
+    let (client, _, asset, collateral) = setup(&env);
+    let user = Address::generate(&env);
+    client.borrow(&user, &asset, &10_000, &collateral, &200_000);
+
+    assert_eq!(
+        client.try_nft_borrow(&user, &asset, &50_000),
+        Err(Ok(NftError::LargeBorrowRequestRequired))
+    );
+    client.nft_borrow(&user, &asset, &40_000);
+}
+
+#[test]
+fn test_per_call_cap_covers_nft_and_rwa_borrows() {
+    let env = Env::default();
+    let (client, admin, asset, collateral) = setup(&env);
+    client.set_max_borrow_per_call(&admin, &20_000);
+    let user = Address::generate(&env);
+    client.borrow(&user, &asset, &10_000, &collateral, &200_000);
+
+    assert_eq!(
+        client.try_nft_borrow(&user, &asset, &20_001),
+        Err(Ok(NftError::BorrowSizeCapExceeded))
+    );
+    assert_eq!(
+        client.try_rwa_borrow(&user, &asset, &20_001),
+        Err(Ok(RwaError::BorrowSizeCapExceeded))
+    );
+    client.rwa_borrow(&user, &asset, &20_000);
+}
@@ -1,14 +1,404 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, Address, Env};
+use soroban_sdk::{contract, contractimpl, Address, Env, Map, Symbol, Vec};
 
+mod account_freeze;
+mod admin;
+mod amortizing;
+mod approvals;
+mod asset_freeze;
+mod asset_mode;
+mod auto_repay;
+mod automation;
 mod borrow;
+mod collateral_tier;
+mod config;
+mod credit_line;
+mod credit_score;
+mod debt_ceiling;
+mod deleverage;
+mod dust;
+mod early_repay;
+mod events;
+mod exit_fee;
+mod fixed_rate;
+mod health_index;
+mod history;
+mod insurance;
+mod large_borrow;
+mod listing;
+mod loyalty;
+mod lp_pricing;
+mod market;
+mod math;
+mod nft;
+mod oracle;
+mod p2p;
+mod pause;
+mod points;
+mod position_hooks;
+mod position_limit;
+mod rate_model;
+mod rate_swap;
+mod receivables;
+mod rehypothecation;
+mod repay;
+mod repayment_stream;
+mod reserve;
+mod rwa;
+mod session_delegate;
+mod shutdown;
+mod simulation;
+mod soft_liquidation;
+mod strategy;
+mod summary;
+mod version;
+mod vesting;
+mod vote_escrow;
+mod withdraw;
+mod withdraw_cooldown;
+mod withdrawal_queue;
+mod yield_collateral;
+pub use account_freeze::AccountFreeze;
+use account_freeze::{freeze_account, get_account_freeze, unfreeze_account, AccountFreezeError};
+use admin::{
+    get_admin, get_compliance_officer, get_guardian, get_risk_manager, get_treasurer, set_admin,
+    set_compliance_officer, set_guardian, set_risk_manager, set_treasurer, AdminError,
+};
+use amortizing::{
+    execute_installments, get_amortization_schedule, get_amortizing_debt, get_amortizing_loan,
+    get_amortizing_status, get_borrower_amortizing_loans, open_amortizing_loan, pay_installment,
+    set_installment_allowance, AmortizingError, AmortizingLoan, AmortizingStatus, Installment,
+};
+use approvals::revoke_all;
+use asset_freeze::{get_asset_freeze, set_asset_freeze, AssetFreezeError};
+pub use asset_freeze::{FREEZE_BORROW, FREEZE_COLLATERAL};
+pub use asset_mode::AssetMode;
+use asset_mode::{get_asset_mode, set_asset_mode, AssetModeError};
+use auto_repay::{
+    disable_auto_repay, enable_auto_repay, execute_auto_repay, get_auto_repay_config,
+    get_auto_repay_tip, set_auto_repay_tip, AutoRepayConfig, AutoRepayError,
+};
+use automation::{
+    cancel_intent, execute_intent, get_intent, get_keeper_tips, get_user_intents, register_intent,
+    AutomationError, Intent, IntentKind,
+};
 use borrow::{
-    borrow, get_user_collateral, get_user_debt, initialize_borrow_settings, set_paused,
-    BorrowError, CollateralPosition, DebtPosition,
+    borrow, get_asset_debt, get_asset_debt_ceiling, get_asset_min_borrow, get_base_interest_rate,
+    get_collateral_params, get_default_min_borrow, get_min_collateral_ratio, get_pause_info,
+    get_user_borrow_cap, get_user_collateral, get_user_debt, set_asset_debt_ceiling,
+    set_asset_min_borrow, set_base_interest_rate, set_collateral_params, set_default_min_borrow,
+    set_min_collateral_ratio, set_paused, set_user_borrow_cap, BorrowError, CollateralParams,
+    CollateralPosition, DebtPosition, MinBorrowConfig, PauseInfo,
+};
+use collateral_tier::{
+    assign_asset_tier, get_asset_tier, get_tier_config, get_tier_exposure, set_tier_config,
+    CollateralTier, TierConfig, TierError,
+};
+use config::{get_config, initialize, set_config};
+pub use config::{ConfigChange, ConfigError, InitAsset, InitConfig, ProtocolConfig};
+use credit_line::{
+    draw_credit, get_credit_line, get_credit_line_losses, get_credit_line_total_drawn,
+    open_credit_line, repay_credit, revoke_credit_line, write_off_credit_line, CreditLine,
+    CreditLineError,
+};
+use credit_score::{
+    get_credit_history, get_credit_score, get_credit_score_config, set_credit_score_config,
+    CreditHistory, CreditScoreConfig, CreditScoreError,
+};
+use debt_ceiling::{
+    get_debt_growth_guard, get_dynamic_ceiling_config, get_effective_borrow_cap,
+    get_liquidity_buffer, get_oracle_confidence, is_asset_borrow_paused, resume_asset_borrows,
+    set_debt_growth_guard, set_dynamic_ceiling_config, set_liquidity_buffer, set_oracle_confidence,
+    CeilingError, DebtGrowthGuard, DynamicCeilingConfig,
+};
+use deleverage::{
+    deleverage, get_crunch_start, get_deleverage_config, get_largest_borrowers, get_utilization,
+    poke_utilization, set_deleverage_config, DeleverageConfig, DeleverageError,
+};
+use dust::{clean_dust, get_dust_config, is_dust_position, set_dust_config, DustConfig, DustError};
+use early_repay::{
+    get_early_repay_config, is_early_repay_eligible, set_early_repay_config, EarlyRepayConfig,
+    EarlyRepayError,
+};
+use events::get_event_sequence;
+pub use exit_fee::ExitFeeConfig;
+use exit_fee::{get_exit_fee_bps, get_exit_fee_config, set_exit_fee_config, ExitFeeError};
+use fixed_rate::{
+    cancel_offer, get_borrower_fixed_loans, get_fixed_debt, get_fixed_loan, get_offer,
+    get_overdue_interest, get_overdue_penalty_rate, get_prepayment_fee, post_offer,
+    repay_fixed_loan, set_overdue_penalty_rate, set_prepayment_penalty, take_offer, FixedLoan,
+    FixedRateError, LoanOffer, PrepaymentPenalty,
+};
+use health_index::{
+    get_health_bucket, get_health_bucket_size, get_user_health_bucket, refresh_health_bucket,
+};
+pub use health_index::{
+    HEALTH_BUCKET_AT_RISK, HEALTH_BUCKET_CRITICAL, HEALTH_BUCKET_HEALTHY,
+    HEALTH_BUCKET_LIQUIDATABLE,
+};
+use history::{get_position_history, is_position_history_enabled, set_position_history_enabled};
+pub use history::{PositionAction, PositionHistoryEntry, POSITION_HISTORY_SIZE};
+pub use insurance::FeeAccrual;
+use insurance::{get_fee_accrual, get_fee_accruals, get_insurance_fund};
+use large_borrow::{
+    cancel_borrow_request, clear_large_borrow_config, execute_borrow, get_large_borrow_config,
+    get_max_borrow_per_call, get_pending_borrow, request_borrow, set_large_borrow_config,
+    set_max_borrow_per_call,
+};
+pub use large_borrow::{LargeBorrowConfig, PendingBorrow};
+use listing::{
+    delist_asset, get_asset_listing, get_delist_schedule, get_listed_assets, list_asset,
+    open_settlement, settle_delisted_position,
+};
+pub use listing::{
+    AssetListing, DelistSchedule, ListingConfig, ListingError, ListingStatus, MIN_SEED_LIQUIDITY,
+};
+use loyalty::{
+    get_loyalty_config, get_loyalty_status, set_loyalty_config, LoyaltyConfig, LoyaltyError,
+    LoyaltyStatus,
+};
+pub use lp_pricing::LiquidityPool;
+use lp_pricing::{
+    deregister_lp_token, get_lp_token_config, get_lp_token_price, register_lp_token,
+    LpPricingError, LpTokenConfig,
+};
+use market::{get_liquidity, get_market_size};
+pub use market::{Liquidity, MarketSize};
+use nft::{
+    approve_nft_collection, bid_nft_auction, escrow_nft, get_nft_auction, get_nft_collateral_value,
+    get_nft_collection, get_nft_owner, get_user_nfts, nft_borrow, set_nft_floor,
+    settle_nft_auction, start_nft_auction, withdraw_nft, NftAuction, NftCollection, NftError,
+    NftId,
+};
+use oracle::{
+    cancel_price_override, confirm_price_override, get_asset_price, get_committed_price_mode,
+    get_deviation_guard, get_effective_price, get_feed_report, get_market_frozen_until,
+    get_oracle_feed_config, get_pending_price_override, get_price_feed_set, get_price_heartbeat,
+    get_price_override, get_stale_price_policy, is_market_frozen, is_price_stale,
+    propose_price_override, set_asset_price, set_committed_price_mode, set_deviation_guard,
+    set_oracle_feed_config, set_price_feed_set, set_stale_price_policy, submit_feed_price,
+    DeviationGuard, OracleError, OracleFeedConfig, PriceData, PriceFeedSet, PriceOverride,
+    StalePricePolicy,
+};
+use p2p::{
+    clear_concentration_limit, get_concentration_limit, get_p2p_borrow_matched, get_p2p_market,
+    get_p2p_matched_total, get_p2p_rates, get_p2p_supply, join_p2p_borrow, p2p_supply,
+    p2p_withdraw, set_concentration_limit, set_p2p_market, ConcentrationLimit, P2PError, P2PMarket,
+    P2PRates, P2PSupplyPosition,
+};
+use pause::{
+    get_guardian_pause, get_max_guardian_pause_duration, get_paused_operations,
+    is_operation_paused, reaffirm_guardian_pause, set_max_guardian_pause_duration,
+    set_paused_operations, GuardianPause, PauseError,
+};
+pub use pause::{
+    PAUSE_BORROW, PAUSE_FLASH_LOAN, PAUSE_LIQUIDATE, PAUSE_REPAY, PAUSE_SUPPLY, PAUSE_WITHDRAW,
+};
+use points::{
+    get_points, get_points_config, get_total_points, set_points_config, PointsConfig, PointsError,
+};
+use position_hooks::{
+    disable_liquidation_callback, enable_liquidation_callback, get_global_position_hook,
+    get_position_hook, has_liquidation_callback, register_position_hook, remove_position_hook,
+    set_global_position_hook, PositionHookError,
+};
+pub use position_hooks::{HookEvent, LiquidationReceiver, PositionHook, UserHook};
+use position_limit::{
+    get_max_user_positions, get_user_position_count, set_max_user_positions, PositionLimitError,
+};
+use rate_model::{
+    get_borrow_rate, get_borrow_rate_bounds, get_borrow_rate_model, get_model_borrow_rate,
+    set_borrow_rate_bounds, set_borrow_rate_model, BorrowRateBounds, BorrowRateModel,
+    RateModelError,
+};
+use rate_swap::{
+    accept_rate_swap, add_swap_margin, cancel_rate_swap, get_rate_swap, get_rate_swap_pnl,
+    propose_rate_swap, settle_rate_swap, RateSwap, RateSwapError, RateSwapSettlement,
+};
+use receivables::{
+    buy_receivable, delist_receivable, get_holder_receivables, get_receivable_listing,
+    get_receivable_proceeds, list_receivable, transfer_receivable, ReceivableError,
+    ReceivableListing,
+};
+use rehypothecation::{is_rehypothecation_enabled, set_rehypothecation, RehypothecationError};
+use repay::{repay, RepayError};
+use repayment_stream::{
+    cancel_repayment_stream, execute_repayment_stream, get_repayment_stream, set_repayment_stream,
+    RepaymentStream, StreamError,
+};
+use reserve::get_reserve_data;
+pub use reserve::ReserveData;
+pub use rwa::RwaCompliance;
+use rwa::{
+    claim_rwa_settlement, deposit_rwa_collateral, get_pending_rwa_settlement, get_rwa_asset,
+    get_rwa_collateral, get_rwa_collateral_value, liquidate_rwa, register_rwa_asset, rwa_borrow,
+    withdraw_rwa_collateral, RwaAssetConfig, RwaError, RwaLiquidation,
+};
+use session_delegate::{
+    authorize_delegate, delegate_repay, delegate_withdraw_collateral, get_delegate_remaining,
+    get_owner_delegates, get_session_delegate, revoke_delegate, DelegateError,
 };
+pub use session_delegate::{DelegateAction, DelegateLimit, SessionDelegate};
+use shutdown::{
+    emergency_shutdown, get_settlement_price, get_shutdown_info, is_shutdown, ShutdownError,
+    ShutdownInfo,
+};
+use simulation::{
+    simulate_borrow, simulate_liquidate, simulate_repay, BorrowSimulation, LiquidationSimulation,
+    RepaySimulation,
+};
+pub use soft_liquidation::DexAdapter;
+use soft_liquidation::{
+    disable_soft_liquidation, enable_soft_liquidation, get_dex_adapter, get_soft_liquidation_band,
+    set_dex_adapter, soft_liquidate, DexAdapterConfig, SoftLiquidationBand, SoftLiquidationError,
+};
+pub use strategy::YieldStrategy;
+use strategy::{
+    approve_strategy, deploy_to_strategy, emergency_divest, get_allowed_strategy,
+    get_pending_strategy, get_strategy_config, get_strategy_deployed, get_strategy_deposits,
+    get_strategy_loss, get_strategy_reserve, harvest_strategy, is_strategy_blocked,
+    propose_strategy, recall_from_strategy, remove_strategy, resume_strategy, set_strategy,
+    write_down_strategy_loss, AllowedStrategy, LossWriteDown, StrategyConfig, StrategyError,
+};
+use summary::get_user_summary;
+pub use summary::{SupplyBalance, UserSummary};
+use version::get_version;
+pub use version::{ContractVersion, SCHEMA_VERSION};
+use vesting::{
+    claim_vested, distribute_reward, exit_vesting, get_claimable_vested, get_vesting_config,
+    get_vesting_schedules, set_vesting_config, VestingConfig, VestingError, VestingSchedule,
+};
+use vote_escrow::{
+    add_gauge, create_lock, extend_lock, get_gauge_multiplier, get_gauge_relative_weight,
+    get_gauge_weight, get_gauges, get_lock, get_reward_boost, get_voting_power,
+    increase_lock_amount, set_full_boost_power, vote_for_gauges, withdraw_lock, VeLock,
+    VoteEscrowError,
+};
+use withdraw::{withdraw_collateral, WithdrawError};
+use withdraw_cooldown::{get_withdraw_cooldown, set_withdraw_cooldown, WithdrawCooldownError};
+use withdrawal_queue::{
+    cancel_withdrawal, get_queue_position, get_withdrawal_queue, queue_withdrawal,
+    WithdrawalQueueError,
+};
+pub use withdrawal_queue::{QueuePosition, QueuedWithdrawal};
+use yield_collateral::{
+    deregister_yield_token, get_exchange_rate, get_yield_token_config, register_yield_token,
+    YieldCollateralError, YieldTokenConfig,
+};
+pub use yield_collateral::{ExchangeRateOracle, EXCHANGE_RATE_SCALE};
 
+#[cfg(test)]
+mod account_freeze_test;
+#[cfg(test)]
+mod amortizing_test;
+#[cfg(test)]
+mod approvals_test;
+#[cfg(test)]
+mod asset_freeze_test;
+#[cfg(test)]
+mod asset_mode_test;
+#[cfg(test)]
+mod auto_repay_test;
+#[cfg(test)]
+mod automation_test;
 #[cfg(test)]
 mod borrow_test;
+#[cfg(test)]
+mod collateral_tier_test;
+#[cfg(test)]
+mod config_test;
+#[cfg(test)]
+mod credit_line_test;
+#[cfg(test)]
+mod credit_score_test;
+#[cfg(test)]
+mod debt_ceiling_test;
+#[cfg(test)]
+mod deleverage_test;
+#[cfg(test)]
+mod dust_test;
+#[cfg(test)]
+mod early_repay_test;
+#[cfg(test)]
+mod events_test;
+#[cfg(test)]
+mod exit_fee_test;
+#[cfg(test)]
+mod fixed_rate_test;
+#[cfg(test)]
+mod health_index_test;
+#[cfg(test)]
+mod history_test;
+#[cfg(test)]
+mod insurance_test;
+#[cfg(test)]
+mod large_borrow_test;
+#[cfg(test)]
+mod listing_test;
+#[cfg(test)]
+mod loyalty_test;
+#[cfg(test)]
+mod lp_pricing_test;
+#[cfg(test)]
+mod market_test;
+#[cfg(test)]
+mod math_test;
+#[cfg(test)]
+mod nft_test;
+#[cfg(test)]
+mod oracle_test;
+#[cfg(test)]
+mod p2p_test;
+#[cfg(test)]
+mod pause_test;
+#[cfg(test)]
+mod points_test;
+#[cfg(test)]
+mod position_hooks_test;
+#[cfg(test)]
+mod position_limit_test;
+#[cfg(test)]
+mod rate_model_test;
+#[cfg(test)]
+mod rate_swap_test;
+#[cfg(test)]
+mod receivables_test;
+#[cfg(test)]
+mod rehypothecation_test;
+#[cfg(test)]
+mod repay_test;
+#[cfg(test)]
+mod repayment_stream_test;
+#[cfg(test)]
+mod reserve_test;
+#[cfg(test)]
+mod rwa_test;
+#[cfg(test)]
+mod session_delegate_test;
+#[cfg(test)]
+mod shutdown_test;
+#[cfg(test)]
+mod simulation_test;
+#[cfg(test)]
+mod soft_liquidation_test;
+#[cfg(test)]
+mod strategy_test;
+#[cfg(test)]
+mod summary_test;
+#[cfg(test)]
+mod version_test;
+#[cfg(test)]
+mod vesting_test;
+#[cfg(test)]
+mod vote_escrow_test;
+#[cfg(test)]
+mod withdraw_cooldown_test;
+#[cfg(test)]
+mod withdraw_test;
+#[cfg(test)]
+mod withdrawal_queue_test;
+#[cfg(test)]
+mod yield_collateral_test;
 
 #[contract]
 pub struct LendingContract;
@@ -54,54 +444,3495 @@ impl LendingContract {
         )
     }
 
-    /// Initialize borrow settings (admin only)
+    /// Initialize the protocol in a single call
     ///
-    /// Sets up the protocol's debt ceiling and minimum borrow amount.
+    /// Sets the admin, roles, global caps, borrow rate model and initial
+    /// assets. Everything is validated first; an invalid config leaves the
+    /// contract uninitialized, and a second call is rejected.
     ///
     /// # Arguments
-    /// * `debt_ceiling` - Maximum total debt allowed in the protocol
-    /// * `min_borrow_amount` - Minimum amount that can be borrowed
-    pub fn initialize_borrow_settings(
-        env: Env,
-        debt_ceiling: i128,
-        min_borrow_amount: i128,
-    ) -> Result<(), BorrowError> {
-        initialize_borrow_settings(&env, debt_ceiling, min_borrow_amount)
+    /// * `admin` - The protocol admin (must authorize)
+    /// * `config` - Full initial configuration (`InitConfig::new` for defaults)
+    pub fn initialize(env: Env, admin: Address, config: InitConfig) -> Result<(), ConfigError> {
+        initialize(&env, admin, config)
     }
 
     /// Set protocol pause state (admin only)
     ///
-    /// Pauses or unpauses the borrow functionality.
+    /// Pauses or unpauses the borrow functionality. The actor, time and
+    /// reason are recorded and published with the pause event.
     ///
     /// # Arguments
+    /// * `caller` - The admin address
     /// * `paused` - True to pause, false to unpause
-    pub fn set_paused(env: Env, paused: bool) -> Result<(), BorrowError> {
-        set_paused(&env, paused)
+    /// * `reason` - Short incident code
+    pub fn set_paused(
+        env: Env,
+        caller: Address,
+        paused: bool,
+        reason: Symbol,
+    ) -> Result<(), BorrowError> {
+        set_paused(&env, caller, paused, reason)
     }
 
-    /// Get user's debt position
+    /// Get who last paused or unpaused the protocol, when and why
+    pub fn get_pause_info(env: Env) -> Option<PauseInfo> {
+        get_pause_info(&env)
+    }
+
+    /// Set the risk parameters of a collateral asset (admin only)
     ///
-    /// Returns the user's current debt including accrued interest.
+    /// # Arguments
+    /// * `caller` - The admin address
+    /// * `asset` - The collateral asset
+    /// * `params` - Origination LTV and the looser liquidation threshold
+    ///
+    /// # Returns
+    /// Returns Ok(()) on success
+    pub fn set_collateral_params(
+        env: Env,
+        caller: Address,
+        asset: Address,
+        params: CollateralParams,
+    ) -> Result<(), BorrowError> {
+        set_collateral_params(&env, caller, asset, params)
+    }
+
+    /// Get the risk parameters of a collateral asset
+    ///
+    /// Falls back to the defaults of the asset's collateral tier.
+    pub fn get_collateral_params(env: Env, asset: Address) -> Option<CollateralParams> {
+        get_collateral_params(&env, &asset)
+    }
+
+    /// Configure a collateral tier (admin only)
     ///
     /// # Arguments
-    /// * `user` - The user's address
+    /// * `caller` - The admin address
+    /// * `tier` - The tier to configure
+    /// * `config` - Default risk parameters and aggregate exposure cap
     ///
     /// # Returns
-    /// DebtPosition with borrowed amount, interest, and last update time
-    pub fn get_user_debt(env: Env, user: Address) -> DebtPosition {
-        get_user_debt(&env, &user)
+    /// Returns Ok(()) on success
+    pub fn set_tier_config(
+        env: Env,
+        caller: Address,
+        tier: CollateralTier,
+        config: TierConfig,
+    ) -> Result<(), TierError> {
+        set_tier_config(&env, caller, tier, config)
     }
 
-    /// Get user's collateral position
+    /// Place a collateral asset in a configured tier (admin only)
     ///
-    /// Returns the user's current collateral.
+    /// # Arguments
+    /// * `caller` - The admin address
+    /// * `asset` - The collateral asset
+    /// * `tier` - The tier whose defaults and exposure cap apply
+    ///
+    /// # Returns
+    /// Returns Ok(()) on success
+    pub fn assign_asset_tier(
+        env: Env,
+        caller: Address,
+        asset: Address,
+        tier: CollateralTier,
+    ) -> Result<(), TierError> {
+        assign_asset_tier(&env, caller, asset, tier)
+    }
+
+    /// Get a collateral tier's configuration, if set
+    pub fn get_tier_config(env: Env, tier: CollateralTier) -> Option<TierConfig> {
+        get_tier_config(&env, tier)
+    }
+
+    /// Get the tier of a collateral asset, if assigned
+    pub fn get_asset_tier(env: Env, asset: Address) -> Option<CollateralTier> {
+        get_asset_tier(&env, &asset)
+    }
+
+    /// Get the collateral deposited across a tier's assets
+    pub fn get_tier_exposure(env: Env, tier: CollateralTier) -> i128 {
+        get_tier_exposure(&env, tier)
+    }
+
+    /// Configure the collateral-responsive debt ceiling (risk manager or admin)
     ///
     /// # Arguments
-    /// * `user` - The user's address
+    /// * `caller` - The risk manager or admin address
+    /// * `config` - Collateral factor and floor/cap bounds
     ///
     /// # Returns
-    /// CollateralPosition with amount and asset
-    pub fn get_user_collateral(env: Env, user: Address) -> CollateralPosition {
-        get_user_collateral(&env, &user)
+    /// Returns Ok(()) on success
+    pub fn set_dynamic_ceiling_config(
+        env: Env,
+        caller: Address,
+        config: DynamicCeilingConfig,
+    ) -> Result<(), CeilingError> {
+        set_dynamic_ceiling_config(&env, caller, config)
+    }
+
+    /// Report the oracle's confidence in collateral prices (risk manager or admin)
+    ///
+    /// # Arguments
+    /// * `caller` - The risk manager or admin address
+    /// * `confidence_bps` - Confidence from 0 to 10000
+    ///
+    /// # Returns
+    /// Returns Ok(()) on success
+    pub fn set_oracle_confidence(
+        env: Env,
+        caller: Address,
+        confidence_bps: i128,
+    ) -> Result<(), CeilingError> {
+        set_oracle_confidence(&env, caller, confidence_bps)
+    }
+
+    /// Get the dynamic debt ceiling configuration, if set
+    pub fn get_dynamic_ceiling_config(env: Env) -> Option<DynamicCeilingConfig> {
+        get_dynamic_ceiling_config(&env)
+    }
+
+    /// Get the reported oracle confidence in basis points
+    pub fn get_oracle_confidence(env: Env) -> i128 {
+        get_oracle_confidence(&env)
+    }
+
+    /// Set the maximum number of open positions per user (admin only)
+    ///
+    /// # Arguments
+    /// * `caller` - The admin address
+    /// * `max_positions` - Maximum positions one address may hold
+    ///
+    /// # Returns
+    /// Returns Ok(()) on success
+    pub fn set_max_user_positions(
+        env: Env,
+        caller: Address,
+        max_positions: u32,
+    ) -> Result<(), PositionLimitError> {
+        set_max_user_positions(&env, caller, max_positions)
+    }
+
+    /// Get the maximum number of open positions per user
+    pub fn get_max_user_positions(env: Env) -> u32 {
+        get_max_user_positions(&env)
+    }
+
+    /// Count a user's open positions
+    ///
+    /// The core pool position, each escrowed NFT, each restricted collateral
+    /// asset and each open fixed-term loan count as one position.
+    pub fn get_user_position_count(env: Env, user: Address) -> u32 {
+        get_user_position_count(&env, &user)
+    }
+
+    /// Set the utilization-based pool borrow rate model (risk manager or admin)
+    ///
+    /// # Arguments
+    /// * `caller` - The risk manager or admin address
+    /// * `model` - Base rate, slopes and optimal utilization
+    ///
+    /// # Returns
+    /// Returns Ok(()) on success
+    pub fn set_borrow_rate_model(
+        env: Env,
+        caller: Address,
+        model: BorrowRateModel,
+    ) -> Result<(), RateModelError> {
+        set_borrow_rate_model(&env, caller, model)
+    }
+
+    /// Set the floor and cap the pool borrow rate is clamped to (admin only)
+    ///
+    /// # Arguments
+    /// * `caller` - The admin address
+    /// * `bounds` - Minimum and maximum rate in basis points per year
+    ///
+    /// # Returns
+    /// Returns Ok(()) on success
+    pub fn set_borrow_rate_bounds(
+        env: Env,
+        caller: Address,
+        bounds: BorrowRateBounds,
+    ) -> Result<(), RateModelError> {
+        set_borrow_rate_bounds(&env, caller, bounds)
+    }
+
+    /// Get the pool borrow rate model, if set
+    pub fn get_borrow_rate_model(env: Env) -> Option<BorrowRateModel> {
+        get_borrow_rate_model(&env)
+    }
+
+    /// Get the pool borrow rate bounds, if set
+    pub fn get_borrow_rate_bounds(env: Env) -> Option<BorrowRateBounds> {
+        get_borrow_rate_bounds(&env)
+    }
+
+    /// Get the pool borrow rate before clamping, in basis points per year
+    pub fn get_model_borrow_rate(env: Env) -> i128 {
+        get_model_borrow_rate(&env)
+    }
+
+    /// Get the pool borrow rate in force, in basis points per year
+    pub fn get_borrow_rate(env: Env) -> i128 {
+        get_borrow_rate(&env)
+    }
+
+    /// Get the global debt ceiling currently in force
+    pub fn get_debt_ceiling(env: Env) -> i128 {
+        borrow::get_debt_ceiling(&env)
+    }
+
+    /// Set the maximum principal that may be borrowed in an asset (admin only)
+    ///
+    /// # Arguments
+    /// * `caller` - The admin address
+    /// * `asset` - The borrowed asset
+    /// * `ceiling` - Maximum outstanding principal in the asset
+    ///
+    /// # Returns
+    /// Returns Ok(()) on success
+    pub fn set_asset_debt_ceiling(
+        env: Env,
+        caller: Address,
+        asset: Address,
+        ceiling: i128,
+    ) -> Result<(), BorrowError> {
+        set_asset_debt_ceiling(&env, caller, asset, ceiling)
+    }
+
+    /// Get the debt ceiling of an asset, if set
+    pub fn get_asset_debt_ceiling(env: Env, asset: Address) -> Option<i128> {
+        get_asset_debt_ceiling(&env, &asset)
+    }
+
+    /// Get the outstanding principal borrowed in an asset
+    pub fn get_asset_debt(env: Env, asset: Address) -> i128 {
+        get_asset_debt(&env, &asset)
+    }
+
+    /// Set the minimum borrow size of an asset (admin only)
+    ///
+    /// # Arguments
+    /// * `caller` - The admin address
+    /// * `asset` - The borrowed asset
+    /// * `config` - Minimum amount in asset units and optional value floor
+    ///
+    /// # Returns
+    /// Returns Ok(()) on success
+    pub fn set_asset_min_borrow(
+        env: Env,
+        caller: Address,
+        asset: Address,
+        config: MinBorrowConfig,
+    ) -> Result<(), BorrowError> {
+        set_asset_min_borrow(&env, caller, asset, config)
+    }
+
+    /// Set or clear an address's absolute borrow cap (risk manager only)
+    ///
+    /// Enforced in addition to the collateral check on every borrow path.
+    ///
+    /// # Arguments
+    /// * `caller` - The risk manager address
+    /// * `user` - The capped address
+    /// * `cap` - Maximum total debt, or None to remove the cap
+    ///
+    /// # Returns
+    /// Returns Ok(()) on success
+    pub fn set_user_borrow_cap(
+        env: Env,
+        caller: Address,
+        user: Address,
+        cap: Option<i128>,
+    ) -> Result<(), BorrowError> {
+        set_user_borrow_cap(&env, caller, user, cap)
+    }
+
+    /// Get an address's absolute borrow cap, if set
+    pub fn get_user_borrow_cap(env: Env, user: Address) -> Option<i128> {
+        get_user_borrow_cap(&env, &user)
+    }
+
+    /// Get the minimum borrow size of an asset, if set
+    pub fn get_asset_min_borrow(env: Env, asset: Address) -> Option<MinBorrowConfig> {
+        get_asset_min_borrow(&env, &asset)
+    }
+
+    /// Get the minimum amount of an asset that may be borrowed or left outstanding
+    pub fn get_min_borrow_amount(env: Env, asset: Address) -> i128 {
+        borrow::get_min_borrow_amount(&env, &asset)
+    }
+
+    /// Report the price of an asset (its feed, the risk manager or admin)
+    ///
+    /// # Arguments
+    /// * `caller` - The asset's feed, the risk manager or the admin
+    /// * `asset` - The priced asset
+    /// * `price` - Value of one unit, scaled by 10^7 or by the feed's decimals
+    ///
+    /// # Returns
+    /// Returns Ok(()) on success
+    pub fn set_asset_price(
+        env: Env,
+        caller: Address,
+        asset: Address,
+        price: i128,
+    ) -> Result<(), OracleError> {
+        set_asset_price(&env, caller, asset, price)
+    }
+
+    /// Get the latest reported price of an asset, if any
+    pub fn get_asset_price(env: Env, asset: Address) -> Option<PriceData> {
+        get_asset_price(&env, &asset)
+    }
+
+    /// Register or update the price feed of an asset (risk manager or admin)
+    ///
+    /// Borrows and withdrawals against the asset are rejected while its price
+    /// is older than the feed's heartbeat.
+    ///
+    /// # Arguments
+    /// * `caller` - The risk manager or admin address
+    /// * `asset` - The priced asset
+    /// * `config` - Feed address, quote currency, decimals, heartbeat and deviation bound
+    ///
+    /// # Returns
+    /// Returns Ok(()) on success
+    pub fn set_oracle_feed_config(
+        env: Env,
+        caller: Address,
+        asset: Address,
+        config: OracleFeedConfig,
+    ) -> Result<(), OracleError> {
+        set_oracle_feed_config(&env, caller, asset, config)
+    }
+
+    /// Get the price feed configuration of an asset, if registered
+    pub fn get_oracle_feed_config(env: Env, asset: Address) -> Option<OracleFeedConfig> {
+        get_oracle_feed_config(&env, &asset)
+    }
+
+    /// Get the heartbeat of an asset's price feed in seconds
+    pub fn get_price_heartbeat(env: Env, asset: Address) -> u64 {
+        get_price_heartbeat(&env, &asset)
+    }
+
+    /// Register the independent price feeds of an asset (risk manager or admin)
+    ///
+    /// The asset is then priced by the median of fresh feed reports, with
+    /// reports too far from the median discarded as outliers.
+    ///
+    /// # Arguments
+    /// * `caller` - The risk manager or admin address
+    /// * `asset` - The priced asset
+    /// * `feed_set` - Up to 5 feeds, the quorum and the outlier bound
+    ///   (an empty feed list removes the set)
+    ///
+    /// # Returns
+    /// Returns Ok(()) on success
+    pub fn set_price_feed_set(
+        env: Env,
+        caller: Address,
+        asset: Address,
+        feed_set: PriceFeedSet,
+    ) -> Result<(), OracleError> {
+        set_price_feed_set(&env, caller, asset, feed_set)
+    }
+
+    /// Get the feed set of an asset, if registered
+    pub fn get_price_feed_set(env: Env, asset: Address) -> Option<PriceFeedSet> {
+        get_price_feed_set(&env, &asset)
+    }
+
+    /// Get the latest report of one feed of an asset
+    pub fn get_feed_report(env: Env, asset: Address, feed: Address) -> Option<PriceData> {
+        get_feed_report(&env, &asset, &feed)
+    }
+
+    /// Report a price from one feed of an asset's feed set
+    ///
+    /// # Arguments
+    /// * `feed` - A feed registered for the asset
+    /// * `asset` - The priced asset
+    /// * `price` - Reported price in the decimals of the asset's feed config
+    ///
+    /// # Returns
+    /// Returns Ok(()) once the report is stored; the asset price updates when
+    /// enough fresh reports agree
+    pub fn submit_feed_price(
+        env: Env,
+        feed: Address,
+        asset: Address,
+        price: i128,
+    ) -> Result<(), OracleError> {
+        submit_feed_price(&env, feed, asset, price)
+    }
+
+    /// Configure the deviation circuit breaker of an asset (risk manager or admin)
+    ///
+    /// A price move beyond the threshold within one heartbeat freezes borrows
+    /// and liquidations in the market for the cooldown and emits a
+    /// `price_deviation_alert` event.
+    ///
+    /// # Arguments
+    /// * `caller` - The risk manager or admin address
+    /// * `asset` - The guarded asset
+    /// * `guard` - Deviation threshold and cooldown (max 7 days)
+    ///
+    /// # Returns
+    /// Returns Ok(()) on success
+    pub fn set_deviation_guard(
+        env: Env,
+        caller: Address,
+        asset: Address,
+        guard: DeviationGuard,
+    ) -> Result<(), OracleError> {
+        set_deviation_guard(&env, caller, asset, guard)
+    }
+
+    /// Get the deviation circuit breaker of an asset, if configured
+    pub fn get_deviation_guard(env: Env, asset: Address) -> Option<DeviationGuard> {
+        get_deviation_guard(&env, &asset)
+    }
+
+    /// Get the end of a market's current deviation freeze (0 if never frozen)
+    pub fn get_market_frozen_until(env: Env, asset: Address) -> u64 {
+        get_market_frozen_until(&env, &asset)
+    }
+
+    /// Check whether a market is frozen by a price deviation
+    pub fn is_market_frozen(env: Env, asset: Address) -> bool {
+        is_market_frozen(&env, &asset)
+    }
+
+    /// Evaluate liquidation eligibility against committed prices (risk manager or admin)
+    ///
+    /// When enabled, a position must also be unhealthy at the prices observed
+    /// at the previous accrual checkpoint before it can be liquidated.
+    ///
+    /// # Arguments
+    /// * `caller` - The risk manager or admin address
+    /// * `enabled` - Whether the committed-price check applies
+    ///
+    /// # Returns
+    /// Returns Ok(()) on success
+    pub fn set_committed_price_mode(
+        env: Env,
+        caller: Address,
+        enabled: bool,
+    ) -> Result<(), OracleError> {
+        set_committed_price_mode(&env, caller, enabled)
+    }
+
+    /// Check whether liquidation eligibility uses committed prices
+    pub fn get_committed_price_mode(env: Env) -> bool {
+        get_committed_price_mode(&env)
+    }
+
+    /// Set whether repayments and liquidations stay open on frozen markets
+    ///
+    /// # Arguments
+    /// * `caller` - The risk manager or admin address
+    /// * `policy` - Operations allowed while a price is stale
+    ///
+    /// # Returns
+    /// Returns Ok(()) on success
+    pub fn set_stale_price_policy(
+        env: Env,
+        caller: Address,
+        policy: StalePricePolicy,
+    ) -> Result<(), OracleError> {
+        set_stale_price_policy(&env, caller, policy)
+    }
+
+    /// Get the operations allowed while a price is stale
+    pub fn get_stale_price_policy(env: Env) -> StalePricePolicy {
+        get_stale_price_policy(&env)
+    }
+
+    /// Check whether an asset's market is frozen by a stale price
+    pub fn is_price_stale(env: Env, asset: Address) -> bool {
+        is_price_stale(&env, &asset)
+    }
+
+    /// Propose a temporary manual price during an oracle outage (guardian only)
+    ///
+    /// # Arguments
+    /// * `guardian` - The guardian address
+    /// * `asset` - The asset to price
+    /// * `price` - Value of one unit, scaled by 10^7
+    /// * `duration` - Seconds the override lasts once confirmed (max 1 day)
+    ///
+    /// # Returns
+    /// Returns Ok(()) on success
+    pub fn propose_price_override(
+        env: Env,
+        guardian: Address,
+        asset: Address,
+        price: i128,
+        duration: u64,
+    ) -> Result<(), OracleError> {
+        propose_price_override(&env, guardian, asset, price, duration)
+    }
+
+    /// Confirm a proposed price override after its 1 hour timelock (admin only)
+    ///
+    /// # Arguments
+    /// * `caller` - The admin address
+    /// * `asset` - The asset with a pending override
+    ///
+    /// # Returns
+    /// Returns Ok(()) on success
+    pub fn confirm_price_override(
+        env: Env,
+        caller: Address,
+        asset: Address,
+    ) -> Result<(), OracleError> {
+        confirm_price_override(&env, caller, asset)
+    }
+
+    /// Cancel a pending or active price override (guardian or admin)
+    pub fn cancel_price_override(
+        env: Env,
+        caller: Address,
+        asset: Address,
+    ) -> Result<(), OracleError> {
+        cancel_price_override(&env, caller, asset)
+    }
+
+    /// Get the override awaiting confirmation for an asset, if any
+    pub fn get_pending_price_override(env: Env, asset: Address) -> Option<PriceOverride> {
+        get_pending_price_override(&env, &asset)
+    }
+
+    /// Get the override in force for an asset, if any
+    pub fn get_price_override(env: Env, asset: Address) -> Option<PriceOverride> {
+        get_price_override(&env, &asset)
+    }
+
+    /// Get the price the protocol currently values an asset at
+    pub fn get_effective_price(env: Env, asset: Address) -> Option<PriceData> {
+        get_effective_price(&env, &asset)
+    }
+
+    /// Register an AMM LP token for fair-reserves pricing (risk manager or admin)
+    ///
+    /// # Arguments
+    /// * `caller` - The risk manager or admin address
+    /// * `lp_token` - The pool contract, which is also the LP token
+    /// * `config` - The pool's underlying tokens, in pool order
+    ///
+    /// # Returns
+    /// Returns Ok(()) on success
+    pub fn register_lp_token(
+        env: Env,
+        caller: Address,
+        lp_token: Address,
+        config: LpTokenConfig,
+    ) -> Result<(), LpPricingError> {
+        register_lp_token(&env, caller, lp_token, config)
+    }
+
+    /// Stop pricing an LP token from its pool (risk manager or admin)
+    pub fn deregister_lp_token(
+        env: Env,
+        caller: Address,
+        lp_token: Address,
+    ) -> Result<(), LpPricingError> {
+        deregister_lp_token(&env, caller, lp_token)
+    }
+
+    /// Get the underlying tokens of a registered LP token
+    pub fn get_lp_token_config(env: Env, lp_token: Address) -> Option<LpTokenConfig> {
+        get_lp_token_config(&env, &lp_token)
+    }
+
+    /// Get the fair price of one LP token, scaled by 10^7
+    pub fn get_lp_token_price(env: Env, lp_token: Address) -> Option<i128> {
+        get_lp_token_price(&env, &lp_token)
+    }
+
+    /// Get user's debt position
+    ///
+    /// Returns the user's current debt including accrued interest.
+    ///
+    /// # Arguments
+    /// * `user` - The user's address
+    ///
+    /// # Returns
+    /// DebtPosition with borrowed amount, interest, and last update time
+    pub fn get_user_debt(env: Env, user: Address) -> DebtPosition {
+        get_user_debt(&env, &user)
+    }
+
+    /// Get user's collateral position
+    ///
+    /// Returns the user's current collateral.
+    ///
+    /// # Arguments
+    /// * `user` - The user's address
+    ///
+    /// # Returns
+    /// CollateralPosition with amount and asset
+    pub fn get_user_collateral(env: Env, user: Address) -> CollateralPosition {
+        get_user_collateral(&env, &user)
+    }
+
+    /// Withdraw deposited collateral
+    ///
+    /// # Arguments
+    /// * `user` - The user's address (must authorize)
+    /// * `amount` - The amount of collateral to withdraw
+    ///
+    /// # Returns
+    /// The remaining collateral balance
+    pub fn withdraw_collateral(
+        env: Env,
+        user: Address,
+        amount: i128,
+    ) -> Result<i128, WithdrawError> {
+        withdraw_collateral(&env, user, amount)
+    }
+
+    /// Get a user's health factor in basis points
+    ///
+    /// 10000 means the collateral exactly meets the liquidation threshold;
+    /// users without debt report `i128::MAX`.
+    pub fn get_health_factor(env: Env, user: Address) -> i128 {
+        borrow::get_health_factor(&env, &user)
+    }
+
+    /// Get the pool borrow index (scaled by 1e9)
+    pub fn get_borrow_index(env: Env) -> i128 {
+        borrow::get_borrow_index(&env)
+    }
+
+    /// Get an asset's borrow index at a past timestamp (scaled by 1e9)
+    ///
+    /// # Arguments
+    /// * `asset` - The market's asset
+    /// * `timestamp` - The time to query
+    ///
+    /// # Returns
+    /// The index interpolated between the asset's recorded checkpoints;
+    /// `None` before its first checkpoint or in the future
+    pub fn get_borrow_index_at(env: Env, asset: Address, timestamp: u64) -> Option<i128> {
+        reserve::get_borrow_index_at(&env, &asset, timestamp)
+    }
+
+    /// Transfer the admin role (current admin only)
+    ///
+    /// The first admin is set by `initialize`; the call fails with
+    /// `NotInitialized` until then.
+    ///
+    /// # Arguments
+    /// * `new_admin` - The new admin address
+    pub fn set_admin(env: Env, new_admin: Address) -> Result<(), AdminError> {
+        set_admin(&env, new_admin)
+    }
+
+    /// Get the admin address
+    ///
+    /// # Returns
+    /// The admin address, or None if no admin has been set
+    pub fn get_admin(env: Env) -> Option<Address> {
+        get_admin(&env)
+    }
+
+    /// Appoint the risk manager (admin only)
+    ///
+    /// # Arguments
+    /// * `caller` - The admin address
+    /// * `manager` - The new risk manager
+    pub fn set_risk_manager(env: Env, caller: Address, manager: Address) -> Result<(), AdminError> {
+        set_risk_manager(&env, caller, manager)
+    }
+
+    /// Get the current risk manager
+    pub fn get_risk_manager(env: Env) -> Option<Address> {
+        get_risk_manager(&env)
+    }
+
+    /// Appoint the guardian (admin only)
+    ///
+    /// # Arguments
+    /// * `caller` - The admin address
+    /// * `guardian` - The new guardian
+    pub fn set_guardian(env: Env, caller: Address, guardian: Address) -> Result<(), AdminError> {
+        set_guardian(&env, caller, guardian)
+    }
+
+    /// Get the current guardian
+    pub fn get_guardian(env: Env) -> Option<Address> {
+        get_guardian(&env)
+    }
+
+    /// Appoint the treasurer (admin only)
+    ///
+    /// # Arguments
+    /// * `caller` - The admin address
+    /// * `treasurer` - The new treasurer
+    pub fn set_treasurer(env: Env, caller: Address, treasurer: Address) -> Result<(), AdminError> {
+        set_treasurer(&env, caller, treasurer)
+    }
+
+    /// Get the current treasurer
+    pub fn get_treasurer(env: Env) -> Option<Address> {
+        get_treasurer(&env)
+    }
+
+    /// Appoint the compliance officer (admin only)
+    ///
+    /// # Arguments
+    /// * `caller` - The admin address
+    /// * `officer` - The new compliance officer
+    pub fn set_compliance_officer(
+        env: Env,
+        caller: Address,
+        officer: Address,
+    ) -> Result<(), AdminError> {
+        set_compliance_officer(&env, caller, officer)
+    }
+
+    /// Get the current compliance officer
+    pub fn get_compliance_officer(env: Env) -> Option<Address> {
+        get_compliance_officer(&env)
+    }
+
+    /// Configure reward vesting (admin only)
+    ///
+    /// When enabled, rewards unlock linearly over `duration` seconds. Exiting
+    /// early forfeits `early_exit_penalty_bps` of the locked portion to the
+    /// insurance fund (capped at 50%).
+    ///
+    /// # Arguments
+    /// * `caller` - The caller address (must be admin)
+    /// * `config` - The new vesting configuration
+    pub fn set_vesting_config(
+        env: Env,
+        caller: Address,
+        config: VestingConfig,
+    ) -> Result<(), VestingError> {
+        set_vesting_config(&env, caller, config)
+    }
+
+    /// Get the reward vesting configuration
+    ///
+    /// # Returns
+    /// The vesting configuration, or None if not configured
+    pub fn get_vesting_config(env: Env) -> Option<VestingConfig> {
+        get_vesting_config(&env)
+    }
+
+    /// Distribute a reward to a user (admin only)
+    ///
+    /// Pays the reward immediately when vesting is disabled, otherwise opens a
+    /// new vesting schedule for it.
+    ///
+    /// # Arguments
+    /// * `caller` - The caller address (must be admin)
+    /// * `user` - The reward recipient
+    /// * `amount` - The reward amount
+    ///
+    /// # Returns
+    /// The amount paid out immediately
+    pub fn distribute_reward(
+        env: Env,
+        caller: Address,
+        user: Address,
+        amount: i128,
+    ) -> Result<i128, VestingError> {
+        distribute_reward(&env, caller, user, amount)
+    }
+
+    /// Claim all unlocked vested rewards
+    ///
+    /// # Arguments
+    /// * `user` - The beneficiary (must authorize)
+    ///
+    /// # Returns
+    /// The amount released
+    pub fn claim_vested(env: Env, user: Address) -> Result<i128, VestingError> {
+        claim_vested(&env, user)
+    }
+
+    /// Exit all vesting schedules early
+    ///
+    /// Releases both unlocked and locked rewards, minus the early-exit penalty
+    /// on the locked portion, which is credited to the insurance fund.
+    ///
+    /// # Arguments
+    /// * `user` - The beneficiary (must authorize)
+    ///
+    /// # Returns
+    /// The amount released after the penalty
+    pub fn exit_vesting(env: Env, user: Address) -> Result<i128, VestingError> {
+        exit_vesting(&env, user)
+    }
+
+    /// Get a user's active vesting schedules
+    ///
+    /// # Arguments
+    /// * `user` - The beneficiary address
+    pub fn get_vesting_schedules(env: Env, user: Address) -> Vec<VestingSchedule> {
+        get_vesting_schedules(&env, &user)
+    }
+
+    /// Get the vested amount a user can claim right now
+    ///
+    /// # Arguments
+    /// * `user` - The beneficiary address
+    pub fn get_claimable_vested(env: Env, user: Address) -> i128 {
+        get_claimable_vested(&env, &user)
+    }
+
+    /// Get the insurance fund balance for an asset
+    ///
+    /// # Arguments
+    /// * `asset` - The asset address
+    pub fn get_insurance_fund(env: Env, asset: Address) -> i128 {
+        get_insurance_fund(&env, &asset)
+    }
+
+    /// Get the protocol fees accrued in an asset and when they last accrued
+    ///
+    /// # Arguments
+    /// * `asset` - The asset address
+    pub fn get_fee_accrual(env: Env, asset: Address) -> Option<FeeAccrual> {
+        get_fee_accrual(&env, &asset)
+    }
+
+    /// Get the fee accruals of every asset that has accrued protocol fees
+    ///
+    /// An old `last_accrual` flags an asset whose revenue has stalled.
+    pub fn get_fee_accruals(env: Env) -> Vec<FeeAccrual> {
+        get_fee_accruals(&env)
+    }
+
+    /// Lock governance tokens for vote-escrowed power
+    ///
+    /// Locks last between one week and four years; the unlock time is rounded
+    /// down to a whole week. Voting power decays linearly until unlock.
+    ///
+    /// # Arguments
+    /// * `user` - The locker's address (must authorize)
+    /// * `amount` - The amount of governance tokens to lock
+    /// * `unlock_time` - The timestamp at which the lock expires
+    pub fn create_lock(
+        env: Env,
+        user: Address,
+        amount: i128,
+        unlock_time: u64,
+    ) -> Result<(), VoteEscrowError> {
+        create_lock(&env, user, amount, unlock_time)
+    }
+
+    /// Add governance tokens to an existing lock
+    ///
+    /// # Arguments
+    /// * `user` - The locker's address (must authorize)
+    /// * `amount` - The additional amount to lock
+    pub fn increase_lock_amount(
+        env: Env,
+        user: Address,
+        amount: i128,
+    ) -> Result<(), VoteEscrowError> {
+        increase_lock_amount(&env, user, amount)
+    }
+
+    /// Extend an existing lock to a later unlock time
+    ///
+    /// # Arguments
+    /// * `user` - The locker's address (must authorize)
+    /// * `unlock_time` - The new unlock timestamp
+    pub fn extend_lock(env: Env, user: Address, unlock_time: u64) -> Result<(), VoteEscrowError> {
+        extend_lock(&env, user, unlock_time)
+    }
+
+    /// Withdraw an expired lock
+    ///
+    /// # Arguments
+    /// * `user` - The locker's address (must authorize)
+    ///
+    /// # Returns
+    /// The amount of governance tokens released
+    pub fn withdraw_lock(env: Env, user: Address) -> Result<i128, VoteEscrowError> {
+        withdraw_lock(&env, user)
+    }
+
+    /// Get a user's vote-escrow lock
+    ///
+    /// # Arguments
+    /// * `user` - The locker's address
+    pub fn get_ve_lock(env: Env, user: Address) -> Option<VeLock> {
+        get_lock(&env, &user)
+    }
+
+    /// Get a user's current (decayed) voting power
+    ///
+    /// # Arguments
+    /// * `user` - The locker's address
+    pub fn get_voting_power(env: Env, user: Address) -> i128 {
+        get_voting_power(&env, &user)
+    }
+
+    /// Get a user's reward boost in basis points (10000 = 1x, max 25000)
+    ///
+    /// # Arguments
+    /// * `user` - The locker's address
+    pub fn get_reward_boost(env: Env, user: Address) -> i128 {
+        get_reward_boost(&env, &user)
+    }
+
+    /// Set the voting power required for the maximum boost (admin only)
+    ///
+    /// # Arguments
+    /// * `caller` - The caller address (must be admin)
+    /// * `power` - The voting power that earns the full boost
+    pub fn set_full_boost_power(
+        env: Env,
+        caller: Address,
+        power: i128,
+    ) -> Result<(), VoteEscrowError> {
+        set_full_boost_power(&env, caller, power)
+    }
+
+    /// Register a market as an emissions gauge (admin only)
+    ///
+    /// # Arguments
+    /// * `caller` - The caller address (must be admin)
+    /// * `market` - The market (asset) address
+    pub fn add_gauge(env: Env, caller: Address, market: Address) -> Result<(), VoteEscrowError> {
+        add_gauge(&env, caller, market)
+    }
+
+    /// Get all registered gauges
+    pub fn get_gauges(env: Env) -> Vec<Address> {
+        get_gauges(&env)
+    }
+
+    /// Allocate voting power across gauges
+    ///
+    /// # Arguments
+    /// * `user` - The voter's address (must authorize)
+    /// * `weights` - Share of voting power per gauge, in basis points (total <= 10000)
+    pub fn vote_for_gauges(
+        env: Env,
+        user: Address,
+        weights: Map<Address, u32>,
+    ) -> Result<(), VoteEscrowError> {
+        vote_for_gauges(&env, user, weights)
+    }
+
+    /// Get the absolute voting weight of a gauge
+    ///
+    /// # Arguments
+    /// * `market` - The gauge's market address
+    pub fn get_gauge_weight(env: Env, market: Address) -> i128 {
+        get_gauge_weight(&env, &market)
+    }
+
+    /// Get a gauge's share of emissions in basis points
+    ///
+    /// # Arguments
+    /// * `market` - The gauge's market address
+    pub fn get_gauge_relative_weight(env: Env, market: Address) -> i128 {
+        get_gauge_relative_weight(&env, &market)
+    }
+
+    /// Get the points multiplier a market's gauge weight earns, in basis points
+    ///
+    /// # Arguments
+    /// * `market` - The market address
+    pub fn get_gauge_multiplier(env: Env, market: Address) -> i128 {
+        get_gauge_multiplier(&env, &market)
+    }
+
+    /// Repay borrowed assets
+    ///
+    /// Accrued interest is paid first, then principal. Payments larger than the
+    /// outstanding debt are capped at the amount owed.
+    ///
+    /// # Arguments
+    /// * `user` - The borrower's address (must authorize)
+    /// * `amount` - The amount to repay
+    ///
+    /// # Returns
+    /// Returns a tuple (remaining_debt, interest_paid, principal_paid)
+    ///
+    /// # Errors
+    /// - `InvalidAmount` - Amount is zero or negative
+    /// - `NoDebt` - User has no outstanding debt
+    pub fn repay(env: Env, user: Address, amount: i128) -> Result<(i128, i128, i128), RepayError> {
+        repay(&env, user, amount)
+    }
+
+    /// Configure the loyalty rebate for consistent repayers (admin only)
+    ///
+    /// Borrowers who clear their accrued interest in `cycles_required`
+    /// consecutive cycles receive `rebate_bps` off future interest (max 20%).
+    ///
+    /// # Arguments
+    /// * `caller` - The caller address (must be admin)
+    /// * `config` - The new loyalty configuration
+    pub fn set_loyalty_config(
+        env: Env,
+        caller: Address,
+        config: LoyaltyConfig,
+    ) -> Result<(), LoyaltyError> {
+        set_loyalty_config(&env, caller, config)
+    }
+
+    /// Get the loyalty rebate configuration
+    ///
+    /// # Returns
+    /// The loyalty configuration, or None if not configured
+    pub fn get_loyalty_config(env: Env) -> Option<LoyaltyConfig> {
+        get_loyalty_config(&env)
+    }
+
+    /// Get a user's repayment streak and active interest rebate
+    ///
+    /// # Arguments
+    /// * `user` - The borrower's address
+    pub fn get_loyalty_status(env: Env, user: Address) -> LoyaltyStatus {
+        get_loyalty_status(&env, &user)
+    }
+
+    /// Configure the early-repayment rebate for variable-rate loans (admin only)
+    ///
+    /// A loan repaid in full within `window` seconds of origination has
+    /// `rebate_bps` of its outstanding interest forgiven (max 10%).
+    ///
+    /// # Arguments
+    /// * `caller` - The caller address (must be admin)
+    /// * `config` - The new rebate configuration
+    pub fn set_early_repay_config(
+        env: Env,
+        caller: Address,
+        config: EarlyRepayConfig,
+    ) -> Result<(), EarlyRepayError> {
+        set_early_repay_config(&env, caller, config)
+    }
+
+    /// Get the early-repayment rebate configuration
+    ///
+    /// # Returns
+    /// The rebate configuration, or None if not configured
+    pub fn get_early_repay_config(env: Env) -> Option<EarlyRepayConfig> {
+        get_early_repay_config(&env)
+    }
+
+    /// Check whether repaying a user's loan in full now would earn the rebate
+    ///
+    /// # Arguments
+    /// * `user` - The borrower's address
+    pub fn is_early_repay_eligible(env: Env, user: Address) -> bool {
+        is_early_repay_eligible(&env, &user)
+    }
+
+    /// Configure the points program (admin only)
+    ///
+    /// Points are non-transferable and accrue per second on supplied and
+    /// borrowed value, at the configured rates per 10,000 units per day.
+    ///
+    /// # Arguments
+    /// * `caller` - The caller address (must be admin)
+    /// * `config` - The new points configuration
+    pub fn set_points_config(
+        env: Env,
+        caller: Address,
+        config: PointsConfig,
+    ) -> Result<(), PointsError> {
+        set_points_config(&env, caller, config)
+    }
+
+    /// Get the points program configuration
+    ///
+    /// # Returns
+    /// The points configuration, or None if not configured
+    pub fn get_points_config(env: Env) -> Option<PointsConfig> {
+        get_points_config(&env)
+    }
+
+    /// Get a user's points, including points accrued since their last checkpoint
+    ///
+    /// # Arguments
+    /// * `user` - The user's address
+    pub fn get_points(env: Env, user: Address) -> i128 {
+        get_points(&env, &user)
+    }
+
+    /// Get the total settled points across all users
+    pub fn get_total_points(env: Env) -> i128 {
+        get_total_points(&env)
+    }
+
+    /// Post a fixed-rate lending offer
+    ///
+    /// # Arguments
+    /// * `lender` - The lender's address (must authorize)
+    /// * `asset` - The asset being lent
+    /// * `amount` - The maximum amount borrowers may take
+    /// * `rate_bps` - Annual fixed rate in basis points (max 100%)
+    /// * `duration` - Loan term in seconds (1 day to 5 years)
+    ///
+    /// # Returns
+    /// The new offer id
+    pub fn post_offer(
+        env: Env,
+        lender: Address,
+        asset: Address,
+        amount: i128,
+        rate_bps: i128,
+        duration: u64,
+    ) -> Result<u64, FixedRateError> {
+        post_offer(&env, lender, asset, amount, rate_bps, duration)
+    }
+
+    /// Cancel the unfilled remainder of a lending offer
+    ///
+    /// # Arguments
+    /// * `lender` - The offer owner (must authorize)
+    /// * `offer_id` - The offer to cancel
+    pub fn cancel_offer(env: Env, lender: Address, offer_id: u64) -> Result<(), FixedRateError> {
+        cancel_offer(&env, lender, offer_id)
+    }
+
+    /// Take a fixed-rate offer against collateral in the core pool
+    ///
+    /// Opens a fixed-term loan whose interest is fixed at origination. The
+    /// borrower's collateral must cover core and fixed-term debt at the
+    /// minimum collateral ratio.
+    ///
+    /// # Arguments
+    /// * `borrower` - The borrower's address (must authorize)
+    /// * `offer_id` - The offer to take
+    /// * `amount` - The amount to borrow from the offer
+    ///
+    /// # Returns
+    /// The new loan id
+    pub fn take_offer(
+        env: Env,
+        borrower: Address,
+        offer_id: u64,
+        amount: i128,
+    ) -> Result<u64, FixedRateError> {
+        take_offer(&env, borrower, offer_id, amount)
+    }
+
+    /// Repay a fixed-term loan in full
+    ///
+    /// # Arguments
+    /// * `borrower` - The borrower's address (must authorize)
+    /// * `loan_id` - The loan to repay
+    ///
+    /// # Returns
+    /// The total amount repaid (principal plus fixed interest, plus the
+    /// prepayment penalty when repaid early)
+    pub fn repay_fixed_loan(
+        env: Env,
+        borrower: Address,
+        loan_id: u64,
+    ) -> Result<i128, FixedRateError> {
+        repay_fixed_loan(&env, borrower, loan_id)
+    }
+
+    /// Set the prepayment penalty of an open offer
+    ///
+    /// # Arguments
+    /// * `lender` - The offer's lender (must authorize)
+    /// * `offer_id` - The offer
+    /// * `penalty` - Decaying fee terms and recipient (zero bps for none)
+    ///
+    /// # Returns
+    /// Returns Ok(()) on success
+    pub fn set_prepayment_penalty(
+        env: Env,
+        lender: Address,
+        offer_id: u64,
+        penalty: PrepaymentPenalty,
+    ) -> Result<(), FixedRateError> {
+        set_prepayment_penalty(&env, lender, offer_id, penalty)
+    }
+
+    /// Get the prepayment penalty a loan would pay if repaid now
+    pub fn get_prepayment_fee(env: Env, loan_id: u64) -> i128 {
+        get_prepayment_fee(&env, loan_id)
+    }
+
+    /// Set the annual penalty rate on fixed-term debt past maturity (admin only)
+    ///
+    /// # Arguments
+    /// * `caller` - The caller address (must be admin)
+    /// * `rate_bps` - Annual penalty rate in basis points (max 100%)
+    pub fn set_overdue_penalty_rate(
+        env: Env,
+        caller: Address,
+        rate_bps: i128,
+    ) -> Result<(), FixedRateError> {
+        set_overdue_penalty_rate(&env, caller, rate_bps)
+    }
+
+    /// Get the annual penalty rate on overdue fixed-term debt
+    pub fn get_overdue_penalty_rate(env: Env) -> i128 {
+        get_overdue_penalty_rate(&env)
+    }
+
+    /// Get the penalty interest a loan has accrued since maturity
+    pub fn get_overdue_interest(env: Env, loan_id: u64) -> i128 {
+        get_overdue_interest(&env, loan_id)
+    }
+
+    /// Get a fixed-rate lending offer
+    ///
+    /// # Arguments
+    /// * `offer_id` - The offer id
+    pub fn get_offer(env: Env, offer_id: u64) -> Option<LoanOffer> {
+        get_offer(&env, offer_id)
+    }
+
+    /// Get a fixed-term loan
+    ///
+    /// # Arguments
+    /// * `loan_id` - The loan id
+    pub fn get_fixed_loan(env: Env, loan_id: u64) -> Option<FixedLoan> {
+        get_fixed_loan(&env, loan_id)
+    }
+
+    /// Get the ids of a borrower's open fixed-term loans
+    ///
+    /// # Arguments
+    /// * `borrower` - The borrower's address
+    pub fn get_borrower_fixed_loans(env: Env, borrower: Address) -> Vec<u64> {
+        get_borrower_fixed_loans(&env, &borrower)
+    }
+
+    /// Get a borrower's outstanding fixed-term principal
+    ///
+    /// # Arguments
+    /// * `borrower` - The borrower's address
+    pub fn get_fixed_debt(env: Env, borrower: Address) -> i128 {
+        get_fixed_debt(&env, &borrower)
+    }
+
+    /// Open a loan repaid in level installments of principal and interest
+    ///
+    /// The rate is fixed at the pool borrow rate at origination.
+    ///
+    /// # Arguments
+    /// * `borrower` - The borrower's address
+    /// * `asset` - The borrowed asset
+    /// * `principal` - The amount borrowed
+    /// * `interval` - Seconds between installments (at least 1 day)
+    /// * `installments` - Number of installments (at most 360, 5 years in total)
+    ///
+    /// # Returns
+    /// The loan id
+    pub fn open_amortizing_loan(
+        env: Env,
+        borrower: Address,
+        asset: Address,
+        principal: i128,
+        interval: u64,
+        installments: u32,
+    ) -> Result<u64, AmortizingError> {
+        open_amortizing_loan(&env, borrower, asset, principal, interval, installments)
+    }
+
+    /// Pre-approve the amount keepers may pull for a loan's installments
+    ///
+    /// # Arguments
+    /// * `borrower` - The borrower's address
+    /// * `loan_id` - The amortizing loan
+    /// * `allowance` - Total amount keepers may pull
+    pub fn set_installment_allowance(
+        env: Env,
+        borrower: Address,
+        loan_id: u64,
+        allowance: i128,
+    ) -> Result<(), AmortizingError> {
+        set_installment_allowance(&env, borrower, loan_id, allowance)
+    }
+
+    /// Pay the next installment of an amortizing loan
+    ///
+    /// # Returns
+    /// The amount paid
+    pub fn pay_installment(
+        env: Env,
+        borrower: Address,
+        loan_id: u64,
+    ) -> Result<i128, AmortizingError> {
+        pay_installment(&env, borrower, loan_id)
+    }
+
+    /// Pull due installments within the borrower's allowance (permissionless)
+    ///
+    /// # Returns
+    /// The amount pulled
+    pub fn execute_installments(env: Env, loan_id: u64) -> Result<i128, AmortizingError> {
+        execute_installments(&env, loan_id)
+    }
+
+    /// Get an amortizing loan by id
+    pub fn get_amortizing_loan(env: Env, loan_id: u64) -> Option<AmortizingLoan> {
+        get_amortizing_loan(&env, loan_id)
+    }
+
+    /// Get the ids of a borrower's open amortizing loans
+    pub fn get_borrower_amortizing_loans(env: Env, borrower: Address) -> Vec<u64> {
+        get_borrower_amortizing_loans(&env, &borrower)
+    }
+
+    /// Get the full installment schedule of an amortizing loan
+    pub fn get_amortization_schedule(
+        env: Env,
+        loan_id: u64,
+    ) -> Result<Vec<Installment>, AmortizingError> {
+        get_amortization_schedule(&env, loan_id)
+    }
+
+    /// Get whether an amortizing loan is current, delinquent or repaid
+    pub fn get_amortizing_status(
+        env: Env,
+        loan_id: u64,
+    ) -> Result<AmortizingStatus, AmortizingError> {
+        get_amortizing_status(&env, loan_id)
+    }
+
+    /// Get a borrower's outstanding amortizing principal
+    pub fn get_amortizing_debt(env: Env, borrower: Address) -> i128 {
+        get_amortizing_debt(&env, &borrower)
+    }
+
+    /// Transfer the repayment claim on an outstanding fixed-term loan
+    ///
+    /// # Arguments
+    /// * `holder` - The current claim holder (must authorize)
+    /// * `loan_id` - The fixed-term loan
+    /// * `to` - The new holder
+    pub fn transfer_receivable(
+        env: Env,
+        holder: Address,
+        loan_id: u64,
+        to: Address,
+    ) -> Result<(), ReceivableError> {
+        transfer_receivable(&env, holder, loan_id, to)
+    }
+
+    /// List the claim on an outstanding fixed-term loan for sale
+    pub fn list_receivable(
+        env: Env,
+        holder: Address,
+        loan_id: u64,
+        price: i128,
+    ) -> Result<(), ReceivableError> {
+        list_receivable(&env, holder, loan_id, price)
+    }
+
+    /// Withdraw a receivable listing
+    pub fn delist_receivable(
+        env: Env,
+        holder: Address,
+        loan_id: u64,
+    ) -> Result<(), ReceivableError> {
+        delist_receivable(&env, holder, loan_id)
+    }
+
+    /// Buy a listed receivable at its asking price
+    ///
+    /// # Returns
+    /// The price paid to the seller
+    pub fn buy_receivable(env: Env, buyer: Address, loan_id: u64) -> Result<i128, ReceivableError> {
+        buy_receivable(&env, buyer, loan_id)
+    }
+
+    /// Get the sale listing for a loan's claim
+    pub fn get_receivable_listing(env: Env, loan_id: u64) -> Option<ReceivableListing> {
+        get_receivable_listing(&env, loan_id)
+    }
+
+    /// Get the ids of outstanding fixed-term loans whose claims a holder owns
+    pub fn get_holder_receivables(env: Env, holder: Address) -> Vec<u64> {
+        get_holder_receivables(&env, &holder)
+    }
+
+    /// Get the total fixed-term repayments routed to a claim holder
+    pub fn get_receivable_proceeds(env: Env, holder: Address) -> i128 {
+        get_receivable_proceeds(&env, &holder)
+    }
+
+    /// Configure peer-to-peer matching for an asset (admin only)
+    ///
+    /// # Arguments
+    /// * `caller` - The admin address
+    /// * `asset` - The asset to configure
+    /// * `market` - Pool supply rate and the matched-rate cursor
+    pub fn set_p2p_market(
+        env: Env,
+        caller: Address,
+        asset: Address,
+        market: P2PMarket,
+    ) -> Result<(), P2PError> {
+        set_p2p_market(&env, caller, asset, market)
+    }
+
+    /// Get the matching configuration of an asset
+    pub fn get_p2p_market(env: Env, asset: Address) -> Option<P2PMarket> {
+        get_p2p_market(&env, &asset)
+    }
+
+    /// Get the pool supply, matched and pool borrow rates of an asset
+    pub fn get_p2p_rates(env: Env, asset: Address) -> Option<P2PRates> {
+        get_p2p_rates(&env, &asset)
+    }
+
+    /// Supply liquidity, matching it peer-to-peer with waiting borrowers
+    ///
+    /// # Arguments
+    /// * `supplier` - The supplier's address (must authorize)
+    /// * `asset` - The asset supplied
+    /// * `amount` - The amount supplied
+    ///
+    /// # Returns
+    /// The amount matched peer-to-peer; the remainder stays on the pool
+    pub fn p2p_supply(
+        env: Env,
+        supplier: Address,
+        asset: Address,
+        amount: i128,
+    ) -> Result<i128, P2PError> {
+        p2p_supply(&env, supplier, asset, amount)
+    }
+
+    /// Withdraw supplied liquidity, rebalancing matched positions
+    ///
+    /// # Arguments
+    /// * `supplier` - The supplier's address (must authorize)
+    /// * `asset` - The asset to withdraw
+    /// * `amount` - The amount to withdraw
+    pub fn p2p_withdraw(
+        env: Env,
+        supplier: Address,
+        asset: Address,
+        amount: i128,
+    ) -> Result<i128, P2PError> {
+        p2p_withdraw(&env, supplier, asset, amount)
+    }
+
+    /// Opt a borrower's pool debt into peer-to-peer matching
+    ///
+    /// # Arguments
+    /// * `user` - The borrower's address (must authorize)
+    ///
+    /// # Returns
+    /// The borrower's total matched debt
+    pub fn join_p2p_borrow(env: Env, user: Address) -> Result<i128, P2PError> {
+        join_p2p_borrow(&env, user)
+    }
+
+    /// Get a supplier's matched and pool balances
+    pub fn get_p2p_supply(env: Env, user: Address, asset: Address) -> P2PSupplyPosition {
+        get_p2p_supply(&env, &user, &asset)
+    }
+
+    /// Get the part of a borrower's principal matched peer-to-peer
+    pub fn get_p2p_borrow_matched(env: Env, user: Address) -> i128 {
+        get_p2p_borrow_matched(&env, &user)
+    }
+
+    /// Get the total amount of an asset matched peer-to-peer
+    pub fn get_p2p_matched_total(env: Env, asset: Address) -> i128 {
+        get_p2p_matched_total(&env, &asset)
+    }
+
+    /// Approve an NFT collection as collateral (admin only)
+    ///
+    /// # Arguments
+    /// * `caller` - The admin address
+    /// * `collection` - The NFT contract address
+    /// * `ltv_bps` - Share of the floor value counted as collateral (max 70%)
+    pub fn approve_nft_collection(
+        env: Env,
+        caller: Address,
+        collection: Address,
+        ltv_bps: i128,
+    ) -> Result<(), NftError> {
+        approve_nft_collection(&env, caller, collection, ltv_bps)
+    }
+
+    /// Record the appraised floor value of an approved collection (admin only)
+    ///
+    /// # Arguments
+    /// * `caller` - The admin address
+    /// * `collection` - The NFT contract address
+    /// * `floor_price` - Appraised value of one token
+    pub fn set_nft_floor(
+        env: Env,
+        caller: Address,
+        collection: Address,
+        floor_price: i128,
+    ) -> Result<(), NftError> {
+        set_nft_floor(&env, caller, collection, floor_price)
+    }
+
+    /// Get an approved collection's configuration
+    pub fn get_nft_collection(env: Env, collection: Address) -> Option<NftCollection> {
+        get_nft_collection(&env, &collection)
+    }
+
+    /// Escrow an NFT as collateral
+    ///
+    /// # Arguments
+    /// * `user` - The owner's address (must authorize)
+    /// * `collection` - The NFT contract address
+    /// * `token_id` - The token to escrow
+    pub fn escrow_nft(
+        env: Env,
+        user: Address,
+        collection: Address,
+        token_id: u64,
+    ) -> Result<(), NftError> {
+        escrow_nft(&env, user, collection, token_id)
+    }
+
+    /// Withdraw an escrowed NFT while keeping the position collateralized
+    ///
+    /// # Arguments
+    /// * `user` - The owner's address (must authorize)
+    /// * `collection` - The NFT contract address
+    /// * `token_id` - The token to withdraw
+    pub fn withdraw_nft(
+        env: Env,
+        user: Address,
+        collection: Address,
+        token_id: u64,
+    ) -> Result<(), NftError> {
+        withdraw_nft(&env, user, collection, token_id)
+    }
+
+    /// Borrow against escrowed NFTs and deposited collateral
+    ///
+    /// # Arguments
+    /// * `user` - The borrower's address (must authorize)
+    /// * `asset` - The asset to borrow
+    /// * `amount` - The amount to borrow
+    pub fn nft_borrow(
+        env: Env,
+        user: Address,
+        asset: Address,
+        amount: i128,
+    ) -> Result<(), NftError> {
+        nft_borrow(&env, user, asset, amount)
+    }
+
+    /// Get the NFTs a user has escrowed
+    pub fn get_user_nfts(env: Env, user: Address) -> Vec<NftId> {
+        get_user_nfts(&env, &user)
+    }
+
+    /// Get the owner of an escrowed NFT
+    pub fn get_nft_owner(env: Env, collection: Address, token_id: u64) -> Option<Address> {
+        get_nft_owner(&env, &collection, token_id)
+    }
+
+    /// Get the collateral value of a user's escrowed NFTs
+    pub fn get_nft_collateral_value(env: Env, user: Address) -> i128 {
+        get_nft_collateral_value(&env, &user)
+    }
+
+    /// Start an English auction for an NFT backing an undercollateralized position
+    ///
+    /// # Arguments
+    /// * `collection` - The NFT contract address
+    /// * `token_id` - The token to auction
+    pub fn start_nft_auction(env: Env, collection: Address, token_id: u64) -> Result<(), NftError> {
+        start_nft_auction(&env, collection, token_id)
+    }
+
+    /// Bid in an NFT liquidation auction
+    ///
+    /// # Arguments
+    /// * `bidder` - The bidder's address (must authorize)
+    /// * `collection` - The NFT contract address
+    /// * `token_id` - The auctioned token
+    /// * `amount` - The bid amount
+    pub fn bid_nft_auction(
+        env: Env,
+        bidder: Address,
+        collection: Address,
+        token_id: u64,
+        amount: i128,
+    ) -> Result<(), NftError> {
+        bid_nft_auction(&env, bidder, collection, token_id, amount)
+    }
+
+    /// Settle an ended NFT auction
+    ///
+    /// # Returns
+    /// The amount of the winning bid applied to the owner's debt
+    pub fn settle_nft_auction(
+        env: Env,
+        collection: Address,
+        token_id: u64,
+    ) -> Result<i128, NftError> {
+        settle_nft_auction(&env, collection, token_id)
+    }
+
+    /// Get the active auction of an NFT
+    pub fn get_nft_auction(env: Env, collection: Address, token_id: u64) -> Option<NftAuction> {
+        get_nft_auction(&env, &collection, token_id)
+    }
+
+    /// Register a transfer-restricted (RWA) collateral asset (admin only)
+    ///
+    /// # Arguments
+    /// * `caller` - The admin address
+    /// * `asset` - The restricted token
+    /// * `config` - Compliance contract and loan-to-value ratio
+    pub fn register_rwa_asset(
+        env: Env,
+        caller: Address,
+        asset: Address,
+        config: RwaAssetConfig,
+    ) -> Result<(), RwaError> {
+        register_rwa_asset(&env, caller, asset, config)
+    }
+
+    /// Get the configuration of a registered restricted asset
+    pub fn get_rwa_asset(env: Env, asset: Address) -> Option<RwaAssetConfig> {
+        get_rwa_asset(&env, &asset)
+    }
+
+    /// Deposit restricted collateral, subject to the token's compliance check
+    ///
+    /// # Arguments
+    /// * `user` - The depositor's address (must authorize)
+    /// * `asset` - The restricted token
+    /// * `amount` - The amount to deposit
+    pub fn deposit_rwa_collateral(
+        env: Env,
+        user: Address,
+        asset: Address,
+        amount: i128,
+    ) -> Result<(), RwaError> {
+        deposit_rwa_collateral(&env, user, asset, amount)
+    }
+
+    /// Withdraw restricted collateral, subject to the token's compliance check
+    ///
+    /// # Arguments
+    /// * `user` - The depositor's address (must authorize)
+    /// * `asset` - The restricted token
+    /// * `amount` - The amount to withdraw
+    pub fn withdraw_rwa_collateral(
+        env: Env,
+        user: Address,
+        asset: Address,
+        amount: i128,
+    ) -> Result<(), RwaError> {
+        withdraw_rwa_collateral(&env, user, asset, amount)
+    }
+
+    /// Borrow against restricted collateral and other deposited collateral
+    ///
+    /// # Arguments
+    /// * `user` - The borrower's address (must authorize)
+    /// * `asset` - The asset to borrow
+    /// * `amount` - The amount to borrow
+    pub fn rwa_borrow(
+        env: Env,
+        user: Address,
+        asset: Address,
+        amount: i128,
+    ) -> Result<(), RwaError> {
+        rwa_borrow(&env, user, asset, amount)
+    }
+
+    /// Liquidate restricted collateral of an undercollateralized position
+    ///
+    /// # Arguments
+    /// * `liquidator` - An eligible holder of the token (must authorize)
+    /// * `user` - The borrower being liquidated
+    /// * `asset` - The restricted collateral to seize
+    /// * `repay_amount` - The amount of debt to repay
+    pub fn liquidate_rwa(
+        env: Env,
+        liquidator: Address,
+        user: Address,
+        asset: Address,
+        repay_amount: i128,
+    ) -> Result<RwaLiquidation, RwaError> {
+        liquidate_rwa(&env, liquidator, user, asset, repay_amount)
+    }
+
+    /// Claim collateral held after a refused liquidation transfer
+    ///
+    /// # Arguments
+    /// * `liquidator` - The liquidator owed the collateral (must authorize)
+    /// * `asset` - The restricted token
+    /// * `recipient` - The eligible address to settle to
+    pub fn claim_rwa_settlement(
+        env: Env,
+        liquidator: Address,
+        asset: Address,
+        recipient: Address,
+    ) -> Result<i128, RwaError> {
+        claim_rwa_settlement(&env, liquidator, asset, recipient)
+    }
+
+    /// Get a user's deposited amount of a restricted asset
+    pub fn get_rwa_collateral(env: Env, user: Address, asset: Address) -> i128 {
+        get_rwa_collateral(&env, &user, &asset)
+    }
+
+    /// Get the collateral value of a user's restricted deposits
+    pub fn get_rwa_collateral_value(env: Env, user: Address) -> i128 {
+        get_rwa_collateral_value(&env, &user)
+    }
+
+    /// Get collateral awaiting settlement to a liquidator
+    pub fn get_pending_rwa_settlement(env: Env, liquidator: Address, asset: Address) -> i128 {
+        get_pending_rwa_settlement(&env, &liquidator, &asset)
+    }
+
+    /// Open or update an underwritten credit line (risk manager only)
+    ///
+    /// # Arguments
+    /// * `caller` - The risk manager or admin
+    /// * `borrower` - The vetted borrower
+    /// * `asset` - The asset the line is drawn in
+    /// * `limit` - Maximum principal that may be drawn
+    /// * `collateral_bps` - Collateral required per unit drawn (0 = unsecured)
+    /// * `rate_bps` - Annual interest rate in basis points
+    pub fn open_credit_line(
+        env: Env,
+        caller: Address,
+        borrower: Address,
+        asset: Address,
+        limit: i128,
+        collateral_bps: i128,
+        rate_bps: i128,
+    ) -> Result<(), CreditLineError> {
+        open_credit_line(
+            &env,
+            caller,
+            borrower,
+            asset,
+            limit,
+            collateral_bps,
+            rate_bps,
+        )
+    }
+
+    /// Stop further draws on a credit line (risk manager only)
+    pub fn revoke_credit_line(
+        env: Env,
+        caller: Address,
+        borrower: Address,
+    ) -> Result<(), CreditLineError> {
+        revoke_credit_line(&env, caller, borrower)
+    }
+
+    /// Draw on a credit line
+    ///
+    /// # Arguments
+    /// * `borrower` - The borrower's address (must authorize)
+    /// * `amount` - The amount to draw
+    pub fn draw_credit(env: Env, borrower: Address, amount: i128) -> Result<(), CreditLineError> {
+        draw_credit(&env, borrower, amount)
+    }
+
+    /// Repay a credit line, interest first
+    ///
+    /// # Returns
+    /// The remaining balance on the line
+    pub fn repay_credit(
+        env: Env,
+        borrower: Address,
+        amount: i128,
+    ) -> Result<i128, CreditLineError> {
+        repay_credit(&env, borrower, amount)
+    }
+
+    /// Write off a defaulted credit line (risk manager only)
+    ///
+    /// # Returns
+    /// The amount recorded as a credit line loss
+    pub fn write_off_credit_line(
+        env: Env,
+        caller: Address,
+        borrower: Address,
+    ) -> Result<i128, CreditLineError> {
+        write_off_credit_line(&env, caller, borrower)
+    }
+
+    /// Get a borrower's credit line
+    pub fn get_credit_line(env: Env, borrower: Address) -> Option<CreditLine> {
+        get_credit_line(&env, &borrower)
+    }
+
+    /// Get the total principal drawn across credit lines
+    pub fn get_credit_line_total_drawn(env: Env) -> i128 {
+        get_credit_line_total_drawn(&env)
+    }
+
+    /// Get cumulative credit line losses
+    pub fn get_credit_line_losses(env: Env) -> i128 {
+        get_credit_line_losses(&env)
+    }
+
+    /// Configure score-based collateral ratio adjustment (admin only)
+    ///
+    /// # Arguments
+    /// * `caller` - The admin address
+    /// * `config` - Whether scores apply and the maximum ratio adjustment
+    pub fn set_credit_score_config(
+        env: Env,
+        caller: Address,
+        config: CreditScoreConfig,
+    ) -> Result<(), CreditScoreError> {
+        set_credit_score_config(&env, caller, config)
+    }
+
+    /// Get the credit score configuration
+    pub fn get_credit_score_config(env: Env) -> Option<CreditScoreConfig> {
+        get_credit_score_config(&env)
+    }
+
+    /// Get a user's credit score (0 to 1000)
+    pub fn get_credit_score(env: Env, user: Address) -> u32 {
+        get_credit_score(&env, &user)
+    }
+
+    /// Get the borrowing and repayment record behind a user's score
+    pub fn get_credit_history(env: Env, user: Address) -> CreditHistory {
+        get_credit_history(&env, &user)
+    }
+
+    /// Get the minimum collateral ratio for borrowing and withdrawals that
+    /// applies to a user, in basis points
+    pub fn get_collateral_ratio(env: Env, user: Address) -> i128 {
+        borrow::get_collateral_ratio(&env, &user)
+    }
+
+    /// Get the collateral ratio below which a user becomes liquidatable, in basis points
+    pub fn get_liquidation_ratio(env: Env, user: Address) -> i128 {
+        borrow::get_liquidation_ratio(&env, &user)
+    }
+
+    /// Configure a streaming repayment plan
+    ///
+    /// # Arguments
+    /// * `user` - The borrower's address (must authorize)
+    /// * `amount_per_interval` - Amount repaid per installment
+    /// * `interval` - Seconds between installments (at least 1 hour)
+    /// * `allowance` - Total amount the stream may pull
+    /// * `expiration_ledger` - Last ledger the stream can pull installments in
+    pub fn set_repayment_stream(
+        env: Env,
+        user: Address,
+        amount_per_interval: i128,
+        interval: u64,
+        allowance: i128,
+        expiration_ledger: u32,
+    ) -> Result<(), StreamError> {
+        set_repayment_stream(
+            &env,
+            user,
+            amount_per_interval,
+            interval,
+            allowance,
+            expiration_ledger,
+        )
+    }
+
+    /// Cancel a streaming repayment plan
+    pub fn cancel_repayment_stream(env: Env, user: Address) -> Result<(), StreamError> {
+        cancel_repayment_stream(&env, user)
+    }
+
+    /// Execute due installments of a borrower's repayment stream (permissionless)
+    ///
+    /// # Returns
+    /// The amount repaid
+    pub fn execute_repayment_stream(env: Env, user: Address) -> Result<i128, StreamError> {
+        execute_repayment_stream(&env, user)
+    }
+
+    /// Get a borrower's repayment stream
+    pub fn get_repayment_stream(env: Env, user: Address) -> Option<RepaymentStream> {
+        get_repayment_stream(&env, &user)
+    }
+
+    /// Register a conditional operation any keeper may execute
+    ///
+    /// # Arguments
+    /// * `owner` - The user authorizing the operation (must authorize)
+    /// * `kind` - The operation and its trigger condition
+    /// * `amount` - The exact amount the operation moves
+    /// * `tip` - Paid to the executing keeper
+    /// * `expiration_ledger` - Last ledger the intent can be executed in
+    ///
+    /// # Returns
+    /// The intent id
+    pub fn register_intent(
+        env: Env,
+        owner: Address,
+        kind: IntentKind,
+        amount: i128,
+        tip: i128,
+        expiration_ledger: u32,
+    ) -> Result<u64, AutomationError> {
+        register_intent(&env, owner, kind, amount, tip, expiration_ledger)
+    }
+
+    /// Cancel a pending intent
+    pub fn cancel_intent(env: Env, owner: Address, id: u64) -> Result<(), AutomationError> {
+        cancel_intent(&env, owner, id)
+    }
+
+    /// Execute an intent whose condition is met
+    ///
+    /// # Arguments
+    /// * `keeper` - The executing keeper (must authorize)
+    /// * `id` - The intent to execute
+    ///
+    /// # Returns
+    /// The tip earned
+    pub fn execute_intent(env: Env, keeper: Address, id: u64) -> Result<i128, AutomationError> {
+        execute_intent(&env, keeper, id)
+    }
+
+    /// Get an intent by id
+    pub fn get_intent(env: Env, id: u64) -> Option<Intent> {
+        get_intent(&env, id)
+    }
+
+    /// Get the ids of a user's pending intents
+    pub fn get_user_intents(env: Env, owner: Address) -> Vec<u64> {
+        get_user_intents(&env, &owner)
+    }
+
+    /// Get the tips a keeper has earned
+    pub fn get_keeper_tips(env: Env, keeper: Address) -> i128 {
+        get_keeper_tips(&env, &keeper)
+    }
+
+    /// Propose a fixed-for-floating swap referencing the pool borrow index
+    ///
+    /// # Arguments
+    /// * `proposer` - The proposing side (must authorize)
+    /// * `pay_fixed` - Whether the proposer pays fixed and receives floating
+    /// * `notional` - The notional amount both legs accrue on
+    /// * `fixed_rate_bps` - The fixed leg's annual rate in basis points
+    /// * `tenor` - Swap duration in seconds, counted from acceptance
+    /// * `margin` - Margin posted (at least 10% of notional)
+    ///
+    /// # Returns
+    /// The swap id
+    pub fn propose_rate_swap(
+        env: Env,
+        proposer: Address,
+        pay_fixed: bool,
+        notional: i128,
+        fixed_rate_bps: i128,
+        tenor: u64,
+        margin: i128,
+    ) -> Result<u64, RateSwapError> {
+        propose_rate_swap(
+            &env,
+            proposer,
+            pay_fixed,
+            notional,
+            fixed_rate_bps,
+            tenor,
+            margin,
+        )
+    }
+
+    /// Take the open side of a proposed swap
+    pub fn accept_rate_swap(
+        env: Env,
+        counterparty: Address,
+        id: u64,
+        margin: i128,
+    ) -> Result<(), RateSwapError> {
+        accept_rate_swap(&env, counterparty, id, margin)
+    }
+
+    /// Cancel an unaccepted swap proposal
+    ///
+    /// # Returns
+    /// The margin released to the proposer
+    pub fn cancel_rate_swap(env: Env, proposer: Address, id: u64) -> Result<i128, RateSwapError> {
+        cancel_rate_swap(&env, proposer, id)
+    }
+
+    /// Add margin to one side of an active swap
+    pub fn add_swap_margin(
+        env: Env,
+        party: Address,
+        id: u64,
+        amount: i128,
+    ) -> Result<(), RateSwapError> {
+        add_swap_margin(&env, party, id, amount)
+    }
+
+    /// Settle a matured or under-margined swap (permissionless)
+    ///
+    /// # Returns
+    /// The margin released to each side
+    pub fn settle_rate_swap(env: Env, id: u64) -> Result<RateSwapSettlement, RateSwapError> {
+        settle_rate_swap(&env, id)
+    }
+
+    /// Get a swap by id
+    pub fn get_rate_swap(env: Env, id: u64) -> Option<RateSwap> {
+        get_rate_swap(&env, id)
+    }
+
+    /// Get an active swap's mark-to-market value to the fixed payer
+    pub fn get_rate_swap_pnl(env: Env, id: u64) -> Result<i128, RateSwapError> {
+        get_rate_swap_pnl(&env, id)
+    }
+
+    /// Configure automated deleveraging (admin only)
+    pub fn set_deleverage_config(
+        env: Env,
+        caller: Address,
+        config: DeleverageConfig,
+    ) -> Result<(), DeleverageError> {
+        set_deleverage_config(&env, caller, config)
+    }
+
+    /// Partially close one of the largest positions during a sustained liquidity crunch
+    ///
+    /// # Arguments
+    /// * `keeper` - The executing account (must authorize)
+    /// * `user` - A borrower among the largest positions
+    /// * `amount` - The debt to close from the borrower's collateral
+    ///
+    /// # Returns
+    /// The collateral seized, including the penalty
+    pub fn deleverage(
+        env: Env,
+        keeper: Address,
+        user: Address,
+        amount: i128,
+    ) -> Result<i128, DeleverageError> {
+        deleverage(&env, keeper, user, amount)
+    }
+
+    /// Checkpoint utilization against the critical threshold (permissionless)
+    ///
+    /// # Returns
+    /// The current utilization in basis points
+    pub fn poke_utilization(env: Env) -> i128 {
+        poke_utilization(&env)
+    }
+
+    /// Get the deleveraging configuration
+    pub fn get_deleverage_config(env: Env) -> Option<DeleverageConfig> {
+        get_deleverage_config(&env)
+    }
+
+    /// Get pool utilization in basis points
+    pub fn get_utilization(env: Env) -> i128 {
+        get_utilization(&env)
+    }
+
+    /// Get when the current liquidity crunch started
+    pub fn get_crunch_start(env: Env) -> Option<u64> {
+        get_crunch_start(&env)
+    }
+
+    /// Get the largest borrowers by principal
+    pub fn get_largest_borrowers(env: Env) -> Vec<Address> {
+        get_largest_borrowers(&env)
+    }
+
+    /// Configure minimum position sizes (admin only)
+    ///
+    /// # Arguments
+    /// * `caller` - The admin address
+    /// * `config` - Minimum collateral at origination and the dust threshold
+    ///   below which positions may be cleaned up
+    ///
+    /// # Returns
+    /// Returns Ok(()) on success
+    pub fn set_dust_config(env: Env, caller: Address, config: DustConfig) -> Result<(), DustError> {
+        set_dust_config(&env, caller, config)
+    }
+
+    /// Get the minimum position sizes
+    pub fn get_dust_config(env: Env) -> DustConfig {
+        get_dust_config(&env)
+    }
+
+    /// Check whether a user's position is below the dust threshold
+    pub fn is_dust_position(env: Env, user: Address) -> bool {
+        is_dust_position(&env, &user)
+    }
+
+    /// Close a position below the dust threshold (permissionless)
+    ///
+    /// The collateral is absorbed into the insurance fund and the debt is
+    /// written off against it.
+    ///
+    /// # Arguments
+    /// * `user` - The owner of the dust position
+    ///
+    /// # Returns
+    /// The collateral absorbed and the debt written off
+    pub fn clean_dust(env: Env, user: Address) -> Result<(i128, i128), DustError> {
+        clean_dust(&env, user)
+    }
+
+    /// Configure the debt growth breaker of an asset (risk manager or admin)
+    ///
+    /// New borrows of the asset pause automatically once its debt grows past
+    /// the allowance within one window.
+    ///
+    /// # Arguments
+    /// * `caller` - The risk manager or admin address
+    /// * `asset` - The guarded asset
+    /// * `guard` - Growth threshold, window length and minimum allowance
+    ///
+    /// # Returns
+    /// Returns Ok(()) on success
+    pub fn set_debt_growth_guard(
+        env: Env,
+        caller: Address,
+        asset: Address,
+        guard: DebtGrowthGuard,
+    ) -> Result<(), CeilingError> {
+        set_debt_growth_guard(&env, caller, asset, guard)
+    }
+
+    /// Get the debt growth breaker of an asset, if configured
+    pub fn get_debt_growth_guard(env: Env, asset: Address) -> Option<DebtGrowthGuard> {
+        get_debt_growth_guard(&env, &asset)
+    }
+
+    /// Check whether borrows of an asset are paused by the debt growth breaker
+    pub fn is_asset_borrow_paused(env: Env, asset: Address) -> bool {
+        is_asset_borrow_paused(&env, &asset)
+    }
+
+    /// Resume borrows of an asset after the debt growth breaker tripped (admin only)
+    ///
+    /// # Arguments
+    /// * `caller` - The admin address
+    /// * `asset` - The paused asset
+    ///
+    /// # Returns
+    /// Returns Ok(()) on success
+    pub fn resume_asset_borrows(
+        env: Env,
+        caller: Address,
+        asset: Address,
+    ) -> Result<(), CeilingError> {
+        resume_asset_borrows(&env, caller, asset)
+    }
+
+    /// Pause individual operations (guardian or admin)
+    ///
+    /// # Arguments
+    /// * `caller` - The guardian or admin address
+    /// * `operations` - Bitmask of `PAUSE_*` flags to pause; cleared bits
+    ///   resume their operations
+    ///
+    /// # Returns
+    /// Returns Ok(()) on success
+    pub fn set_paused_operations(
+        env: Env,
+        caller: Address,
+        operations: u32,
+    ) -> Result<(), PauseError> {
+        set_paused_operations(&env, caller, operations)
+    }
+
+    /// Get the bitmask of paused operations
+    pub fn get_paused_operations(env: Env) -> u32 {
+        get_paused_operations(&env)
+    }
+
+    /// Check whether an operation (one of the `PAUSE_*` flags) is paused
+    pub fn is_operation_paused(env: Env, operation: u32) -> bool {
+        is_operation_paused(&env, operation)
+    }
+
+    /// Make the guardian's current pauses permanent (admin only)
+    ///
+    /// # Arguments
+    /// * `caller` - The admin address
+    ///
+    /// # Returns
+    /// The resulting bitmask of paused operations
+    pub fn reaffirm_guardian_pause(env: Env, caller: Address) -> Result<u32, PauseError> {
+        reaffirm_guardian_pause(&env, caller)
+    }
+
+    /// Set how long guardian pauses last before lapsing (admin only)
+    ///
+    /// # Arguments
+    /// * `caller` - The admin address
+    /// * `duration` - Seconds, up to 30 days
+    ///
+    /// # Returns
+    /// Returns Ok(()) on success
+    pub fn set_max_guardian_pause_duration(
+        env: Env,
+        caller: Address,
+        duration: u64,
+    ) -> Result<(), PauseError> {
+        set_max_guardian_pause_duration(&env, caller, duration)
+    }
+
+    /// Get how long guardian pauses last before lapsing, in seconds
+    pub fn get_max_guardian_pause_duration(env: Env) -> u64 {
+        get_max_guardian_pause_duration(&env)
+    }
+
+    /// Get the guardian's pauses, if still in force
+    pub fn get_guardian_pause(env: Env) -> Option<GuardianPause> {
+        get_guardian_pause(&env)
+    }
+
+    /// Shut the protocol down for wind-down (admin only, irreversible)
+    ///
+    /// Snapshots the price of every priced asset, which the protocol then
+    /// uses for all valuations. New borrows and supply stop; repayments and
+    /// withdrawals stay open.
+    ///
+    /// # Arguments
+    /// * `caller` - The admin address
+    ///
+    /// # Returns
+    /// The shutdown record
+    pub fn emergency_shutdown(env: Env, caller: Address) -> Result<ShutdownInfo, ShutdownError> {
+        emergency_shutdown(&env, caller)
+    }
+
+    /// Get the emergency shutdown record, if the protocol has been shut down
+    pub fn get_shutdown_info(env: Env) -> Option<ShutdownInfo> {
+        get_shutdown_info(&env)
+    }
+
+    /// Check whether the protocol has been shut down
+    pub fn is_shutdown(env: Env) -> bool {
+        is_shutdown(&env)
+    }
+
+    /// Get the settlement price snapshotted for an asset at shutdown
+    pub fn get_settlement_price(env: Env, asset: Address) -> Option<PriceData> {
+        get_settlement_price(&env, &asset)
+    }
+
+    /// Get a page of the borrowers filed under a health bucket
+    ///
+    /// Buckets: 0 below a 1.0 health factor, 1 up to 1.1, 2 up to 1.3 and
+    /// 3 above, as of each borrower's last position touch.
+    ///
+    /// # Arguments
+    /// * `bucket` - The health bucket
+    /// * `cursor` - Position of the first borrower to return (0 for the first)
+    ///
+    /// # Returns
+    /// Up to 50 borrowers, in no particular order
+    pub fn get_health_bucket(env: Env, bucket: u32, cursor: u32) -> Vec<Address> {
+        get_health_bucket(&env, bucket, cursor)
+    }
+
+    /// Get the number of borrowers filed under a health bucket
+    pub fn get_health_bucket_size(env: Env, bucket: u32) -> u32 {
+        get_health_bucket_size(&env, bucket)
+    }
+
+    /// Get the health bucket a borrower is filed under, if any
+    pub fn get_user_health_bucket(env: Env, user: Address) -> Option<u32> {
+        get_user_health_bucket(&env, &user)
+    }
+
+    /// Re-file a borrower under their current health bucket (permissionless)
+    ///
+    /// # Arguments
+    /// * `user` - The borrower whose health may have drifted since their last touch
+    ///
+    /// # Returns
+    /// The borrower's bucket, or `None` without debt
+    pub fn refresh_health_bucket(env: Env, user: Address) -> Option<u32> {
+        refresh_health_bucket(&env, user)
+    }
+
+    /// Get the sequence number of the last event this contract published
+    ///
+    /// Every event carries its sequence number as the last topic; numbers
+    /// start at 1 and have no gaps, so indexers can spot missed or replayed events.
+    pub fn get_event_sequence(env: Env) -> u64 {
+        get_event_sequence(&env)
+    }
+
+    /// Opt in to or out of on-chain position history
+    ///
+    /// # Arguments
+    /// * `user` - The user recording history (must authorize)
+    /// * `enabled` - Whether to record future position-changing actions
+    pub fn set_position_history_enabled(env: Env, user: Address, enabled: bool) {
+        set_position_history_enabled(&env, user, enabled)
+    }
+
+    /// Check whether a user records position history
+    pub fn is_position_history_enabled(env: Env, user: Address) -> bool {
+        is_position_history_enabled(&env, &user)
+    }
+
+    /// Get a page of a user's recorded position history
+    ///
+    /// Only the last `POSITION_HISTORY_SIZE` actions are retained.
+    ///
+    /// # Arguments
+    /// * `user` - The user whose history to read
+    /// * `cursor` - Index of the first entry to return (0 for the oldest retained)
+    ///
+    /// # Returns
+    /// Up to 10 entries, oldest first
+    pub fn get_position_history(env: Env, user: Address, cursor: u64) -> Vec<PositionHistoryEntry> {
+        get_position_history(&env, &user, cursor)
+    }
+
+    /// Get the size and composition of an asset's market
+    ///
+    /// # Arguments
+    /// * `asset` - The market's asset
+    ///
+    /// # Returns
+    /// Supplied, borrowed, reserve and available liquidity, plus the asset's
+    /// share of all supplied liquidity in basis points
+    pub fn get_market_size(env: Env, asset: Address) -> MarketSize {
+        get_market_size(&env, &asset)
+    }
+
+    /// Get an asset's available cash and how much of it is withdrawable now
+    ///
+    /// # Arguments
+    /// * `asset` - The market's asset
+    ///
+    /// # Returns
+    /// Available cash, the part locked by the liquidity buffer, the part
+    /// reserved for queued withdrawals, and the withdrawable remainder
+    pub fn get_liquidity(env: Env, asset: Address) -> Liquidity {
+        get_liquidity(&env, &asset)
+    }
+
+    /// Get a user's full account in one call
+    ///
+    /// # Arguments
+    /// * `user` - The account to summarize
+    ///
+    /// # Returns
+    /// Supplied balances per asset, pool collateral and debt, totals across
+    /// all loan types, health factor, pending rewards and the collateral tier
+    pub fn get_user_summary(env: Env, user: Address) -> UserSummary {
+        get_user_summary(&env, &user)
+    }
+
+    /// Get the protocol-wide configuration in one call
+    ///
+    /// # Returns
+    /// Roles, global debt ceiling and minimum borrow, the borrow rate model,
+    /// bounds and current rate, and the pause and shutdown state
+    pub fn get_config(env: Env) -> ProtocolConfig {
+        get_config(&env)
+    }
+
+    /// Apply several configuration changes atomically (admin only)
+    ///
+    /// # Arguments
+    /// * `caller` - The admin address
+    /// * `changes` - Updates applied in order, each validated like its own setter
+    ///
+    /// # Returns
+    /// `InvalidConfig` if any change is invalid, in which case none are applied
+    pub fn set_config(
+        env: Env,
+        caller: Address,
+        changes: Vec<ConfigChange>,
+    ) -> Result<(), ConfigError> {
+        set_config(&env, caller, changes)
+    }
+
+    /// Get the contract's version metadata
+    ///
+    /// # Returns
+    /// Semantic version, storage schema version and build identifier recorded
+    /// at initialization, with the time they were recorded
+    pub fn get_version(env: Env) -> ContractVersion {
+        get_version(&env)
+    }
+
+    /// Set the global minimum borrow amount (admin only)
+    ///
+    /// Applies to assets without their own minimum and emits a `param_updated` event.
+    ///
+    /// # Arguments
+    /// * `caller` - The admin address
+    /// * `amount` - The new minimum (1000 by default)
+    pub fn set_default_min_borrow(
+        env: Env,
+        caller: Address,
+        amount: i128,
+    ) -> Result<(), BorrowError> {
+        set_default_min_borrow(&env, caller, amount)
+    }
+
+    /// Get the global minimum borrow amount
+    pub fn get_default_min_borrow(env: Env) -> i128 {
+        get_default_min_borrow(&env)
+    }
+
+    /// Set the collateral ratio required of assets without risk parameters (admin only)
+    ///
+    /// # Arguments
+    /// * `caller` - The admin address
+    /// * `ratio_bps` - The new ratio, from 11000 to 50000 (15000 by default)
+    pub fn set_min_collateral_ratio(
+        env: Env,
+        caller: Address,
+        ratio_bps: i128,
+    ) -> Result<(), BorrowError> {
+        set_min_collateral_ratio(&env, caller, ratio_bps)
+    }
+
+    /// Get the collateral ratio required of assets without risk parameters
+    pub fn get_min_collateral_ratio(env: Env) -> i128 {
+        get_min_collateral_ratio(&env)
+    }
+
+    /// Set the pool interest rate used while no rate model is set (admin only)
+    ///
+    /// # Arguments
+    /// * `caller` - The admin address
+    /// * `rate_bps` - The new annual rate, from 0 to 10000 (500 by default)
+    pub fn set_base_interest_rate(
+        env: Env,
+        caller: Address,
+        rate_bps: i128,
+    ) -> Result<(), BorrowError> {
+        set_base_interest_rate(&env, caller, rate_bps)
+    }
+
+    /// Get the pool interest rate used while no rate model is set
+    pub fn get_base_interest_rate(env: Env) -> i128 {
+        get_base_interest_rate(&env)
+    }
+
+    /// Get an asset's reserve state
+    ///
+    /// # Arguments
+    /// * `asset` - The market's asset
+    ///
+    /// # Returns
+    /// Supplied and borrowed totals, the asset's borrow index accrued to now
+    /// and the time of the last update
+    pub fn get_reserve_data(env: Env, asset: Address) -> ReserveData {
+        get_reserve_data(&env, &asset)
+    }
+
+    /// List a new asset with its oracle, rates, caps and seed liquidity (admin only)
+    ///
+    /// # Arguments
+    /// * `caller` - The admin address, which supplies the seed liquidity
+    /// * `asset` - The asset to list
+    /// * `config` - Feed, initial price, supply rates, collateral parameters,
+    ///   caps and seed amount (at least `MIN_SEED_LIQUIDITY`)
+    ///
+    /// # Returns
+    /// The active listing; nothing is stored if any check fails
+    pub fn list_asset(
+        env: Env,
+        caller: Address,
+        asset: Address,
+        config: ListingConfig,
+    ) -> Result<AssetListing, ListingError> {
+        list_asset(&env, caller, asset, config)
+    }
+
+    /// Get an asset's listing, if it was listed through `list_asset`
+    pub fn get_asset_listing(env: Env, asset: Address) -> Option<AssetListing> {
+        get_asset_listing(&env, &asset)
+    }
+
+    /// Get the assets listed through `list_asset`, in listing order
+    pub fn get_listed_assets(env: Env) -> Vec<Address> {
+        get_listed_assets(&env)
+    }
+
+    /// Start winding down a listed asset (admin only)
+    ///
+    /// # Arguments
+    /// * `caller` - The admin address
+    /// * `asset` - The listed asset
+    /// * `wind_down_period` - Seconds until residual positions may be settled
+    /// * `max_rate_premium_bps` - Borrow rate premium reached at the deadline (at most 5000)
+    ///
+    /// # Returns
+    /// The wind-down schedule; new borrows, collateral deposits and supply in
+    /// the asset are rejected from now on
+    pub fn delist_asset(
+        env: Env,
+        caller: Address,
+        asset: Address,
+        wind_down_period: u64,
+        max_rate_premium_bps: i128,
+    ) -> Result<DelistSchedule, ListingError> {
+        delist_asset(&env, caller, asset, wind_down_period, max_rate_premium_bps)
+    }
+
+    /// Move a wound-down asset to forced settlement once its deadline passed (permissionless)
+    pub fn open_settlement(env: Env, asset: Address) -> Result<(), ListingError> {
+        open_settlement(&env, asset)
+    }
+
+    /// Force-settle a position in an asset under settlement (permissionless)
+    ///
+    /// # Arguments
+    /// * `keeper` - The caller settling the position
+    /// * `user` - The borrower whose debt or collateral is in the delisted asset
+    ///
+    /// # Returns
+    /// The debt repaid and the collateral seized at oracle prices
+    pub fn settle_delisted_position(
+        env: Env,
+        keeper: Address,
+        user: Address,
+    ) -> Result<(i128, i128), ListingError> {
+        settle_delisted_position(&env, keeper, user)
+    }
+
+    /// Get the wind-down schedule of a delisted asset, if any
+    pub fn get_delist_schedule(env: Env, asset: Address) -> Option<DelistSchedule> {
+        get_delist_schedule(&env, &asset)
+    }
+
+    /// Freeze uses of an asset without pausing its market (risk manager or admin)
+    ///
+    /// # Arguments
+    /// * `caller` - The risk manager or admin address
+    /// * `asset` - The asset to freeze
+    /// * `flags` - Bitmask of `FREEZE_*` flags: `FREEZE_COLLATERAL` rejects new
+    ///   collateral deposits, `FREEZE_BORROW` new borrows; cleared bits lift
+    ///   the freeze. Existing positions stay valid
+    pub fn set_asset_freeze(
+        env: Env,
+        caller: Address,
+        asset: Address,
+        flags: u32,
+    ) -> Result<(), AssetFreezeError> {
+        set_asset_freeze(&env, caller, asset, flags)
+    }
+
+    /// Get the bitmask of frozen uses of an asset
+    pub fn get_asset_freeze(env: Env, asset: Address) -> u32 {
+        get_asset_freeze(&env, &asset)
+    }
+
+    /// Designate how an asset may be used (admin only)
+    ///
+    /// # Arguments
+    /// * `caller` - The admin address
+    /// * `asset` - The asset to designate
+    /// * `mode` - `Standard`, or `CollateralOnly` to reject every borrow of it
+    pub fn set_asset_mode(
+        env: Env,
+        caller: Address,
+        asset: Address,
+        mode: AssetMode,
+    ) -> Result<(), AssetModeError> {
+        set_asset_mode(&env, caller, asset, mode)
+    }
+
+    /// Get how an asset may be used
+    pub fn get_asset_mode(env: Env, asset: Address) -> AssetMode {
+        get_asset_mode(&env, &asset)
+    }
+
+    /// Cap any single supplier's share of an asset's supply (risk manager or admin)
+    ///
+    /// # Arguments
+    /// * `caller` - The risk manager or admin address
+    /// * `asset` - The market's asset
+    /// * `limit` - Largest share one address may hold after a deposit, in
+    ///   basis points, and the balance always allowed regardless of share
+    pub fn set_concentration_limit(
+        env: Env,
+        caller: Address,
+        asset: Address,
+        limit: ConcentrationLimit,
+    ) -> Result<(), P2PError> {
+        set_concentration_limit(&env, caller, asset, limit)
+    }
+
+    /// Remove an asset's supplier concentration limit (risk manager or admin)
+    pub fn clear_concentration_limit(
+        env: Env,
+        caller: Address,
+        asset: Address,
+    ) -> Result<(), P2PError> {
+        clear_concentration_limit(&env, caller, asset)
+    }
+
+    /// Get an asset's supplier concentration limit, if set
+    pub fn get_concentration_limit(env: Env, asset: Address) -> Option<ConcentrationLimit> {
+        get_concentration_limit(&env, &asset)
+    }
+
+    /// Queue a supply withdrawal that available liquidity cannot serve
+    ///
+    /// # Arguments
+    /// * `supplier` - The supplier withdrawing
+    /// * `asset` - The market's asset
+    /// * `amount` - The amount to withdraw, above what is withdrawable now
+    ///
+    /// # Returns
+    /// The supplier's position in the queue; it is filled first in, first out
+    /// as repayments and new supply arrive
+    pub fn queue_withdrawal(
+        env: Env,
+        supplier: Address,
+        asset: Address,
+        amount: i128,
+    ) -> Result<QueuePosition, WithdrawalQueueError> {
+        queue_withdrawal(&env, supplier, asset, amount)
+    }
+
+    /// Leave an asset's withdrawal queue
+    ///
+    /// # Returns
+    /// The unfilled amount that was queued
+    pub fn cancel_withdrawal(
+        env: Env,
+        supplier: Address,
+        asset: Address,
+    ) -> Result<i128, WithdrawalQueueError> {
+        cancel_withdrawal(&env, supplier, asset)
+    }
+
+    /// Get a supplier's position in an asset's withdrawal queue, if queued
+    pub fn get_queue_position(
+        env: Env,
+        supplier: Address,
+        asset: Address,
+    ) -> Option<QueuePosition> {
+        get_queue_position(&env, &supplier, &asset)
+    }
+
+    /// Get an asset's queued withdrawals, in arrival order
+    pub fn get_withdrawal_queue(env: Env, asset: Address) -> Vec<QueuedWithdrawal> {
+        get_withdrawal_queue(&env, &asset)
+    }
+
+    /// Set an asset's utilization-dependent withdrawal fee (admin only)
+    ///
+    /// # Arguments
+    /// * `caller` - The admin address
+    /// * `asset` - The market's asset
+    /// * `config` - Utilization above which withdrawals are charged and the
+    ///   fee at full utilization (at most 100 basis points)
+    pub fn set_exit_fee_config(
+        env: Env,
+        caller: Address,
+        asset: Address,
+        config: ExitFeeConfig,
+    ) -> Result<(), ExitFeeError> {
+        set_exit_fee_config(&env, caller, asset, config)
+    }
+
+    /// Get an asset's withdrawal fee schedule, if set
+    pub fn get_exit_fee_config(env: Env, asset: Address) -> Option<ExitFeeConfig> {
+        get_exit_fee_config(&env, &asset)
+    }
+
+    /// Get the withdrawal fee an asset charges at current utilization, in basis points
+    pub fn get_exit_fee_bps(env: Env, asset: Address) -> i128 {
+        get_exit_fee_bps(&env, &asset)
+    }
+
+    /// Require a share of an asset's supply to stay un-borrowed (risk manager or admin)
+    ///
+    /// # Arguments
+    /// * `caller` - The risk manager or admin address
+    /// * `asset` - The market's asset
+    /// * `buffer_bps` - Share of supply borrows may not use, up to 5000; zero
+    ///   removes the buffer
+    pub fn set_liquidity_buffer(
+        env: Env,
+        caller: Address,
+        asset: Address,
+        buffer_bps: i128,
+    ) -> Result<(), CeilingError> {
+        set_liquidity_buffer(&env, caller, asset, buffer_bps)
+    }
+
+    /// Get the share of an asset's supply that must stay un-borrowed, in basis points
+    pub fn get_liquidity_buffer(env: Env, asset: Address) -> i128 {
+        get_liquidity_buffer(&env, &asset)
+    }
+
+    /// Get the most principal that may be outstanding in an asset
+    ///
+    /// # Returns
+    /// The lower of the asset's debt ceiling and its supply outside the
+    /// liquidity buffer; `i128::MAX` when neither applies
+    pub fn get_effective_borrow_cap(env: Env, asset: Address) -> i128 {
+        get_effective_borrow_cap(&env, &asset)
+    }
+
+    /// Set the DEX adapter that sells collateral in soft liquidations (admin only)
+    ///
+    /// # Arguments
+    /// * `caller` - The admin address
+    /// * `config` - Adapter address and the slippage tolerated against the
+    ///   oracle, at most 500 basis points
+    pub fn set_dex_adapter(
+        env: Env,
+        caller: Address,
+        config: DexAdapterConfig,
+    ) -> Result<(), SoftLiquidationError> {
+        set_dex_adapter(&env, caller, config)
+    }
+
+    /// Get the DEX adapter used for soft liquidations, if set
+    pub fn get_dex_adapter(env: Env) -> Option<DexAdapterConfig> {
+        get_dex_adapter(&env)
+    }
+
+    /// Opt into gradual conversion of collateral to the debt asset across a price band
+    ///
+    /// # Arguments
+    /// * `user` - The borrower
+    /// * `upper_price` - Collateral price, in debt asset units scaled by 1e7,
+    ///   below which conversion starts
+    /// * `lower_price` - Price at which all collateral is converted
+    /// * `expiration_ledger` - Last ledger keepers may convert collateral in
+    ///
+    /// # Returns
+    /// The stored band
+    pub fn enable_soft_liquidation(
+        env: Env,
+        user: Address,
+        upper_price: i128,
+        lower_price: i128,
+        expiration_ledger: u32,
+    ) -> Result<SoftLiquidationBand, SoftLiquidationError> {
+        enable_soft_liquidation(&env, user, upper_price, lower_price, expiration_ledger)
+    }
+
+    /// Opt out of soft liquidation
+    pub fn disable_soft_liquidation(env: Env, user: Address) {
+        disable_soft_liquidation(&env, user)
+    }
+
+    /// Get a user's soft liquidation band, if enabled
+    pub fn get_soft_liquidation_band(env: Env, user: Address) -> Option<SoftLiquidationBand> {
+        get_soft_liquidation_band(&env, &user)
+    }
+
+    /// Sell the share of a position's collateral its band calls for (permissionless)
+    ///
+    /// # Arguments
+    /// * `keeper` - The caller
+    /// * `user` - The borrower in soft liquidation
+    ///
+    /// # Returns
+    /// The collateral sold and the debt repaid
+    pub fn soft_liquidate(
+        env: Env,
+        keeper: Address,
+        user: Address,
+    ) -> Result<(i128, i128), SoftLiquidationError> {
+        soft_liquidate(&env, keeper, user)
+    }
+
+    /// Set the fixed keeper tip paid per auto-repay execution (admin only)
+    ///
+    /// # Arguments
+    /// * `caller` - The admin address
+    /// * `tip` - Tip in units of the repaid debt asset
+    pub fn set_auto_repay_tip(env: Env, caller: Address, tip: i128) -> Result<(), AutoRepayError> {
+        set_auto_repay_tip(&env, caller, tip)
+    }
+
+    /// Get the fixed keeper tip paid per auto-repay execution
+    pub fn get_auto_repay_tip(env: Env) -> i128 {
+        get_auto_repay_tip(&env)
+    }
+
+    /// Opt in to repaying debt from the supplied balance of the debt asset
+    ///
+    /// # Arguments
+    /// * `user` - The borrower
+    /// * `config` - Health factor trigger and the most debt repaid per execution
+    pub fn enable_auto_repay(
+        env: Env,
+        user: Address,
+        config: AutoRepayConfig,
+    ) -> Result<(), AutoRepayError> {
+        enable_auto_repay(&env, user, config)
+    }
+
+    /// Opt out of auto-repayment
+    pub fn disable_auto_repay(env: Env, user: Address) {
+        disable_auto_repay(&env, user)
+    }
+
+    /// Get a user's auto-repay instruction, if enabled
+    pub fn get_auto_repay_config(env: Env, user: Address) -> Option<AutoRepayConfig> {
+        get_auto_repay_config(&env, &user)
+    }
+
+    /// Repay a user's debt from their supplied balance once health falls below
+    /// their trigger (permissionless)
+    ///
+    /// # Arguments
+    /// * `keeper` - The caller, credited the fixed tip
+    /// * `user` - The borrower
+    ///
+    /// # Returns
+    /// The debt repaid
+    pub fn execute_auto_repay(
+        env: Env,
+        keeper: Address,
+        user: Address,
+    ) -> Result<i128, AutoRepayError> {
+        execute_auto_repay(&env, keeper, user)
+    }
+
+    /// Register a yield-bearing collateral token (risk manager or admin)
+    ///
+    /// # Arguments
+    /// * `caller` - The risk manager or admin address
+    /// * `token` - The yield-bearing token
+    /// * `config` - Its rate oracle and underlying asset
+    pub fn register_yield_token(
+        env: Env,
+        caller: Address,
+        token: Address,
+        config: YieldTokenConfig,
+    ) -> Result<(), YieldCollateralError> {
+        register_yield_token(&env, caller, token, config)
+    }
+
+    /// Stop valuing a token at its exchange rate (risk manager or admin)
+    pub fn deregister_yield_token(
+        env: Env,
+        caller: Address,
+        token: Address,
+    ) -> Result<(), YieldCollateralError> {
+        deregister_yield_token(&env, caller, token)
+    }
+
+    /// Get the rate oracle and underlying of a registered yield-bearing token
+    pub fn get_yield_token_config(env: Env, token: Address) -> Option<YieldTokenConfig> {
+        get_yield_token_config(&env, &token)
+    }
+
+    /// Get the underlying a yield-bearing token redeems for, scaled by 10^9
+    pub fn get_exchange_rate(env: Env, token: Address) -> Option<i128> {
+        get_exchange_rate(&env, &token)
+    }
+
+    /// Allow or forbid lending out collateral of an asset (risk manager or admin)
+    ///
+    /// # Arguments
+    /// * `caller` - The risk manager or admin address
+    /// * `asset` - The collateral asset
+    /// * `enabled` - False to hold the asset's collateral idle in full, limiting
+    ///   its borrows to supplied liquidity
+    pub fn set_rehypothecation(
+        env: Env,
+        caller: Address,
+        asset: Address,
+        enabled: bool,
+    ) -> Result<(), RehypothecationError> {
+        set_rehypothecation(&env, caller, asset, enabled)
+    }
+
+    /// Whether collateral of an asset may be lent out to borrowers
+    pub fn is_rehypothecation_enabled(env: Env, asset: Address) -> bool {
+        is_rehypothecation_enabled(&env, &asset)
+    }
+
+    /// Register the external yield strategy of an asset (treasurer or admin)
+    ///
+    /// # Arguments
+    /// * `caller` - The treasurer or admin address
+    /// * `asset` - The market's asset
+    /// * `config` - Strategy contract, the most of the market's un-borrowed
+    ///   liquidity it may hold and the suppliers' share of harvested gains, in
+    ///   basis points
+    pub fn set_strategy(
+        env: Env,
+        caller: Address,
+        asset: Address,
+        config: StrategyConfig,
+    ) -> Result<(), StrategyError> {
+        set_strategy(&env, caller, asset, config)
+    }
+
+    /// Get the yield strategy of an asset, if registered
+    pub fn get_strategy_config(env: Env, asset: Address) -> Option<StrategyConfig> {
+        get_strategy_config(&env, &asset)
+    }
+
+    /// Get the principal of an asset deployed to its strategy
+    pub fn get_strategy_deployed(env: Env, asset: Address) -> i128 {
+        get_strategy_deployed(&env, &asset)
+    }
+
+    /// Deploy idle cash of an asset to its strategy (treasurer or admin)
+    ///
+    /// # Arguments
+    /// * `caller` - The treasurer or admin address
+    /// * `asset` - The market's asset
+    /// * `amount` - Cash to deploy
+    ///
+    /// # Returns
+    /// The principal deployed after the deposit
+    pub fn deploy_to_strategy(
+        env: Env,
+        caller: Address,
+        asset: Address,
+        amount: i128,
+    ) -> Result<i128, StrategyError> {
+        deploy_to_strategy(&env, caller, asset, amount)
+    }
+
+    /// Recall deployed principal of an asset from its strategy (treasurer or admin)
+    ///
+    /// # Arguments
+    /// * `caller` - The treasurer or admin address
+    /// * `asset` - The market's asset
+    /// * `amount` - Principal to recall
+    ///
+    /// # Returns
+    /// The principal still deployed
+    pub fn recall_from_strategy(
+        env: Env,
+        caller: Address,
+        asset: Address,
+        amount: i128,
+    ) -> Result<i128, StrategyError> {
+        recall_from_strategy(&env, caller, asset, amount)
+    }
+
+    /// Recall everything deployed to an asset's strategy and block further
+    /// deployments (guardian only)
+    ///
+    /// # Arguments
+    /// * `asset` - The market's asset
+    ///
+    /// # Returns
+    /// The amount the strategy returned; any shortfall is recorded as the
+    /// asset's strategy loss
+    pub fn emergency_divest(env: Env, asset: Address) -> Result<i128, StrategyError> {
+        emergency_divest(&env, asset)
+    }
+
+    /// Allow deployments to an asset's strategy again after a divestment (admin only)
+    pub fn resume_strategy(env: Env, caller: Address, asset: Address) -> Result<(), StrategyError> {
+        resume_strategy(&env, caller, asset)
+    }
+
+    /// Whether deployments to an asset's strategy are blocked by a divestment
+    pub fn is_strategy_blocked(env: Env, asset: Address) -> bool {
+        is_strategy_blocked(&env, &asset)
+    }
+
+    /// Get the principal of an asset lost in its strategy and not yet written down
+    pub fn get_strategy_loss(env: Env, asset: Address) -> i128 {
+        get_strategy_loss(&env, &asset)
+    }
+
+    /// Propose adding a strategy to the allowlist, or changing its deposit cap
+    /// (admin only)
+    ///
+    /// # Arguments
+    /// * `caller` - The admin address
+    /// * `strategy` - The strategy contract
+    /// * `deposit_cap` - Most principal the strategy may hold across all assets
+    ///
+    /// # Returns
+    /// The earliest time the entry can be approved (two days out)
+    pub fn propose_strategy(
+        env: Env,
+        caller: Address,
+        strategy: Address,
+        deposit_cap: i128,
+    ) -> Result<u64, StrategyError> {
+        propose_strategy(&env, caller, strategy, deposit_cap)
+    }
+
+    /// Add a proposed strategy to the allowlist once its timelock has passed
+    /// (admin only)
+    pub fn approve_strategy(
+        env: Env,
+        caller: Address,
+        strategy: Address,
+    ) -> Result<(), StrategyError> {
+        approve_strategy(&env, caller, strategy)
+    }
+
+    /// Remove a strategy from the allowlist, or drop its pending proposal
+    /// (admin only)
+    pub fn remove_strategy(
+        env: Env,
+        caller: Address,
+        strategy: Address,
+    ) -> Result<(), StrategyError> {
+        remove_strategy(&env, caller, strategy)
+    }
+
+    /// Get the allowlist entry of a strategy, if approved
+    pub fn get_allowed_strategy(env: Env, strategy: Address) -> Option<AllowedStrategy> {
+        get_allowed_strategy(&env, &strategy)
+    }
+
+    /// Get the proposed allowlist entry of a strategy awaiting approval, if any
+    pub fn get_pending_strategy(env: Env, strategy: Address) -> Option<AllowedStrategy> {
+        get_pending_strategy(&env, &strategy)
+    }
+
+    /// Get the principal deployed to a strategy across all assets
+    pub fn get_strategy_deposits(env: Env, strategy: Address) -> i128 {
+        get_strategy_deposits(&env, &strategy)
+    }
+
+    /// Realize an asset's strategy gains and split them between suppliers and
+    /// the reserve (permissionless)
+    ///
+    /// # Arguments
+    /// * `keeper` - The caller
+    /// * `asset` - The market's asset
+    ///
+    /// # Returns
+    /// The realized profit
+    pub fn harvest_strategy(
+        env: Env,
+        keeper: Address,
+        asset: Address,
+    ) -> Result<i128, StrategyError> {
+        harvest_strategy(&env, keeper, asset)
+    }
+
+    /// Write an asset's strategy loss down from its insurance fund, then its
+    /// strategy reserve, then supplier balances (permissionless)
+    ///
+    /// # Arguments
+    /// * `keeper` - The caller
+    /// * `asset` - The market's asset
+    ///
+    /// # Returns
+    /// How much each tranche covered
+    pub fn write_down_strategy_loss(
+        env: Env,
+        keeper: Address,
+        asset: Address,
+    ) -> Result<LossWriteDown, StrategyError> {
+        write_down_strategy_loss(&env, keeper, asset)
+    }
+
+    /// Get the harvested gains of an asset held back to absorb strategy losses
+    pub fn get_strategy_reserve(env: Env, asset: Address) -> i128 {
+        get_strategy_reserve(&env, &asset)
+    }
+
+    /// Require borrows valued at or above a threshold to be requested and
+    /// executed a number of ledgers later (admin only)
+    ///
+    /// # Arguments
+    /// * `caller` - The admin
+    /// * `config` - Threshold value, scaled by `PRICE_SCALE`, and delay in ledgers
+    pub fn set_large_borrow_config(
+        env: Env,
+        caller: Address,
+        config: LargeBorrowConfig,
+    ) -> Result<(), BorrowError> {
+        set_large_borrow_config(&env, caller, config)
+    }
+
+    /// Stop requiring requests for large borrows (admin only)
+    pub fn clear_large_borrow_config(env: Env, caller: Address) -> Result<(), BorrowError> {
+        clear_large_borrow_config(&env, caller)
+    }
+
+    /// Get the large-borrow threshold and delay, if set
+    pub fn get_large_borrow_config(env: Env) -> Option<LargeBorrowConfig> {
+        get_large_borrow_config(&env)
+    }
+
+    /// Record a large borrow for execution after the configured delay
+    ///
+    /// # Arguments
+    /// * `user` - The borrower (must authorize)
+    /// * `asset` - The asset to borrow
+    /// * `amount` - The amount to borrow
+    /// * `collateral_asset` - The collateral asset
+    /// * `collateral_amount` - The collateral amount
+    ///
+    /// # Returns
+    /// The first ledger the borrow can be executed in
+    pub fn request_borrow(
+        env: Env,
+        user: Address,
+        asset: Address,
+        amount: i128,
+        collateral_asset: Address,
+        collateral_amount: i128,
+    ) -> Result<u32, BorrowError> {
+        request_borrow(
+            &env,
+            user,
+            asset,
+            amount,
+            collateral_asset,
+            collateral_amount,
+        )
+    }
+
+    /// Execute a requested borrow once its delay has passed
+    ///
+    /// # Arguments
+    /// * `user` - The borrower (must authorize)
+    pub fn execute_borrow(env: Env, user: Address) -> Result<(), BorrowError> {
+        execute_borrow(&env, user)
+    }
+
+    /// Cancel a user's pending borrow (the user or the guardian)
+    ///
+    /// # Arguments
+    /// * `caller` - The user or the guardian
+    /// * `user` - The borrower
+    pub fn cancel_borrow_request(
+        env: Env,
+        caller: Address,
+        user: Address,
+    ) -> Result<(), BorrowError> {
+        cancel_borrow_request(&env, caller, user)
+    }
+
+    /// Get a user's pending borrow, if any
+    pub fn get_pending_borrow(env: Env, user: Address) -> Option<PendingBorrow> {
+        get_pending_borrow(&env, &user)
+    }
+
+    /// Cap the value a single direct borrow may take (admin only)
+    ///
+    /// # Arguments
+    /// * `caller` - The admin
+    /// * `max_value` - Most value per call, scaled by `PRICE_SCALE`; zero
+    ///   removes the cap
+    pub fn set_max_borrow_per_call(
+        env: Env,
+        caller: Address,
+        max_value: i128,
+    ) -> Result<(), BorrowError> {
+        set_max_borrow_per_call(&env, caller, max_value)
+    }
+
+    /// Get the most value a single direct borrow may take, if capped
+    pub fn get_max_borrow_per_call(env: Env) -> Option<i128> {
+        get_max_borrow_per_call(&env)
+    }
+
+    /// Lock collateral of an asset for a number of ledgers after a borrow
+    /// against it (risk manager or admin)
+    ///
+    /// # Arguments
+    /// * `caller` - The risk manager or admin
+    /// * `asset` - The collateral asset
+    /// * `ledgers` - Ledgers that must close before the collateral can be
+    ///   withdrawn; zero removes the cooldown
+    pub fn set_withdraw_cooldown(
+        env: Env,
+        caller: Address,
+        asset: Address,
+        ledgers: u32,
+    ) -> Result<(), WithdrawCooldownError> {
+        set_withdraw_cooldown(&env, caller, asset, ledgers)
+    }
+
+    /// Get the ledgers collateral of an asset stays locked after a borrow
+    pub fn get_withdraw_cooldown(env: Env, asset: Address) -> u32 {
+        get_withdraw_cooldown(&env, &asset)
+    }
+
+    /// Freeze an account's new borrows and supplies (compliance officer only)
+    ///
+    /// # Arguments
+    /// * `caller` - The compliance officer
+    /// * `account` - The account to freeze
+    /// * `reason` - Reason code published with the event
+    pub fn freeze_account(
+        env: Env,
+        caller: Address,
+        account: Address,
+        reason: Symbol,
+    ) -> Result<(), AccountFreezeError> {
+        freeze_account(&env, caller, account, reason)
+    }
+
+    /// Lift an account freeze (compliance officer only)
+    ///
+    /// # Arguments
+    /// * `caller` - The compliance officer
+    /// * `account` - The frozen account
+    /// * `reason` - Reason code published with the event
+    pub fn unfreeze_account(
+        env: Env,
+        caller: Address,
+        account: Address,
+        reason: Symbol,
+    ) -> Result<(), AccountFreezeError> {
+        unfreeze_account(&env, caller, account, reason)
+    }
+
+    /// Get an account's freeze, if it is frozen
+    pub fn get_account_freeze(env: Env, account: Address) -> Option<AccountFreeze> {
+        get_account_freeze(&env, &account)
+    }
+
+    /// Revoke every standing approval: automation intents, auto-repay,
+    /// soft liquidation, the repayment stream and session delegates
+    ///
+    /// # Arguments
+    /// * `owner` - The owner (must authorize)
+    pub fn revoke_all(env: Env, owner: Address) {
+        revoke_all(&env, owner)
+    }
+
+    /// Authorize a delegate key to act on the owner's position within
+    /// per-action daily caps
+    ///
+    /// # Arguments
+    /// * `owner` - The position owner (must authorize)
+    /// * `delegate` - The delegate key
+    /// * `limits` - Actions the delegate may perform and their daily caps
+    /// * `expiration_ledger` - Last ledger the delegate can act in
+    pub fn authorize_delegate(
+        env: Env,
+        owner: Address,
+        delegate: Address,
+        limits: Vec<DelegateLimit>,
+        expiration_ledger: u32,
+    ) -> Result<(), DelegateError> {
+        authorize_delegate(&env, owner, delegate, limits, expiration_ledger)
+    }
+
+    /// Revoke a session delegate
+    pub fn revoke_delegate(env: Env, owner: Address, delegate: Address) {
+        revoke_delegate(&env, owner, delegate)
+    }
+
+    /// Get a delegate's scope, if authorized and not expired
+    pub fn get_session_delegate(
+        env: Env,
+        owner: Address,
+        delegate: Address,
+    ) -> Option<SessionDelegate> {
+        get_session_delegate(&env, &owner, &delegate)
+    }
+
+    /// Get the delegates an owner has authorized
+    pub fn get_owner_delegates(env: Env, owner: Address) -> Vec<Address> {
+        get_owner_delegates(&env, &owner)
+    }
+
+    /// Get how much of an action's daily cap a delegate has left today
+    pub fn get_delegate_remaining(
+        env: Env,
+        owner: Address,
+        delegate: Address,
+        action: DelegateAction,
+    ) -> i128 {
+        get_delegate_remaining(&env, &owner, &delegate, action)
+    }
+
+    /// Repay the owner's debt as their delegate
+    ///
+    /// # Arguments
+    /// * `delegate` - The delegate key (must authorize)
+    /// * `owner` - The borrower
+    /// * `amount` - The amount to repay
+    ///
+    /// # Returns
+    /// The remaining debt, interest paid and principal paid
+    pub fn delegate_repay(
+        env: Env,
+        delegate: Address,
+        owner: Address,
+        amount: i128,
+    ) -> Result<(i128, i128, i128), DelegateError> {
+        delegate_repay(&env, delegate, owner, amount)
+    }
+
+    /// Withdraw the owner's collateral as their delegate
+    ///
+    /// # Arguments
+    /// * `delegate` - The delegate key (must authorize)
+    /// * `owner` - The borrower
+    /// * `amount` - The collateral to withdraw
+    ///
+    /// # Returns
+    /// The remaining collateral balance
+    pub fn delegate_withdraw_collateral(
+        env: Env,
+        delegate: Address,
+        owner: Address,
+        amount: i128,
+    ) -> Result<i128, DelegateError> {
+        delegate_withdraw_collateral(&env, delegate, owner, amount)
+    }
+
+    /// Register a hook contract called after the user's borrows and
+    /// repayments and when their health falls below a threshold
+    ///
+    /// # Arguments
+    /// * `user` - The position owner (must authorize)
+    /// * `hook` - The hook contract and health threshold
+    pub fn register_position_hook(
+        env: Env,
+        user: Address,
+        hook: UserHook,
+    ) -> Result<(), PositionHookError> {
+        register_position_hook(&env, user, hook)
+    }
+
+    /// Remove the user's hook contract
+    pub fn remove_position_hook(env: Env, user: Address) {
+        remove_position_hook(&env, user)
+    }
+
+    /// Get the user's hook contract, if any
+    pub fn get_position_hook(env: Env, user: Address) -> Option<UserHook> {
+        get_position_hook(&env, &user)
+    }
+
+    /// Set or clear the hook called after every position's borrows and
+    /// repayments (admin only)
+    ///
+    /// # Arguments
+    /// * `caller` - The admin
+    /// * `hook` - The hook contract, or `None` to clear it
+    pub fn set_global_position_hook(
+        env: Env,
+        caller: Address,
+        hook: Option<Address>,
+    ) -> Result<(), PositionHookError> {
+        set_global_position_hook(&env, caller, hook)
+    }
+
+    /// Get the hook called after every position change, if any
+    pub fn get_global_position_hook(env: Env) -> Option<Address> {
+        get_global_position_hook(&env)
+    }
+
+    /// Have the borrower contract's `on_liquidation()` called after each of
+    /// its liquidations
+    ///
+    /// # Arguments
+    /// * `user` - The borrower contract (must authorize)
+    pub fn enable_liquidation_callback(env: Env, user: Address) {
+        enable_liquidation_callback(&env, user)
+    }
+
+    /// Stop calling the borrower back on liquidation
+    ///
+    /// # Arguments
+    /// * `caller` - The borrower or the guardian
+    /// * `user` - The borrower
+    pub fn disable_liquidation_callback(
+        env: Env,
+        caller: Address,
+        user: Address,
+    ) -> Result<(), PositionHookError> {
+        disable_liquidation_callback(&env, caller, user)
+    }
+
+    /// Whether the borrower is called back on liquidation
+    pub fn has_liquidation_callback(env: Env, user: Address) -> bool {
+        has_liquidation_callback(&env, &user)
+    }
+
+    /// Preview a borrow without writing state
+    ///
+    /// # Arguments
+    /// * `user` - The borrower
+    /// * `asset` - The asset to borrow
+    /// * `amount` - The amount to borrow
+    /// * `collateral_asset` - The collateral asset
+    /// * `collateral_amount` - The collateral to deposit
+    ///
+    /// # Returns
+    /// The resulting debt, collateral and health factor, or the error the
+    /// borrow would fail with
+    pub fn simulate_borrow(
+        env: Env,
+        user: Address,
+        asset: Address,
+        amount: i128,
+        collateral_asset: Address,
+        collateral_amount: i128,
+    ) -> Result<BorrowSimulation, BorrowError> {
+        simulate_borrow(
+            &env,
+            user,
+            asset,
+            amount,
+            collateral_asset,
+            collateral_amount,
+        )
+    }
+
+    /// Preview a repayment without writing state
+    ///
+    /// # Arguments
+    /// * `user` - The borrower
+    /// * `amount` - The amount to repay
+    ///
+    /// # Returns
+    /// The split of the payment, any early-repayment rebate, the remaining
+    /// debt and health factor, or the error the repayment would fail with
+    pub fn simulate_repay(
+        env: Env,
+        user: Address,
+        amount: i128,
+    ) -> Result<RepaySimulation, RepayError> {
+        simulate_repay(&env, user, amount)
+    }
+
+    /// Preview an RWA liquidation without writing state
+    ///
+    /// # Arguments
+    /// * `liquidator` - The would-be liquidator
+    /// * `user` - The borrower
+    /// * `asset` - The restricted collateral to seize
+    /// * `repay_amount` - The amount of debt to repay
+    ///
+    /// # Returns
+    /// The debt repaid, the collateral seized and the resulting health
+    /// factor, or the error the liquidation would fail with
+    pub fn simulate_liquidate(
+        env: Env,
+        liquidator: Address,
+        user: Address,
+        asset: Address,
+        repay_amount: i128,
+    ) -> Result<LiquidationSimulation, RwaError> {
+        simulate_liquidate(&env, liquidator, user, asset, repay_amount)
     }
 }
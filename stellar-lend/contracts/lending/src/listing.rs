@@ -0,0 +1,396 @@
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol, Vec};
+
+use crate::admin::require_admin;
+use crate::borrow::{
+    apply_asset_debt_ceiling, apply_asset_min_borrow, apply_collateral_params,
+    get_collateral_position, get_user_debt, save_collateral_position, CollateralParams,
+    MinBorrowConfig,
+};
+use crate::history::PositionAction;
+use crate::math::{mul_div_down, mul_div_up};
+use crate::oracle::{apply_asset_price, apply_oracle_feed_config, OracleFeedConfig, PRICE_SCALE};
+use crate::p2p::{apply_p2p_market, apply_supply, P2PError, P2PMarket};
+use crate::repay::apply_repayment;
+
+/// Errors that can occur while listing an asset
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ListingError {
+    Unauthorized = 1,
+    InvalidConfig = 2,
+    AlreadyListed = 3,
+    SeedTooSmall = 4,
+    PriceUnavailable = 5,
+    ProtocolPaused = 6,
+    NotListed = 7,
+    WrongStage = 8,
+    DeadlineNotReached = 9,
+    NothingToSettle = 10,
+}
+
+/// Storage keys for asset listings
+#[contracttype]
+#[derive(Clone)]
+pub enum ListingDataKey {
+    AssetListing(Address),
+    /// Assets listed through `list_asset`, in listing order
+    ListedAssets,
+    DelistSchedule(Address),
+}
+
+/// Lifecycle stage of a listed market
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ListingStatus {
+    Active,
+    /// New exposure is blocked and borrow rates ramp up until the deadline
+    WindDown,
+    /// Residual positions can be force-settled at oracle prices
+    Settlement,
+}
+
+/// A listed market and its seed deposit
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AssetListing {
+    pub status: ListingStatus,
+    pub listed_at: u64,
+    /// Admin that provided the seed liquidity
+    pub seed_supplier: Address,
+    pub seed_amount: i128,
+}
+
+/// Everything a market needs before it becomes active
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ListingConfig {
+    pub oracle_feed: OracleFeedConfig,
+    /// First price of the asset, with the feed's decimals
+    pub initial_price: i128,
+    /// Supply-side rates of the market
+    pub market: P2PMarket,
+    pub collateral_params: CollateralParams,
+    pub debt_ceiling: i128,
+    pub min_borrow: MinBorrowConfig,
+    /// Liquidity the admin supplies when the market opens
+    pub seed_amount: i128,
+}
+
+/// Wind-down of a delisted asset
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DelistSchedule {
+    pub started_at: u64,
+    /// When residual positions may be force-settled
+    pub settlement_at: u64,
+    /// Borrow rate premium reached at the deadline, in basis points
+    pub max_rate_premium_bps: i128,
+}
+
+/// Forced settlement event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct DelistSettlementEvent {
+    pub user: Address,
+    pub keeper: Address,
+    pub debt_asset: Address,
+    pub collateral_asset: Address,
+    pub debt_repaid: i128,
+    pub collateral_seized: i128,
+    pub timestamp: u64,
+}
+
+/// Smallest seed deposit a new market accepts
+pub const MIN_SEED_LIQUIDITY: i128 = 1_000;
+const MAX_DELIST_RATE_PREMIUM_BPS: i128 = 5000; // 50%
+
+/// List a new asset in a single call (admin only)
+///
+/// Registers the oracle feed, supply rates, collateral parameters and caps,
+/// records the initial price and supplies the seed liquidity from the admin.
+/// The price must be retrievable and fresh once normalized by the feed's
+/// decimals; if any step fails nothing is stored and the market stays closed.
+pub fn list_asset(
+    env: &Env,
+    caller: Address,
+    asset: Address,
+    config: ListingConfig,
+) -> Result<AssetListing, ListingError> {
+    require_admin(env, &caller).map_err(|_| ListingError::Unauthorized)?;
+    if get_asset_listing(env, &asset).is_some() {
+        return Err(ListingError::AlreadyListed);
+    }
+    if config.seed_amount < MIN_SEED_LIQUIDITY {
+        return Err(ListingError::SeedTooSmall);
+    }
+
+    let valid = apply_oracle_feed_config(env, asset.clone(), config.oracle_feed).is_ok()
+        && apply_p2p_market(env, asset.clone(), config.market).is_ok()
+        && apply_collateral_params(env, asset.clone(), config.collateral_params).is_ok()
+        && apply_asset_debt_ceiling(env, asset.clone(), config.debt_ceiling).is_ok()
+        && apply_asset_min_borrow(env, asset.clone(), config.min_borrow).is_ok();
+    if !valid {
+        return Err(ListingError::InvalidConfig);
+    }
+
+    // A price that rounds to zero at the feed's decimals is rejected here
+    apply_asset_price(env, asset.clone(), config.initial_price)
+        .map_err(|_| ListingError::PriceUnavailable)?;
+    match crate::oracle::get_effective_price(env, &asset) {
+        Some(price) if price.price > 0 && !crate::oracle::is_price_stale(env, &asset) => {}
+        _ => return Err(ListingError::PriceUnavailable),
+    }
+
+    apply_supply(env, caller.clone(), asset.clone(), config.seed_amount).map_err(
+        |err| match err {
+            P2PError::ProtocolPaused => ListingError::ProtocolPaused,
+            _ => ListingError::InvalidConfig,
+        },
+    )?;
+
+    let listing = AssetListing {
+        status: ListingStatus::Active,
+        listed_at: env.ledger().timestamp(),
+        seed_supplier: caller,
+        seed_amount: config.seed_amount,
+    };
+    env.storage()
+        .persistent()
+        .set(&ListingDataKey::AssetListing(asset.clone()), &listing);
+    let mut listed = get_listed_assets(env);
+    listed.push_back(asset.clone());
+    env.storage()
+        .persistent()
+        .set(&ListingDataKey::ListedAssets, &listed);
+
+    crate::events::publish(
+        env,
+        (Symbol::new(env, "asset_listed"), asset),
+        listing.clone(),
+    );
+    Ok(listing)
+}
+
+/// Get an asset's listing, if it was listed through `list_asset`
+pub fn get_asset_listing(env: &Env, asset: &Address) -> Option<AssetListing> {
+    env.storage()
+        .persistent()
+        .get(&ListingDataKey::AssetListing(asset.clone()))
+}
+
+/// Assets listed through `list_asset`, in listing order
+pub fn get_listed_assets(env: &Env) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&ListingDataKey::ListedAssets)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Start winding down a listed asset (admin only)
+///
+/// New borrows, collateral deposits and supply in the asset are rejected from
+/// now on, and its borrow rate carries a premium rising linearly to
+/// `max_rate_premium_bps` over `wind_down_period` seconds.
+pub fn delist_asset(
+    env: &Env,
+    caller: Address,
+    asset: Address,
+    wind_down_period: u64,
+    max_rate_premium_bps: i128,
+) -> Result<DelistSchedule, ListingError> {
+    require_admin(env, &caller).map_err(|_| ListingError::Unauthorized)?;
+    if wind_down_period == 0 || !(0..=MAX_DELIST_RATE_PREMIUM_BPS).contains(&max_rate_premium_bps) {
+        return Err(ListingError::InvalidConfig);
+    }
+    let mut listing = get_asset_listing(env, &asset).ok_or(ListingError::NotListed)?;
+    if listing.status != ListingStatus::Active {
+        return Err(ListingError::WrongStage);
+    }
+
+    // Accrue the reserve before the premium starts ramping
+    crate::reserve::update_reserve(env, &asset, 0, 0);
+    let now = env.ledger().timestamp();
+    let schedule = DelistSchedule {
+        started_at: now,
+        settlement_at: now.saturating_add(wind_down_period),
+        max_rate_premium_bps,
+    };
+    env.storage()
+        .persistent()
+        .set(&ListingDataKey::DelistSchedule(asset.clone()), &schedule);
+    listing.status = ListingStatus::WindDown;
+    set_listing_status(env, &asset, listing);
+
+    crate::events::publish(
+        env,
+        (Symbol::new(env, "asset_wind_down"), asset),
+        schedule.clone(),
+    );
+    Ok(schedule)
+}
+
+/// Open forced settlement of a wound-down asset once its deadline passed (permissionless)
+pub fn open_settlement(env: &Env, asset: Address) -> Result<(), ListingError> {
+    let mut listing = get_asset_listing(env, &asset).ok_or(ListingError::NotListed)?;
+    if listing.status != ListingStatus::WindDown {
+        return Err(ListingError::WrongStage);
+    }
+    let schedule = get_delist_schedule(env, &asset).ok_or(ListingError::WrongStage)?;
+    if env.ledger().timestamp() < schedule.settlement_at {
+        return Err(ListingError::DeadlineNotReached);
+    }
+
+    listing.status = ListingStatus::Settlement;
+    set_listing_status(env, &asset, listing);
+    crate::events::publish(
+        env,
+        (Symbol::new(env, "asset_settlement_open"), asset),
+        env.ledger().timestamp(),
+    );
+    Ok(())
+}
+
+/// Force-settle a position borrowing or collateralized by an asset in settlement
+///
+/// Permissionless. The debt is repaid from the collateral converted at oracle
+/// prices; if the collateral falls short, all of it is seized and the
+/// uncovered debt remains.
+///
+/// # Returns
+/// The debt repaid and the collateral seized
+pub fn settle_delisted_position(
+    env: &Env,
+    keeper: Address,
+    user: Address,
+) -> Result<(i128, i128), ListingError> {
+    keeper.require_auth();
+
+    let debt = get_user_debt(env, &user);
+    let mut collateral = get_collateral_position(env, &user);
+    if !is_in_settlement(env, &debt.asset) && !is_in_settlement(env, &collateral.asset) {
+        return Err(ListingError::WrongStage);
+    }
+    let owed = debt.borrowed_amount.saturating_add(debt.interest_accrued);
+    if owed == 0 || collateral.amount == 0 {
+        return Err(ListingError::NothingToSettle);
+    }
+
+    let debt_price = effective_price(env, &debt.asset)?;
+    let collateral_price = effective_price(env, &collateral.asset)?;
+    let needed = mul_div_up(
+        mul_div_up(owed, debt_price, PRICE_SCALE),
+        PRICE_SCALE,
+        collateral_price,
+    );
+    let (repaid, seized) = if needed <= collateral.amount {
+        (owed, needed)
+    } else {
+        let covered = mul_div_down(
+            mul_div_down(collateral.amount, collateral_price, PRICE_SCALE),
+            PRICE_SCALE,
+            debt_price,
+        );
+        (covered, collateral.amount)
+    };
+    if repaid > 0 {
+        apply_repayment(env, user.clone(), repaid).map_err(|_| ListingError::NothingToSettle)?;
+    }
+    crate::points::checkpoint(env, &user);
+    collateral.amount -= seized;
+    save_collateral_position(env, &user, &collateral);
+    crate::history::record_action(
+        env,
+        &user,
+        PositionAction::CollateralSeized,
+        &collateral.asset,
+        seized,
+    );
+    crate::position_hooks::notify_liquidation(env, &user, repaid, seized);
+
+    let event = DelistSettlementEvent {
+        user,
+        keeper,
+        debt_asset: debt.asset,
+        collateral_asset: collateral.asset,
+        debt_repaid: repaid,
+        collateral_seized: seized,
+        timestamp: env.ledger().timestamp(),
+    };
+    crate::events::publish(
+        env,
+        (
+            Symbol::new(env, "delisted_position_settled"),
+            event.user.clone(),
+            event.debt_asset.clone(),
+            event.collateral_asset.clone(),
+        ),
+        event,
+    );
+    Ok((repaid, seized))
+}
+
+/// Get the wind-down schedule of a delisted asset, if any
+pub fn get_delist_schedule(env: &Env, asset: &Address) -> Option<DelistSchedule> {
+    env.storage()
+        .persistent()
+        .get(&ListingDataKey::DelistSchedule(asset.clone()))
+}
+
+/// Whether new exposure to an asset is blocked by its delisting
+pub(crate) fn is_winding_down(env: &Env, asset: &Address) -> bool {
+    get_asset_listing(env, asset).is_some_and(|listing| listing.status != ListingStatus::Active)
+}
+
+/// Borrow rate premium of a delisted asset accrued from `from` to `to`, in
+/// basis points times seconds
+///
+/// The premium ramps linearly from zero at the start of the wind-down to its
+/// maximum at settlement, and stays there.
+pub(crate) fn get_delist_premium_accrued(env: &Env, asset: &Address, from: u64, to: u64) -> i128 {
+    if !is_winding_down(env, asset) {
+        return 0;
+    }
+    let Some(schedule) = get_delist_schedule(env, asset) else {
+        return 0;
+    };
+    let start = from.max(schedule.started_at);
+    if to <= start {
+        return 0;
+    }
+    let period = schedule
+        .settlement_at
+        .saturating_sub(schedule.started_at)
+        .max(1);
+    let ramp_end = to.min(schedule.settlement_at);
+    let ramp = if start < ramp_end {
+        let low = (start - schedule.started_at) as i128;
+        let high = (ramp_end - schedule.started_at) as i128;
+        mul_div_down(
+            schedule.max_rate_premium_bps,
+            high * high - low * low,
+            2 * period as i128,
+        )
+    } else {
+        0
+    };
+    let flat = to.saturating_sub(start.max(schedule.settlement_at)) as i128;
+    ramp.saturating_add(schedule.max_rate_premium_bps.saturating_mul(flat))
+}
+
+fn is_in_settlement(env: &Env, asset: &Address) -> bool {
+    get_asset_listing(env, asset).is_some_and(|listing| listing.status == ListingStatus::Settlement)
+}
+
+fn effective_price(env: &Env, asset: &Address) -> Result<i128, ListingError> {
+    crate::oracle::get_effective_price(env, asset)
+        .map(|price| price.price)
+        .filter(|price| *price > 0)
+        .ok_or(ListingError::PriceUnavailable)
+}
+
+fn set_listing_status(env: &Env, asset: &Address, listing: AssetListing) {
+    env.storage()
+        .persistent()
+        .set(&ListingDataKey::AssetListing(asset.clone()), &listing);
+}
@@ -0,0 +1,159 @@
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env, Symbol,
+};
+
+fn setup(env: &Env) -> (LendingContractClient<'_>, Address) {
+    env.mock_all_auths();
+    let client = LendingContractClient::new(env, &env.register(LendingContract, ()));
+    let admin = Address::generate(env);
+    client.initialize(&admin, &InitConfig::new(env, 1_000_000_000, 1000));
+    (client, admin)
+}
+
+fn listing_config(env: &Env, decimals: u32, initial_price: i128) -> ListingConfig {
+    ListingConfig {
+        oracle_feed: OracleFeedConfig {
+            feed: Address::generate(env),
+            quote: Symbol::new(env, "USD"),
+            decimals,
+            heartbeat: 3600,
+            max_deviation_bps: 0,
+        },
+        initial_price,
+        market: P2PMarket {
+            pool_supply_rate_bps: 200,
+            p2p_cursor_bps: 5000,
+        },
+        collateral_params: CollateralParams {
+            ltv_bps: 7000,
+            liquidation_threshold_bps: 8000,
+        },
+        debt_ceiling: 500_000,
+        min_borrow: MinBorrowConfig {
+            min_amount: 100,
+            min_value: 0,
+        },
+        seed_amount: 10_000,
+    }
+}
+
+#[test]
+fn test_list_asset_configures_and_seeds_market() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let asset = Address::generate(&env);
+
+    // 1.5 at 8 decimals
+    let listing = client.list_asset(&admin, &asset, &listing_config(&env, 8, 150_000_000));
+    assert_eq!(listing.status, ListingStatus::Active);
+    assert_eq!(listing.seed_amount, 10_000);
+    assert_eq!(client.get_asset_listing(&asset), Some(listing));
+    assert_eq!(client.get_listed_assets().len(), 1);
+
+    assert_eq!(client.get_asset_price(&asset).unwrap().price, 15_000_000);
+    assert_eq!(client.get_collateral_params(&asset).unwrap().ltv_bps, 7000);
+    assert_eq!(client.get_asset_debt_ceiling(&asset), Some(500_000));
+    assert_eq!(client.get_p2p_supply(&admin, &asset).on_pool, 10_000);
+    assert_eq!(client.get_market_size(&asset).supplied, 10_000);
+
+    assert_eq!(
+        client.try_list_asset(&admin, &asset, &listing_config(&env, 8, 150_000_000)),
+        Err(Ok(ListingError::AlreadyListed))
+    );
+}
+
+#[test]
+fn test_list_asset_rejects_bad_listing_atomically() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let asset = Address::generate(&env);
+
+    let mut config = listing_config(&env, 8, 150_000_000);
+    config.seed_amount = MIN_SEED_LIQUIDITY - 1;
+    assert_eq!(
+        client.try_list_asset(&admin, &asset, &config),
+        Err(Ok(ListingError::SeedTooSmall))
+    );
+
+    // A price that vanishes at the configured decimals points to a decimals mismatch
+    let config = listing_config(&env, 18, 150_000_000);
+    assert_eq!(
+        client.try_list_asset(&admin, &asset, &config),
+        Err(Ok(ListingError::PriceUnavailable))
+    );
+
+    let mut config = listing_config(&env, 8, 150_000_000);
+    config.collateral_params.liquidation_threshold_bps = 6000;
+    assert_eq!(
+        client.try_list_asset(&admin, &asset, &config),
+        Err(Ok(ListingError::InvalidConfig))
+    );
+
+    assert_eq!(client.get_asset_listing(&asset), None);
+    assert_eq!(client.get_oracle_feed_config(&asset), None);
+    assert_eq!(client.get_asset_price(&asset), None);
+    assert_eq!(
+        client.try_list_asset(
+            &Address::generate(&env),
+            &asset,
+            &listing_config(&env, 8, 150_000_000)
+        ),
+        Err(Ok(ListingError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_delisting_stages() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let usdc = Address::generate(&env);
+    let xlm = Address::generate(&env);
+    client.list_asset(&admin, &usdc, &listing_config(&env, 8, 100_000_000));
+    client.set_asset_price(&admin, &xlm, &5_000_000);
+
+    let user = Address::generate(&env);
+    client.borrow(&user, &usdc, &10_000, &xlm, &40_000);
+
+    let schedule = client.delist_asset(&admin, &usdc, &1000, &2000);
+    assert_eq!(schedule.settlement_at, schedule.started_at + 1000);
+    assert_eq!(
+        client.get_asset_listing(&usdc).unwrap().status,
+        ListingStatus::WindDown
+    );
+    assert_eq!(
+        client.try_borrow(&user, &usdc, &1000, &xlm, &4000),
+        Err(Ok(BorrowError::AssetDelisted))
+    );
+    assert_eq!(
+        client.try_p2p_supply(&admin, &usdc, &1000),
+        Err(Ok(P2PError::MarketClosed))
+    );
+    assert_eq!(
+        client.try_settle_delisted_position(&admin, &user),
+        Err(Ok(ListingError::WrongStage))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp += 500);
+    assert_eq!(
+        client.try_open_settlement(&usdc),
+        Err(Ok(ListingError::DeadlineNotReached))
+    );
+    env.ledger().with_mut(|li| li.timestamp += 500);
+    client.open_settlement(&usdc);
+    assert_eq!(
+        client.get_asset_listing(&usdc).unwrap().status,
+        ListingStatus::Settlement
+    );
+
+    // Collateral at half the debt asset's price covers the debt twice over
+    let debt = client.get_user_debt(&user);
+    let owed = debt.borrowed_amount + debt.interest_accrued;
+    assert!(owed > 10_000);
+    let (repaid, seized) = client.settle_delisted_position(&admin, &user);
+    assert_eq!(repaid, owed);
+    assert_eq!(seized, owed * 2);
+    assert_eq!(client.get_user_debt(&user).borrowed_amount, 0);
+    assert_eq!(client.get_user_collateral(&user).amount, 40_000 - owed * 2);
+}
@@ -0,0 +1,163 @@
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol};
+
+use crate::admin::require_admin;
+use crate::borrow::{get_position_metadata, save_position_metadata, DebtPosition};
+
+/// Errors that can occur during loyalty configuration
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum LoyaltyError {
+    Unauthorized = 1,
+    InvalidConfig = 2,
+}
+
+/// Storage keys for loyalty data
+#[contracttype]
+#[derive(Clone)]
+pub enum LoyaltyDataKey {
+    LoyaltyConfig,
+}
+
+/// Loyalty rebate configuration
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LoyaltyConfig {
+    /// Length of one repayment cycle in seconds
+    pub cycle_duration: u64,
+    /// Consecutive on-time cycles required to earn the rebate
+    pub cycles_required: u32,
+    /// Interest rebate in basis points of accrued interest
+    pub rebate_bps: i128,
+}
+
+/// A user's loyalty standing
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LoyaltyStatus {
+    pub repay_streak: u32,
+    pub rebate_bps: i128,
+}
+
+/// Loyalty tier event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct LoyaltyTierEvent {
+    pub user: Address,
+    pub repay_streak: u32,
+    pub rebate_bps: i128,
+    pub timestamp: u64,
+}
+
+const MAX_REBATE_BPS: i128 = 2000; // 20% of accrued interest
+
+/// Configure the loyalty rebate (admin only)
+pub fn set_loyalty_config(
+    env: &Env,
+    caller: Address,
+    config: LoyaltyConfig,
+) -> Result<(), LoyaltyError> {
+    require_admin(env, &caller).map_err(|_| LoyaltyError::Unauthorized)?;
+
+    if config.cycle_duration == 0
+        || config.cycles_required == 0
+        || config.rebate_bps < 0
+        || config.rebate_bps > MAX_REBATE_BPS
+    {
+        return Err(LoyaltyError::InvalidConfig);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&LoyaltyDataKey::LoyaltyConfig, &config);
+    Ok(())
+}
+
+/// Get the loyalty configuration, if set
+pub fn get_loyalty_config(env: &Env) -> Option<LoyaltyConfig> {
+    env.storage()
+        .persistent()
+        .get(&LoyaltyDataKey::LoyaltyConfig)
+}
+
+/// Get a user's repayment streak and currently active rebate
+pub fn get_loyalty_status(env: &Env, user: &Address) -> LoyaltyStatus {
+    LoyaltyStatus {
+        repay_streak: get_position_metadata(env, user)
+            .map(|m| m.repay_streak)
+            .unwrap_or(0),
+        rebate_bps: get_active_rebate_bps(env, user),
+    }
+}
+
+/// Rebate applied to the user's interest at accrual time
+///
+/// The rebate stays active while the streak meets the requirement and the
+/// user has not missed the current cycle.
+pub fn get_active_rebate_bps(env: &Env, user: &Address) -> i128 {
+    let config = match get_loyalty_config(env) {
+        Some(config) => config,
+        None => return 0,
+    };
+    let metadata = match get_position_metadata(env, user) {
+        Some(metadata) => metadata,
+        None => return 0,
+    };
+
+    let current = cycle_index(env, metadata.cycle_anchor, config.cycle_duration);
+    if metadata.repay_streak >= config.cycles_required
+        && current <= metadata.last_on_time_cycle.saturating_add(1)
+    {
+        config.rebate_bps
+    } else {
+        0
+    }
+}
+
+/// Update the repayment streak after a repayment
+///
+/// A repayment is on time when it leaves no accrued interest outstanding. The
+/// streak grows by one per consecutive cycle and restarts after a missed cycle.
+pub(crate) fn record_repayment(env: &Env, user: &Address, position: &DebtPosition) {
+    let config = match get_loyalty_config(env) {
+        Some(config) => config,
+        None => return,
+    };
+    if position.interest_accrued != 0 {
+        return;
+    }
+    let mut metadata = match get_position_metadata(env, user) {
+        Some(metadata) => metadata,
+        None => return,
+    };
+
+    let current = cycle_index(env, metadata.cycle_anchor, config.cycle_duration);
+    if metadata.repay_streak > 0 && current == metadata.last_on_time_cycle {
+        return;
+    }
+    if metadata.repay_streak > 0 && current == metadata.last_on_time_cycle + 1 {
+        metadata.repay_streak = metadata.repay_streak.saturating_add(1);
+    } else {
+        metadata.repay_streak = 1;
+    }
+    metadata.last_on_time_cycle = current;
+    save_position_metadata(env, user, &metadata);
+
+    if metadata.repay_streak == config.cycles_required {
+        let event = LoyaltyTierEvent {
+            user: user.clone(),
+            repay_streak: metadata.repay_streak,
+            rebate_bps: config.rebate_bps,
+            timestamp: env.ledger().timestamp(),
+        };
+        crate::events::publish(
+            env,
+            (Symbol::new(env, "loyalty_tier_reached"), event.user.clone()),
+            event,
+        );
+    }
+}
+
+fn cycle_index(env: &Env, anchor: u64, cycle_duration: u64) -> u64 {
+    env.ledger().timestamp().saturating_sub(anchor) / cycle_duration
+}
@@ -0,0 +1,137 @@
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+
+const CYCLE: u64 = 30 * 86400;
+
+fn setup(env: &Env) -> (LendingContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 0);
+
+    let contract_id = env.register(LendingContract, ());
+    let client = LendingContractClient::new(env, &contract_id);
+    client.initialize(
+        &Address::generate(env),
+        &InitConfig::new(env, 1_000_000_000, 1000),
+    );
+
+    let admin = Address::generate(env);
+    client.set_admin(&admin);
+    client.set_loyalty_config(
+        &admin,
+        &LoyaltyConfig {
+            cycle_duration: CYCLE,
+            cycles_required: 3,
+            rebate_bps: 1000,
+        },
+    );
+
+    let user = Address::generate(env);
+    let asset = Address::generate(env);
+    let collateral_asset = Address::generate(env);
+    client.borrow(&user, &asset, &1_000_000, &collateral_asset, &2_000_000);
+
+    (client, admin, user)
+}
+
+/// Repay all accrued interest at the given time
+fn repay_interest_at(env: &Env, client: &LendingContractClient, user: &Address, time: u64) {
+    env.ledger().with_mut(|li| li.timestamp = time);
+    let interest = client.get_user_debt(user).interest_accrued;
+    if interest > 0 {
+        client.repay(user, &interest);
+    }
+}
+
+#[test]
+fn test_streak_earns_rebate() {
+    let env = Env::default();
+    let (client, _, user) = setup(&env);
+
+    repay_interest_at(&env, &client, &user, CYCLE / 2);
+    repay_interest_at(&env, &client, &user, CYCLE + CYCLE / 2);
+    assert_eq!(client.get_loyalty_status(&user).rebate_bps, 0);
+
+    repay_interest_at(&env, &client, &user, 2 * CYCLE + CYCLE / 2);
+    let status = client.get_loyalty_status(&user);
+    assert_eq!(status.repay_streak, 3);
+    assert_eq!(status.rebate_bps, 1000);
+}
+
+#[test]
+fn test_rebate_applied_at_accrual() {
+    let env = Env::default();
+    let (client, _, user) = setup(&env);
+
+    for cycle in 0..3 {
+        repay_interest_at(&env, &client, &user, cycle * CYCLE + CYCLE / 2);
+    }
+
+    // Interest over the next half cycle is reduced by 10%
+    env.ledger()
+        .with_mut(|li| li.timestamp = 3 * CYCLE + CYCLE / 4);
+    let elapsed = (3 * CYCLE + CYCLE / 4 - (2 * CYCLE + CYCLE / 2)) as i128;
+    // Interest rounds up, the rebate rounds down
+    let base = (1_000_000 * 500 * elapsed + 10000 * 31536000 - 1) / (10000 * 31536000);
+    let debt = client.get_user_debt(&user);
+    assert_eq!(debt.interest_accrued, base - base * 1000 / 10000);
+}
+
+#[test]
+fn test_repayments_in_same_cycle_count_once() {
+    let env = Env::default();
+    let (client, _, user) = setup(&env);
+
+    repay_interest_at(&env, &client, &user, CYCLE / 4);
+    repay_interest_at(&env, &client, &user, CYCLE / 2);
+    repay_interest_at(&env, &client, &user, 3 * CYCLE / 4);
+
+    assert_eq!(client.get_loyalty_status(&user).repay_streak, 1);
+}
+
+#[test]
+fn test_missed_cycle_resets_streak() {
+    let env = Env::default();
+    let (client, _, user) = setup(&env);
+
+    for cycle in 0..3 {
+        repay_interest_at(&env, &client, &user, cycle * CYCLE + CYCLE / 2);
+    }
+    assert_eq!(client.get_loyalty_status(&user).rebate_bps, 1000);
+
+    // Skip a full cycle: the rebate lapses and the streak restarts
+    env.ledger()
+        .with_mut(|li| li.timestamp = 4 * CYCLE + CYCLE / 2);
+    assert_eq!(client.get_loyalty_status(&user).rebate_bps, 0);
+
+    repay_interest_at(&env, &client, &user, 4 * CYCLE + CYCLE / 2);
+    assert_eq!(client.get_loyalty_status(&user).repay_streak, 1);
+}
+
+#[test]
+fn test_loyalty_config_validation() {
+    let env = Env::default();
+    let (client, admin, user) = setup(&env);
+
+    let result = client.try_set_loyalty_config(
+        &admin,
+        &LoyaltyConfig {
+            cycle_duration: CYCLE,
+            cycles_required: 3,
+            rebate_bps: 2001,
+        },
+    );
+    assert_eq!(result, Err(Ok(LoyaltyError::InvalidConfig)));
+
+    let result = client.try_set_loyalty_config(
+        &user,
+        &LoyaltyConfig {
+            cycle_duration: CYCLE,
+            cycles_required: 3,
+            rebate_bps: 100,
+        },
+    );
+    assert_eq!(result, Err(Ok(LoyaltyError::Unauthorized)));
+}
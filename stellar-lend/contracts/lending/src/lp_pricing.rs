@@ -0,0 +1,124 @@
+use soroban_sdk::{contractclient, contracterror, contracttype, Address, Env, Symbol};
+
+use crate::admin::require_risk_manager;
+use crate::math::{mul_div_down, sqrt_down};
+use crate::oracle::{get_effective_price, is_price_stale, PRICE_SCALE};
+
+/// Interface exposed by constant-product AMM pools whose shares are LP tokens
+#[contractclient(name = "LiquidityPoolClient")]
+pub trait LiquidityPool {
+    /// Reserves of the pool's two tokens, in pool order
+    fn get_reserves(env: Env) -> (i128, i128);
+    /// Outstanding LP token supply
+    fn total_supply(env: Env) -> i128;
+}
+
+/// Errors that can occur when registering LP tokens
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum LpPricingError {
+    Unauthorized = 1,
+    InvalidConfig = 2,
+    NotRegistered = 3,
+}
+
+/// Storage keys for LP token data
+#[contracttype]
+#[derive(Clone)]
+pub enum LpPricingDataKey {
+    LpToken(Address),
+}
+
+/// Underlying tokens of a registered LP token, in pool order
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LpTokenConfig {
+    pub token_a: Address,
+    pub token_b: Address,
+}
+
+/// Register an AMM LP token for fair-reserves pricing (risk manager or admin)
+///
+/// The LP token address is the pool contract. Once registered, the token is
+/// priced from its pool like any oracle-priced asset.
+pub fn register_lp_token(
+    env: &Env,
+    caller: Address,
+    lp_token: Address,
+    config: LpTokenConfig,
+) -> Result<(), LpPricingError> {
+    require_risk_manager(env, &caller).map_err(|_| LpPricingError::Unauthorized)?;
+
+    if config.token_a == config.token_b || config.token_a == lp_token || config.token_b == lp_token
+    {
+        return Err(LpPricingError::InvalidConfig);
+    }
+    env.storage()
+        .persistent()
+        .set(&LpPricingDataKey::LpToken(lp_token.clone()), &config);
+    crate::oracle::track_priced_asset(env, &lp_token);
+
+    crate::events::publish(
+        env,
+        (Symbol::new(env, "lp_token_registered"), lp_token),
+        config,
+    );
+    Ok(())
+}
+
+/// Stop pricing an LP token from its pool (risk manager or admin)
+pub fn deregister_lp_token(
+    env: &Env,
+    caller: Address,
+    lp_token: Address,
+) -> Result<(), LpPricingError> {
+    require_risk_manager(env, &caller).map_err(|_| LpPricingError::Unauthorized)?;
+
+    let key = LpPricingDataKey::LpToken(lp_token);
+    if !env.storage().persistent().has(&key) {
+        return Err(LpPricingError::NotRegistered);
+    }
+    env.storage().persistent().remove(&key);
+    Ok(())
+}
+
+/// Get the underlying tokens of a registered LP token
+pub fn get_lp_token_config(env: &Env, lp_token: &Address) -> Option<LpTokenConfig> {
+    env.storage()
+        .persistent()
+        .get(&LpPricingDataKey::LpToken(lp_token.clone()))
+}
+
+/// Fair price of one LP token, scaled by `PRICE_SCALE`
+///
+/// Uses the fair-reserves formula `2 * sqrt(k * p_a * p_b) / supply`, which
+/// depends on the pool's invariant `k` rather than its spot reserves, so
+/// swapping the reserves out of balance cannot inflate the price. `None` if
+/// the token is not registered, an underlying is unpriced or the pool is empty.
+pub fn get_lp_token_price(env: &Env, lp_token: &Address) -> Option<i128> {
+    let config = get_lp_token_config(env, lp_token)?;
+    let price_a = get_effective_price(env, &config.token_a)?.price;
+    let price_b = get_effective_price(env, &config.token_b)?.price;
+
+    let pool = LiquidityPoolClient::new(env, lp_token);
+    let (reserve_a, reserve_b) = pool.get_reserves();
+    let supply = pool.total_supply();
+    if reserve_a <= 0 || reserve_b <= 0 || supply <= 0 {
+        return None;
+    }
+
+    let value_a = mul_div_down(reserve_a, price_a, PRICE_SCALE);
+    let value_b = mul_div_down(reserve_b, price_b, PRICE_SCALE);
+    // sqrt(value_a * value_b), splitting the root when the product overflows
+    let root = match value_a.checked_mul(value_b) {
+        Some(product) => sqrt_down(product),
+        None => sqrt_down(value_a).saturating_mul(sqrt_down(value_b)),
+    };
+    Some(mul_div_down(root.saturating_mul(2), PRICE_SCALE, supply))
+}
+
+/// Whether either underlying price of a registered LP token is stale
+pub(crate) fn is_lp_price_stale(env: &Env, config: &LpTokenConfig) -> bool {
+    is_price_stale(env, &config.token_a) || is_price_stale(env, &config.token_b)
+}
@@ -0,0 +1,142 @@
+use super::*;
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, Address, Env, Symbol};
+
+/// Constant-product pool with settable reserves and supply
+#[contract]
+pub struct MockPool;
+
+#[contractimpl]
+impl MockPool {
+    pub fn set_state(env: Env, reserve_a: i128, reserve_b: i128, supply: i128) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "state"), &(reserve_a, reserve_b, supply));
+    }
+
+    pub fn get_reserves(env: Env) -> (i128, i128) {
+        let (a, b, _): (i128, i128, i128) = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "state"))
+            .unwrap();
+        (a, b)
+    }
+
+    pub fn total_supply(env: Env) -> i128 {
+        let (_, _, supply): (i128, i128, i128) = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "state"))
+            .unwrap();
+        supply
+    }
+}
+
+fn setup(
+    env: &Env,
+) -> (
+    LendingContractClient<'_>,
+    MockPoolClient<'_>,
+    Address,
+    Address,
+    Address,
+) {
+    env.mock_all_auths();
+    let client = LendingContractClient::new(env, &env.register(LendingContract, ()));
+    client.initialize(
+        &Address::generate(env),
+        &InitConfig::new(env, 1_000_000_000, 1000),
+    );
+    let admin = Address::generate(env);
+    client.set_admin(&admin);
+
+    let pool_id = env.register(MockPool, ());
+    let pool = MockPoolClient::new(env, &pool_id);
+    let token_a = Address::generate(env);
+    let token_b = Address::generate(env);
+    client.set_asset_price(&admin, &token_a, &10_000_000); // 1.0
+    client.set_asset_price(&admin, &token_b, &40_000_000); // 4.0
+    client.register_lp_token(
+        &admin,
+        &pool_id,
+        &LpTokenConfig {
+            token_a: token_a.clone(),
+            token_b: token_b.clone(),
+        },
+    );
+
+    (client, pool, admin, token_a, token_b)
+}
+
+#[test]
+fn test_lp_price_from_fair_reserves() {
+    let env = Env::default();
+    let (client, pool, _, _, _) = setup(&env);
+
+    // 4000 A and 1000 B, worth 4000 each: 8000 of value over 100 LP tokens
+    pool.set_state(&4000, &1000, &100);
+    assert_eq!(client.get_lp_token_price(&pool.address), Some(800_000_000));
+    assert_eq!(
+        client.get_effective_price(&pool.address).unwrap().price,
+        800_000_000
+    );
+}
+
+#[test]
+fn test_skewed_reserves_do_not_inflate_price() {
+    let env = Env::default();
+    let (client, pool, _, _, _) = setup(&env);
+
+    pool.set_state(&4000, &1000, &100);
+    let fair = client.get_lp_token_price(&pool.address).unwrap();
+
+    // Same invariant, pushed off the oracle ratio by a large swap: spot value
+    // of the reserves rises (16000 + 1000) but the fair price does not
+    pool.set_state(&16_000, &250, &100);
+    assert_eq!(client.get_lp_token_price(&pool.address), Some(fair));
+}
+
+#[test]
+fn test_lp_price_requires_underlying_prices() {
+    let env = Env::default();
+    let (client, pool, admin, token_a, _) = setup(&env);
+    pool.set_state(&4000, &1000, &100);
+
+    client.set_oracle_feed_config(
+        &admin,
+        &token_a,
+        &OracleFeedConfig {
+            feed: Address::generate(&env),
+            quote: Symbol::new(&env, "USD"),
+            decimals: 7,
+            heartbeat: 1,
+            max_deviation_bps: 0,
+        },
+    );
+    assert!(!client.is_price_stale(&pool.address));
+
+    let unpriced = Address::generate(&env);
+    client.register_lp_token(
+        &admin,
+        &pool.address,
+        &LpTokenConfig {
+            token_a: token_a.clone(),
+            token_b: unpriced.clone(),
+        },
+    );
+    assert_eq!(client.get_lp_token_price(&pool.address), None);
+
+    assert_eq!(
+        client.try_register_lp_token(
+            &admin,
+            &pool.address,
+            &LpTokenConfig {
+                token_a: unpriced.clone(),
+                token_b: unpriced,
+            },
+        ),
+        Err(Ok(LpPricingError::InvalidConfig))
+    );
+    client.deregister_lp_token(&admin, &pool.address);
+    assert_eq!(client.get_lp_token_config(&pool.address), None);
+}
@@ -0,0 +1,85 @@
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::borrow::get_asset_debt;
+use crate::insurance::get_insurance_fund;
+use crate::p2p::{get_asset_supplied, get_supplied_total};
+
+/// Size and composition of one asset's market
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MarketSize {
+    /// Net liquidity supplied
+    pub supplied: i128,
+    /// Outstanding pool principal
+    pub borrowed: i128,
+    /// Insurance fund reserve held for the asset
+    pub reserve: i128,
+    /// Supplied liquidity not lent out
+    pub available_liquidity: i128,
+    /// Share of all supplied liquidity, in basis points
+    pub tvl_share_bps: i128,
+}
+
+/// Cash breakdown of an asset's market
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Liquidity {
+    /// Supplied liquidity not lent out
+    pub available_cash: i128,
+    /// Cash that must stay un-borrowed under the liquidity buffer
+    pub buffer_locked: i128,
+    /// Cash owed to suppliers waiting in the withdrawal queue
+    pub pending_withdrawals: i128,
+    /// Cash deployed to the asset's yield strategy
+    pub deployed: i128,
+    /// Cash lost in the asset's yield strategy and not yet written down
+    pub strategy_loss: i128,
+    /// Cash a supplier can withdraw right now
+    pub withdrawable: i128,
+}
+
+/// Get the supplied, borrowed and reserve totals of an asset's market
+pub fn get_market_size(env: &Env, asset: &Address) -> MarketSize {
+    let supplied = get_asset_supplied(env, asset);
+    let borrowed = get_asset_debt(env, asset);
+    let total_supplied = get_supplied_total(env);
+    let tvl_share_bps = if total_supplied > 0 {
+        supplied.saturating_mul(10000) / total_supplied
+    } else {
+        0
+    };
+
+    MarketSize {
+        supplied,
+        borrowed,
+        reserve: get_insurance_fund(env, asset),
+        available_liquidity: supplied.saturating_sub(borrowed).max(0),
+        tvl_share_bps,
+    }
+}
+
+/// Get how much of an asset's cash suppliers can withdraw right now
+///
+/// Cash owed to queued withdrawals is served first and is not withdrawable,
+/// nor is cash deployed to a yield strategy until it is recalled or cash a
+/// strategy lost.
+pub fn get_liquidity(env: &Env, asset: &Address) -> Liquidity {
+    let deployed = crate::strategy::get_strategy_deployed(env, asset);
+    let strategy_loss = crate::strategy::get_strategy_loss(env, asset);
+    let available_cash = get_asset_supplied(env, asset)
+        .saturating_sub(get_asset_debt(env, asset))
+        .saturating_sub(deployed)
+        .saturating_sub(strategy_loss)
+        .max(0);
+    let buffer_locked = crate::debt_ceiling::get_buffer_amount(env, asset).min(available_cash);
+    let pending_withdrawals = crate::withdrawal_queue::get_pending_withdrawals(env, asset);
+
+    Liquidity {
+        available_cash,
+        buffer_locked,
+        pending_withdrawals,
+        deployed,
+        strategy_loss,
+        withdrawable: available_cash.saturating_sub(pending_withdrawals).max(0),
+    }
+}
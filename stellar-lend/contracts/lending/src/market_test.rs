@@ -0,0 +1,90 @@
+use super::*;
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+#[test]
+fn test_market_size_reports_composition() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = LendingContractClient::new(&env, &env.register(LendingContract, ()));
+    client.initialize(
+        &Address::generate(&env),
+        &InitConfig::new(&env, 1_000_000_000, 1000),
+    );
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let market = P2PMarket {
+        pool_supply_rate_bps: 200,
+        p2p_cursor_bps: 5000,
+    };
+    let usdc = Address::generate(&env);
+    let xlm = Address::generate(&env);
+    client.set_p2p_market(&admin, &usdc, &market);
+    client.set_p2p_market(&admin, &xlm, &market);
+
+    let supplier = Address::generate(&env);
+    client.p2p_supply(&supplier, &usdc, &60_000);
+    client.p2p_supply(&supplier, &xlm, &40_000);
+    client.borrow(
+        &Address::generate(&env),
+        &usdc,
+        &10_000,
+        &Address::generate(&env),
+        &20_000,
+    );
+
+    assert_eq!(
+        client.get_market_size(&usdc),
+        MarketSize {
+            supplied: 60_000,
+            borrowed: 10_000,
+            reserve: 0,
+            available_liquidity: 50_000,
+            tvl_share_bps: 6000,
+        }
+    );
+
+    client.p2p_withdraw(&supplier, &xlm, &40_000);
+    let size = client.get_market_size(&usdc);
+    assert_eq!(size.tvl_share_bps, 10000);
+    assert_eq!(client.get_market_size(&xlm).supplied, 0);
+}
+
+#[test]
+fn test_liquidity_tracks_outstanding_debt() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = LendingContractClient::new(&env, &env.register(LendingContract, ()));
+    client.initialize(
+        &Address::generate(&env),
+        &InitConfig::new(&env, 1_000_000_000, 1000),
+    );
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let asset = Address::generate(&env);
+    client.set_p2p_market(
+        &admin,
+        &asset,
+        &P2PMarket {
+            pool_supply_rate_bps: 200,
+            p2p_cursor_bps: 5000,
+        },
+    );
+    client.p2p_supply(&Address::generate(&env), &asset, &50_000);
+    let borrower = Address::generate(&env);
+    client.borrow(
+        &borrower,
+        &asset,
+        &20_000,
+        &Address::generate(&env),
+        &40_000,
+    );
+
+    let liquidity = client.get_liquidity(&asset);
+    assert_eq!(liquidity.available_cash, 30_000);
+    assert_eq!(liquidity.withdrawable, 30_000);
+
+    client.repay(&borrower, &5000);
+    assert_eq!(client.get_liquidity(&asset).withdrawable, 35_000);
+}
@@ -0,0 +1,51 @@
+//! Rounding-direction helpers
+//!
+//! Every division that moves value between users and the protocol rounds in
+//! the protocol's favor: amounts owed to the protocol (interest, penalties,
+//! collateral requirements) round up, amounts credited to users (collateral
+//! value, payouts, shares) round down. Divisors must be positive.
+
+/// `numerator / denominator`, rounded towards positive infinity
+pub(crate) fn div_up(numerator: i128, denominator: i128) -> i128 {
+    let quotient = numerator / denominator;
+    if numerator % denominator > 0 {
+        quotient + 1
+    } else {
+        quotient
+    }
+}
+
+/// `numerator / denominator`, rounded towards negative infinity
+pub(crate) fn div_down(numerator: i128, denominator: i128) -> i128 {
+    let quotient = numerator / denominator;
+    if numerator % denominator < 0 {
+        quotient - 1
+    } else {
+        quotient
+    }
+}
+
+/// `a * b / denominator` rounded up, saturating on overflow
+pub(crate) fn mul_div_up(a: i128, b: i128, denominator: i128) -> i128 {
+    div_up(a.saturating_mul(b), denominator)
+}
+
+/// `a * b / denominator` rounded down, saturating on overflow
+pub(crate) fn mul_div_down(a: i128, b: i128, denominator: i128) -> i128 {
+    div_down(a.saturating_mul(b), denominator)
+}
+
+/// Integer square root, rounded down (zero for non-positive input)
+pub(crate) fn sqrt_down(value: i128) -> i128 {
+    if value <= 0 {
+        return 0;
+    }
+    // Newton's method from an initial guess above the root
+    let mut x = value;
+    let mut y = x / 2 + x % 2;
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+    x
+}
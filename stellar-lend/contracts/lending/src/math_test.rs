@@ -0,0 +1,89 @@
+use super::*;
+use crate::math::{div_down, div_up, mul_div_down, mul_div_up, sqrt_down};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+
+fn setup(env: &Env) -> LendingContractClient<'_> {
+    env.mock_all_auths();
+    let client = LendingContractClient::new(env, &env.register(LendingContract, ()));
+    client.initialize(
+        &Address::generate(env),
+        &InitConfig::new(env, 1_000_000_000, 1000),
+    );
+    client
+}
+
+#[test]
+fn test_div_rounding_directions() {
+    assert_eq!(div_up(10, 3), 4);
+    assert_eq!(div_down(10, 3), 3);
+    assert_eq!(div_up(9, 3), 3);
+    assert_eq!(div_down(9, 3), 3);
+    assert_eq!(div_up(0, 7), 0);
+
+    // Negative numerators round towards the same infinities
+    assert_eq!(div_up(-10, 3), -3);
+    assert_eq!(div_down(-10, 3), -4);
+}
+
+#[test]
+fn test_sqrt_rounds_down() {
+    assert_eq!(sqrt_down(0), 0);
+    assert_eq!(sqrt_down(1), 1);
+    assert_eq!(sqrt_down(15), 3);
+    assert_eq!(sqrt_down(16), 4);
+    assert_eq!(sqrt_down(i128::MAX), 13_043_817_825_332_782_212);
+}
+
+#[test]
+fn test_mul_div_rounding() {
+    // 1.5% of 999 is 14.985
+    assert_eq!(mul_div_up(999, 150, 10000), 15);
+    assert_eq!(mul_div_down(999, 150, 10000), 14);
+    assert_eq!(mul_div_up(i128::MAX, 2, 1), i128::MAX);
+}
+
+#[test]
+fn test_requirements_round_up_and_credits_round_down() {
+    let env = Env::default();
+    let client = setup(&env);
+    let user = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let collateral_asset = Address::generate(&env);
+
+    // 1001 at 150% requires 1501.5 collateral, rounded up
+    assert_eq!(
+        client.try_borrow(&user, &asset, &1001, &collateral_asset, &1501),
+        Err(Ok(BorrowError::InsufficientCollateral))
+    );
+    client.borrow(&user, &asset, &1001, &collateral_asset, &1502);
+
+    // The same rounded-up requirement puts the position exactly at the threshold
+    assert_eq!(client.get_health_factor(&user), 10000);
+
+    // 1502 / 1500 = 1.001333..., rounded down
+    let other = Address::generate(&env);
+    client.borrow(&other, &asset, &1000, &collateral_asset, &1502);
+    assert_eq!(client.get_health_factor(&other), 10013);
+}
+
+#[test]
+fn test_interest_rounds_up() {
+    let env = Env::default();
+    let client = setup(&env);
+    let user = Address::generate(&env);
+
+    client.borrow(
+        &user,
+        &Address::generate(&env),
+        &1000,
+        &Address::generate(&env),
+        &2000,
+    );
+    env.ledger().with_mut(|li| li.timestamp += 1);
+
+    // One second at 5% on 1000 is a fraction of a unit, charged as one
+    assert_eq!(client.get_user_debt(&user).interest_accrued, 1);
+}
@@ -0,0 +1,541 @@
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol, Vec};
+
+use crate::admin::require_admin;
+use crate::borrow::{
+    emit_borrow_event, get_debt_position, get_min_borrow_amount, get_total_collateral_value,
+    get_total_user_debt, increase_debt, is_liquidatable, is_paused, validate_collateral_ratio,
+    BorrowError,
+};
+use crate::credit_score::record_liquidation;
+use crate::math::{div_up, mul_div_down};
+use crate::repay::{apply_repayment, ensure_no_dust, RepayError};
+
+/// Errors that can occur during NFT collateral operations
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum NftError {
+    Unauthorized = 1,
+    InvalidConfig = 2,
+    CollectionNotApproved = 3,
+    AlreadyEscrowed = 4,
+    NotFound = 5,
+    NotOwner = 6,
+    TooManyNfts = 7,
+    InsufficientCollateral = 8,
+    ProtocolPaused = 9,
+    InvalidAmount = 10,
+    BelowMinimumBorrow = 11,
+    DebtCeilingReached = 12,
+    PositionHealthy = 13,
+    AuctionActive = 14,
+    AuctionNotFound = 15,
+    AuctionEnded = 16,
+    AuctionNotEnded = 17,
+    BidTooLow = 18,
+    Overflow = 19,
+    DustRemaining = 20,
+    TooManyPositions = 21,
+    BorrowCapExceeded = 22,
+    PriceStale = 23,
+    MarketFrozen = 24,
+    /// The borrow must go through `request_borrow`
+    LargeBorrowRequestRequired = 25,
+    /// The borrow is valued above the per-call maximum
+    BorrowSizeCapExceeded = 26,
+    /// The account is under a compliance freeze
+    AccountFrozen = 27,
+    AssetBorrowsPaused = 28,
+    AssetDelisted = 29,
+    BorrowFrozen = 30,
+    AssetCollateralOnly = 31,
+    LiquidityBufferReached = 32,
+    RehypothecationDisabled = 33,
+}
+
+/// Storage keys for NFT collateral data
+#[contracttype]
+#[derive(Clone)]
+pub enum NftDataKey {
+    NftCollection(Address),
+    NftEscrow(Address, u64),
+    UserNfts(Address),
+    NftAuction(Address, u64),
+}
+
+/// An approved NFT collection and its appraisal
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct NftCollection {
+    /// Share of the floor value counted as collateral, in basis points
+    pub ltv_bps: i128,
+    /// Appraised floor value of one token
+    pub floor_price: i128,
+    pub floor_updated_at: u64,
+    /// Floor in effect at the previous accrual checkpoint
+    pub committed_floor: i128,
+}
+
+/// Identifies one escrowed token
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct NftId {
+    pub collection: Address,
+    pub token_id: u64,
+}
+
+/// English auction of a liquidated NFT
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct NftAuction {
+    pub owner: Address,
+    pub start: u64,
+    pub end: u64,
+    pub reserve_price: i128,
+    pub highest_bid: i128,
+    pub highest_bidder: Option<Address>,
+}
+
+/// NFT collateral event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct NftEvent {
+    pub user: Address,
+    pub collection: Address,
+    pub token_id: u64,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+const MAX_NFT_LTV_BPS: i128 = 7000; // 70% of the floor value
+const MAX_NFTS_PER_USER: u32 = 16;
+const NFT_AUCTION_DURATION: u64 = 86400; // 1 day
+const NFT_AUCTION_EXTENSION: u64 = 600; // Bids in the last 10 minutes extend the auction
+const NFT_AUCTION_RESERVE_BPS: i128 = 5000; // 50% of the floor value
+const MIN_BID_INCREMENT_BPS: i128 = 500; // 5%
+
+/// Approve an NFT collection as collateral (admin only)
+pub fn approve_nft_collection(
+    env: &Env,
+    caller: Address,
+    collection: Address,
+    ltv_bps: i128,
+) -> Result<(), NftError> {
+    require_admin(env, &caller).map_err(|_| NftError::Unauthorized)?;
+
+    if ltv_bps <= 0 || ltv_bps > MAX_NFT_LTV_BPS {
+        return Err(NftError::InvalidConfig);
+    }
+
+    let mut config = get_nft_collection(env, &collection).unwrap_or(NftCollection {
+        ltv_bps,
+        floor_price: 0,
+        floor_updated_at: 0,
+        committed_floor: 0,
+    });
+    config.ltv_bps = ltv_bps;
+    save_collection(env, &collection, &config);
+    Ok(())
+}
+
+/// Record the appraised floor value of a collection (admin only)
+pub fn set_nft_floor(
+    env: &Env,
+    caller: Address,
+    collection: Address,
+    floor_price: i128,
+) -> Result<(), NftError> {
+    require_admin(env, &caller).map_err(|_| NftError::Unauthorized)?;
+
+    if floor_price < 0 {
+        return Err(NftError::InvalidAmount);
+    }
+    let mut config = get_nft_collection(env, &collection).ok_or(NftError::CollectionNotApproved)?;
+    config.committed_floor = committed_floor(env, &config);
+    config.floor_price = floor_price;
+    config.floor_updated_at = env.ledger().timestamp();
+    save_collection(env, &collection, &config);
+    Ok(())
+}
+
+/// Get an approved collection's configuration
+pub fn get_nft_collection(env: &Env, collection: &Address) -> Option<NftCollection> {
+    env.storage()
+        .persistent()
+        .get(&NftDataKey::NftCollection(collection.clone()))
+}
+
+/// Escrow an NFT from an approved collection as collateral
+pub fn escrow_nft(
+    env: &Env,
+    user: Address,
+    collection: Address,
+    token_id: u64,
+) -> Result<(), NftError> {
+    user.require_auth();
+
+    get_nft_collection(env, &collection).ok_or(NftError::CollectionNotApproved)?;
+    let key = NftDataKey::NftEscrow(collection.clone(), token_id);
+    if env.storage().persistent().has(&key) {
+        return Err(NftError::AlreadyEscrowed);
+    }
+
+    let mut nfts = get_user_nfts(env, &user);
+    if nfts.len() >= MAX_NFTS_PER_USER {
+        return Err(NftError::TooManyNfts);
+    }
+    if !crate::position_limit::has_position_capacity(env, &user) {
+        return Err(NftError::TooManyPositions);
+    }
+    nfts.push_back(NftId {
+        collection: collection.clone(),
+        token_id,
+    });
+    save_user_nfts(env, &user, &nfts);
+    env.storage().persistent().set(&key, &user);
+
+    emit_nft_event(env, "nft_escrowed", user, collection, token_id, 0);
+    Ok(())
+}
+
+/// Withdraw an escrowed NFT if the remaining collateral still covers the debt
+pub fn withdraw_nft(
+    env: &Env,
+    user: Address,
+    collection: Address,
+    token_id: u64,
+) -> Result<(), NftError> {
+    user.require_auth();
+
+    if get_nft_owner(env, &collection, token_id) != Some(user.clone()) {
+        return Err(NftError::NotOwner);
+    }
+    if get_nft_auction(env, &collection, token_id).is_some() {
+        return Err(NftError::AuctionActive);
+    }
+
+    release_nft(env, &user, &collection, token_id);
+
+    let debt = get_total_user_debt(env, &user);
+    if debt > 0 {
+        validate_collateral_ratio(env, &user, get_total_collateral_value(env, &user), debt)
+            .map_err(|_| NftError::InsufficientCollateral)?;
+    }
+
+    emit_nft_event(env, "nft_withdrawn", user, collection, token_id, 0);
+    Ok(())
+}
+
+/// Borrow against escrowed NFTs and deposited collateral
+///
+/// The appraised value of the user's NFTs is added to their fungible
+/// collateral, which must cover all outstanding debt at the minimum ratio.
+pub fn nft_borrow(env: &Env, user: Address, asset: Address, amount: i128) -> Result<(), NftError> {
+    user.require_auth();
+
+    if is_paused(env) {
+        return Err(NftError::ProtocolPaused);
+    }
+    if amount <= 0 {
+        return Err(NftError::InvalidAmount);
+    }
+    if amount < get_min_borrow_amount(env, &asset) {
+        return Err(NftError::BelowMinimumBorrow);
+    }
+
+    let debt = get_total_user_debt(env, &user)
+        .checked_add(amount)
+        .ok_or(NftError::Overflow)?;
+    validate_collateral_ratio(env, &user, get_total_collateral_value(env, &user), debt)
+        .map_err(|_| NftError::InsufficientCollateral)?;
+
+    increase_debt(env, &user, &asset, amount).map_err(debt_error)?;
+
+    emit_borrow_event(env, user, asset, amount, 0);
+    Ok(())
+}
+
+/// Get the NFTs a user has escrowed
+pub fn get_user_nfts(env: &Env, user: &Address) -> Vec<NftId> {
+    env.storage()
+        .persistent()
+        .get(&NftDataKey::UserNfts(user.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Get the owner of an escrowed NFT
+pub fn get_nft_owner(env: &Env, collection: &Address, token_id: u64) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&NftDataKey::NftEscrow(collection.clone(), token_id))
+}
+
+/// Collateral value of a user's escrowed NFTs (floor value times the collection LTV)
+pub fn get_nft_collateral_value(env: &Env, user: &Address) -> i128 {
+    get_user_nfts(env, user)
+        .iter()
+        .filter_map(|nft| get_nft_collection(env, &nft.collection))
+        .map(|config| mul_div_down(config.floor_price, config.ltv_bps, 10000))
+        .fold(0i128, |acc, value| acc.saturating_add(value))
+}
+
+/// Collateral value of a user's escrowed NFTs at their committed floors
+pub(crate) fn get_committed_nft_collateral_value(env: &Env, user: &Address) -> i128 {
+    get_user_nfts(env, user)
+        .iter()
+        .filter_map(|nft| get_nft_collection(env, &nft.collection))
+        .map(|config| mul_div_down(committed_floor(env, &config), config.ltv_bps, 10000))
+        .fold(0i128, |acc, value| acc.saturating_add(value))
+}
+
+/// Floor observed at the latest accrual checkpoint
+///
+/// A floor set before the checkpoint is committed; one set since falls back
+/// to the floor it replaced.
+fn committed_floor(env: &Env, config: &NftCollection) -> i128 {
+    if config.floor_updated_at < crate::borrow::get_last_accrual_checkpoint(env) {
+        config.floor_price
+    } else {
+        config.committed_floor
+    }
+}
+
+/// Start an English auction for an NFT backing an undercollateralized position
+///
+/// Anyone may start the auction once the owner's collateral no longer covers
+/// their debt at the liquidation threshold.
+pub fn start_nft_auction(env: &Env, collection: Address, token_id: u64) -> Result<(), NftError> {
+    let owner = get_nft_owner(env, &collection, token_id).ok_or(NftError::NotFound)?;
+    if get_nft_auction(env, &collection, token_id).is_some() {
+        return Err(NftError::AuctionActive);
+    }
+
+    if crate::pause::is_operation_paused(env, crate::pause::PAUSE_LIQUIDATE) {
+        return Err(NftError::ProtocolPaused);
+    }
+    if !is_liquidatable(env, &owner) {
+        return Err(NftError::PositionHealthy);
+    }
+    let debt_asset = get_debt_position(env, &owner).asset;
+    if crate::oracle::is_liquidation_frozen(env, &debt_asset) {
+        return Err(NftError::PriceStale);
+    }
+    if crate::oracle::is_market_frozen(env, &debt_asset) {
+        return Err(NftError::MarketFrozen);
+    }
+
+    let config = get_nft_collection(env, &collection).ok_or(NftError::CollectionNotApproved)?;
+    let now = env.ledger().timestamp();
+    let auction = NftAuction {
+        owner: owner.clone(),
+        start: now,
+        end: now.saturating_add(NFT_AUCTION_DURATION),
+        reserve_price: config.floor_price.saturating_mul(NFT_AUCTION_RESERVE_BPS) / 10000,
+        highest_bid: 0,
+        highest_bidder: None,
+    };
+    save_auction(env, &collection, token_id, &auction);
+
+    emit_nft_event(
+        env,
+        "nft_auction_started",
+        owner,
+        collection,
+        token_id,
+        auction.reserve_price,
+    );
+    Ok(())
+}
+
+/// Bid in an NFT auction
+///
+/// The first bid must meet the reserve price; later bids must beat the
+/// current bid by the minimum increment. Bids close to the end extend the
+/// auction.
+pub fn bid_nft_auction(
+    env: &Env,
+    bidder: Address,
+    collection: Address,
+    token_id: u64,
+    amount: i128,
+) -> Result<(), NftError> {
+    bidder.require_auth();
+
+    let mut auction =
+        get_nft_auction(env, &collection, token_id).ok_or(NftError::AuctionNotFound)?;
+    let now = env.ledger().timestamp();
+    if now >= auction.end {
+        return Err(NftError::AuctionEnded);
+    }
+    if bidder == auction.owner {
+        return Err(NftError::Unauthorized);
+    }
+
+    let min_bid = if auction.highest_bidder.is_some() {
+        auction
+            .highest_bid
+            .checked_mul(10000 + MIN_BID_INCREMENT_BPS)
+            .map(|bid| div_up(bid, 10000))
+            .ok_or(NftError::Overflow)?
+    } else {
+        auction.reserve_price.max(1)
+    };
+    if amount < min_bid {
+        return Err(NftError::BidTooLow);
+    }
+    ensure_no_dust(env, &auction.owner, amount).map_err(|_| NftError::DustRemaining)?;
+
+    auction.highest_bid = amount;
+    auction.highest_bidder = Some(bidder.clone());
+    if auction.end - now < NFT_AUCTION_EXTENSION {
+        auction.end = now + NFT_AUCTION_EXTENSION;
+    }
+    save_auction(env, &collection, token_id, &auction);
+
+    emit_nft_event(env, "nft_bid", bidder, collection, token_id, amount);
+    Ok(())
+}
+
+/// Settle an ended NFT auction
+///
+/// The winning bid repays the owner's debt and any surplus is returned to the
+/// owner; the NFT is released to the winner. Without bids the auction is
+/// cleared and the NFT stays in escrow.
+///
+/// # Returns
+/// The amount of the winning bid applied to the owner's debt
+pub fn settle_nft_auction(env: &Env, collection: Address, token_id: u64) -> Result<i128, NftError> {
+    let auction = get_nft_auction(env, &collection, token_id).ok_or(NftError::AuctionNotFound)?;
+    if env.ledger().timestamp() < auction.end {
+        return Err(NftError::AuctionNotEnded);
+    }
+    env.storage()
+        .persistent()
+        .remove(&NftDataKey::NftAuction(collection.clone(), token_id));
+
+    let winner = match auction.highest_bidder {
+        Some(winner) => winner,
+        None => return Ok(0),
+    };
+
+    let repaid = match apply_repayment(env, auction.owner.clone(), auction.highest_bid) {
+        Ok((_, interest_paid, principal_paid)) => interest_paid + principal_paid,
+        Err(RepayError::NoDebt) => 0,
+        Err(_) => return Err(NftError::Overflow),
+    };
+    release_nft(env, &auction.owner, &collection, token_id);
+    record_liquidation(env, &auction.owner);
+
+    emit_nft_event(
+        env,
+        "nft_auction_settled",
+        winner,
+        collection,
+        token_id,
+        auction.highest_bid,
+    );
+    Ok(repaid)
+}
+
+/// Get the active auction of an NFT, if any
+pub fn get_nft_auction(env: &Env, collection: &Address, token_id: u64) -> Option<NftAuction> {
+    env.storage()
+        .persistent()
+        .get(&NftDataKey::NftAuction(collection.clone(), token_id))
+}
+
+fn release_nft(env: &Env, user: &Address, collection: &Address, token_id: u64) {
+    let mut nfts = get_user_nfts(env, user);
+    let id = NftId {
+        collection: collection.clone(),
+        token_id,
+    };
+    if let Some(index) = nfts.first_index_of(&id) {
+        nfts.remove(index);
+    }
+    save_user_nfts(env, user, &nfts);
+    env.storage()
+        .persistent()
+        .remove(&NftDataKey::NftEscrow(collection.clone(), token_id));
+}
+
+fn save_collection(env: &Env, collection: &Address, config: &NftCollection) {
+    env.storage()
+        .persistent()
+        .set(&NftDataKey::NftCollection(collection.clone()), config);
+}
+
+fn save_user_nfts(env: &Env, user: &Address, nfts: &Vec<NftId>) {
+    env.storage()
+        .persistent()
+        .set(&NftDataKey::UserNfts(user.clone()), nfts);
+}
+
+fn save_auction(env: &Env, collection: &Address, token_id: u64, auction: &NftAuction) {
+    env.storage().persistent().set(
+        &NftDataKey::NftAuction(collection.clone(), token_id),
+        auction,
+    );
+}
+
+/// Map a failed debt increase onto the NFT error it surfaces as
+fn debt_error(error: BorrowError) -> NftError {
+    match error {
+        BorrowError::ProtocolPaused => NftError::ProtocolPaused,
+        BorrowError::TooManyPositions => NftError::TooManyPositions,
+        BorrowError::AccountFrozen => NftError::AccountFrozen,
+        BorrowError::BorrowCapExceeded => NftError::BorrowCapExceeded,
+        BorrowError::PriceStale => NftError::PriceStale,
+        BorrowError::MarketFrozen => NftError::MarketFrozen,
+        BorrowError::AssetBorrowsPaused => NftError::AssetBorrowsPaused,
+        BorrowError::AssetDelisted => NftError::AssetDelisted,
+        BorrowError::BorrowFrozen => NftError::BorrowFrozen,
+        BorrowError::AssetCollateralOnly => NftError::AssetCollateralOnly,
+        BorrowError::DebtCeilingReached => NftError::DebtCeilingReached,
+        BorrowError::LiquidityBufferReached => NftError::LiquidityBufferReached,
+        BorrowError::RehypothecationDisabled => NftError::RehypothecationDisabled,
+        BorrowError::LargeBorrowRequestRequired => NftError::LargeBorrowRequestRequired,
+        BorrowError::BorrowSizeCapExceeded => NftError::BorrowSizeCapExceeded,
+        BorrowError::Overflow => NftError::Overflow,
+        BorrowError::InvalidAmount => NftError::InvalidAmount,
+        BorrowError::BelowMinimumBorrow => NftError::BelowMinimumBorrow,
+        BorrowError::Unauthorized => NftError::Unauthorized,
+        BorrowError::AssetBorrowOnly => NftError::InsufficientCollateral,
+        // Collateral-side and request errors are never returned by the debt check
+        BorrowError::InsufficientCollateral
+        | BorrowError::AssetNotSupported
+        | BorrowError::ExposureCapReached
+        | BorrowError::BelowMinimumCollateral
+        | BorrowError::CollateralFrozen => NftError::InsufficientCollateral,
+        BorrowError::InvalidConfig
+        | BorrowError::NoPendingBorrow
+        | BorrowError::BorrowDelayActive
+        | BorrowError::PendingBorrowExists => NftError::InvalidConfig,
+    }
+}
+
+fn emit_nft_event(
+    env: &Env,
+    name: &str,
+    user: Address,
+    collection: Address,
+    token_id: u64,
+    amount: i128,
+) {
+    let event = NftEvent {
+        user,
+        collection,
+        token_id,
+        amount,
+        timestamp: env.ledger().timestamp(),
+    };
+    crate::events::publish(
+        env,
+        (
+            Symbol::new(env, name),
+            event.user.clone(),
+            event.collection.clone(),
+        ),
+        event,
+    );
+}
@@ -0,0 +1,209 @@
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env, Symbol,
+};
+
+const DAY: u64 = 86400;
+
+fn setup(env: &Env) -> (LendingContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 0);
+
+    let contract_id = env.register(LendingContract, ());
+    let client = LendingContractClient::new(env, &contract_id);
+    client.initialize(
+        &Address::generate(env),
+        &InitConfig::new(env, 1_000_000_000, 1000),
+    );
+
+    let admin = Address::generate(env);
+    client.set_admin(&admin);
+
+    // 50% LTV on a 100,000 floor: 50,000 of collateral value per token
+    let collection = Address::generate(env);
+    client.approve_nft_collection(&admin, &collection, &5000);
+    client.set_nft_floor(&admin, &collection, &100_000);
+    (client, admin, collection)
+}
+
+#[test]
+fn test_escrow_and_borrow_against_nft() {
+    let env = Env::default();
+    let (client, _admin, collection) = setup(&env);
+    let user = Address::generate(&env);
+    let asset = Address::generate(&env);
+
+    client.escrow_nft(&user, &collection, &1);
+    assert_eq!(client.get_nft_owner(&collection, &1), Some(user.clone()));
+    assert_eq!(client.get_nft_collateral_value(&user), 50_000);
+
+    let result = client.try_nft_borrow(&user, &asset, &33_334);
+    assert_eq!(result, Err(Ok(NftError::InsufficientCollateral)));
+
+    client.nft_borrow(&user, &asset, &33_333);
+    assert_eq!(client.get_user_debt(&user).borrowed_amount, 33_333);
+
+    let other = Address::generate(&env);
+    let result = client.try_escrow_nft(&other, &collection, &1);
+    assert_eq!(result, Err(Ok(NftError::AlreadyEscrowed)));
+}
+
+#[test]
+fn test_withdraw_nft_requires_coverage() {
+    let env = Env::default();
+    let (client, _admin, collection) = setup(&env);
+    let user = Address::generate(&env);
+    let asset = Address::generate(&env);
+
+    client.escrow_nft(&user, &collection, &1);
+    client.escrow_nft(&user, &collection, &2);
+    client.nft_borrow(&user, &asset, &30_000);
+
+    // One token still covers 30,000 at 150%; removing both would not
+    client.withdraw_nft(&user, &collection, &2);
+    let result = client.try_withdraw_nft(&user, &collection, &1);
+    assert_eq!(result, Err(Ok(NftError::InsufficientCollateral)));
+    assert_eq!(client.get_user_nfts(&user).len(), 1);
+}
+
+#[test]
+fn test_unapproved_collection_rejected() {
+    let env = Env::default();
+    let (client, admin, _collection) = setup(&env);
+    let user = Address::generate(&env);
+    let unknown = Address::generate(&env);
+
+    let result = client.try_escrow_nft(&user, &unknown, &1);
+    assert_eq!(result, Err(Ok(NftError::CollectionNotApproved)));
+
+    let result = client.try_approve_nft_collection(&admin, &unknown, &7001);
+    assert_eq!(result, Err(Ok(NftError::InvalidConfig)));
+
+    let result = client.try_approve_nft_collection(&user, &unknown, &5000);
+    assert_eq!(result, Err(Ok(NftError::Unauthorized)));
+}
+
+#[test]
+fn test_auction_liquidates_undercollateralized_nft() {
+    let env = Env::default();
+    let (client, admin, collection) = setup(&env);
+    let user = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let bidder = Address::generate(&env);
+    let rival = Address::generate(&env);
+
+    client.escrow_nft(&user, &collection, &1);
+    client.nft_borrow(&user, &asset, &30_000);
+
+    let result = client.try_start_nft_auction(&collection, &1);
+    assert_eq!(result, Err(Ok(NftError::PositionHealthy)));
+
+    // Floor falls to 60,000: 30,000 of value against 30,000 of debt
+    client.set_nft_floor(&admin, &collection, &60_000);
+    client.start_nft_auction(&collection, &1);
+    assert_eq!(
+        client
+            .get_nft_auction(&collection, &1)
+            .unwrap()
+            .reserve_price,
+        30_000
+    );
+
+    let result = client.try_bid_nft_auction(&bidder, &collection, &1, &29_999);
+    assert_eq!(result, Err(Ok(NftError::BidTooLow)));
+    client.bid_nft_auction(&bidder, &collection, &1, &35_000);
+
+    // Next bid must be at least 5% higher
+    let result = client.try_bid_nft_auction(&rival, &collection, &1, &36_749);
+    assert_eq!(result, Err(Ok(NftError::BidTooLow)));
+    client.bid_nft_auction(&rival, &collection, &1, &36_750);
+
+    let result = client.try_withdraw_nft(&user, &collection, &1);
+    assert_eq!(result, Err(Ok(NftError::AuctionActive)));
+
+    let result = client.try_settle_nft_auction(&collection, &1);
+    assert_eq!(result, Err(Ok(NftError::AuctionNotEnded)));
+
+    env.ledger().with_mut(|li| li.timestamp = DAY);
+
+    // Winning bid covers principal plus one day of interest (rounded up)
+    assert_eq!(client.settle_nft_auction(&collection, &1), 30_005);
+    let debt = client.get_user_debt(&user);
+    assert_eq!(debt.borrowed_amount + debt.interest_accrued, 0);
+    assert_eq!(client.get_nft_owner(&collection, &1), None);
+    assert_eq!(client.get_user_nfts(&user).len(), 0);
+}
+
+#[test]
+fn test_late_bid_extends_auction() {
+    let env = Env::default();
+    let (client, admin, collection) = setup(&env);
+    let user = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let bidder = Address::generate(&env);
+
+    client.escrow_nft(&user, &collection, &1);
+    client.nft_borrow(&user, &asset, &30_000);
+    client.set_nft_floor(&admin, &collection, &60_000);
+    client.start_nft_auction(&collection, &1);
+
+    env.ledger().with_mut(|li| li.timestamp = DAY - 60);
+    client.bid_nft_auction(&bidder, &collection, &1, &31_000);
+    assert_eq!(
+        client.get_nft_auction(&collection, &1).unwrap().end,
+        DAY + 540
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = DAY);
+    let result = client.try_settle_nft_auction(&collection, &1);
+    assert_eq!(result, Err(Ok(NftError::AuctionNotEnded)));
+}
+
+#[test]
+fn test_committed_floor_blocks_same_window_liquidation() {
+    let env = Env::default();
+    let (client, admin, collection) = setup(&env);
+    let user = Address::generate(&env);
+    let other = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let collateral = Address::generate(&env);
+
+    client.escrow_nft(&user, &collection, &1);
+    client.nft_borrow(&user, &asset, &30_000);
+    client.set_committed_price_mode(&admin, &true);
+
+    // Any pool activity checkpoints accrual, committing the 100,000 floor
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    client.borrow(&other, &asset, &1000, &collateral, &3000);
+
+    // A floor pushed since the checkpoint cannot be liquidated against yet
+    client.set_nft_floor(&admin, &collection, &60_000);
+    assert_eq!(
+        client.try_start_nft_auction(&collection, &1),
+        Err(Ok(NftError::PositionHealthy))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 200);
+    client.borrow(&other, &asset, &1000, &collateral, &3000);
+    client.start_nft_auction(&collection, &1);
+}
+
+#[test]
+fn test_nft_borrow_reports_asset_restrictions() {
+    let env = Env::default();
+    let (client, admin, collection) = setup(&env);
+    let user = Address::generate(&env);
+    let asset = Address::generate(&env);
+    client.escrow_nft(&user, &collection, &1);
+
+    client.set_asset_freeze(&admin, &asset, &FREEZE_BORROW);
+    let result = client.try_nft_borrow(&user, &asset, &10_000);
+    assert_eq!(result, Err(Ok(NftError::BorrowFrozen)));
+
+    client.set_asset_freeze(&admin, &asset, &0);
+    client.set_compliance_officer(&admin, &admin);
+    client.freeze_account(&admin, &user, &Symbol::new(&env, "sanctions"));
+    let result = client.try_nft_borrow(&user, &asset, &10_000);
+    assert_eq!(result, Err(Ok(NftError::AccountFrozen)));
+}
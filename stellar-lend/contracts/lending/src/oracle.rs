@@ -0,0 +1,841 @@
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol, Vec};
+
+use crate::admin::{require_admin, require_guardian, require_risk_manager};
+use crate::math::{mul_div_down, mul_div_up};
+
+/// Errors that can occur when updating prices
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum OracleError {
+    Unauthorized = 1,
+    InvalidPrice = 2,
+    InvalidDuration = 3,
+    NoOverride = 4,
+    TimelockActive = 5,
+    InvalidConfig = 6,
+    PriceDeviation = 7,
+}
+
+/// Storage keys for price data
+#[contracttype]
+#[derive(Clone)]
+pub enum OracleDataKey {
+    AssetPrice(Address),
+    OracleFeedConfig(Address),
+    StalePricePolicy,
+    CommittedPriceMode,
+    PendingPriceOverride(Address),
+    PriceOverride(Address),
+    DeviationGuard(Address),
+    /// Price at the start of the current heartbeat window
+    PriceReference(Address),
+    MarketFrozenUntil(Address),
+    PriceFeedSet(Address),
+    /// Latest report of one feed in an asset's feed set
+    FeedReport(Address, Address),
+    /// Every asset that has been priced, snapshotted on shutdown
+    PricedAssets,
+}
+
+/// Latest reported price of an asset
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PriceData {
+    /// Value of one unit of the asset, scaled by `PRICE_SCALE`
+    pub price: i128,
+    pub timestamp: u64,
+}
+
+/// Price update event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct PriceEvent {
+    pub asset: Address,
+    pub price: i128,
+    pub timestamp: u64,
+}
+
+/// Price feed of an asset in the asset registry
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OracleFeedConfig {
+    /// Address allowed to report the asset's price besides the risk manager
+    pub feed: Address,
+    /// Currency the feed quotes the asset in
+    pub quote: Symbol,
+    /// Decimals of reported prices; normalized to `PRICE_SCALE` on update
+    pub decimals: u32,
+    /// Maximum price age in seconds before the market freezes (0 = unchecked)
+    pub heartbeat: u64,
+    /// Maximum move from the previous price per update, in basis points
+    /// (0 = unbounded)
+    pub max_deviation_bps: i128,
+}
+
+/// Operations still allowed on a market frozen by a stale price
+///
+/// New borrows and withdrawals are always blocked. Both flags default to true,
+/// so liquidations proceed with the last good price.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct StalePricePolicy {
+    pub allow_repay: bool,
+    pub allow_liquidation: bool,
+}
+
+/// Manually pinned price used while an asset's oracles are down
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PriceOverride {
+    pub price: i128,
+    /// Seconds the override stays in force once confirmed
+    pub duration: u64,
+    /// Earliest confirmation time of a proposed override
+    pub executable_at: u64,
+    /// Expiry of a confirmed override (zero while pending)
+    pub expires_at: u64,
+}
+
+/// Price override event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct PriceOverrideEvent {
+    pub asset: Address,
+    pub price: i128,
+    pub caller: Address,
+    pub expires_at: u64,
+    pub timestamp: u64,
+}
+
+/// Circuit breaker on abnormal price moves
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeviationGuard {
+    /// Move within one heartbeat, in basis points, that freezes the market
+    pub threshold_bps: i128,
+    /// Seconds borrows and liquidations stay frozen after a trip
+    pub cooldown: u64,
+}
+
+/// Price deviation alert event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct PriceDeviationAlert {
+    pub asset: Address,
+    pub reference_price: i128,
+    pub price: i128,
+    pub moved_bps: i128,
+    pub frozen_until: u64,
+    pub timestamp: u64,
+}
+
+/// Independent feeds aggregated into an asset's price
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PriceFeedSet {
+    pub feeds: Vec<Address>,
+    /// Reports needed after outlier rejection before the price updates
+    pub min_feeds: u32,
+    /// Distance from the median, in basis points, beyond which a report is
+    /// discarded
+    pub max_outlier_bps: i128,
+}
+
+/// Fixed-point scale of reported prices (7 decimals)
+pub const PRICE_SCALE: i128 = 10_000_000;
+
+const PRICE_OVERRIDE_TIMELOCK: u64 = 3600; // 1 hour
+const MAX_PRICE_OVERRIDE_DURATION: u64 = 86400; // 1 day
+const MAX_FEED_DECIMALS: u32 = 18;
+const MAX_DEVIATION_COOLDOWN: u64 = 7 * 86400; // 7 days
+pub const MAX_FEEDS_PER_ASSET: u32 = 5;
+
+/// Report the price of an asset (its feed, the risk manager or admin)
+///
+/// Assets with a feed configuration take prices in the feed's decimals, and
+/// updates moving further than its deviation bound are rejected.
+pub fn set_asset_price(
+    env: &Env,
+    caller: Address,
+    asset: Address,
+    price: i128,
+) -> Result<(), OracleError> {
+    let config = get_oracle_feed_config(env, &asset);
+    // Assets with a feed set are priced by the median of its reports
+    if config.as_ref().map(|config| &config.feed) == Some(&caller)
+        && get_price_feed_set(env, &asset).is_none()
+    {
+        caller.require_auth();
+    } else {
+        require_risk_manager(env, &caller).map_err(|_| OracleError::Unauthorized)?;
+    }
+
+    apply_asset_price(env, asset, price)
+}
+
+/// Normalize and store a reported price; callers check authorization
+pub(crate) fn apply_asset_price(env: &Env, asset: Address, price: i128) -> Result<(), OracleError> {
+    if price <= 0 {
+        return Err(OracleError::InvalidPrice);
+    }
+    let config = get_oracle_feed_config(env, &asset);
+    let price = match &config {
+        Some(config) => normalize_price(price, config.decimals),
+        None => price,
+    };
+    record_price(env, asset, price, config.as_ref())
+}
+
+/// Validate a normalized price against the feed bounds and store it
+fn record_price(
+    env: &Env,
+    asset: Address,
+    price: i128,
+    config: Option<&OracleFeedConfig>,
+) -> Result<(), OracleError> {
+    if price <= 0 {
+        return Err(OracleError::InvalidPrice);
+    }
+    if let (Some(config), Some(previous)) = (config, get_asset_price(env, &asset)) {
+        let moved = price.abs_diff(previous.price) as i128;
+        if config.max_deviation_bps > 0
+            && moved.saturating_mul(10000) > previous.price.saturating_mul(config.max_deviation_bps)
+        {
+            return Err(OracleError::PriceDeviation);
+        }
+    }
+    let recovered = is_price_stale(env, &asset);
+    let timestamp = env.ledger().timestamp();
+    env.storage().persistent().set(
+        &OracleDataKey::AssetPrice(asset.clone()),
+        &PriceData { price, timestamp },
+    );
+    track_priced_asset(env, &asset);
+    check_deviation(env, &asset, price);
+
+    if recovered {
+        crate::events::publish(
+            env,
+            (Symbol::new(env, "price_feed_recovered"), asset.clone()),
+            asset.clone(),
+        );
+    }
+    let event = PriceEvent {
+        asset,
+        price,
+        timestamp,
+    };
+    crate::events::publish(
+        env,
+        (Symbol::new(env, "asset_price_updated"), event.asset.clone()),
+        event,
+    );
+    Ok(())
+}
+
+/// Register or update the price feed of an asset (risk manager or admin)
+///
+/// Once no price has been reported for the feed's heartbeat, new borrows and
+/// withdrawals against the asset are rejected until the feed recovers.
+pub fn set_oracle_feed_config(
+    env: &Env,
+    caller: Address,
+    asset: Address,
+    config: OracleFeedConfig,
+) -> Result<(), OracleError> {
+    require_risk_manager(env, &caller).map_err(|_| OracleError::Unauthorized)?;
+    apply_oracle_feed_config(env, asset, config)
+}
+
+/// Validate and store an asset's price feed; callers check authorization
+pub(crate) fn apply_oracle_feed_config(
+    env: &Env,
+    asset: Address,
+    config: OracleFeedConfig,
+) -> Result<(), OracleError> {
+    if config.decimals > MAX_FEED_DECIMALS || !(0..=10000).contains(&config.max_deviation_bps) {
+        return Err(OracleError::InvalidConfig);
+    }
+    env.storage()
+        .persistent()
+        .set(&OracleDataKey::OracleFeedConfig(asset.clone()), &config);
+
+    crate::events::publish(env, (Symbol::new(env, "oracle_feed_set"), asset), config);
+    Ok(())
+}
+
+/// Get the price feed configuration of an asset, if registered
+pub fn get_oracle_feed_config(env: &Env, asset: &Address) -> Option<OracleFeedConfig> {
+    env.storage()
+        .persistent()
+        .get(&OracleDataKey::OracleFeedConfig(asset.clone()))
+}
+
+/// Get the heartbeat of an asset's price feed in seconds (0 if unchecked)
+pub fn get_price_heartbeat(env: &Env, asset: &Address) -> u64 {
+    get_oracle_feed_config(env, asset)
+        .map(|config| config.heartbeat)
+        .unwrap_or(0)
+}
+
+/// Register the independent feeds of an asset (risk manager or admin)
+///
+/// Once set, the asset's price is the median of the fresh feed reports after
+/// discarding those more than `max_outlier_bps` away from the median. An
+/// empty feed list removes the set.
+pub fn set_price_feed_set(
+    env: &Env,
+    caller: Address,
+    asset: Address,
+    feed_set: PriceFeedSet,
+) -> Result<(), OracleError> {
+    require_risk_manager(env, &caller).map_err(|_| OracleError::Unauthorized)?;
+
+    let key = OracleDataKey::PriceFeedSet(asset.clone());
+    if let Some(previous) = get_price_feed_set(env, &asset) {
+        for feed in previous.feeds.iter() {
+            env.storage()
+                .persistent()
+                .remove(&OracleDataKey::FeedReport(asset.clone(), feed));
+        }
+    }
+    if feed_set.feeds.is_empty() {
+        env.storage().persistent().remove(&key);
+        return Ok(());
+    }
+
+    let count = feed_set.feeds.len();
+    if count > MAX_FEEDS_PER_ASSET
+        || feed_set.min_feeds == 0
+        || feed_set.min_feeds > count
+        || !(1..=10000).contains(&feed_set.max_outlier_bps)
+    {
+        return Err(OracleError::InvalidConfig);
+    }
+    for (i, feed) in feed_set.feeds.iter().enumerate() {
+        if feed_set.feeds.first_index_of(&feed) != Some(i as u32) {
+            return Err(OracleError::InvalidConfig);
+        }
+    }
+    env.storage().persistent().set(&key, &feed_set);
+
+    crate::events::publish(env, (Symbol::new(env, "price_feed_set"), asset), feed_set);
+    Ok(())
+}
+
+/// Get the feed set of an asset, if registered
+pub fn get_price_feed_set(env: &Env, asset: &Address) -> Option<PriceFeedSet> {
+    env.storage()
+        .persistent()
+        .get(&OracleDataKey::PriceFeedSet(asset.clone()))
+}
+
+/// Get the latest report of one feed of an asset
+pub fn get_feed_report(env: &Env, asset: &Address, feed: &Address) -> Option<PriceData> {
+    env.storage()
+        .persistent()
+        .get(&OracleDataKey::FeedReport(asset.clone(), feed.clone()))
+}
+
+/// Report a price from one feed of an asset's feed set
+///
+/// The asset price is re-aggregated on every report and updated once enough
+/// fresh reports agree; prices use the decimals of the asset's feed config.
+pub fn submit_feed_price(
+    env: &Env,
+    feed: Address,
+    asset: Address,
+    price: i128,
+) -> Result<(), OracleError> {
+    feed.require_auth();
+
+    let feed_set = get_price_feed_set(env, &asset).ok_or(OracleError::Unauthorized)?;
+    if !feed_set.feeds.contains(&feed) {
+        return Err(OracleError::Unauthorized);
+    }
+    if price <= 0 {
+        return Err(OracleError::InvalidPrice);
+    }
+    let config = get_oracle_feed_config(env, &asset);
+    let price = match &config {
+        Some(config) => normalize_price(price, config.decimals),
+        None => price,
+    };
+    if price <= 0 {
+        return Err(OracleError::InvalidPrice);
+    }
+    let now = env.ledger().timestamp();
+    env.storage().persistent().set(
+        &OracleDataKey::FeedReport(asset.clone(), feed),
+        &PriceData {
+            price,
+            timestamp: now,
+        },
+    );
+
+    match aggregate_feed_reports(env, &asset, &feed_set) {
+        Some(median) => record_price(env, asset, median, config.as_ref()),
+        None => Ok(()),
+    }
+}
+
+/// Median of the fresh reports after outlier rejection, if enough remain
+fn aggregate_feed_reports(env: &Env, asset: &Address, feed_set: &PriceFeedSet) -> Option<i128> {
+    let now = env.ledger().timestamp();
+    let heartbeat = get_price_heartbeat(env, asset);
+    let mut prices = Vec::new(env);
+    for feed in feed_set.feeds.iter() {
+        if let Some(report) = get_feed_report(env, asset, &feed) {
+            if heartbeat == 0 || now.saturating_sub(report.timestamp) <= heartbeat {
+                prices.push_back(report.price);
+            }
+        }
+    }
+    let reference = median(env, &prices)?;
+
+    let mut accepted = Vec::new(env);
+    for price in prices.iter() {
+        let distance = price.abs_diff(reference) as i128;
+        if distance.saturating_mul(10000) <= reference.saturating_mul(feed_set.max_outlier_bps) {
+            accepted.push_back(price);
+        }
+    }
+    if accepted.len() < feed_set.min_feeds {
+        return None;
+    }
+    median(env, &accepted)
+}
+
+fn median(env: &Env, prices: &Vec<i128>) -> Option<i128> {
+    let mut sorted = Vec::new(env);
+    for price in prices.iter() {
+        let index = sorted
+            .iter()
+            .position(|p| p > price)
+            .unwrap_or(sorted.len() as usize);
+        sorted.insert(index as u32, price);
+    }
+    let count = sorted.len();
+    if count == 0 {
+        return None;
+    }
+    let upper = sorted.get_unchecked(count / 2);
+    if count % 2 == 1 {
+        return Some(upper);
+    }
+    let lower = sorted.get_unchecked(count / 2 - 1);
+    Some(lower + (upper - lower) / 2)
+}
+
+/// Configure the deviation circuit breaker of an asset (risk manager or admin)
+///
+/// When a reported price moves more than `threshold_bps` from the price at
+/// the start of the current heartbeat window (or from the previous price, for
+/// feeds without a heartbeat), new borrows and liquidations in the market are
+/// frozen for `cooldown` seconds and an alert is emitted.
+pub fn set_deviation_guard(
+    env: &Env,
+    caller: Address,
+    asset: Address,
+    guard: DeviationGuard,
+) -> Result<(), OracleError> {
+    require_risk_manager(env, &caller).map_err(|_| OracleError::Unauthorized)?;
+
+    if !(1..=10000).contains(&guard.threshold_bps)
+        || guard.cooldown == 0
+        || guard.cooldown > MAX_DEVIATION_COOLDOWN
+    {
+        return Err(OracleError::InvalidConfig);
+    }
+    env.storage()
+        .persistent()
+        .set(&OracleDataKey::DeviationGuard(asset), &guard);
+    Ok(())
+}
+
+/// Get the deviation circuit breaker of an asset, if configured
+pub fn get_deviation_guard(env: &Env, asset: &Address) -> Option<DeviationGuard> {
+    env.storage()
+        .persistent()
+        .get(&OracleDataKey::DeviationGuard(asset.clone()))
+}
+
+/// End of the current deviation freeze of a market (0 if never frozen)
+pub fn get_market_frozen_until(env: &Env, asset: &Address) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&OracleDataKey::MarketFrozenUntil(asset.clone()))
+        .unwrap_or(0)
+}
+
+/// Whether borrows and liquidations in a market are frozen by a price deviation
+pub fn is_market_frozen(env: &Env, asset: &Address) -> bool {
+    env.ledger().timestamp() < get_market_frozen_until(env, asset)
+}
+
+/// Trip the deviation circuit breaker if a new price moved too far
+fn check_deviation(env: &Env, asset: &Address, price: i128) {
+    let guard = match get_deviation_guard(env, asset) {
+        Some(guard) => guard,
+        None => return,
+    };
+    let now = env.ledger().timestamp();
+    let window = get_price_heartbeat(env, asset);
+    let reference_key = OracleDataKey::PriceReference(asset.clone());
+    let reference: Option<PriceData> = env.storage().persistent().get(&reference_key);
+    let new_reference = PriceData {
+        price,
+        timestamp: now,
+    };
+
+    let reference = match reference {
+        Some(reference) if now.saturating_sub(reference.timestamp) <= window => reference,
+        stale => {
+            // A new window starts; feeds without a heartbeat compare against
+            // the previous price
+            env.storage()
+                .persistent()
+                .set(&reference_key, &new_reference);
+            match stale {
+                Some(previous) if window == 0 => previous,
+                _ => return,
+            }
+        }
+    };
+
+    let moved_bps = mul_div_down(
+        price.abs_diff(reference.price) as i128,
+        10000,
+        reference.price,
+    );
+    if moved_bps <= guard.threshold_bps {
+        return;
+    }
+
+    let frozen_until = now.saturating_add(guard.cooldown);
+    env.storage().persistent().set(
+        &OracleDataKey::MarketFrozenUntil(asset.clone()),
+        &frozen_until,
+    );
+    env.storage()
+        .persistent()
+        .set(&reference_key, &new_reference);
+
+    let alert = PriceDeviationAlert {
+        asset: asset.clone(),
+        reference_price: reference.price,
+        price,
+        moved_bps,
+        frozen_until,
+        timestamp: now,
+    };
+    crate::events::publish(
+        env,
+        (
+            Symbol::new(env, "price_deviation_alert"),
+            alert.asset.clone(),
+        ),
+        alert,
+    );
+}
+
+/// Set which operations remain open while a market is frozen (risk manager or admin)
+pub fn set_stale_price_policy(
+    env: &Env,
+    caller: Address,
+    policy: StalePricePolicy,
+) -> Result<(), OracleError> {
+    require_risk_manager(env, &caller).map_err(|_| OracleError::Unauthorized)?;
+    env.storage()
+        .persistent()
+        .set(&OracleDataKey::StalePricePolicy, &policy);
+    Ok(())
+}
+
+/// Get the stale price policy
+pub fn get_stale_price_policy(env: &Env) -> StalePricePolicy {
+    env.storage()
+        .persistent()
+        .get(&OracleDataKey::StalePricePolicy)
+        .unwrap_or(StalePricePolicy {
+            allow_repay: true,
+            allow_liquidation: true,
+        })
+}
+
+/// Evaluate liquidation eligibility against committed prices (risk manager or admin)
+///
+/// When enabled, a position may only be liquidated if it is also unhealthy
+/// at the prices observed at the previous accrual checkpoint, so a price
+/// pushed in the same window cannot be liquidated against immediately.
+pub fn set_committed_price_mode(
+    env: &Env,
+    caller: Address,
+    enabled: bool,
+) -> Result<(), OracleError> {
+    require_risk_manager(env, &caller).map_err(|_| OracleError::Unauthorized)?;
+
+    env.storage()
+        .persistent()
+        .set(&OracleDataKey::CommittedPriceMode, &enabled);
+    Ok(())
+}
+
+/// Whether liquidation eligibility is evaluated against committed prices
+pub fn get_committed_price_mode(env: &Env) -> bool {
+    env.storage()
+        .persistent()
+        .get(&OracleDataKey::CommittedPriceMode)
+        .unwrap_or(false)
+}
+
+/// Propose a temporary manual price for an asset (guardian only)
+///
+/// Meant for outages of every oracle of the asset. The override needs the
+/// admin's confirmation after a timelock and expires `duration` seconds later.
+pub fn propose_price_override(
+    env: &Env,
+    guardian: Address,
+    asset: Address,
+    price: i128,
+    duration: u64,
+) -> Result<(), OracleError> {
+    require_guardian(env, &guardian).map_err(|_| OracleError::Unauthorized)?;
+
+    if price <= 0 {
+        return Err(OracleError::InvalidPrice);
+    }
+    if duration == 0 || duration > MAX_PRICE_OVERRIDE_DURATION {
+        return Err(OracleError::InvalidDuration);
+    }
+
+    let executable_at = env
+        .ledger()
+        .timestamp()
+        .saturating_add(PRICE_OVERRIDE_TIMELOCK);
+    env.storage().persistent().set(
+        &OracleDataKey::PendingPriceOverride(asset.clone()),
+        &PriceOverride {
+            price,
+            duration,
+            executable_at,
+            expires_at: 0,
+        },
+    );
+
+    emit_override_event(env, "price_override_proposed", asset, price, guardian, 0);
+    Ok(())
+}
+
+/// Confirm a proposed price override once its timelock has passed (admin only)
+pub fn confirm_price_override(
+    env: &Env,
+    caller: Address,
+    asset: Address,
+) -> Result<(), OracleError> {
+    require_admin(env, &caller).map_err(|_| OracleError::Unauthorized)?;
+
+    let pending_key = OracleDataKey::PendingPriceOverride(asset.clone());
+    let mut pending: PriceOverride = env
+        .storage()
+        .persistent()
+        .get(&pending_key)
+        .ok_or(OracleError::NoOverride)?;
+    let now = env.ledger().timestamp();
+    if now < pending.executable_at {
+        return Err(OracleError::TimelockActive);
+    }
+
+    pending.expires_at = now.saturating_add(pending.duration);
+    env.storage().persistent().remove(&pending_key);
+    env.storage()
+        .persistent()
+        .set(&OracleDataKey::PriceOverride(asset.clone()), &pending);
+    track_priced_asset(env, &asset);
+
+    emit_override_event(
+        env,
+        "price_override_activated",
+        asset,
+        pending.price,
+        caller,
+        pending.expires_at,
+    );
+    Ok(())
+}
+
+/// Cancel a pending or active price override (guardian or admin)
+pub fn cancel_price_override(
+    env: &Env,
+    caller: Address,
+    asset: Address,
+) -> Result<(), OracleError> {
+    require_guardian(env, &caller)
+        .or_else(|_| require_admin(env, &caller))
+        .map_err(|_| OracleError::Unauthorized)?;
+
+    let pending_key = OracleDataKey::PendingPriceOverride(asset.clone());
+    let active_key = OracleDataKey::PriceOverride(asset.clone());
+    if !env.storage().persistent().has(&pending_key) && !env.storage().persistent().has(&active_key)
+    {
+        return Err(OracleError::NoOverride);
+    }
+    env.storage().persistent().remove(&pending_key);
+    env.storage().persistent().remove(&active_key);
+
+    emit_override_event(env, "price_override_cancelled", asset, 0, caller, 0);
+    Ok(())
+}
+
+/// Get the proposed price override of an asset awaiting confirmation, if any
+pub fn get_pending_price_override(env: &Env, asset: &Address) -> Option<PriceOverride> {
+    env.storage()
+        .persistent()
+        .get(&OracleDataKey::PendingPriceOverride(asset.clone()))
+}
+
+/// Get the price override in force for an asset, if any
+pub fn get_price_override(env: &Env, asset: &Address) -> Option<PriceOverride> {
+    env.storage()
+        .persistent()
+        .get::<_, PriceOverride>(&OracleDataKey::PriceOverride(asset.clone()))
+        .filter(|pinned| env.ledger().timestamp() < pinned.expires_at)
+}
+
+/// Price the protocol values an asset at
+///
+/// An active override takes precedence over the reported price until it
+/// expires or is cancelled. Registered LP tokens are priced from their pool
+/// and yield-bearing tokens from their underlying and exchange rate.
+/// After an emergency shutdown every asset is valued at its settlement
+/// snapshot.
+pub fn get_effective_price(env: &Env, asset: &Address) -> Option<PriceData> {
+    if crate::shutdown::is_shutdown(env) {
+        return crate::shutdown::get_settlement_price(env, asset);
+    }
+    if let Some(pinned) = get_price_override(env, asset) {
+        return Some(PriceData {
+            price: pinned.price,
+            timestamp: env.ledger().timestamp(),
+        });
+    }
+    if crate::lp_pricing::get_lp_token_config(env, asset).is_some() {
+        return crate::lp_pricing::get_lp_token_price(env, asset).map(|price| PriceData {
+            price,
+            timestamp: env.ledger().timestamp(),
+        });
+    }
+    if let Some(config) = crate::yield_collateral::get_yield_token_config(env, asset) {
+        return crate::yield_collateral::get_yield_token_price(env, &config, asset).map(|price| {
+            PriceData {
+                price,
+                timestamp: env.ledger().timestamp(),
+            }
+        });
+    }
+    get_asset_price(env, asset)
+}
+
+/// Whether an asset's market is frozen because its price missed the heartbeat
+///
+/// Never true while a price override is in force. LP tokens are stale when
+/// either underlying price is, yield-bearing tokens when their underlying's is.
+pub fn is_price_stale(env: &Env, asset: &Address) -> bool {
+    if crate::shutdown::is_shutdown(env) || get_price_override(env, asset).is_some() {
+        return false;
+    }
+    if let Some(config) = crate::lp_pricing::get_lp_token_config(env, asset) {
+        return crate::lp_pricing::is_lp_price_stale(env, &config);
+    }
+    if let Some(config) = crate::yield_collateral::get_yield_token_config(env, asset) {
+        return crate::yield_collateral::is_yield_price_stale(env, &config);
+    }
+    let heartbeat = get_price_heartbeat(env, asset);
+    if heartbeat == 0 {
+        return false;
+    }
+    match get_asset_price(env, asset) {
+        Some(data) => env.ledger().timestamp().saturating_sub(data.timestamp) > heartbeat,
+        None => true,
+    }
+}
+
+/// Whether repayments of debt in `asset` are blocked by a stale price
+pub(crate) fn is_repay_frozen(env: &Env, asset: &Address) -> bool {
+    is_price_stale(env, asset) && !get_stale_price_policy(env).allow_repay
+}
+
+/// Whether liquidations involving `asset` are blocked by a stale price
+pub(crate) fn is_liquidation_frozen(env: &Env, asset: &Address) -> bool {
+    is_price_stale(env, asset) && !get_stale_price_policy(env).allow_liquidation
+}
+
+/// Get the latest reported price of an asset, if any
+pub fn get_asset_price(env: &Env, asset: &Address) -> Option<PriceData> {
+    env.storage()
+        .persistent()
+        .get(&OracleDataKey::AssetPrice(asset.clone()))
+}
+
+/// Convert a price reported with `decimals` decimals to `PRICE_SCALE`
+///
+/// Rounds down; a feed with more precision than the protocol loses the excess.
+fn normalize_price(price: i128, decimals: u32) -> i128 {
+    let feed_scale = 10i128.pow(decimals);
+    mul_div_down(price, PRICE_SCALE, feed_scale)
+}
+
+/// Value of an amount of an asset at its effective price, rounded down
+///
+/// Unpriced assets are valued 1:1.
+pub(crate) fn amount_to_value(env: &Env, asset: &Address, amount: i128) -> i128 {
+    match get_effective_price(env, asset) {
+        Some(price) => mul_div_down(amount, price.price, PRICE_SCALE),
+        None => amount,
+    }
+}
+
+/// Amount of an asset worth at least `value`, rounded up
+///
+/// `None` while the asset has no price.
+pub(crate) fn value_to_amount(env: &Env, asset: &Address, value: i128) -> Option<i128> {
+    let price = get_effective_price(env, asset)?.price;
+    Some(mul_div_up(value, PRICE_SCALE, price))
+}
+
+/// Every asset that has been priced by a feed, an override or its pool
+pub(crate) fn get_priced_assets(env: &Env) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&OracleDataKey::PricedAssets)
+        .unwrap_or(Vec::new(env))
+}
+
+pub(crate) fn track_priced_asset(env: &Env, asset: &Address) {
+    let mut assets = get_priced_assets(env);
+    if !assets.contains(asset) {
+        assets.push_back(asset.clone());
+        env.storage()
+            .persistent()
+            .set(&OracleDataKey::PricedAssets, &assets);
+    }
+}
+
+fn emit_override_event(
+    env: &Env,
+    name: &str,
+    asset: Address,
+    price: i128,
+    caller: Address,
+    expires_at: u64,
+) {
+    let event = PriceOverrideEvent {
+        asset,
+        price,
+        caller,
+        expires_at,
+        timestamp: env.ledger().timestamp(),
+    };
+    crate::events::publish(env, (Symbol::new(env, name), event.asset.clone()), event);
+}
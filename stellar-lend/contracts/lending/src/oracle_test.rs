@@ -0,0 +1,411 @@
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env, Symbol, Vec,
+};
+
+fn setup(env: &Env) -> (LendingContractClient<'_>, Address) {
+    env.mock_all_auths();
+    let client = LendingContractClient::new(env, &env.register(LendingContract, ()));
+    client.initialize(
+        &Address::generate(env),
+        &InitConfig::new(env, 1_000_000_000, 1000),
+    );
+
+    let admin = Address::generate(env);
+    client.set_admin(&admin);
+    (client, admin)
+}
+
+/// Register a 7-decimal feed of `asset` with the given heartbeat
+fn set_heartbeat(
+    env: &Env,
+    client: &LendingContractClient,
+    admin: &Address,
+    asset: &Address,
+    heartbeat: u64,
+) {
+    client.set_oracle_feed_config(
+        admin,
+        asset,
+        &OracleFeedConfig {
+            feed: Address::generate(env),
+            quote: Symbol::new(env, "USD"),
+            decimals: 7,
+            heartbeat,
+            max_deviation_bps: 0,
+        },
+    );
+}
+
+#[test]
+fn test_set_asset_price() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let asset = Address::generate(&env);
+
+    assert_eq!(client.get_asset_price(&asset), None);
+    client.set_asset_price(&admin, &asset, &1_200_000);
+    assert_eq!(client.get_asset_price(&asset).unwrap().price, 1_200_000);
+
+    assert_eq!(
+        client.try_set_asset_price(&Address::generate(&env), &asset, &1),
+        Err(Ok(OracleError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_asset_price(&admin, &asset, &0),
+        Err(Ok(OracleError::InvalidPrice))
+    );
+}
+
+#[test]
+fn test_value_floor_follows_price() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let xlm = Address::generate(&env);
+    client.set_asset_min_borrow(
+        &admin,
+        &xlm,
+        &MinBorrowConfig {
+            min_amount: 100,
+            min_value: 50,
+        },
+    );
+
+    // Only the unit minimum applies until the asset is priced
+    assert_eq!(client.get_min_borrow_amount(&xlm), 100);
+
+    // At 0.12 per unit, a value of 50 takes 417 units (rounded up)
+    client.set_asset_price(&admin, &xlm, &1_200_000);
+    assert_eq!(client.get_min_borrow_amount(&xlm), 417);
+    assert_eq!(
+        client.try_borrow(
+            &Address::generate(&env),
+            &xlm,
+            &416,
+            &Address::generate(&env),
+            &1000,
+        ),
+        Err(Ok(BorrowError::BelowMinimumBorrow))
+    );
+}
+
+#[test]
+fn test_stale_price_freezes_borrows_and_withdrawals() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    let user = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let collateral = Address::generate(&env);
+
+    client.set_asset_price(&admin, &collateral, &oracle::PRICE_SCALE);
+    set_heartbeat(&env, &client, &admin, &collateral, 3600);
+    client.borrow(&user, &asset, &10_000, &collateral, &30_000);
+
+    env.ledger().with_mut(|li| li.timestamp = 1000 + 3601);
+    assert!(client.is_price_stale(&collateral));
+    assert_eq!(
+        client.try_borrow(&user, &asset, &1000, &collateral, &2000),
+        Err(Ok(BorrowError::PriceStale))
+    );
+    assert_eq!(
+        client.try_withdraw_collateral(&user, &1000),
+        Err(Ok(WithdrawError::PriceStale))
+    );
+    // Repayments stay open by default
+    client.repay(&user, &1000);
+
+    // A fresh price lifts the freeze
+    client.set_asset_price(&admin, &collateral, &oracle::PRICE_SCALE);
+    assert!(!client.is_price_stale(&collateral));
+    client.withdraw_collateral(&user, &1000);
+}
+
+#[test]
+fn test_stale_price_policy_can_block_repayments() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    let user = Address::generate(&env);
+    let asset = Address::generate(&env);
+
+    client.borrow(&user, &asset, &10_000, &Address::generate(&env), &30_000);
+    // A heartbeat on an asset that was never priced freezes it immediately
+    set_heartbeat(&env, &client, &admin, &asset, 3600);
+    assert!(client.is_price_stale(&asset));
+    client.set_stale_price_policy(
+        &admin,
+        &StalePricePolicy {
+            allow_repay: false,
+            allow_liquidation: true,
+        },
+    );
+    assert_eq!(
+        client.try_repay(&user, &1000),
+        Err(Ok(RepayError::PriceStale))
+    );
+
+    set_heartbeat(&env, &client, &admin, &asset, 0);
+    client.repay(&user, &1000);
+}
+
+#[test]
+fn test_price_override_requires_timelock_and_expires() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    let guardian = Address::generate(&env);
+    client.set_guardian(&admin, &guardian);
+    let asset = Address::generate(&env);
+    client.set_asset_price(&admin, &asset, &1_000_000);
+    set_heartbeat(&env, &client, &admin, &asset, 600);
+
+    assert_eq!(
+        client.try_propose_price_override(&admin, &asset, &900_000, &7200),
+        Err(Ok(OracleError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_propose_price_override(&guardian, &asset, &900_000, &(86400 + 1)),
+        Err(Ok(OracleError::InvalidDuration))
+    );
+    client.propose_price_override(&guardian, &asset, &900_000, &7200);
+    assert_eq!(
+        client.try_confirm_price_override(&admin, &asset),
+        Err(Ok(OracleError::TimelockActive))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 1000 + 3600);
+    assert!(client.is_price_stale(&asset));
+    assert_eq!(
+        client.try_confirm_price_override(&guardian, &asset),
+        Err(Ok(OracleError::Unauthorized))
+    );
+    client.confirm_price_override(&admin, &asset);
+    assert_eq!(client.get_effective_price(&asset).unwrap().price, 900_000);
+    assert!(!client.is_price_stale(&asset));
+    assert_eq!(client.get_pending_price_override(&asset), None);
+
+    // The override lapses and the stale feed freezes the market again
+    env.ledger()
+        .with_mut(|li| li.timestamp = 1000 + 3600 + 7200);
+    assert_eq!(client.get_price_override(&asset), None);
+    assert_eq!(client.get_effective_price(&asset).unwrap().price, 1_000_000);
+    assert!(client.is_price_stale(&asset));
+}
+
+#[test]
+fn test_cancel_price_override() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let guardian = Address::generate(&env);
+    client.set_guardian(&admin, &guardian);
+    let asset = Address::generate(&env);
+
+    assert_eq!(
+        client.try_cancel_price_override(&guardian, &asset),
+        Err(Ok(OracleError::NoOverride))
+    );
+    client.propose_price_override(&guardian, &asset, &900_000, &7200);
+    client.cancel_price_override(&guardian, &asset);
+    assert_eq!(client.get_pending_price_override(&asset), None);
+    assert_eq!(
+        client.try_confirm_price_override(&admin, &asset),
+        Err(Ok(OracleError::NoOverride))
+    );
+}
+
+#[test]
+fn test_feed_reports_in_its_decimals_within_deviation_bound() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let asset = Address::generate(&env);
+    let feed = Address::generate(&env);
+    let config = OracleFeedConfig {
+        feed: feed.clone(),
+        quote: Symbol::new(&env, "USD"),
+        decimals: 8,
+        heartbeat: 3600,
+        max_deviation_bps: 1000,
+    };
+    client.set_oracle_feed_config(&admin, &asset, &config);
+    assert_eq!(client.get_oracle_feed_config(&asset), Some(config.clone()));
+    assert_eq!(client.get_price_heartbeat(&asset), 3600);
+
+    // 1.5 with 8 decimals is stored with 7
+    client.set_asset_price(&feed, &asset, &150_000_000);
+    assert_eq!(client.get_asset_price(&asset).unwrap().price, 15_000_000);
+
+    // A 10% move passes, a larger one is rejected
+    client.set_asset_price(&feed, &asset, &165_000_000);
+    assert_eq!(
+        client.try_set_asset_price(&feed, &asset, &182_000_000),
+        Err(Ok(OracleError::PriceDeviation))
+    );
+    assert_eq!(
+        client.try_set_asset_price(&Address::generate(&env), &asset, &165_000_000),
+        Err(Ok(OracleError::Unauthorized))
+    );
+
+    let invalid = OracleFeedConfig {
+        decimals: 19,
+        ..config
+    };
+    assert_eq!(
+        client.try_set_oracle_feed_config(&admin, &asset, &invalid),
+        Err(Ok(OracleError::InvalidConfig))
+    );
+}
+
+#[test]
+fn test_price_deviation_freezes_market_for_cooldown() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    let user = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let collateral = Address::generate(&env);
+
+    set_heartbeat(&env, &client, &admin, &collateral, 3600);
+    client.set_deviation_guard(
+        &admin,
+        &collateral,
+        &DeviationGuard {
+            threshold_bps: 2000,
+            cooldown: 1800,
+        },
+    );
+    client.set_asset_price(&admin, &collateral, &10_000_000);
+
+    // Two moves of 15% within one heartbeat add up to more than 20%
+    env.ledger().with_mut(|li| li.timestamp = 1600);
+    client.set_asset_price(&admin, &collateral, &8_500_000);
+    assert!(!client.is_market_frozen(&collateral));
+    env.ledger().with_mut(|li| li.timestamp = 2200);
+    client.set_asset_price(&admin, &collateral, &7_200_000);
+    assert!(client.is_market_frozen(&collateral));
+    assert_eq!(client.get_market_frozen_until(&collateral), 2200 + 1800);
+    assert_eq!(
+        client.try_borrow(&user, &asset, &10_000, &collateral, &30_000),
+        Err(Ok(BorrowError::MarketFrozen))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 2200 + 1800);
+    client.set_asset_price(&admin, &collateral, &7_200_000);
+    assert!(!client.is_market_frozen(&collateral));
+    client.borrow(&user, &asset, &10_000, &collateral, &30_000);
+}
+
+#[test]
+fn test_deviation_guard_validation() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let asset = Address::generate(&env);
+
+    for guard in [
+        DeviationGuard {
+            threshold_bps: 0,
+            cooldown: 600,
+        },
+        DeviationGuard {
+            threshold_bps: 500,
+            cooldown: 0,
+        },
+        DeviationGuard {
+            threshold_bps: 500,
+            cooldown: 7 * 86400 + 1,
+        },
+    ] {
+        assert_eq!(
+            client.try_set_deviation_guard(&admin, &asset, &guard),
+            Err(Ok(OracleError::InvalidConfig))
+        );
+    }
+    assert_eq!(client.get_deviation_guard(&asset), None);
+}
+
+#[test]
+fn test_feed_set_prices_by_median_and_rejects_outliers() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let asset = Address::generate(&env);
+    let feeds = [
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    client.set_price_feed_set(
+        &admin,
+        &asset,
+        &PriceFeedSet {
+            feeds: Vec::from_array(&env, feeds.clone()),
+            min_feeds: 2,
+            max_outlier_bps: 500,
+        },
+    );
+
+    // A single report is below the quorum
+    client.submit_feed_price(&feeds[0], &asset, &10_000_000);
+    assert_eq!(client.get_asset_price(&asset), None);
+
+    client.submit_feed_price(&feeds[1], &asset, &10_200_000);
+    assert_eq!(client.get_asset_price(&asset).unwrap().price, 10_100_000);
+
+    // A glitching feed is discarded instead of moving the median
+    client.submit_feed_price(&feeds[2], &asset, &15_000_000);
+    assert_eq!(client.get_asset_price(&asset).unwrap().price, 10_100_000);
+    assert_eq!(
+        client.get_feed_report(&asset, &feeds[2]).unwrap().price,
+        15_000_000
+    );
+
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_submit_feed_price(&stranger, &asset, &10_000_000),
+        Err(Ok(OracleError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_feed_set_validation() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let asset = Address::generate(&env);
+    let feed = Address::generate(&env);
+
+    let duplicate = PriceFeedSet {
+        feeds: Vec::from_array(&env, [feed.clone(), feed.clone()]),
+        min_feeds: 1,
+        max_outlier_bps: 500,
+    };
+    assert_eq!(
+        client.try_set_price_feed_set(&admin, &asset, &duplicate),
+        Err(Ok(OracleError::InvalidConfig))
+    );
+    let no_quorum = PriceFeedSet {
+        feeds: Vec::from_array(&env, [feed.clone()]),
+        min_feeds: 2,
+        max_outlier_bps: 500,
+    };
+    assert_eq!(
+        client.try_set_price_feed_set(&admin, &asset, &no_quorum),
+        Err(Ok(OracleError::InvalidConfig))
+    );
+
+    let single = PriceFeedSet {
+        feeds: Vec::from_array(&env, [feed.clone()]),
+        min_feeds: 1,
+        max_outlier_bps: 500,
+    };
+    client.set_price_feed_set(&admin, &asset, &single);
+    client.set_price_feed_set(
+        &admin,
+        &asset,
+        &PriceFeedSet {
+            feeds: Vec::new(&env),
+            min_feeds: 0,
+            max_outlier_bps: 0,
+        },
+    );
+    assert_eq!(client.get_price_feed_set(&asset), None);
+}
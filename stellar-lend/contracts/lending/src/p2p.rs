@@ -0,0 +1,758 @@
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol, Vec};
+
+use crate::admin::{require_admin, require_risk_manager};
+use crate::borrow::{calculate_interest, get_debt_position, save_debt_position, SECONDS_PER_YEAR};
+use crate::math::mul_div_down;
+
+/// Errors that can occur in the peer-to-peer matching layer
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum P2PError {
+    Unauthorized = 1,
+    InvalidConfig = 2,
+    InvalidAmount = 3,
+    MarketNotFound = 4,
+    NoDebt = 5,
+    InsufficientBalance = 6,
+    QueueFull = 7,
+    Overflow = 8,
+    ProtocolPaused = 9,
+    MarketClosed = 10,
+    ConcentrationExceeded = 11,
+    InsufficientLiquidity = 12,
+    /// The account is under a compliance freeze
+    AccountFrozen = 13,
+}
+
+/// Storage keys for peer-to-peer matching data
+#[contracttype]
+#[derive(Clone)]
+pub enum P2PDataKey {
+    MatchMarket(Address),
+    /// Suppliers of an asset eligible for matching, in arrival order
+    MatchSuppliers(Address),
+    /// Borrowers of an asset eligible for matching, in arrival order
+    MatchBorrowers(Address),
+    MatchedSupply(Address, Address),
+    MatchedBorrow(Address),
+    MatchedTotal(Address),
+    /// Net liquidity supplied across all assets
+    SuppliedTotal,
+    /// Assets a user has ever supplied
+    SupplierAssets(Address),
+    ConcentrationLimit(Address),
+}
+
+/// Cap on one supplier's share of an asset's supply
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConcentrationLimit {
+    /// Largest share of the market's supply one address may hold, in basis points
+    pub max_share_bps: i128,
+    /// Balance always allowed per supplier, so new markets can be seeded
+    pub min_balance: i128,
+}
+
+/// Per-asset matching configuration
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct P2PMarket {
+    /// Annual rate paid to suppliers left on the pool, in basis points
+    pub pool_supply_rate_bps: i128,
+    /// Position of the matched rate between the pool supply and borrow rates
+    /// (0 = pool supply rate, 10000 = pool borrow rate)
+    pub p2p_cursor_bps: i128,
+}
+
+/// A supplier's balance split between matched and pool liquidity
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct P2PSupplyPosition {
+    pub in_p2p: i128,
+    pub on_pool: i128,
+    pub last_update: u64,
+}
+
+/// Rates offered by a matching market
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct P2PRates {
+    pub pool_supply_rate_bps: i128,
+    pub p2p_rate_bps: i128,
+    pub pool_borrow_rate_bps: i128,
+}
+
+/// Peer-to-peer matching event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct P2PEvent {
+    pub user: Address,
+    pub asset: Address,
+    pub amount: i128,
+    pub matched: i128,
+    pub timestamp: u64,
+}
+
+const MAX_QUEUE_LEN: u32 = 32;
+
+/// Configure peer-to-peer matching for an asset (admin only)
+pub fn set_p2p_market(
+    env: &Env,
+    caller: Address,
+    asset: Address,
+    market: P2PMarket,
+) -> Result<(), P2PError> {
+    require_admin(env, &caller).map_err(|_| P2PError::Unauthorized)?;
+    apply_p2p_market(env, asset, market)
+}
+
+/// Validate and store an asset's matching configuration; callers check authorization
+pub(crate) fn apply_p2p_market(
+    env: &Env,
+    asset: Address,
+    market: P2PMarket,
+) -> Result<(), P2PError> {
+    if market.pool_supply_rate_bps < 0
+        || market.pool_supply_rate_bps > crate::borrow::get_base_interest_rate(env)
+        || !(0..=10000).contains(&market.p2p_cursor_bps)
+    {
+        return Err(P2PError::InvalidConfig);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&P2PDataKey::MatchMarket(asset), &market);
+    Ok(())
+}
+
+/// Get the matching configuration of an asset, if set
+pub fn get_p2p_market(env: &Env, asset: &Address) -> Option<P2PMarket> {
+    env.storage()
+        .persistent()
+        .get(&P2PDataKey::MatchMarket(asset.clone()))
+}
+
+/// Cap any single supplier's share of an asset's supply (risk manager or admin)
+///
+/// Checked on deposit against the market's supply after the deposit, so a
+/// whale's exit cannot drain most of the market at once. Existing balances
+/// above the cap are not forced out.
+pub fn set_concentration_limit(
+    env: &Env,
+    caller: Address,
+    asset: Address,
+    limit: ConcentrationLimit,
+) -> Result<(), P2PError> {
+    require_risk_manager(env, &caller).map_err(|_| P2PError::Unauthorized)?;
+    if !(1..=10000).contains(&limit.max_share_bps) || limit.min_balance < 0 {
+        return Err(P2PError::InvalidConfig);
+    }
+    env.storage()
+        .persistent()
+        .set(&P2PDataKey::ConcentrationLimit(asset), &limit);
+    Ok(())
+}
+
+/// Remove an asset's supplier concentration limit (risk manager or admin)
+pub fn clear_concentration_limit(
+    env: &Env,
+    caller: Address,
+    asset: Address,
+) -> Result<(), P2PError> {
+    require_risk_manager(env, &caller).map_err(|_| P2PError::Unauthorized)?;
+    env.storage()
+        .persistent()
+        .remove(&P2PDataKey::ConcentrationLimit(asset));
+    Ok(())
+}
+
+/// Get an asset's supplier concentration limit, if set
+pub fn get_concentration_limit(env: &Env, asset: &Address) -> Option<ConcentrationLimit> {
+    env.storage()
+        .persistent()
+        .get(&P2PDataKey::ConcentrationLimit(asset.clone()))
+}
+
+/// Get the pool and matched rates of an asset
+pub fn get_p2p_rates(env: &Env, asset: &Address) -> Option<P2PRates> {
+    get_p2p_market(env, asset).map(|market| P2PRates {
+        pool_supply_rate_bps: market.pool_supply_rate_bps,
+        p2p_rate_bps: p2p_rate(env, &market),
+        pool_borrow_rate_bps: crate::rate_model::get_borrow_rate(env),
+    })
+}
+
+/// Supply liquidity, matching it against waiting borrowers first
+///
+/// The unmatched remainder stays on the pool and is matched as new borrowers
+/// join.
+///
+/// # Returns
+/// The amount matched peer-to-peer
+pub fn p2p_supply(
+    env: &Env,
+    supplier: Address,
+    asset: Address,
+    amount: i128,
+) -> Result<i128, P2PError> {
+    supplier.require_auth();
+    apply_supply(env, supplier, asset, amount)
+}
+
+/// Supply liquidity without requiring the supplier's authorization
+///
+/// Used where the supplier already authorized the enclosing call, e.g. the
+/// seed deposit of an asset listing.
+pub(crate) fn apply_supply(
+    env: &Env,
+    supplier: Address,
+    asset: Address,
+    amount: i128,
+) -> Result<i128, P2PError> {
+    if amount <= 0 {
+        return Err(P2PError::InvalidAmount);
+    }
+    if crate::pause::is_operation_paused(env, crate::pause::PAUSE_SUPPLY)
+        || crate::shutdown::is_shutdown(env)
+    {
+        return Err(P2PError::ProtocolPaused);
+    }
+    if crate::listing::is_winding_down(env, &asset) {
+        return Err(P2PError::MarketClosed);
+    }
+    if crate::account_freeze::is_account_frozen(env, &supplier) {
+        return Err(P2PError::AccountFrozen);
+    }
+    let market = get_p2p_market(env, &asset).ok_or(P2PError::MarketNotFound)?;
+
+    let mut position = accrue_supplier(env, &supplier, &asset, &market);
+    if let Some(limit) = get_concentration_limit(env, &asset) {
+        let balance = position
+            .in_p2p
+            .saturating_add(position.on_pool)
+            .saturating_add(amount);
+        let market_supply = get_asset_supplied(env, &asset).saturating_add(amount);
+        if balance > limit.min_balance
+            && balance.saturating_mul(10000) > market_supply.saturating_mul(limit.max_share_bps)
+        {
+            return Err(P2PError::ConcentrationExceeded);
+        }
+    }
+    let matched = if enqueue(env, &P2PDataKey::MatchSuppliers(asset.clone()), &supplier) {
+        shift_borrowers(env, &asset, amount, Some(&supplier), true)
+    } else {
+        0
+    };
+
+    position.in_p2p = position
+        .in_p2p
+        .checked_add(matched)
+        .ok_or(P2PError::Overflow)?;
+    position.on_pool = position
+        .on_pool
+        .checked_add(amount - matched)
+        .ok_or(P2PError::Overflow)?;
+    save_supply_position(env, &supplier, &asset, &position);
+    adjust_matched_total(env, &asset, matched);
+    adjust_supplied_total(env, &asset, amount);
+    crate::deleverage::update_crunch(env);
+
+    emit_p2p_event(env, "p2p_supply", supplier, asset.clone(), amount, matched);
+    crate::withdrawal_queue::process_withdrawal_queue(env, &asset);
+    Ok(matched)
+}
+
+/// Withdraw supplied liquidity
+///
+/// Pool liquidity is withdrawn first. Matched liquidity is replaced by other
+/// suppliers where possible; borrowers that cannot be rematched fall back to
+/// the pool. Withdrawals above the market's withdrawable cash are rejected;
+/// the supplier can join the withdrawal queue instead. The asset's
+/// withdrawal fee, if any, is deducted from the amount paid out.
+///
+/// # Returns
+/// The amount paid out
+pub fn p2p_withdraw(
+    env: &Env,
+    supplier: Address,
+    asset: Address,
+    amount: i128,
+) -> Result<i128, P2PError> {
+    supplier.require_auth();
+
+    if amount <= 0 {
+        return Err(P2PError::InvalidAmount);
+    }
+    let position = get_p2p_supply(env, &supplier, &asset);
+    if amount > position.on_pool.saturating_add(position.in_p2p) {
+        return Err(P2PError::InsufficientBalance);
+    }
+    if amount > crate::market::get_liquidity(env, &asset).withdrawable {
+        return Err(P2PError::InsufficientLiquidity);
+    }
+    apply_withdrawal(env, supplier, asset, amount)
+}
+
+/// Withdraw supplied liquidity without authorization or liquidity checks
+///
+/// Used to fill queued withdrawals as cash becomes available.
+pub(crate) fn apply_withdrawal(
+    env: &Env,
+    supplier: Address,
+    asset: Address,
+    amount: i128,
+) -> Result<i128, P2PError> {
+    if amount <= 0 {
+        return Err(P2PError::InvalidAmount);
+    }
+    let market = get_p2p_market(env, &asset).ok_or(P2PError::MarketNotFound)?;
+
+    let mut position = accrue_supplier(env, &supplier, &asset, &market);
+    let balance = position
+        .on_pool
+        .checked_add(position.in_p2p)
+        .ok_or(P2PError::Overflow)?;
+    if amount > balance {
+        return Err(P2PError::InsufficientBalance);
+    }
+
+    let fee = crate::exit_fee::charge_exit_fee(env, &supplier, &asset, amount);
+    let from_pool = amount.min(position.on_pool);
+    let from_p2p = amount - from_pool;
+    if from_p2p > 0 {
+        let replaced = shift_suppliers(env, &asset, &market, from_p2p, Some(&supplier), true);
+        shift_borrowers(env, &asset, from_p2p - replaced, None, false);
+        adjust_matched_total(env, &asset, replaced - from_p2p);
+    }
+
+    position.on_pool -= from_pool;
+    position.in_p2p -= from_p2p;
+    save_supply_position(env, &supplier, &asset, &position);
+    if position.on_pool == 0 && position.in_p2p == 0 {
+        dequeue(env, &P2PDataKey::MatchSuppliers(asset.clone()), &supplier);
+    }
+    // The fee stays in the market, owned by the remaining suppliers
+    adjust_supplied_total(env, &asset, fee - amount);
+    crate::deleverage::update_crunch(env);
+
+    emit_p2p_event(env, "p2p_withdraw", supplier, asset, amount, from_p2p);
+    Ok(amount - fee)
+}
+
+/// Opt a borrower's pool debt into matching against waiting suppliers
+///
+/// Debt borrowed after joining stays on the pool until it is matched by a new
+/// supplier or the borrower joins again.
+///
+/// # Returns
+/// The borrower's total matched debt
+pub fn join_p2p_borrow(env: &Env, user: Address) -> Result<i128, P2PError> {
+    user.require_auth();
+
+    let position = get_debt_position(env, &user);
+    if position.borrowed_amount == 0 {
+        return Err(P2PError::NoDebt);
+    }
+    let asset = position.asset.clone();
+    let market = get_p2p_market(env, &asset).ok_or(P2PError::MarketNotFound)?;
+    if !enqueue(env, &P2PDataKey::MatchBorrowers(asset.clone()), &user) {
+        return Err(P2PError::QueueFull);
+    }
+
+    accrue_borrower(env, &user);
+    let current = get_p2p_borrow_matched(env, &user);
+    let matched = shift_suppliers(
+        env,
+        &asset,
+        &market,
+        position.borrowed_amount - current,
+        Some(&user),
+        true,
+    );
+    let total = current + matched;
+    set_borrow_matched(env, &user, total);
+    adjust_matched_total(env, &asset, matched);
+
+    emit_p2p_event(env, "p2p_borrow_joined", user, asset, total, matched);
+    Ok(total)
+}
+
+/// Get a supplier's position, including interest accrued since the last update
+pub fn get_p2p_supply(env: &Env, user: &Address, asset: &Address) -> P2PSupplyPosition {
+    match get_p2p_market(env, asset) {
+        Some(market) => with_fee_share(
+            env,
+            user,
+            asset,
+            with_accrued_interest(env, get_supply_position(env, user, asset), &market),
+        ),
+        None => get_supply_position(env, user, asset),
+    }
+}
+
+/// Get the part of a borrower's principal that is matched peer-to-peer
+pub fn get_p2p_borrow_matched(env: &Env, user: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&P2PDataKey::MatchedBorrow(user.clone()))
+        .unwrap_or(0)
+}
+
+/// Get the total amount of an asset matched peer-to-peer
+pub fn get_p2p_matched_total(env: &Env, asset: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&P2PDataKey::MatchedTotal(asset.clone()))
+        .unwrap_or(0)
+}
+
+/// Net liquidity supplied to the pool across all assets
+pub(crate) fn get_supplied_total(env: &Env) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&P2PDataKey::SuppliedTotal)
+        .unwrap_or(0)
+}
+
+/// Assets a user has supplied, including ones since fully withdrawn
+pub(crate) fn get_supplier_assets(env: &Env, user: &Address) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&P2PDataKey::SupplierAssets(user.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Net liquidity supplied to the pool of one asset
+pub(crate) fn get_asset_supplied(env: &Env, asset: &Address) -> i128 {
+    crate::reserve::get_reserve_data(env, asset).total_supplied
+}
+
+/// Matched principal of a borrower and the rate it accrues at
+///
+/// Used by interest accrual; returns zero when the borrower is not matched.
+pub(crate) fn get_matched_borrow(env: &Env, user: &Address, asset: &Address) -> (i128, i128) {
+    let matched = get_p2p_borrow_matched(env, user);
+    if matched == 0 {
+        return (0, 0);
+    }
+    match get_p2p_market(env, asset) {
+        Some(market) => (matched, p2p_rate(env, &market)),
+        None => (0, 0),
+    }
+}
+
+/// Rebalance after a repayment reduced a borrower's principal
+///
+/// Matched debt above the remaining principal is handed to other waiting
+/// borrowers; suppliers that cannot be rematched fall back to the pool.
+pub(crate) fn on_principal_repaid(env: &Env, user: &Address, principal: i128, asset: &Address) {
+    let matched = get_p2p_borrow_matched(env, user);
+    if matched > principal {
+        let excess = matched - principal;
+        if let Some(market) = get_p2p_market(env, asset) {
+            let replaced = shift_borrowers(env, asset, excess, Some(user), true);
+            shift_suppliers(env, asset, &market, excess - replaced, None, false);
+            adjust_matched_total(env, asset, replaced - excess);
+        }
+        set_borrow_matched(env, user, principal);
+    }
+    if principal == 0 {
+        dequeue(env, &P2PDataKey::MatchBorrowers(asset.clone()), user);
+    }
+}
+
+/// Matched rate: the pool supply rate plus the cursor share of the spread
+fn p2p_rate(env: &Env, market: &P2PMarket) -> i128 {
+    let spread = (crate::rate_model::get_borrow_rate(env) - market.pool_supply_rate_bps).max(0);
+    market.pool_supply_rate_bps + spread * market.p2p_cursor_bps / 10000
+}
+
+/// Move up to `amount` of supplier liquidity between the pool and matches
+///
+/// Returns the amount moved.
+fn shift_suppliers(
+    env: &Env,
+    asset: &Address,
+    market: &P2PMarket,
+    amount: i128,
+    exclude: Option<&Address>,
+    to_p2p: bool,
+) -> i128 {
+    let mut remaining = amount;
+    for supplier in get_queue(env, &P2PDataKey::MatchSuppliers(asset.clone())).iter() {
+        if remaining == 0 {
+            break;
+        }
+        if exclude == Some(&supplier) {
+            continue;
+        }
+        let mut position = accrue_supplier(env, &supplier, asset, market);
+        let available = if to_p2p {
+            position.on_pool
+        } else {
+            position.in_p2p
+        };
+        let moved = available.min(remaining);
+        if moved == 0 {
+            continue;
+        }
+        if to_p2p {
+            position.on_pool -= moved;
+            position.in_p2p += moved;
+        } else {
+            position.in_p2p -= moved;
+            position.on_pool += moved;
+        }
+        save_supply_position(env, &supplier, asset, &position);
+        remaining -= moved;
+    }
+    amount - remaining
+}
+
+/// Move up to `amount` of borrower debt between the pool and matches
+///
+/// Returns the amount moved.
+fn shift_borrowers(
+    env: &Env,
+    asset: &Address,
+    amount: i128,
+    exclude: Option<&Address>,
+    to_p2p: bool,
+) -> i128 {
+    let mut remaining = amount;
+    for borrower in get_queue(env, &P2PDataKey::MatchBorrowers(asset.clone())).iter() {
+        if remaining == 0 {
+            break;
+        }
+        if exclude == Some(&borrower) {
+            continue;
+        }
+        let position = get_debt_position(env, &borrower);
+        if position.asset != *asset {
+            continue;
+        }
+        let matched = get_p2p_borrow_matched(env, &borrower);
+        let available = if to_p2p {
+            position.borrowed_amount - matched
+        } else {
+            matched
+        };
+        let moved = available.min(remaining);
+        if moved <= 0 {
+            continue;
+        }
+        accrue_borrower(env, &borrower);
+        if to_p2p {
+            set_borrow_matched(env, &borrower, matched + moved);
+        } else {
+            set_borrow_matched(env, &borrower, matched - moved);
+        }
+        remaining -= moved;
+    }
+    amount - remaining
+}
+
+/// Accrue a supplier's interest into their pool balance
+fn accrue_supplier(
+    env: &Env,
+    user: &Address,
+    asset: &Address,
+    market: &P2PMarket,
+) -> P2PSupplyPosition {
+    let position = with_fee_share(
+        env,
+        user,
+        asset,
+        with_accrued_interest(env, get_supply_position(env, user, asset), market),
+    );
+    crate::exit_fee::checkpoint_fee_share(env, user, asset);
+    save_supply_position(env, user, asset, &position);
+    position
+}
+
+/// Credit withdrawal fees and yield, net of loss write-downs, earned since the
+/// position was last touched
+fn with_fee_share(
+    env: &Env,
+    user: &Address,
+    asset: &Address,
+    mut position: P2PSupplyPosition,
+) -> P2PSupplyPosition {
+    let balance = position.on_pool.saturating_add(position.in_p2p);
+    position.on_pool = position
+        .on_pool
+        .saturating_add(crate::exit_fee::get_fee_share(env, user, asset, balance))
+        .max(0);
+    position
+}
+
+/// Interest is rounded down so suppliers are never overpaid
+fn with_accrued_interest(
+    env: &Env,
+    mut position: P2PSupplyPosition,
+    market: &P2PMarket,
+) -> P2PSupplyPosition {
+    let now = env.ledger().timestamp();
+    let elapsed = now.saturating_sub(position.last_update) as i128;
+    let interest = mul_div_down(
+        position
+            .in_p2p
+            .saturating_mul(p2p_rate(env, market))
+            .saturating_add(position.on_pool.saturating_mul(market.pool_supply_rate_bps)),
+        elapsed,
+        10000 * SECONDS_PER_YEAR as i128,
+    );
+    position.on_pool = position.on_pool.saturating_add(interest);
+    position.last_update = now;
+    position
+}
+
+/// Checkpoint a borrower's interest before their matched amount changes
+fn accrue_borrower(env: &Env, user: &Address) {
+    let mut position = get_debt_position(env, user);
+    let accrued = calculate_interest(env, user, &position);
+    position.interest_accrued = position.interest_accrued.saturating_add(accrued);
+    position.last_update = env.ledger().timestamp();
+    position.borrow_index = crate::reserve::checkpoint_borrow_index(env, &position.asset);
+    save_debt_position(env, user, &position);
+}
+
+fn get_supply_position(env: &Env, user: &Address, asset: &Address) -> P2PSupplyPosition {
+    env.storage()
+        .persistent()
+        .get(&P2PDataKey::MatchedSupply(user.clone(), asset.clone()))
+        .unwrap_or(P2PSupplyPosition {
+            in_p2p: 0,
+            on_pool: 0,
+            last_update: env.ledger().timestamp(),
+        })
+}
+
+fn save_supply_position(env: &Env, user: &Address, asset: &Address, position: &P2PSupplyPosition) {
+    // Points settle on the supply held until now
+    crate::points::checkpoint(env, user);
+    env.storage().persistent().set(
+        &P2PDataKey::MatchedSupply(user.clone(), asset.clone()),
+        position,
+    );
+    let mut assets = get_supplier_assets(env, user);
+    if !assets.contains(asset) {
+        assets.push_back(asset.clone());
+        env.storage()
+            .persistent()
+            .set(&P2PDataKey::SupplierAssets(user.clone()), &assets);
+    }
+}
+
+fn set_borrow_matched(env: &Env, user: &Address, amount: i128) {
+    env.storage()
+        .persistent()
+        .set(&P2PDataKey::MatchedBorrow(user.clone()), &amount);
+}
+
+fn adjust_matched_total(env: &Env, asset: &Address, delta: i128) {
+    if delta == 0 {
+        return;
+    }
+    let total = get_p2p_matched_total(env, asset)
+        .saturating_add(delta)
+        .max(0);
+    env.storage()
+        .persistent()
+        .set(&P2PDataKey::MatchedTotal(asset.clone()), &total);
+}
+
+/// Grow an asset's supply by `amount` shared pro rata among its suppliers
+///
+/// # Returns
+/// False, crediting nothing, when the asset has no supply
+pub(crate) fn credit_suppliers(env: &Env, asset: &Address, amount: i128) -> bool {
+    let supplied = get_asset_supplied(env, asset);
+    if amount <= 0 || supplied <= 0 {
+        return false;
+    }
+    crate::exit_fee::distribute_to_suppliers(env, asset, amount, supplied);
+    adjust_supplied_total(env, asset, amount);
+    true
+}
+
+/// Shrink an asset's supply by up to `amount`, shared pro rata among its suppliers
+///
+/// # Returns
+/// The amount written off, limited by the supply
+pub(crate) fn haircut_suppliers(env: &Env, asset: &Address, amount: i128) -> i128 {
+    let haircut = amount.clamp(0, get_asset_supplied(env, asset).max(0));
+    if haircut > 0 {
+        crate::exit_fee::distribute_to_suppliers(
+            env,
+            asset,
+            -haircut,
+            get_asset_supplied(env, asset),
+        );
+        adjust_supplied_total(env, asset, -haircut);
+    }
+    haircut
+}
+
+fn adjust_supplied_total(env: &Env, asset: &Address, delta: i128) {
+    let total = get_supplied_total(env).saturating_add(delta).max(0);
+    env.storage()
+        .persistent()
+        .set(&P2PDataKey::SuppliedTotal, &total);
+    crate::reserve::update_reserve(env, asset, delta, 0);
+}
+
+fn get_queue(env: &Env, key: &P2PDataKey) -> Vec<Address> {
+    env.storage().persistent().get(key).unwrap_or(Vec::new(env))
+}
+
+/// Add a user to a matching queue
+///
+/// Returns false when the queue is full and the user is not already in it.
+fn enqueue(env: &Env, key: &P2PDataKey, user: &Address) -> bool {
+    let mut queue = get_queue(env, key);
+    if queue.contains(user) {
+        return true;
+    }
+    if queue.len() >= MAX_QUEUE_LEN {
+        return false;
+    }
+    queue.push_back(user.clone());
+    env.storage().persistent().set(key, &queue);
+    true
+}
+
+fn dequeue(env: &Env, key: &P2PDataKey, user: &Address) {
+    let mut queue = get_queue(env, key);
+    if let Some(index) = queue.first_index_of(user) {
+        queue.remove(index);
+        env.storage().persistent().set(key, &queue);
+    }
+}
+
+fn emit_p2p_event(
+    env: &Env,
+    name: &str,
+    user: Address,
+    asset: Address,
+    amount: i128,
+    matched: i128,
+) {
+    let event = P2PEvent {
+        user,
+        asset,
+        amount,
+        matched,
+        timestamp: env.ledger().timestamp(),
+    };
+    crate::events::publish(
+        env,
+        (
+            Symbol::new(env, name),
+            event.user.clone(),
+            event.asset.clone(),
+        ),
+        event,
+    );
+}
@@ -0,0 +1,184 @@
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+
+const YEAR: u64 = 31536000;
+
+fn setup(env: &Env) -> (LendingContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 0);
+
+    let contract_id = env.register(LendingContract, ());
+    let client = LendingContractClient::new(env, &contract_id);
+    client.initialize(
+        &Address::generate(env),
+        &InitConfig::new(env, 1_000_000_000, 1000),
+    );
+
+    let admin = Address::generate(env);
+    client.set_admin(&admin);
+
+    // Pool supply 2%, pool borrow 5%, matched at the 3.5% mid-rate
+    let asset = Address::generate(env);
+    client.set_p2p_market(
+        &admin,
+        &asset,
+        &P2PMarket {
+            pool_supply_rate_bps: 200,
+            p2p_cursor_bps: 5000,
+        },
+    );
+    (client, admin, asset)
+}
+
+fn borrow(env: &Env, client: &LendingContractClient<'_>, asset: &Address, amount: i128) -> Address {
+    let user = Address::generate(env);
+    let collateral_asset = Address::generate(env);
+    client.borrow(&user, asset, &amount, &collateral_asset, &(amount * 2));
+    user
+}
+
+#[test]
+fn test_matched_borrower_and_supplier_share_mid_rate() {
+    let env = Env::default();
+    let (client, _admin, asset) = setup(&env);
+    let supplier = Address::generate(&env);
+
+    assert_eq!(client.get_p2p_rates(&asset).unwrap().p2p_rate_bps, 350);
+
+    client.p2p_supply(&supplier, &asset, &6000);
+    let borrower = borrow(&env, &client, &asset, 10_000);
+    assert_eq!(client.join_p2p_borrow(&borrower), 6000);
+    assert_eq!(client.get_p2p_matched_total(&asset), 6000);
+
+    env.ledger().with_mut(|li| li.timestamp = YEAR);
+
+    // 4,000 on the pool at 5% plus 6,000 matched at 3.5%
+    assert_eq!(client.get_user_debt(&borrower).interest_accrued, 200 + 210);
+    // Matched supply earns 3.5% instead of the 2% pool rate
+    let position = client.get_p2p_supply(&supplier, &asset);
+    assert_eq!(position.in_p2p, 6000);
+    assert_eq!(position.on_pool, 210);
+}
+
+#[test]
+fn test_supply_matches_waiting_borrower_and_leaves_remainder_on_pool() {
+    let env = Env::default();
+    let (client, _admin, asset) = setup(&env);
+    let supplier = Address::generate(&env);
+
+    let borrower = borrow(&env, &client, &asset, 10_000);
+    assert_eq!(client.join_p2p_borrow(&borrower), 0);
+
+    assert_eq!(client.p2p_supply(&supplier, &asset, &15_000), 10_000);
+    let position = client.get_p2p_supply(&supplier, &asset);
+    assert_eq!(position.in_p2p, 10_000);
+    assert_eq!(position.on_pool, 5000);
+    assert_eq!(client.get_p2p_borrow_matched(&borrower), 10_000);
+}
+
+#[test]
+fn test_withdraw_rematches_with_pool_suppliers() {
+    let env = Env::default();
+    let (client, _admin, asset) = setup(&env);
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+
+    client.p2p_supply(&first, &asset, &10_000);
+    let borrower = borrow(&env, &client, &asset, 10_000);
+    client.join_p2p_borrow(&borrower);
+    assert_eq!(client.p2p_supply(&second, &asset, &4000), 0);
+
+    // Only the second supplier's 4,000 is cash; it replaces the matched withdrawal
+    assert_eq!(client.p2p_withdraw(&first, &asset, &4000), 4000);
+    assert_eq!(client.get_p2p_supply(&second, &asset).in_p2p, 4000);
+    assert_eq!(client.get_p2p_supply(&first, &asset).in_p2p, 6000);
+    assert_eq!(client.get_p2p_borrow_matched(&borrower), 10_000);
+    assert_eq!(client.get_p2p_matched_total(&asset), 10_000);
+
+    let result = client.try_p2p_withdraw(&first, &asset, &1);
+    assert_eq!(result, Err(Ok(P2PError::InsufficientLiquidity)));
+    let result = client.try_p2p_withdraw(&first, &asset, &6001);
+    assert_eq!(result, Err(Ok(P2PError::InsufficientBalance)));
+}
+
+#[test]
+fn test_repay_unmatches_supplier() {
+    let env = Env::default();
+    let (client, _admin, asset) = setup(&env);
+    let supplier = Address::generate(&env);
+
+    client.p2p_supply(&supplier, &asset, &10_000);
+    let borrower = borrow(&env, &client, &asset, 10_000);
+    client.join_p2p_borrow(&borrower);
+
+    client.repay(&borrower, &4000);
+
+    assert_eq!(client.get_p2p_borrow_matched(&borrower), 6000);
+    let position = client.get_p2p_supply(&supplier, &asset);
+    assert_eq!(position.in_p2p, 6000);
+    assert_eq!(position.on_pool, 4000);
+    assert_eq!(client.get_p2p_matched_total(&asset), 6000);
+}
+
+#[test]
+fn test_p2p_validation() {
+    let env = Env::default();
+    let (client, admin, asset) = setup(&env);
+    let user = Address::generate(&env);
+    let other_asset = Address::generate(&env);
+
+    let market = P2PMarket {
+        pool_supply_rate_bps: 200,
+        p2p_cursor_bps: 10_001,
+    };
+    let result = client.try_set_p2p_market(&admin, &asset, &market);
+    assert_eq!(result, Err(Ok(P2PError::InvalidConfig)));
+
+    let result = client.try_set_p2p_market(&user, &asset, &market);
+    assert_eq!(result, Err(Ok(P2PError::Unauthorized)));
+
+    let result = client.try_p2p_supply(&user, &other_asset, &1000);
+    assert_eq!(result, Err(Ok(P2PError::MarketNotFound)));
+
+    let result = client.try_join_p2p_borrow(&user);
+    assert_eq!(result, Err(Ok(P2PError::NoDebt)));
+}
+
+#[test]
+fn test_supplier_concentration_limit() {
+    let env = Env::default();
+    let (client, admin, asset) = setup(&env);
+    client.set_concentration_limit(
+        &admin,
+        &asset,
+        &ConcentrationLimit {
+            max_share_bps: 4000,
+            min_balance: 10_000,
+        },
+    );
+
+    // The seed balance is allowed regardless of share
+    let whale = Address::generate(&env);
+    client.p2p_supply(&whale, &asset, &10_000);
+    assert_eq!(
+        client.try_p2p_supply(&whale, &asset, &1),
+        Err(Ok(P2PError::ConcentrationExceeded))
+    );
+
+    let others = Address::generate(&env);
+    client.p2p_supply(&others, &asset, &10_000);
+    client.p2p_supply(&Address::generate(&env), &asset, &10_000);
+    // 12_000 of 32_000 is within 40%, 14_000 of 34_000 is not
+    client.p2p_supply(&whale, &asset, &2000);
+    assert_eq!(
+        client.try_p2p_supply(&whale, &asset, &2000),
+        Err(Ok(P2PError::ConcentrationExceeded))
+    );
+
+    client.clear_concentration_limit(&admin, &asset);
+    client.p2p_supply(&whale, &asset, &1000);
+    assert_eq!(client.get_concentration_limit(&asset), None);
+}
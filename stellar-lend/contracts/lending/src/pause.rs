@@ -0,0 +1,178 @@
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol};
+
+use crate::admin::{require_admin, require_guardian};
+
+/// Errors that can occur when setting operation pauses
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum PauseError {
+    Unauthorized = 1,
+    InvalidConfig = 2,
+}
+
+/// Storage keys for operation pause data
+#[contracttype]
+#[derive(Clone)]
+pub enum PauseDataKey {
+    /// Bitmask of operations paused by the admin
+    PausedOperations,
+    /// Operations paused by the guardian, lapsing unless reaffirmed
+    GuardianPause,
+    MaxGuardianPauseDuration,
+}
+
+/// Operations paused by the guardian
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct GuardianPause {
+    pub operations: u32,
+    /// The pause lapses at this time unless the admin reaffirms it
+    pub expires_at: u64,
+}
+
+/// Operation pause event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct PausedOperationsEvent {
+    pub operations: u32,
+    pub actor: Address,
+    pub timestamp: u64,
+}
+
+/// New borrows, on every borrow path
+pub const PAUSE_BORROW: u32 = 1 << 0;
+/// Liquidity supply
+pub const PAUSE_SUPPLY: u32 = 1 << 1;
+/// Collateral withdrawals
+pub const PAUSE_WITHDRAW: u32 = 1 << 2;
+/// Borrower repayments
+pub const PAUSE_REPAY: u32 = 1 << 3;
+/// Liquidations, NFT auctions and deleveraging
+pub const PAUSE_LIQUIDATE: u32 = 1 << 4;
+/// Flash loans (reserved until flash loans are offered)
+pub const PAUSE_FLASH_LOAN: u32 = 1 << 5;
+const ALL_OPERATIONS: u32 = (1 << 6) - 1;
+const DEFAULT_MAX_GUARDIAN_PAUSE: u64 = 3 * 86400; // 3 days
+const MAX_GUARDIAN_PAUSE_LIMIT: u64 = 30 * 86400; // 30 days
+
+/// Set the bitmask of paused operations (guardian or admin)
+///
+/// Replaces the caller's whole mask: set a bit to pause an operation, clear
+/// it to resume. A guardian's pauses lapse after the maximum guardian pause
+/// duration unless the admin reaffirms them; the admin's mask replaces any
+/// guardian pause and does not expire. The protocol-wide borrow pause of
+/// `set_paused` applies on top.
+pub fn set_paused_operations(
+    env: &Env,
+    caller: Address,
+    operations: u32,
+) -> Result<(), PauseError> {
+    let by_guardian = require_guardian(env, &caller).is_ok();
+    if !by_guardian {
+        require_admin(env, &caller).map_err(|_| PauseError::Unauthorized)?;
+    }
+
+    if operations & !ALL_OPERATIONS != 0 {
+        return Err(PauseError::InvalidConfig);
+    }
+    if by_guardian {
+        let pause = GuardianPause {
+            operations,
+            expires_at: env
+                .ledger()
+                .timestamp()
+                .saturating_add(get_max_guardian_pause_duration(env)),
+        };
+        env.storage()
+            .persistent()
+            .set(&PauseDataKey::GuardianPause, &pause);
+    } else {
+        env.storage()
+            .persistent()
+            .set(&PauseDataKey::PausedOperations, &operations);
+        env.storage()
+            .persistent()
+            .remove(&PauseDataKey::GuardianPause);
+    }
+
+    let event = PausedOperationsEvent {
+        operations,
+        actor: caller,
+        timestamp: env.ledger().timestamp(),
+    };
+    crate::events::publish(env, (Symbol::new(env, "paused_operations_set"),), event);
+    Ok(())
+}
+
+/// Make the guardian's current pauses permanent (admin only)
+///
+/// # Returns
+/// The resulting bitmask of paused operations
+pub fn reaffirm_guardian_pause(env: &Env, caller: Address) -> Result<u32, PauseError> {
+    require_admin(env, &caller).map_err(|_| PauseError::Unauthorized)?;
+
+    let operations = get_paused_operations(env);
+    env.storage()
+        .persistent()
+        .set(&PauseDataKey::PausedOperations, &operations);
+    env.storage()
+        .persistent()
+        .remove(&PauseDataKey::GuardianPause);
+
+    let event = PausedOperationsEvent {
+        operations,
+        actor: caller,
+        timestamp: env.ledger().timestamp(),
+    };
+    crate::events::publish(env, (Symbol::new(env, "guardian_pause_reaffirmed"),), event);
+    Ok(operations)
+}
+
+/// Set how long guardian pauses last before lapsing (admin only)
+pub fn set_max_guardian_pause_duration(
+    env: &Env,
+    caller: Address,
+    duration: u64,
+) -> Result<(), PauseError> {
+    require_admin(env, &caller).map_err(|_| PauseError::Unauthorized)?;
+
+    if duration == 0 || duration > MAX_GUARDIAN_PAUSE_LIMIT {
+        return Err(PauseError::InvalidConfig);
+    }
+    env.storage()
+        .persistent()
+        .set(&PauseDataKey::MaxGuardianPauseDuration, &duration);
+    Ok(())
+}
+
+/// Get how long guardian pauses last before lapsing, in seconds
+pub fn get_max_guardian_pause_duration(env: &Env) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&PauseDataKey::MaxGuardianPauseDuration)
+        .unwrap_or(DEFAULT_MAX_GUARDIAN_PAUSE)
+}
+
+/// Get the guardian's pauses, if still in force
+pub fn get_guardian_pause(env: &Env) -> Option<GuardianPause> {
+    env.storage()
+        .persistent()
+        .get::<_, GuardianPause>(&PauseDataKey::GuardianPause)
+        .filter(|pause| env.ledger().timestamp() < pause.expires_at)
+}
+
+/// Get the bitmask of paused operations, admin and unexpired guardian pauses
+pub fn get_paused_operations(env: &Env) -> u32 {
+    let admin_paused: u32 = env
+        .storage()
+        .persistent()
+        .get(&PauseDataKey::PausedOperations)
+        .unwrap_or(0);
+    admin_paused | get_guardian_pause(env).map_or(0, |pause| pause.operations)
+}
+
+/// Whether an operation (one of the `PAUSE_*` bits) is paused
+pub fn is_operation_paused(env: &Env, operation: u32) -> bool {
+    get_paused_operations(env) & operation != 0
+}
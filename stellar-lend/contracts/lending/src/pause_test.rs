@@ -0,0 +1,96 @@
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+
+fn setup(env: &Env) -> (LendingContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    let client = LendingContractClient::new(env, &env.register(LendingContract, ()));
+    client.initialize(
+        &Address::generate(env),
+        &InitConfig::new(env, 1_000_000_000, 1000),
+    );
+
+    let admin = Address::generate(env);
+    client.set_admin(&admin);
+    let guardian = Address::generate(env);
+    client.set_guardian(&admin, &guardian);
+    (client, admin, guardian)
+}
+
+#[test]
+fn test_paused_operations_block_only_their_flags() {
+    let env = Env::default();
+    let (client, _, guardian) = setup(&env);
+    let user = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let collateral = Address::generate(&env);
+    client.borrow(&user, &asset, &10_000, &collateral, &30_000);
+
+    client.set_paused_operations(&guardian, &(PAUSE_BORROW | PAUSE_WITHDRAW));
+    assert!(client.is_operation_paused(&PAUSE_BORROW));
+    assert_eq!(
+        client.try_borrow(&user, &asset, &1000, &collateral, &3000),
+        Err(Ok(BorrowError::ProtocolPaused))
+    );
+    assert_eq!(
+        client.try_withdraw_collateral(&user, &1000),
+        Err(Ok(WithdrawError::ProtocolPaused))
+    );
+    // Repayments stay open
+    client.repay(&user, &5000);
+
+    client.set_paused_operations(&guardian, &PAUSE_REPAY);
+    assert_eq!(
+        client.try_repay(&user, &1000),
+        Err(Ok(RepayError::ProtocolPaused))
+    );
+    client.withdraw_collateral(&user, &1000);
+    client.borrow(&user, &asset, &1000, &collateral, &3000);
+}
+
+#[test]
+fn test_paused_operations_validation() {
+    let env = Env::default();
+    let (client, admin, _) = setup(&env);
+    let stranger = Address::generate(&env);
+
+    assert_eq!(
+        client.try_set_paused_operations(&stranger, &PAUSE_BORROW),
+        Err(Ok(PauseError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_paused_operations(&admin, &(1 << 6)),
+        Err(Ok(PauseError::InvalidConfig))
+    );
+    client.set_paused_operations(&admin, &(PAUSE_LIQUIDATE | PAUSE_FLASH_LOAN));
+    assert_eq!(
+        client.get_paused_operations(),
+        PAUSE_LIQUIDATE | PAUSE_FLASH_LOAN
+    );
+}
+
+#[test]
+fn test_guardian_pause_lapses_unless_reaffirmed() {
+    let env = Env::default();
+    let (client, admin, guardian) = setup(&env);
+    client.set_max_guardian_pause_duration(&admin, &3600);
+
+    client.set_paused_operations(&guardian, &PAUSE_BORROW);
+    assert_eq!(client.get_guardian_pause().unwrap().expires_at, 3600);
+    env.ledger().with_mut(|li| li.timestamp = 3600);
+    assert!(!client.is_operation_paused(&PAUSE_BORROW));
+
+    // Reaffirmed pauses no longer lapse
+    client.set_paused_operations(&guardian, &PAUSE_SUPPLY);
+    assert_eq!(client.reaffirm_guardian_pause(&admin), PAUSE_SUPPLY);
+    assert_eq!(client.get_guardian_pause(), None);
+    env.ledger().with_mut(|li| li.timestamp = 100_000);
+    assert!(client.is_operation_paused(&PAUSE_SUPPLY));
+
+    assert_eq!(
+        client.try_set_max_guardian_pause_duration(&admin, &0),
+        Err(Ok(PauseError::InvalidConfig))
+    );
+}
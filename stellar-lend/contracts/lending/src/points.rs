@@ -0,0 +1,195 @@
+use soroban_sdk::{contracterror, contracttype, Address, Env};
+
+use crate::admin::require_admin;
+use crate::borrow::{get_collateral_position, get_debt_position};
+use crate::oracle::amount_to_value;
+use crate::vote_escrow::{get_gauge_multiplier, get_reward_boost};
+
+/// Errors that can occur during points configuration
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum PointsError {
+    Unauthorized = 1,
+    InvalidConfig = 2,
+}
+
+/// Storage keys for points data
+#[contracttype]
+#[derive(Clone)]
+pub enum PointsDataKey {
+    PointsConfig,
+    PointsAccount(Address),
+    TotalPoints,
+    PointsStartTime,
+}
+
+/// Points accrual rates, in points per 10,000 units of value per day
+///
+/// Each market's value is weighted by its gauge multiplier and the total by
+/// the user's vote-escrow reward boost.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PointsConfig {
+    pub active: bool,
+    pub supply_rate: i128,
+    pub borrow_rate: i128,
+}
+
+/// A user's settled points balance
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PointsAccount {
+    pub points: i128,
+    pub last_update: u64,
+}
+
+const SECONDS_PER_DAY: i128 = 86400;
+
+/// Configure the points program (admin only)
+///
+/// Activating the program starts accrual from the current ledger time.
+/// Outstanding points are not recalculated; new rates apply from each user's
+/// last checkpoint.
+pub fn set_points_config(
+    env: &Env,
+    caller: Address,
+    config: PointsConfig,
+) -> Result<(), PointsError> {
+    require_admin(env, &caller).map_err(|_| PointsError::Unauthorized)?;
+
+    if config.supply_rate < 0 || config.borrow_rate < 0 {
+        return Err(PointsError::InvalidConfig);
+    }
+
+    let was_active = get_points_config(env).map(|c| c.active).unwrap_or(false);
+    if config.active && !was_active {
+        env.storage()
+            .persistent()
+            .set(&PointsDataKey::PointsStartTime, &env.ledger().timestamp());
+    }
+
+    env.storage()
+        .persistent()
+        .set(&PointsDataKey::PointsConfig, &config);
+    Ok(())
+}
+
+/// Get the points program configuration, if set
+pub fn get_points_config(env: &Env) -> Option<PointsConfig> {
+    env.storage().persistent().get(&PointsDataKey::PointsConfig)
+}
+
+/// Settle points earned by a user's current position since the last checkpoint
+///
+/// Must be called before any change to the user's collateral or debt. Gauge
+/// multipliers and the reward boost are taken as of the checkpoint.
+pub(crate) fn checkpoint(env: &Env, user: &Address) {
+    let earned = pending_points(env, user);
+    let mut account = get_account(env, user);
+    account.points = account.points.saturating_add(earned);
+    account.last_update = env.ledger().timestamp();
+    env.storage()
+        .persistent()
+        .set(&PointsDataKey::PointsAccount(user.clone()), &account);
+
+    if earned > 0 {
+        let total = get_total_points(env).saturating_add(earned);
+        env.storage()
+            .persistent()
+            .set(&PointsDataKey::TotalPoints, &total);
+    }
+}
+
+/// Get a user's points, including points earned since the last checkpoint
+pub fn get_points(env: &Env, user: &Address) -> i128 {
+    get_account(env, user)
+        .points
+        .saturating_add(pending_points(env, user))
+}
+
+/// Get the total settled points across all users
+pub fn get_total_points(env: &Env) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&PointsDataKey::TotalPoints)
+        .unwrap_or(0)
+}
+
+fn get_account(env: &Env, user: &Address) -> PointsAccount {
+    env.storage()
+        .persistent()
+        .get(&PointsDataKey::PointsAccount(user.clone()))
+        .unwrap_or(PointsAccount {
+            points: 0,
+            last_update: env.ledger().timestamp(),
+        })
+}
+
+fn pending_points(env: &Env, user: &Address) -> i128 {
+    let config = match get_points_config(env) {
+        Some(config) if config.active => config,
+        _ => return 0,
+    };
+
+    let start_time: u64 = env
+        .storage()
+        .persistent()
+        .get(&PointsDataKey::PointsStartTime)
+        .unwrap_or(0);
+    let from = env
+        .storage()
+        .persistent()
+        .get::<_, PointsAccount>(&PointsDataKey::PointsAccount(user.clone()))
+        .map(|a| a.last_update.max(start_time))
+        .unwrap_or(start_time);
+    let elapsed = env.ledger().timestamp().saturating_sub(from) as i128;
+    if elapsed == 0 {
+        return 0;
+    }
+
+    let supplied = supplied_value(env, user);
+    let debt = get_debt_position(env, user);
+    let borrowed = gauge_weighted(
+        env,
+        &debt.asset,
+        amount_to_value(env, &debt.asset, debt.borrowed_amount),
+    );
+
+    let supply_points = supplied
+        .saturating_mul(config.supply_rate)
+        .saturating_mul(elapsed)
+        / (10000 * SECONDS_PER_DAY);
+    let borrow_points = borrowed
+        .saturating_mul(config.borrow_rate)
+        .saturating_mul(elapsed)
+        / (10000 * SECONDS_PER_DAY);
+    supply_points
+        .saturating_add(borrow_points)
+        .saturating_mul(get_reward_boost(env, user))
+        / 10000
+}
+
+/// Gauge-weighted value of a user's collateral and peer-to-peer supply at oracle prices
+fn supplied_value(env: &Env, user: &Address) -> i128 {
+    let collateral = get_collateral_position(env, user);
+    crate::p2p::get_supplier_assets(env, user).iter().fold(
+        gauge_weighted(
+            env,
+            &collateral.asset,
+            amount_to_value(env, &collateral.asset, collateral.amount),
+        ),
+        |total, asset| {
+            let supply = crate::p2p::get_p2p_supply(env, user, &asset);
+            total.saturating_add(gauge_weighted(
+                env,
+                &asset,
+                amount_to_value(env, &asset, supply.in_p2p.saturating_add(supply.on_pool)),
+            ))
+        },
+    )
+}
+
+fn gauge_weighted(env: &Env, market: &Address, value: i128) -> i128 {
+    value.saturating_mul(get_gauge_multiplier(env, market)) / 10000
+}
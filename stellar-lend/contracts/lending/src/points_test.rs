@@ -0,0 +1,156 @@
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env, Map,
+};
+
+const DAY: u64 = 86400;
+
+fn setup(env: &Env) -> (LendingContractClient<'_>, Address) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 0);
+
+    let contract_id = env.register(LendingContract, ());
+    let client = LendingContractClient::new(env, &contract_id);
+    client.initialize(
+        &Address::generate(env),
+        &InitConfig::new(env, 1_000_000_000, 1000),
+    );
+
+    let admin = Address::generate(env);
+    client.set_admin(&admin);
+    (client, admin)
+}
+
+fn config(active: bool) -> PointsConfig {
+    PointsConfig {
+        active,
+        supply_rate: 10,
+        borrow_rate: 20,
+    }
+}
+
+#[test]
+fn test_points_accrue_on_supply_and_borrow() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let user = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let collateral_asset = Address::generate(&env);
+
+    client.set_points_config(&admin, &config(true));
+    client.borrow(&user, &asset, &100_000, &collateral_asset, &200_000);
+
+    env.ledger().with_mut(|li| li.timestamp = DAY);
+    // 200,000 * 10 / 10,000 + 100,000 * 20 / 10,000 per day
+    assert_eq!(client.get_points(&user), 200 + 200);
+}
+
+#[test]
+fn test_points_settle_on_position_change() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let user = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let collateral_asset = Address::generate(&env);
+
+    client.set_points_config(&admin, &config(true));
+    client.borrow(&user, &asset, &100_000, &collateral_asset, &200_000);
+
+    env.ledger().with_mut(|li| li.timestamp = DAY);
+    client.repay(&user, &200_000);
+    assert_eq!(client.get_total_points(), 400);
+
+    // Only collateral keeps earning after full repayment
+    env.ledger().with_mut(|li| li.timestamp = 2 * DAY);
+    assert_eq!(client.get_points(&user), 400 + 200);
+}
+
+#[test]
+fn test_no_retroactive_points_before_activation() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let user = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let collateral_asset = Address::generate(&env);
+
+    client.set_points_config(&admin, &config(false));
+    client.borrow(&user, &asset, &100_000, &collateral_asset, &200_000);
+
+    env.ledger().with_mut(|li| li.timestamp = 10 * DAY);
+    assert_eq!(client.get_points(&user), 0);
+
+    client.set_points_config(&admin, &config(true));
+    env.ledger().with_mut(|li| li.timestamp = 11 * DAY);
+    assert_eq!(client.get_points(&user), 400);
+}
+
+#[test]
+fn test_points_use_priced_value_and_p2p_supply() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let user = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let collateral_asset = Address::generate(&env);
+
+    // Collateral at 2.0 and debt at 0.5 of the unit of value
+    client.set_asset_price(&admin, &collateral_asset, &20_000_000);
+    client.set_asset_price(&admin, &asset, &5_000_000);
+    client.set_p2p_market(
+        &admin,
+        &asset,
+        &P2PMarket {
+            pool_supply_rate_bps: 0,
+            p2p_cursor_bps: 5000,
+        },
+    );
+    client.set_points_config(&admin, &config(true));
+    client.borrow(&user, &asset, &100_000, &collateral_asset, &200_000);
+    client.p2p_supply(&user, &asset, &40_000);
+
+    env.ledger().with_mut(|li| li.timestamp = DAY);
+    // (400,000 + 20,000) * 10 / 10,000 + 50,000 * 20 / 10,000 per day
+    assert_eq!(client.get_points(&user), 420 + 100);
+}
+
+#[test]
+fn test_points_follow_gauge_weights_and_boost() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let user = Address::generate(&env);
+    let voter = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let collateral_asset = Address::generate(&env);
+
+    // All votes go to the collateral market, none to the debt market
+    client.add_gauge(&admin, &collateral_asset);
+    client.add_gauge(&admin, &asset);
+    client.create_lock(&voter, &1_000_000, &(4 * 365 * DAY));
+    let mut weights = Map::new(&env);
+    weights.set(collateral_asset.clone(), 10000u32);
+    client.vote_for_gauges(&voter, &weights);
+    assert_eq!(client.get_gauge_multiplier(&collateral_asset), 20000);
+    assert_eq!(client.get_gauge_multiplier(&asset), 0);
+    assert_eq!(client.get_gauge_multiplier(&Address::generate(&env)), 10000);
+
+    // The user's own lock earns the full 2.5x boost
+    client.set_full_boost_power(&admin, &500_000);
+    client.create_lock(&user, &1_000_000, &(4 * 365 * DAY));
+
+    client.set_points_config(&admin, &config(true));
+    client.borrow(&user, &asset, &100_000, &collateral_asset, &200_000);
+
+    env.ledger().with_mut(|li| li.timestamp = DAY);
+    // 200,000 * 2x * 10 / 10,000 per day with no borrow points, boosted 2.5x
+    assert_eq!(client.get_points(&user), 400 * 25 / 10);
+}
+
+#[test]
+fn test_points_config_admin_only() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    let user = Address::generate(&env);
+
+    let result = client.try_set_points_config(&user, &config(true));
+    assert_eq!(result, Err(Ok(PointsError::Unauthorized)));
+}
@@ -0,0 +1,228 @@
+use soroban_sdk::{contractclient, contracterror, contracttype, Address, Env, Symbol};
+
+use crate::admin::{get_guardian, require_admin};
+use crate::borrow::get_health_factor;
+
+/// Interface a hook contract implements to follow position changes
+#[contractclient(name = "PositionHookClient")]
+pub trait PositionHook {
+    /// Called after `user`'s position changed
+    fn on_position_event(env: Env, user: Address, event: HookEvent);
+}
+
+/// Interface a borrower contract implements to hear about its liquidations
+#[contractclient(name = "LiquidationReceiverClient")]
+pub trait LiquidationReceiver {
+    /// Called after part of the caller's position was liquidated
+    fn on_liquidation(env: Env, debt_repaid: i128, collateral_seized: i128);
+}
+
+/// Errors that can occur when registering position hooks
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum PositionHookError {
+    Unauthorized = 1,
+    InvalidConfig = 2,
+}
+
+/// Storage keys for position hook data
+#[contracttype]
+#[derive(Clone)]
+pub enum PositionHookDataKey {
+    UserHook(Address),
+    /// Hook the admin registered for every position
+    GlobalHook,
+    /// Present while a user's health is below their hook's threshold
+    HookHealthBelow(Address),
+    /// Present when the borrower is called back on liquidation
+    LiquidationCallback(Address),
+}
+
+/// A position change reported to hooks
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum HookEvent {
+    /// Principal borrowed
+    Borrowed(i128),
+    /// Debt repaid, including repayments from liquidations
+    Repaid(i128),
+    /// The health factor fell below the user's hook threshold
+    HealthBelowThreshold(i128),
+}
+
+/// A user's hook contract
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct UserHook {
+    pub hook: Address,
+    /// Health factor whose downward crossing is reported (basis points,
+    /// 10000 = 1.0); zero reports no crossings
+    pub health_threshold: i128,
+}
+
+/// Register a hook contract called after the user's position changes
+///
+/// Hooks are called after borrows and repayments, and when the health factor
+/// falls below the threshold. A call is made at most once per hook and
+/// position change, and a failing hook never fails the operation.
+pub fn register_position_hook(
+    env: &Env,
+    user: Address,
+    hook: UserHook,
+) -> Result<(), PositionHookError> {
+    user.require_auth();
+    if hook.health_threshold < 0 || hook.hook == env.current_contract_address() {
+        return Err(PositionHookError::InvalidConfig);
+    }
+    env.storage()
+        .persistent()
+        .set(&PositionHookDataKey::UserHook(user.clone()), &hook);
+    env.storage()
+        .persistent()
+        .remove(&PositionHookDataKey::HookHealthBelow(user.clone()));
+    crate::events::publish(
+        env,
+        (Symbol::new(env, "position_hook_registered"), user),
+        hook.hook,
+    );
+    Ok(())
+}
+
+/// Remove the user's hook contract
+pub fn remove_position_hook(env: &Env, user: Address) {
+    user.require_auth();
+    env.storage()
+        .persistent()
+        .remove(&PositionHookDataKey::UserHook(user.clone()));
+    env.storage()
+        .persistent()
+        .remove(&PositionHookDataKey::HookHealthBelow(user));
+}
+
+/// Get the user's hook contract, if any
+pub fn get_position_hook(env: &Env, user: &Address) -> Option<UserHook> {
+    env.storage()
+        .persistent()
+        .get(&PositionHookDataKey::UserHook(user.clone()))
+}
+
+/// Register a hook called after every position's borrows and repayments
+/// (admin only)
+pub fn set_global_position_hook(
+    env: &Env,
+    caller: Address,
+    hook: Option<Address>,
+) -> Result<(), PositionHookError> {
+    require_admin(env, &caller).map_err(|_| PositionHookError::Unauthorized)?;
+    match hook {
+        Some(hook) if hook == env.current_contract_address() => {
+            return Err(PositionHookError::InvalidConfig)
+        }
+        Some(hook) => env
+            .storage()
+            .persistent()
+            .set(&PositionHookDataKey::GlobalHook, &hook),
+        None => env
+            .storage()
+            .persistent()
+            .remove(&PositionHookDataKey::GlobalHook),
+    }
+    Ok(())
+}
+
+/// Get the hook called after every position change, if any
+pub fn get_global_position_hook(env: &Env) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&PositionHookDataKey::GlobalHook)
+}
+
+/// Have the borrower contract's `on_liquidation()` called after each of its
+/// liquidations
+///
+/// A liquidation cannot tell a contract from an account by its address, so
+/// contract borrowers opt in. The call is best-effort: a failing receiver
+/// does not fail the liquidation. Soroban gives a sub-call no budget of its
+/// own, so the guardian may disable a receiver that exhausts it.
+pub fn enable_liquidation_callback(env: &Env, user: Address) {
+    user.require_auth();
+    env.storage()
+        .persistent()
+        .set(&PositionHookDataKey::LiquidationCallback(user), &true);
+}
+
+/// Stop calling the borrower back on liquidation (the borrower or guardian)
+pub fn disable_liquidation_callback(
+    env: &Env,
+    caller: Address,
+    user: Address,
+) -> Result<(), PositionHookError> {
+    if caller != user && get_guardian(env).as_ref() != Some(&caller) {
+        return Err(PositionHookError::Unauthorized);
+    }
+    caller.require_auth();
+    env.storage()
+        .persistent()
+        .remove(&PositionHookDataKey::LiquidationCallback(user));
+    Ok(())
+}
+
+/// Whether the borrower is called back on liquidation
+pub fn has_liquidation_callback(env: &Env, user: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&PositionHookDataKey::LiquidationCallback(user.clone()))
+}
+
+/// Tell an opted-in borrower what a liquidation repaid and seized
+pub(crate) fn notify_liquidation(
+    env: &Env,
+    user: &Address,
+    debt_repaid: i128,
+    collateral_seized: i128,
+) {
+    if has_liquidation_callback(env, user) {
+        let _ = LiquidationReceiverClient::new(env, user)
+            .try_on_liquidation(&debt_repaid, &collateral_seized);
+    }
+}
+
+/// Report a position change to the user's and the global hook
+///
+/// `event` is `None` for changes that are only checked for a health crossing,
+/// such as collateral withdrawals.
+pub(crate) fn notify(env: &Env, user: &Address, event: Option<HookEvent>) {
+    let user_hook = get_position_hook(env, user);
+    if let Some(event) = event {
+        if let Some(hook) = get_global_position_hook(env) {
+            call(env, &hook, user, &event);
+        }
+        if let Some(hook) = &user_hook {
+            call(env, &hook.hook, user, &event);
+        }
+    }
+
+    let Some(hook) = user_hook.filter(|hook| hook.health_threshold > 0) else {
+        return;
+    };
+    let key = PositionHookDataKey::HookHealthBelow(user.clone());
+    let health = get_health_factor(env, user);
+    let was_below = env.storage().persistent().has(&key);
+    if health < hook.health_threshold && !was_below {
+        env.storage().persistent().set(&key, &true);
+        call(
+            env,
+            &hook.hook,
+            user,
+            &HookEvent::HealthBelowThreshold(health),
+        );
+    } else if health >= hook.health_threshold && was_below {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Call a hook, ignoring any failure
+fn call(env: &Env, hook: &Address, user: &Address, event: &HookEvent) {
+    let _ = PositionHookClient::new(env, hook).try_on_position_event(user, event);
+}
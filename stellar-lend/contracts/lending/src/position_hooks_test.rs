@@ -0,0 +1,190 @@
+use super::*;
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Ledger},
+    Address, Env, Symbol, Vec,
+};
+
+/// Hook recording every event it receives
+#[contract]
+pub struct MockHook;
+
+#[contractimpl]
+impl MockHook {
+    pub fn on_position_event(env: Env, _user: Address, event: HookEvent) {
+        let mut events = Self::events(env.clone());
+        events.push_back(event);
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "events"), &events);
+    }
+
+    pub fn events(env: Env) -> Vec<HookEvent> {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "events"))
+            .unwrap_or(Vec::new(&env))
+    }
+}
+
+mod failing {
+    use super::*;
+
+    /// Hook that always fails
+    #[contract]
+    pub struct FailingHook;
+
+    #[contractimpl]
+    impl FailingHook {
+        pub fn on_position_event(_env: Env, _user: Address, _event: HookEvent) {
+            panic!("hook failure");
+        }
+    }
+}
+
+/// Borrowing vault recording the liquidations it is told about
+#[contract]
+pub struct MockVault;
+
+#[contractimpl]
+impl MockVault {
+    pub fn on_liquidation(env: Env, debt_repaid: i128, collateral_seized: i128) {
+        env.storage().instance().set(
+            &Symbol::new(&env, "liquidated"),
+            &(debt_repaid, collateral_seized),
+        );
+    }
+
+    pub fn last_liquidation(env: Env) -> Option<(i128, i128)> {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "liquidated"))
+    }
+}
+
+fn setup(env: &Env) -> (LendingContractClient<'_>, Address) {
+    env.mock_all_auths();
+    let client = LendingContractClient::new(env, &env.register(LendingContract, ()));
+    let admin = Address::generate(env);
+    client.initialize(&admin, &InitConfig::new(env, 1_000_000_000, 1000));
+    (client, admin)
+}
+
+#[test]
+fn test_hook_follows_position_changes() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    let hook = MockHookClient::new(&env, &env.register(MockHook, ()));
+    let user = Address::generate(&env);
+    let mut config = UserHook {
+        hook: hook.address.clone(),
+        health_threshold: 0,
+    };
+    client.register_position_hook(&user, &config);
+
+    client.borrow(
+        &user,
+        &Address::generate(&env),
+        &10_000,
+        &Address::generate(&env),
+        &20_000,
+    );
+    client.repay(&user, &1_000);
+    client.withdraw_collateral(&user, &3_000);
+
+    // Dropping below the threshold is reported once, not on every change
+    config.health_threshold = client.get_health_factor(&user) - 1;
+    client.register_position_hook(&user, &config);
+    client.withdraw_collateral(&user, &3_000);
+    let health = client.get_health_factor(&user);
+    client.withdraw_collateral(&user, &500);
+
+    let events = hook.events();
+    assert_eq!(events.len(), 3);
+    assert_eq!(events.get(0), Some(HookEvent::Borrowed(10_000)));
+    assert_eq!(events.get(1), Some(HookEvent::Repaid(1_000)));
+    assert_eq!(events.get(2), Some(HookEvent::HealthBelowThreshold(health)));
+
+    client.remove_position_hook(&user);
+    assert_eq!(client.get_position_hook(&user), None);
+}
+
+#[test]
+fn test_failing_hook_is_non_fatal() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let failing = env.register(failing::FailingHook, ());
+    client.set_global_position_hook(&admin, &Some(failing.clone()));
+    assert_eq!(client.get_global_position_hook(), Some(failing));
+
+    let user = Address::generate(&env);
+    client.borrow(
+        &user,
+        &Address::generate(&env),
+        &10_000,
+        &Address::generate(&env),
+        &20_000,
+    );
+    client.repay(&user, &1_000);
+    assert_eq!(client.get_user_debt(&user).borrowed_amount, 9_000);
+}
+
+#[test]
+fn test_liquidated_contract_borrower_is_called_back() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let asset = Address::generate(&env);
+    client.set_p2p_market(
+        &admin,
+        &asset,
+        &P2PMarket {
+            pool_supply_rate_bps: 200,
+            p2p_cursor_bps: 5000,
+        },
+    );
+    client.p2p_supply(&Address::generate(&env), &asset, &100_000);
+    client.set_deleverage_config(
+        &admin,
+        &DeleverageConfig {
+            critical_utilization_bps: 9000,
+            target_utilization_bps: 8000,
+            min_crunch_duration: 86400,
+            max_close_bps: 2500,
+            penalty_bps: 200,
+        },
+    );
+
+    let vault = MockVaultClient::new(&env, &env.register(MockVault, ()));
+    client.enable_liquidation_callback(&vault.address);
+    assert!(client.has_liquidation_callback(&vault.address));
+    client.borrow(
+        &vault.address,
+        &asset,
+        &60_000,
+        &Address::generate(&env),
+        &120_000,
+    );
+    client.borrow(
+        &Address::generate(&env),
+        &asset,
+        &32_000,
+        &Address::generate(&env),
+        &64_000,
+    );
+    env.ledger().with_mut(|li| li.timestamp += 86400);
+
+    let keeper = Address::generate(&env);
+    client.deleverage(&keeper, &vault.address, &1_000);
+    assert_eq!(vault.last_liquidation(), Some((1_000, 1_020)));
+
+    // The guardian can switch off a receiver that stands in the way
+    assert_eq!(
+        client.try_disable_liquidation_callback(&keeper, &vault.address),
+        Err(Ok(PositionHookError::Unauthorized))
+    );
+    let guardian = Address::generate(&env);
+    client.set_guardian(&admin, &guardian);
+    client.disable_liquidation_callback(&guardian, &vault.address);
+    client.deleverage(&keeper, &vault.address, &500);
+    assert_eq!(vault.last_liquidation(), Some((1_000, 1_020)));
+}
@@ -0,0 +1,74 @@
+use soroban_sdk::{contracterror, contracttype, Address, Env};
+
+use crate::admin::require_admin;
+use crate::borrow::{get_collateral_position, get_debt_position};
+
+/// Errors that can occur when configuring the position limit
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum PositionLimitError {
+    Unauthorized = 1,
+    InvalidConfig = 2,
+}
+
+/// Storage keys for position limit data
+#[contracttype]
+#[derive(Clone)]
+pub enum PositionLimitDataKey {
+    MaxUserPositions,
+}
+
+const DEFAULT_MAX_USER_POSITIONS: u32 = 20;
+
+/// Set the maximum number of open positions per user (admin only)
+///
+/// Existing positions above a lowered limit stay open; only new ones are
+/// rejected.
+pub fn set_max_user_positions(
+    env: &Env,
+    caller: Address,
+    max_positions: u32,
+) -> Result<(), PositionLimitError> {
+    require_admin(env, &caller).map_err(|_| PositionLimitError::Unauthorized)?;
+
+    if max_positions == 0 {
+        return Err(PositionLimitError::InvalidConfig);
+    }
+    env.storage()
+        .persistent()
+        .set(&PositionLimitDataKey::MaxUserPositions, &max_positions);
+    Ok(())
+}
+
+/// Get the maximum number of open positions per user
+pub fn get_max_user_positions(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&PositionLimitDataKey::MaxUserPositions)
+        .unwrap_or(DEFAULT_MAX_USER_POSITIONS)
+}
+
+/// Count a user's open positions
+///
+/// The core collateral/debt position counts once; every escrowed NFT,
+/// restricted collateral asset and open fixed-term or amortizing loan counts
+/// separately, as each is visited when valuing or liquidating the portfolio.
+pub fn get_user_position_count(env: &Env, user: &Address) -> u32 {
+    let core = if has_core_position(env, user) { 1 } else { 0 };
+    core + crate::nft::get_user_nfts(env, user).len()
+        + crate::rwa::get_rwa_user_assets(env, user).len()
+        + crate::fixed_rate::get_borrower_fixed_loans(env, user).len()
+        + crate::amortizing::get_borrower_amortizing_loans(env, user).len()
+}
+
+/// Whether a user may open one more position
+pub(crate) fn has_position_capacity(env: &Env, user: &Address) -> bool {
+    get_user_position_count(env, user) < get_max_user_positions(env)
+}
+
+/// Whether a user holds core pool collateral or debt
+pub(crate) fn has_core_position(env: &Env, user: &Address) -> bool {
+    get_collateral_position(env, user).amount > 0
+        || get_debt_position(env, user).borrowed_amount > 0
+}
@@ -0,0 +1,100 @@
+use super::*;
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+fn setup(env: &Env) -> (LendingContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    let client = LendingContractClient::new(env, &env.register(LendingContract, ()));
+    client.initialize(
+        &Address::generate(env),
+        &InitConfig::new(env, 1_000_000_000, 1000),
+    );
+
+    let admin = Address::generate(env);
+    client.set_admin(&admin);
+    let collection = Address::generate(env);
+    client.approve_nft_collection(&admin, &collection, &5000);
+    client.set_nft_floor(&admin, &collection, &100_000);
+    client.set_max_user_positions(&admin, &3);
+    (client, admin, collection)
+}
+
+#[test]
+fn test_positions_counted_across_kinds() {
+    let env = Env::default();
+    let (client, _, collection) = setup(&env);
+    let user = Address::generate(&env);
+    let asset = Address::generate(&env);
+
+    assert_eq!(client.get_user_position_count(&user), 0);
+    client.borrow(&user, &asset, &10_000, &Address::generate(&env), &150_000);
+    client.escrow_nft(&user, &collection, &1);
+    let offer_id = client.post_offer(&Address::generate(&env), &asset, &50_000, &500, &86400);
+    client.take_offer(&user, &offer_id, &10_000);
+    assert_eq!(client.get_user_position_count(&user), 3);
+
+    // Adding to an existing core position is not a new position
+    client.borrow(&user, &asset, &1000, &Address::generate(&env), &1500);
+    assert_eq!(client.get_user_position_count(&user), 3);
+}
+
+#[test]
+fn test_new_positions_rejected_at_limit() {
+    let env = Env::default();
+    let (client, _, collection) = setup(&env);
+    let user = Address::generate(&env);
+    let asset = Address::generate(&env);
+
+    client.escrow_nft(&user, &collection, &1);
+    client.escrow_nft(&user, &collection, &2);
+    client.escrow_nft(&user, &collection, &3);
+    assert_eq!(
+        client.try_escrow_nft(&user, &collection, &4),
+        Err(Ok(NftError::TooManyPositions))
+    );
+    // Opening a core position would be a fourth
+    assert_eq!(
+        client.try_nft_borrow(&user, &asset, &10_000),
+        Err(Ok(NftError::TooManyPositions))
+    );
+    assert_eq!(
+        client.try_borrow(&user, &asset, &10_000, &Address::generate(&env), &20_000),
+        Err(Ok(BorrowError::TooManyPositions))
+    );
+}
+
+#[test]
+fn test_closing_positions_frees_capacity() {
+    let env = Env::default();
+    let (client, _, collection) = setup(&env);
+    let user = Address::generate(&env);
+    let asset = Address::generate(&env);
+
+    client.borrow(&user, &asset, &10_000, &Address::generate(&env), &150_000);
+    client.escrow_nft(&user, &collection, &1);
+    let lender = Address::generate(&env);
+    let offer_id = client.post_offer(&lender, &asset, &50_000, &500, &86400);
+    let loan_id = client.take_offer(&user, &offer_id, &10_000);
+    assert_eq!(
+        client.try_take_offer(&user, &offer_id, &10_000),
+        Err(Ok(FixedRateError::TooManyPositions))
+    );
+
+    client.repay_fixed_loan(&user, &loan_id);
+    client.take_offer(&user, &offer_id, &10_000);
+}
+
+#[test]
+fn test_position_limit_config() {
+    let env = Env::default();
+    let (client, admin, _) = setup(&env);
+
+    assert_eq!(client.get_max_user_positions(), 3);
+    assert_eq!(
+        client.try_set_max_user_positions(&Address::generate(&env), &10),
+        Err(Ok(PositionLimitError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_max_user_positions(&admin, &0),
+        Err(Ok(PositionLimitError::InvalidConfig))
+    );
+}
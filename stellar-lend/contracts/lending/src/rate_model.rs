@@ -0,0 +1,178 @@
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol};
+
+use crate::admin::{require_admin, require_risk_manager};
+
+/// Errors that can occur when configuring the borrow rate
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum RateModelError {
+    Unauthorized = 1,
+    InvalidConfig = 2,
+}
+
+/// Storage keys for borrow rate data
+#[contracttype]
+#[derive(Clone)]
+pub enum RateModelDataKey {
+    BorrowRateModel,
+    BorrowRateBounds,
+}
+
+/// Utilization-based pool borrow rate with a kink at the optimal utilization
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BorrowRateModel {
+    /// Rate at zero utilization, in basis points per year
+    pub base_rate_bps: i128,
+    /// Rate added between zero and optimal utilization
+    pub slope_bps: i128,
+    /// Utilization at the kink, in basis points
+    pub optimal_utilization_bps: i128,
+    /// Rate added between optimal and full utilization
+    pub jump_slope_bps: i128,
+}
+
+/// Bounds the model rate is clamped to, in basis points per year
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BorrowRateBounds {
+    pub floor_bps: i128,
+    pub cap_bps: i128,
+}
+
+/// Rate clamping event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct RateClampedEvent {
+    pub model_rate_bps: i128,
+    pub rate_bps: i128,
+    pub utilization_bps: i128,
+    pub timestamp: u64,
+}
+
+/// Set the pool borrow rate model (risk manager or admin)
+pub fn set_borrow_rate_model(
+    env: &Env,
+    caller: Address,
+    model: BorrowRateModel,
+) -> Result<(), RateModelError> {
+    require_risk_manager(env, &caller).map_err(|_| RateModelError::Unauthorized)?;
+    apply_borrow_rate_model(env, model)
+}
+
+/// Validate and store the borrow rate model; callers check authorization
+pub(crate) fn apply_borrow_rate_model(
+    env: &Env,
+    model: BorrowRateModel,
+) -> Result<(), RateModelError> {
+    if !is_valid_rate_model(&model) {
+        return Err(RateModelError::InvalidConfig);
+    }
+    store_borrow_rate_model(env, &model);
+    crate::reserve::refresh_borrow_rates(env);
+    Ok(())
+}
+
+pub(crate) fn is_valid_rate_model(model: &BorrowRateModel) -> bool {
+    model.base_rate_bps >= 0
+        && model.slope_bps >= 0
+        && model.jump_slope_bps >= 0
+        && (1..=10000).contains(&model.optimal_utilization_bps)
+}
+
+pub(crate) fn store_borrow_rate_model(env: &Env, model: &BorrowRateModel) {
+    env.storage()
+        .persistent()
+        .set(&RateModelDataKey::BorrowRateModel, model);
+}
+
+/// Set the floor and cap the borrow rate is clamped to (admin only)
+pub fn set_borrow_rate_bounds(
+    env: &Env,
+    caller: Address,
+    bounds: BorrowRateBounds,
+) -> Result<(), RateModelError> {
+    require_admin(env, &caller).map_err(|_| RateModelError::Unauthorized)?;
+    apply_borrow_rate_bounds(env, bounds)
+}
+
+/// Validate and store the borrow rate bounds; callers check authorization
+pub(crate) fn apply_borrow_rate_bounds(
+    env: &Env,
+    bounds: BorrowRateBounds,
+) -> Result<(), RateModelError> {
+    if bounds.floor_bps < 0 || bounds.cap_bps < bounds.floor_bps {
+        return Err(RateModelError::InvalidConfig);
+    }
+    env.storage()
+        .persistent()
+        .set(&RateModelDataKey::BorrowRateBounds, &bounds);
+    crate::reserve::refresh_borrow_rates(env);
+    Ok(())
+}
+
+/// Get the borrow rate model, if set
+pub fn get_borrow_rate_model(env: &Env) -> Option<BorrowRateModel> {
+    env.storage()
+        .persistent()
+        .get(&RateModelDataKey::BorrowRateModel)
+}
+
+/// Get the borrow rate bounds, if set
+pub fn get_borrow_rate_bounds(env: &Env) -> Option<BorrowRateBounds> {
+    env.storage()
+        .persistent()
+        .get(&RateModelDataKey::BorrowRateBounds)
+}
+
+/// Rate produced by the model at current utilization, before clamping
+///
+/// The governed base interest rate while no model is set.
+pub fn get_model_borrow_rate(env: &Env) -> i128 {
+    let Some(model) = get_borrow_rate_model(env) else {
+        return crate::borrow::get_base_interest_rate(env);
+    };
+    let utilization = crate::deleverage::get_utilization(env).min(10000);
+    let optimal = model.optimal_utilization_bps;
+    if utilization <= optimal {
+        model
+            .base_rate_bps
+            .saturating_add(model.slope_bps.saturating_mul(utilization) / optimal)
+    } else {
+        let excess = utilization - optimal;
+        let jump = if optimal < 10000 {
+            model.jump_slope_bps.saturating_mul(excess) / (10000 - optimal)
+        } else {
+            0
+        };
+        model
+            .base_rate_bps
+            .saturating_add(model.slope_bps)
+            .saturating_add(jump)
+    }
+}
+
+/// Pool borrow rate in basis points per year: the model rate within the bounds
+pub fn get_borrow_rate(env: &Env) -> i128 {
+    let rate = get_model_borrow_rate(env);
+    match get_borrow_rate_bounds(env) {
+        Some(bounds) => rate.clamp(bounds.floor_bps, bounds.cap_bps),
+        None => rate,
+    }
+}
+
+/// Report when the bounds override the model at a rate checkpoint
+pub(crate) fn checkpoint_borrow_rate(env: &Env) {
+    let model_rate = get_model_borrow_rate(env);
+    let rate = get_borrow_rate(env);
+    if rate != model_rate {
+        let event = RateClampedEvent {
+            model_rate_bps: model_rate,
+            rate_bps: rate,
+            utilization_bps: crate::deleverage::get_utilization(env),
+            timestamp: env.ledger().timestamp(),
+        };
+        crate::events::publish(env, (Symbol::new(env, "borrow_rate_clamped"),), event);
+    }
+}
@@ -0,0 +1,149 @@
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    Address, Env, Symbol, TryFromVal,
+};
+
+const YEAR: u64 = 31536000;
+
+fn setup(env: &Env) -> (LendingContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 0);
+    let client = LendingContractClient::new(env, &env.register(LendingContract, ()));
+    client.initialize(
+        &Address::generate(env),
+        &InitConfig::new(env, 1_000_000_000, 1000),
+    );
+
+    let admin = Address::generate(env);
+    client.set_admin(&admin);
+    let asset = Address::generate(env);
+    client.set_p2p_market(
+        &admin,
+        &asset,
+        &P2PMarket {
+            pool_supply_rate_bps: 200,
+            p2p_cursor_bps: 5000,
+        },
+    );
+    client.p2p_supply(&Address::generate(env), &asset, &100_000);
+    client.set_borrow_rate_model(
+        &admin,
+        &BorrowRateModel {
+            base_rate_bps: 200,
+            slope_bps: 800,
+            optimal_utilization_bps: 8000,
+            jump_slope_bps: 100_000,
+        },
+    );
+    (client, admin, asset)
+}
+
+#[test]
+fn test_rate_follows_utilization_curve() {
+    let env = Env::default();
+    let (client, _, asset) = setup(&env);
+
+    assert_eq!(client.get_borrow_rate(), 200);
+    client.borrow(
+        &Address::generate(&env),
+        &asset,
+        &40_000,
+        &Address::generate(&env),
+        &80_000,
+    );
+    assert_eq!(client.get_borrow_rate(), 600);
+    client.borrow(
+        &Address::generate(&env),
+        &asset,
+        &50_000,
+        &Address::generate(&env),
+        &100_000,
+    );
+    // Half way up the jump slope
+    assert_eq!(client.get_borrow_rate(), 51_000);
+}
+
+#[test]
+fn test_cap_bounds_extreme_rates() {
+    let env = Env::default();
+    let (client, admin, asset) = setup(&env);
+    client.set_borrow_rate_bounds(
+        &admin,
+        &BorrowRateBounds {
+            floor_bps: 300,
+            cap_bps: 5000,
+        },
+    );
+    assert_eq!(client.get_borrow_rate(), 300);
+
+    let user = Address::generate(&env);
+    client.borrow(&user, &asset, &90_000, &Address::generate(&env), &180_000);
+    assert_eq!(client.get_model_borrow_rate(), 51_000);
+    assert_eq!(client.get_borrow_rate(), 5000);
+
+    // A year at the capped 50% rather than 510%
+    env.ledger().with_mut(|li| li.timestamp = YEAR);
+    assert_eq!(client.get_user_debt(&user).interest_accrued, 45_000);
+}
+
+#[test]
+fn test_clamping_emits_event() {
+    let env = Env::default();
+    let (client, admin, asset) = setup(&env);
+    client.set_borrow_rate_bounds(
+        &admin,
+        &BorrowRateBounds {
+            floor_bps: 0,
+            cap_bps: 5000,
+        },
+    );
+    client.borrow(
+        &Address::generate(&env),
+        &asset,
+        &90_000,
+        &Address::generate(&env),
+        &180_000,
+    );
+
+    let user = Address::generate(&env);
+    client.borrow(&user, &asset, &1000, &Address::generate(&env), &2000);
+    let clamped = env.events().all().iter().any(|(_, topics, _)| {
+        Symbol::try_from_val(&env, &topics.get(0).unwrap())
+            == Ok(Symbol::new(&env, "borrow_rate_clamped"))
+    });
+    assert!(clamped);
+}
+
+#[test]
+fn test_rate_config_validation() {
+    let env = Env::default();
+    let (client, admin, _) = setup(&env);
+
+    assert_eq!(
+        client.try_set_borrow_rate_bounds(
+            &Address::generate(&env),
+            &BorrowRateBounds {
+                floor_bps: 0,
+                cap_bps: 5000,
+            },
+        ),
+        Err(Ok(RateModelError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_borrow_rate_bounds(
+            &admin,
+            &BorrowRateBounds {
+                floor_bps: 600,
+                cap_bps: 500,
+            },
+        ),
+        Err(Ok(RateModelError::InvalidConfig))
+    );
+    let mut model = client.get_borrow_rate_model().unwrap();
+    model.optimal_utilization_bps = 0;
+    assert_eq!(
+        client.try_set_borrow_rate_model(&admin, &model),
+        Err(Ok(RateModelError::InvalidConfig))
+    );
+}
@@ -0,0 +1,370 @@
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol};
+
+use crate::borrow::{get_borrow_index, update_borrow_index, SECONDS_PER_YEAR};
+use crate::math::{div_up, mul_div_up};
+
+/// Errors that can occur during interest rate swap operations
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum RateSwapError {
+    InvalidAmount = 1,
+    InvalidTerms = 2,
+    SwapNotFound = 3,
+    SwapNotProposed = 4,
+    SwapNotActive = 5,
+    NotSwapParty = 6,
+    SelfSwap = 7,
+    InsufficientMargin = 8,
+    NotSettleable = 9,
+    Overflow = 10,
+}
+
+/// Storage keys for interest rate swap data
+#[contracttype]
+#[derive(Clone)]
+pub enum RateSwapDataKey {
+    NextSwapId,
+    RateSwap(u64),
+}
+
+/// Lifecycle of an interest rate swap
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SwapStatus {
+    /// Posted by one side, waiting for a counterparty
+    Proposed,
+    /// Both sides have posted margin and the swap is accruing
+    Active,
+    /// Net payment applied and margins released
+    Settled,
+}
+
+/// A fixed-for-floating swap on a notional amount
+///
+/// The fixed payer pays `fixed_rate_bps` on the notional and receives the
+/// pool's floating borrow rate, measured by the growth of the borrow index.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RateSwap {
+    pub id: u64,
+    pub fixed_payer: Option<Address>,
+    pub floating_payer: Option<Address>,
+    pub notional: i128,
+    pub fixed_rate_bps: i128,
+    /// Swap duration in seconds, counted from acceptance
+    pub tenor: u64,
+    pub start: u64,
+    pub maturity: u64,
+    /// Pool borrow index when the swap became active
+    pub start_index: i128,
+    pub fixed_margin: i128,
+    pub floating_margin: i128,
+    pub status: SwapStatus,
+}
+
+/// Margin released to each side on settlement
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RateSwapSettlement {
+    pub fixed_payer_payout: i128,
+    pub floating_payer_payout: i128,
+}
+
+/// Interest rate swap event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct RateSwapEvent {
+    pub id: u64,
+    pub party: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+const INITIAL_MARGIN_BPS: i128 = 1000; // 10% of notional
+const MAINTENANCE_MARGIN_BPS: i128 = 500; // 5% of notional
+const MAX_SWAP_RATE_BPS: i128 = 10000; // 100% APR
+const MIN_SWAP_TENOR: u64 = 86400; // 1 day
+const MAX_SWAP_TENOR: u64 = 5 * SECONDS_PER_YEAR;
+
+/// Propose a swap, posting margin for one side
+///
+/// # Arguments
+/// * `pay_fixed` - Whether the proposer pays fixed (and receives floating)
+///
+/// # Returns
+/// The swap id
+pub fn propose_rate_swap(
+    env: &Env,
+    proposer: Address,
+    pay_fixed: bool,
+    notional: i128,
+    fixed_rate_bps: i128,
+    tenor: u64,
+    margin: i128,
+) -> Result<u64, RateSwapError> {
+    proposer.require_auth();
+
+    if notional <= 0 {
+        return Err(RateSwapError::InvalidAmount);
+    }
+    if !(0..=MAX_SWAP_RATE_BPS).contains(&fixed_rate_bps)
+        || !(MIN_SWAP_TENOR..=MAX_SWAP_TENOR).contains(&tenor)
+    {
+        return Err(RateSwapError::InvalidTerms);
+    }
+    if margin < initial_margin(notional)? {
+        return Err(RateSwapError::InsufficientMargin);
+    }
+
+    let id: u64 = env
+        .storage()
+        .persistent()
+        .get(&RateSwapDataKey::NextSwapId)
+        .unwrap_or(1);
+    env.storage()
+        .persistent()
+        .set(&RateSwapDataKey::NextSwapId, &(id + 1));
+
+    let (fixed_payer, floating_payer, fixed_margin, floating_margin) = if pay_fixed {
+        (Some(proposer.clone()), None, margin, 0)
+    } else {
+        (None, Some(proposer.clone()), 0, margin)
+    };
+    let swap = RateSwap {
+        id,
+        fixed_payer,
+        floating_payer,
+        notional,
+        fixed_rate_bps,
+        tenor,
+        start: 0,
+        maturity: 0,
+        start_index: 0,
+        fixed_margin,
+        floating_margin,
+        status: SwapStatus::Proposed,
+    };
+    save_swap(env, &swap);
+
+    emit_swap_event(env, "rate_swap_proposed", id, proposer, margin);
+    Ok(id)
+}
+
+/// Take the open side of a proposed swap, starting accrual
+pub fn accept_rate_swap(
+    env: &Env,
+    counterparty: Address,
+    id: u64,
+    margin: i128,
+) -> Result<(), RateSwapError> {
+    counterparty.require_auth();
+
+    let mut swap = get_rate_swap(env, id).ok_or(RateSwapError::SwapNotFound)?;
+    if swap.status != SwapStatus::Proposed {
+        return Err(RateSwapError::SwapNotProposed);
+    }
+    if swap.fixed_payer.as_ref() == Some(&counterparty)
+        || swap.floating_payer.as_ref() == Some(&counterparty)
+    {
+        return Err(RateSwapError::SelfSwap);
+    }
+    if margin < initial_margin(swap.notional)? {
+        return Err(RateSwapError::InsufficientMargin);
+    }
+
+    if swap.fixed_payer.is_none() {
+        swap.fixed_payer = Some(counterparty.clone());
+        swap.fixed_margin = margin;
+    } else {
+        swap.floating_payer = Some(counterparty.clone());
+        swap.floating_margin = margin;
+    }
+    let now = env.ledger().timestamp();
+    swap.start = now;
+    swap.maturity = now.saturating_add(swap.tenor);
+    swap.start_index = update_borrow_index(env);
+    swap.status = SwapStatus::Active;
+    save_swap(env, &swap);
+
+    emit_swap_event(env, "rate_swap_accepted", id, counterparty, margin);
+    Ok(())
+}
+
+/// Withdraw a proposal nobody has accepted yet
+///
+/// # Returns
+/// The margin released to the proposer
+pub fn cancel_rate_swap(env: &Env, proposer: Address, id: u64) -> Result<i128, RateSwapError> {
+    proposer.require_auth();
+
+    let swap = get_rate_swap(env, id).ok_or(RateSwapError::SwapNotFound)?;
+    if swap.status != SwapStatus::Proposed {
+        return Err(RateSwapError::SwapNotProposed);
+    }
+    let proposer_side = swap.fixed_payer.clone().or(swap.floating_payer.clone());
+    if proposer_side.as_ref() != Some(&proposer) {
+        return Err(RateSwapError::NotSwapParty);
+    }
+
+    env.storage()
+        .persistent()
+        .remove(&RateSwapDataKey::RateSwap(id));
+    let margin = swap.fixed_margin + swap.floating_margin;
+
+    emit_swap_event(env, "rate_swap_cancelled", id, proposer, margin);
+    Ok(margin)
+}
+
+/// Top up margin on one side of an active swap
+pub fn add_swap_margin(
+    env: &Env,
+    party: Address,
+    id: u64,
+    amount: i128,
+) -> Result<(), RateSwapError> {
+    party.require_auth();
+
+    if amount <= 0 {
+        return Err(RateSwapError::InvalidAmount);
+    }
+    let mut swap = get_rate_swap(env, id).ok_or(RateSwapError::SwapNotFound)?;
+    if swap.status != SwapStatus::Active {
+        return Err(RateSwapError::SwapNotActive);
+    }
+
+    if swap.fixed_payer.as_ref() == Some(&party) {
+        swap.fixed_margin = swap
+            .fixed_margin
+            .checked_add(amount)
+            .ok_or(RateSwapError::Overflow)?;
+    } else if swap.floating_payer.as_ref() == Some(&party) {
+        swap.floating_margin = swap
+            .floating_margin
+            .checked_add(amount)
+            .ok_or(RateSwapError::Overflow)?;
+    } else {
+        return Err(RateSwapError::NotSwapParty);
+    }
+    save_swap(env, &swap);
+
+    emit_swap_event(env, "rate_swap_margin_added", id, party, amount);
+    Ok(())
+}
+
+/// Settle a swap (permissionless)
+///
+/// Allowed at maturity, or earlier once either side's margin net of its
+/// mark-to-market loss falls below the maintenance margin. The net payment is
+/// capped at the losing side's margin.
+pub fn settle_rate_swap(env: &Env, id: u64) -> Result<RateSwapSettlement, RateSwapError> {
+    let mut swap = get_rate_swap(env, id).ok_or(RateSwapError::SwapNotFound)?;
+    if swap.status != SwapStatus::Active {
+        return Err(RateSwapError::SwapNotActive);
+    }
+
+    let pnl = fixed_payer_pnl(env, &swap);
+    let matured = env.ledger().timestamp() >= swap.maturity;
+    let maintenance = mul_div_up(swap.notional, MAINTENANCE_MARGIN_BPS, 10000);
+    let under_margined = swap.fixed_margin.saturating_add(pnl) < maintenance
+        || swap.floating_margin.saturating_sub(pnl) < maintenance;
+    if !matured && !under_margined {
+        return Err(RateSwapError::NotSettleable);
+    }
+
+    let payment = pnl.clamp(-swap.fixed_margin, swap.floating_margin);
+    let settlement = RateSwapSettlement {
+        fixed_payer_payout: swap.fixed_margin + payment,
+        floating_payer_payout: swap.floating_margin - payment,
+    };
+    swap.fixed_margin = 0;
+    swap.floating_margin = 0;
+    swap.status = SwapStatus::Settled;
+    save_swap(env, &swap);
+
+    if let Some(fixed_payer) = swap.fixed_payer {
+        emit_swap_event(
+            env,
+            "rate_swap_settled",
+            id,
+            fixed_payer,
+            settlement.fixed_payer_payout,
+        );
+    }
+    if let Some(floating_payer) = swap.floating_payer {
+        emit_swap_event(
+            env,
+            "rate_swap_settled",
+            id,
+            floating_payer,
+            settlement.floating_payer_payout,
+        );
+    }
+    Ok(settlement)
+}
+
+/// Get a swap by id
+pub fn get_rate_swap(env: &Env, id: u64) -> Option<RateSwap> {
+    env.storage()
+        .persistent()
+        .get(&RateSwapDataKey::RateSwap(id))
+}
+
+/// Mark-to-market value of an active swap to the fixed payer
+///
+/// Positive when the floating leg has out-accrued the fixed leg.
+pub fn get_rate_swap_pnl(env: &Env, id: u64) -> Result<i128, RateSwapError> {
+    let swap = get_rate_swap(env, id).ok_or(RateSwapError::SwapNotFound)?;
+    if swap.status != SwapStatus::Active {
+        return Err(RateSwapError::SwapNotActive);
+    }
+    Ok(fixed_payer_pnl(env, &swap))
+}
+
+/// Floating leg minus fixed leg, accrued from the start to maturity at most
+///
+/// Index growth observed after maturity is pro-rated back to the swap's term.
+fn fixed_payer_pnl(env: &Env, swap: &RateSwap) -> i128 {
+    let now = env.ledger().timestamp();
+    let elapsed = now.saturating_sub(swap.start) as i128;
+    if elapsed == 0 {
+        return 0;
+    }
+    let accrual_period = now.min(swap.maturity).saturating_sub(swap.start) as i128;
+
+    let index_growth = get_borrow_index(env).saturating_sub(swap.start_index);
+    let floating = swap
+        .notional
+        .saturating_mul(index_growth)
+        .saturating_mul(accrual_period)
+        / swap.start_index.saturating_mul(elapsed);
+    let fixed = swap
+        .notional
+        .saturating_mul(swap.fixed_rate_bps)
+        .saturating_mul(accrual_period)
+        / (10000 * SECONDS_PER_YEAR as i128);
+    floating.saturating_sub(fixed)
+}
+
+fn initial_margin(notional: i128) -> Result<i128, RateSwapError> {
+    notional
+        .checked_mul(INITIAL_MARGIN_BPS)
+        .map(|m| div_up(m, 10000))
+        .ok_or(RateSwapError::Overflow)
+}
+
+fn save_swap(env: &Env, swap: &RateSwap) {
+    env.storage()
+        .persistent()
+        .set(&RateSwapDataKey::RateSwap(swap.id), swap);
+}
+
+fn emit_swap_event(env: &Env, name: &str, id: u64, party: Address, amount: i128) {
+    let event = RateSwapEvent {
+        id,
+        party,
+        amount,
+        timestamp: env.ledger().timestamp(),
+    };
+    crate::events::publish(env, (Symbol::new(env, name), event.party.clone()), event);
+}
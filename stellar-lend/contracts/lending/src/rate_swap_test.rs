@@ -0,0 +1,178 @@
+use super::*;
+use rate_swap::SwapStatus;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+
+const YEAR: u64 = 31_536_000;
+
+fn setup(env: &Env) -> (LendingContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 0);
+
+    let contract_id = env.register(LendingContract, ());
+    let client = LendingContractClient::new(env, &contract_id);
+    client.initialize(
+        &Address::generate(env),
+        &InitConfig::new(env, 1_000_000_000, 1000),
+    );
+
+    let fixed_payer = Address::generate(env);
+    let floating_payer = Address::generate(env);
+    (client, fixed_payer, floating_payer)
+}
+
+#[test]
+fn test_swap_settles_at_maturity() {
+    let env = Env::default();
+    let (client, fixed_payer, floating_payer) = setup(&env);
+
+    let id = client.propose_rate_swap(&fixed_payer, &true, &100_000, &400, &YEAR, &10_000);
+    client.accept_rate_swap(&floating_payer, &id, &10_000);
+
+    let swap = client.get_rate_swap(&id).unwrap();
+    assert_eq!(swap.status, SwapStatus::Active);
+    assert_eq!(swap.floating_payer, Some(floating_payer.clone()));
+    assert_eq!(swap.maturity, YEAR);
+
+    env.ledger().with_mut(|li| li.timestamp = YEAR / 2);
+    assert_eq!(
+        client.try_settle_rate_swap(&id),
+        Err(Ok(RateSwapError::NotSettleable))
+    );
+
+    // Floating 5% against fixed 4% on 100,000 for a year
+    env.ledger().with_mut(|li| li.timestamp = YEAR);
+    assert_eq!(client.get_rate_swap_pnl(&id), 1000);
+
+    let settlement = client.settle_rate_swap(&id);
+    assert_eq!(settlement.fixed_payer_payout, 11_000);
+    assert_eq!(settlement.floating_payer_payout, 9000);
+    assert_eq!(
+        client.get_rate_swap(&id).unwrap().status,
+        SwapStatus::Settled
+    );
+    assert_eq!(
+        client.try_settle_rate_swap(&id),
+        Err(Ok(RateSwapError::SwapNotActive))
+    );
+}
+
+#[test]
+fn test_under_margined_swap_settles_early() {
+    let env = Env::default();
+    let (client, fixed_payer, floating_payer) = setup(&env);
+
+    let id = client.propose_rate_swap(&floating_payer, &false, &100_000, &0, &(5 * YEAR), &10_000);
+    client.accept_rate_swap(&fixed_payer, &id, &10_000);
+
+    // Loss of 5,000 leaves exactly the maintenance margin
+    env.ledger().with_mut(|li| li.timestamp = YEAR);
+    assert_eq!(
+        client.try_settle_rate_swap(&id),
+        Err(Ok(RateSwapError::NotSettleable))
+    );
+    client.add_swap_margin(&floating_payer, &id, &2000);
+
+    env.ledger().with_mut(|li| li.timestamp = YEAR * 6 / 5);
+    assert_eq!(
+        client.try_settle_rate_swap(&id),
+        Err(Ok(RateSwapError::NotSettleable))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = YEAR * 8 / 5);
+    assert_eq!(client.get_rate_swap_pnl(&id), 8000);
+    let settlement = client.settle_rate_swap(&id);
+    assert_eq!(settlement.fixed_payer_payout, 18_000);
+    assert_eq!(settlement.floating_payer_payout, 4000);
+}
+
+#[test]
+fn test_swap_proposal_validation() {
+    let env = Env::default();
+    let (client, fixed_payer, floating_payer) = setup(&env);
+
+    assert_eq!(
+        client.try_propose_rate_swap(&fixed_payer, &true, &100_000, &400, &YEAR, &9999),
+        Err(Ok(RateSwapError::InsufficientMargin))
+    );
+    assert_eq!(
+        client.try_propose_rate_swap(&fixed_payer, &true, &100_000, &400, &100, &10_000),
+        Err(Ok(RateSwapError::InvalidTerms))
+    );
+
+    let id = client.propose_rate_swap(&fixed_payer, &true, &100_000, &400, &YEAR, &10_000);
+    assert_eq!(
+        client.try_accept_rate_swap(&fixed_payer, &id, &10_000),
+        Err(Ok(RateSwapError::SelfSwap))
+    );
+    assert_eq!(
+        client.try_accept_rate_swap(&floating_payer, &id, &5000),
+        Err(Ok(RateSwapError::InsufficientMargin))
+    );
+    assert_eq!(
+        client.try_settle_rate_swap(&id),
+        Err(Ok(RateSwapError::SwapNotActive))
+    );
+    assert_eq!(
+        client.try_cancel_rate_swap(&floating_payer, &id),
+        Err(Ok(RateSwapError::NotSwapParty))
+    );
+
+    assert_eq!(client.cancel_rate_swap(&fixed_payer, &id), 10_000);
+    assert_eq!(client.get_rate_swap(&id), None);
+    assert_eq!(
+        client.try_accept_rate_swap(&floating_payer, &id, &10_000),
+        Err(Ok(RateSwapError::SwapNotFound))
+    );
+}
+
+#[test]
+fn test_margin_only_from_swap_parties() {
+    let env = Env::default();
+    let (client, fixed_payer, floating_payer) = setup(&env);
+    let outsider = Address::generate(&env);
+
+    let id = client.propose_rate_swap(&fixed_payer, &true, &100_000, &400, &YEAR, &10_000);
+    assert_eq!(
+        client.try_add_swap_margin(&fixed_payer, &id, &1000),
+        Err(Ok(RateSwapError::SwapNotActive))
+    );
+    assert_eq!(
+        client.try_get_rate_swap_pnl(&id),
+        Err(Ok(RateSwapError::SwapNotActive))
+    );
+
+    client.accept_rate_swap(&floating_payer, &id, &10_000);
+    assert_eq!(
+        client.try_add_swap_margin(&outsider, &id, &1000),
+        Err(Ok(RateSwapError::NotSwapParty))
+    );
+    assert_eq!(
+        client.try_accept_rate_swap(&outsider, &id, &10_000),
+        Err(Ok(RateSwapError::SwapNotProposed))
+    );
+
+    client.add_swap_margin(&fixed_payer, &id, &1000);
+    assert_eq!(client.get_rate_swap(&id).unwrap().fixed_margin, 11_000);
+}
+
+#[test]
+fn test_borrow_index_compounds_at_checkpoints() {
+    let env = Env::default();
+    let (client, user, _) = setup(&env);
+    let asset = Address::generate(&env);
+    let collateral_asset = Address::generate(&env);
+
+    assert_eq!(client.get_borrow_index(), 1_000_000_000);
+    client.borrow(&user, &asset, &10_000, &collateral_asset, &20_000);
+
+    env.ledger().with_mut(|li| li.timestamp = YEAR);
+    assert_eq!(client.get_borrow_index(), 1_050_000_000);
+
+    // Borrowing checkpoints the index, so the next year compounds on it
+    client.borrow(&user, &asset, &1000, &collateral_asset, &20_000);
+    env.ledger().with_mut(|li| li.timestamp = 2 * YEAR);
+    assert_eq!(client.get_borrow_index(), 1_102_500_000);
+}
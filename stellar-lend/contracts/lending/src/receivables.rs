@@ -0,0 +1,256 @@
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol, Vec};
+
+use crate::fixed_rate::{get_fixed_loan, save_loan, FixedLoan};
+
+/// Errors that can occur when trading fixed-term receivables
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ReceivableError {
+    InvalidAmount = 1,
+    LoanNotFound = 2,
+    LoanClosed = 3,
+    NotHolder = 4,
+    NotListed = 5,
+    SelfTransfer = 6,
+}
+
+/// Storage keys for receivable ownership and listings
+#[contracttype]
+#[derive(Clone)]
+pub enum ReceivableDataKey {
+    HolderReceivables(Address),
+    ReceivableListing(u64),
+    ReceivableProceeds(Address),
+}
+
+/// A holder's offer to sell the claim on a fixed-term loan
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReceivableListing {
+    pub loan_id: u64,
+    pub seller: Address,
+    pub price: i128,
+}
+
+/// Receivable event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ReceivableEvent {
+    pub loan_id: u64,
+    pub from: Address,
+    pub to: Option<Address>,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+/// Transfer the claim on an outstanding fixed-term loan
+///
+/// Any open listing for the claim is cancelled.
+pub fn transfer_receivable(
+    env: &Env,
+    holder: Address,
+    loan_id: u64,
+    to: Address,
+) -> Result<(), ReceivableError> {
+    holder.require_auth();
+
+    let loan = get_open_loan(env, loan_id)?;
+    if loan.holder != holder {
+        return Err(ReceivableError::NotHolder);
+    }
+    if to == holder {
+        return Err(ReceivableError::SelfTransfer);
+    }
+
+    move_claim(env, loan, to.clone());
+    emit_receivable_event(env, "receivable_transferred", loan_id, holder, Some(to), 0);
+    Ok(())
+}
+
+/// List the claim on an outstanding fixed-term loan for sale at `price`
+///
+/// Relisting replaces the previous price.
+pub fn list_receivable(
+    env: &Env,
+    holder: Address,
+    loan_id: u64,
+    price: i128,
+) -> Result<(), ReceivableError> {
+    holder.require_auth();
+
+    if price <= 0 {
+        return Err(ReceivableError::InvalidAmount);
+    }
+    let loan = get_open_loan(env, loan_id)?;
+    if loan.holder != holder {
+        return Err(ReceivableError::NotHolder);
+    }
+
+    let listing = ReceivableListing {
+        loan_id,
+        seller: holder.clone(),
+        price,
+    };
+    env.storage()
+        .persistent()
+        .set(&ReceivableDataKey::ReceivableListing(loan_id), &listing);
+
+    emit_receivable_event(env, "receivable_listed", loan_id, holder, None, price);
+    Ok(())
+}
+
+/// Withdraw a receivable listing
+pub fn delist_receivable(env: &Env, holder: Address, loan_id: u64) -> Result<(), ReceivableError> {
+    holder.require_auth();
+
+    let listing = get_receivable_listing(env, loan_id).ok_or(ReceivableError::NotListed)?;
+    if listing.seller != holder {
+        return Err(ReceivableError::NotHolder);
+    }
+    env.storage()
+        .persistent()
+        .remove(&ReceivableDataKey::ReceivableListing(loan_id));
+
+    emit_receivable_event(env, "receivable_delisted", loan_id, holder, None, 0);
+    Ok(())
+}
+
+/// Buy a listed receivable at its asking price
+///
+/// Future repayment of the loan is routed to the buyer.
+///
+/// # Returns
+/// The price paid to the seller
+pub fn buy_receivable(env: &Env, buyer: Address, loan_id: u64) -> Result<i128, ReceivableError> {
+    buyer.require_auth();
+
+    let listing = get_receivable_listing(env, loan_id).ok_or(ReceivableError::NotListed)?;
+    let loan = get_open_loan(env, loan_id)?;
+    if listing.seller == buyer {
+        return Err(ReceivableError::SelfTransfer);
+    }
+
+    move_claim(env, loan, buyer.clone());
+    emit_receivable_event(
+        env,
+        "receivable_sold",
+        loan_id,
+        listing.seller,
+        Some(buyer),
+        listing.price,
+    );
+    Ok(listing.price)
+}
+
+/// Get the sale listing for a loan's claim, if any
+pub fn get_receivable_listing(env: &Env, loan_id: u64) -> Option<ReceivableListing> {
+    env.storage()
+        .persistent()
+        .get(&ReceivableDataKey::ReceivableListing(loan_id))
+}
+
+/// Get the ids of outstanding fixed-term loans whose claims a holder owns
+pub fn get_holder_receivables(env: &Env, holder: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&ReceivableDataKey::HolderReceivables(holder.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Total fixed-term repayments routed to a claim holder
+pub fn get_receivable_proceeds(env: &Env, holder: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&ReceivableDataKey::ReceivableProceeds(holder.clone()))
+        .unwrap_or(0)
+}
+
+/// Record a newly originated claim held by its lender
+pub(crate) fn add_holding(env: &Env, holder: &Address, loan_id: u64) {
+    let mut holdings = get_holder_receivables(env, holder);
+    holdings.push_back(loan_id);
+    save_holdings(env, holder, &holdings);
+}
+
+/// Route a repaid loan's principal and interest to its holder and close the claim
+pub(crate) fn on_loan_repaid(env: &Env, loan: &FixedLoan) {
+    remove_holding(env, &loan.holder, loan.id);
+    env.storage()
+        .persistent()
+        .remove(&ReceivableDataKey::ReceivableListing(loan.id));
+
+    credit_proceeds(
+        env,
+        &loan.holder,
+        loan.principal.saturating_add(loan.interest),
+    );
+}
+
+/// Credit repayment proceeds to a claim holder
+pub(crate) fn credit_proceeds(env: &Env, holder: &Address, amount: i128) {
+    if amount <= 0 {
+        return;
+    }
+    let proceeds = get_receivable_proceeds(env, holder).saturating_add(amount);
+    env.storage().persistent().set(
+        &ReceivableDataKey::ReceivableProceeds(holder.clone()),
+        &proceeds,
+    );
+}
+
+fn get_open_loan(env: &Env, loan_id: u64) -> Result<FixedLoan, ReceivableError> {
+    let loan = get_fixed_loan(env, loan_id).ok_or(ReceivableError::LoanNotFound)?;
+    if loan.repaid {
+        return Err(ReceivableError::LoanClosed);
+    }
+    Ok(loan)
+}
+
+fn move_claim(env: &Env, mut loan: FixedLoan, to: Address) {
+    remove_holding(env, &loan.holder, loan.id);
+    add_holding(env, &to, loan.id);
+    env.storage()
+        .persistent()
+        .remove(&ReceivableDataKey::ReceivableListing(loan.id));
+
+    loan.holder = to;
+    save_loan(env, &loan);
+}
+
+fn remove_holding(env: &Env, holder: &Address, loan_id: u64) {
+    let mut holdings = get_holder_receivables(env, holder);
+    if let Some(index) = holdings.first_index_of(loan_id) {
+        holdings.remove(index);
+    }
+    save_holdings(env, holder, &holdings);
+}
+
+fn save_holdings(env: &Env, holder: &Address, holdings: &Vec<u64>) {
+    env.storage().persistent().set(
+        &ReceivableDataKey::HolderReceivables(holder.clone()),
+        holdings,
+    );
+}
+
+fn emit_receivable_event(
+    env: &Env,
+    name: &str,
+    loan_id: u64,
+    from: Address,
+    to: Option<Address>,
+    amount: i128,
+) {
+    let event = ReceivableEvent {
+        loan_id,
+        from,
+        to,
+        amount,
+        timestamp: env.ledger().timestamp(),
+    };
+    crate::events::publish(
+        env,
+        (Symbol::new(env, name), event.from.clone(), event.to.clone()),
+        event,
+    );
+}
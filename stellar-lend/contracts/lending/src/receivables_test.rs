@@ -0,0 +1,141 @@
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, Env,
+};
+
+const YEAR: u64 = 31536000;
+
+fn setup(env: &Env) -> (LendingContractClient<'_>, Address, Address, u64) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+
+    let contract_id = env.register(LendingContract, ());
+    let client = LendingContractClient::new(env, &contract_id);
+    client.initialize(
+        &Address::generate(env),
+        &InitConfig::new(env, 1_000_000_000, 1000),
+    );
+
+    let lender = Address::generate(env);
+    let borrower = Address::generate(env);
+    let asset = Address::generate(env);
+    let collateral_asset = Address::generate(env);
+    client.borrow(&borrower, &asset, &10_000, &collateral_asset, &150_000);
+
+    // 60,000 at 8% for a year: 4,800 interest
+    let offer_id = client.post_offer(&lender, &asset, &100_000, &800, &YEAR);
+    let loan_id = client.take_offer(&borrower, &offer_id, &60_000);
+    (client, lender, borrower, loan_id)
+}
+
+#[test]
+fn test_transferred_claim_receives_repayment() {
+    let env = Env::default();
+    let (client, lender, borrower, loan_id) = setup(&env);
+    let buyer = Address::generate(&env);
+
+    assert_eq!(client.get_holder_receivables(&lender), vec![&env, loan_id]);
+    client.transfer_receivable(&lender, &loan_id, &buyer);
+
+    let loan = client.get_fixed_loan(&loan_id).unwrap();
+    assert_eq!(loan.lender, lender);
+    assert_eq!(loan.holder, buyer);
+    assert_eq!(client.get_holder_receivables(&lender).len(), 0);
+    assert_eq!(client.get_holder_receivables(&buyer), vec![&env, loan_id]);
+
+    assert_eq!(client.repay_fixed_loan(&borrower, &loan_id), 64_800);
+    assert_eq!(client.get_receivable_proceeds(&buyer), 64_800);
+    assert_eq!(client.get_receivable_proceeds(&lender), 0);
+    assert_eq!(client.get_holder_receivables(&buyer).len(), 0);
+}
+
+#[test]
+fn test_listed_claim_can_be_bought() {
+    let env = Env::default();
+    let (client, lender, _, loan_id) = setup(&env);
+    let buyer = Address::generate(&env);
+
+    assert_eq!(
+        client.try_buy_receivable(&buyer, &loan_id),
+        Err(Ok(ReceivableError::NotListed))
+    );
+    client.list_receivable(&lender, &loan_id, &62_000);
+    assert_eq!(
+        client.try_buy_receivable(&lender, &loan_id),
+        Err(Ok(ReceivableError::SelfTransfer))
+    );
+
+    assert_eq!(client.buy_receivable(&buyer, &loan_id), 62_000);
+    assert_eq!(client.get_fixed_loan(&loan_id).unwrap().holder, buyer);
+    assert_eq!(client.get_receivable_listing(&loan_id), None);
+}
+
+#[test]
+fn test_only_holder_can_transfer_or_list() {
+    let env = Env::default();
+    let (client, lender, borrower, loan_id) = setup(&env);
+    let buyer = Address::generate(&env);
+
+    assert_eq!(
+        client.try_transfer_receivable(&borrower, &loan_id, &buyer),
+        Err(Ok(ReceivableError::NotHolder))
+    );
+    assert_eq!(
+        client.try_list_receivable(&borrower, &loan_id, &1000),
+        Err(Ok(ReceivableError::NotHolder))
+    );
+    assert_eq!(
+        client.try_transfer_receivable(&lender, &loan_id, &lender),
+        Err(Ok(ReceivableError::SelfTransfer))
+    );
+    assert_eq!(
+        client.try_list_receivable(&lender, &loan_id, &0),
+        Err(Ok(ReceivableError::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_transfer_receivable(&lender, &99, &buyer),
+        Err(Ok(ReceivableError::LoanNotFound))
+    );
+}
+
+#[test]
+fn test_transfer_cancels_listing() {
+    let env = Env::default();
+    let (client, lender, _, loan_id) = setup(&env);
+    let holder = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    client.list_receivable(&lender, &loan_id, &62_000);
+    client.transfer_receivable(&lender, &loan_id, &holder);
+    assert_eq!(client.get_receivable_listing(&loan_id), None);
+    assert_eq!(
+        client.try_buy_receivable(&buyer, &loan_id),
+        Err(Ok(ReceivableError::NotListed))
+    );
+
+    client.list_receivable(&holder, &loan_id, &63_000);
+    assert_eq!(
+        client.try_delist_receivable(&lender, &loan_id),
+        Err(Ok(ReceivableError::NotHolder))
+    );
+    client.delist_receivable(&holder, &loan_id);
+    assert_eq!(client.get_receivable_listing(&loan_id), None);
+}
+
+#[test]
+fn test_repaid_claim_cannot_be_traded() {
+    let env = Env::default();
+    let (client, lender, borrower, loan_id) = setup(&env);
+    let buyer = Address::generate(&env);
+
+    client.list_receivable(&lender, &loan_id, &62_000);
+    client.repay_fixed_loan(&borrower, &loan_id);
+
+    assert_eq!(client.get_receivable_listing(&loan_id), None);
+    assert_eq!(client.get_receivable_proceeds(&lender), 64_800);
+    assert_eq!(
+        client.try_transfer_receivable(&lender, &loan_id, &buyer),
+        Err(Ok(ReceivableError::LoanClosed))
+    );
+}
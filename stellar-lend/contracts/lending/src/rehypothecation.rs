@@ -0,0 +1,80 @@
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol};
+
+use crate::admin::require_risk_manager;
+
+/// Errors that can occur when configuring rehypothecation
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum RehypothecationError {
+    Unauthorized = 1,
+}
+
+/// Storage keys for rehypothecation data
+#[contracttype]
+#[derive(Clone)]
+pub enum RehypothecationDataKey {
+    /// Present when collateral of the asset must be held idle
+    RehypothecationDisabled(Address),
+}
+
+/// Rehypothecation event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct RehypothecationEvent {
+    pub asset: Address,
+    pub enabled: bool,
+    pub actor: Address,
+    pub timestamp: u64,
+}
+
+/// Allow or forbid lending out collateral of an asset (risk manager or admin)
+///
+/// Rehypothecation is allowed by default. While it is off, collateral of the
+/// asset is held idle in full: borrows of the asset are limited to what
+/// suppliers have provided and never draw on deposited collateral.
+pub fn set_rehypothecation(
+    env: &Env,
+    caller: Address,
+    asset: Address,
+    enabled: bool,
+) -> Result<(), RehypothecationError> {
+    require_risk_manager(env, &caller).map_err(|_| RehypothecationError::Unauthorized)?;
+
+    let key = RehypothecationDataKey::RehypothecationDisabled(asset.clone());
+    if enabled {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &true);
+    }
+    let event = RehypothecationEvent {
+        asset,
+        enabled,
+        actor: caller,
+        timestamp: env.ledger().timestamp(),
+    };
+    crate::events::publish(
+        env,
+        (
+            Symbol::new(env, "rehypothecation_updated"),
+            event.asset.clone(),
+        ),
+        event,
+    );
+    Ok(())
+}
+
+/// Whether collateral of an asset may be lent out to borrowers
+pub fn is_rehypothecation_enabled(env: &Env, asset: &Address) -> bool {
+    !env.storage()
+        .persistent()
+        .has(&RehypothecationDataKey::RehypothecationDisabled(
+            asset.clone(),
+        ))
+}
+
+/// Whether outstanding principal of `asset` would exceed what may be lent out
+pub(crate) fn exceeds_lendable(env: &Env, asset: &Address, asset_debt: i128) -> bool {
+    !is_rehypothecation_enabled(env, asset)
+        && asset_debt > crate::p2p::get_asset_supplied(env, asset)
+}
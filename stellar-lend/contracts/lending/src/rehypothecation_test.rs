@@ -0,0 +1,42 @@
+use super::*;
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+#[test]
+fn test_rehypothecation_off_limits_borrows_to_supply() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = LendingContractClient::new(&env, &env.register(LendingContract, ()));
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &InitConfig::new(&env, 1_000_000_000, 1000));
+    let usdc = Address::generate(&env);
+    let xlm = Address::generate(&env);
+    client.set_p2p_market(
+        &admin,
+        &xlm,
+        &P2PMarket {
+            pool_supply_rate_bps: 200,
+            p2p_cursor_bps: 5000,
+        },
+    );
+    client.p2p_supply(&Address::generate(&env), &xlm, &5_000);
+    client.borrow(&Address::generate(&env), &usdc, &10_000, &xlm, &20_000);
+
+    assert!(client.is_rehypothecation_enabled(&xlm));
+    client.set_rehypothecation(&admin, &xlm, &false);
+    assert!(!client.is_rehypothecation_enabled(&xlm));
+
+    // Borrows of the asset may use supplied liquidity, not the idle collateral
+    let borrower = Address::generate(&env);
+    client.borrow(&borrower, &xlm, &5_000, &usdc, &10_000);
+    assert_eq!(
+        client.try_borrow(&borrower, &xlm, &1_000, &usdc, &2_000),
+        Err(Ok(BorrowError::RehypothecationDisabled))
+    );
+
+    client.set_rehypothecation(&admin, &xlm, &true);
+    client.borrow(&borrower, &xlm, &1_000, &usdc, &2_000);
+    assert_eq!(
+        client.try_set_rehypothecation(&Address::generate(&env), &xlm, &false),
+        Err(Ok(RehypothecationError::Unauthorized))
+    );
+}
@@ -0,0 +1,185 @@
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol};
+
+use crate::borrow::{
+    adjust_asset_debt, calculate_interest, get_debt_position, get_min_borrow_amount,
+    get_total_debt, get_user_debt, save_debt_position, set_total_debt, update_borrow_index,
+    DebtPosition,
+};
+use crate::history::PositionAction;
+
+/// Errors that can occur during repay operations
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum RepayError {
+    InvalidAmount = 1,
+    NoDebt = 2,
+    Overflow = 3,
+    DustRemaining = 4,
+    PriceStale = 5,
+    ProtocolPaused = 6,
+}
+
+/// Repay event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct RepayEvent {
+    pub user: Address,
+    pub asset: Address,
+    pub amount: i128,
+    pub interest_paid: i128,
+    pub principal_paid: i128,
+    pub timestamp: u64,
+}
+
+/// Repay borrowed assets
+///
+/// Accrues outstanding interest, then applies the payment to interest first
+/// and principal second. Payments above the outstanding debt are capped.
+/// Partial payments must leave at least the minimum borrow amount outstanding.
+///
+/// # Returns
+/// Returns a tuple (remaining_debt, interest_paid, principal_paid)
+pub fn repay(env: &Env, user: Address, amount: i128) -> Result<(i128, i128, i128), RepayError> {
+    user.require_auth();
+    apply_borrower_repayment(env, user, amount)
+}
+
+/// Apply a payment made by or pre-authorized by the borrower
+///
+/// Unlike protocol-initiated payments, these count towards the borrower's
+/// repayment record (loyalty streak and credit history).
+pub(crate) fn apply_borrower_repayment(
+    env: &Env,
+    user: Address,
+    amount: i128,
+) -> Result<(i128, i128, i128), RepayError> {
+    check_repay_open(env, &user)?;
+    crate::early_repay::apply_closing_rebate(env, &user, amount);
+    ensure_no_dust(env, &user, amount)?;
+    let result = apply_repayment(env, user.clone(), amount)?;
+
+    let position = get_debt_position(env, &user);
+    crate::credit_score::record_repayment(env, &user, &position);
+    crate::loyalty::record_repayment(env, &user, &position);
+    Ok(result)
+}
+
+/// Reject borrower repayments while they are paused or the debt asset's
+/// price blocks them
+pub(crate) fn check_repay_open(env: &Env, user: &Address) -> Result<(), RepayError> {
+    if crate::pause::is_operation_paused(env, crate::pause::PAUSE_REPAY) {
+        return Err(RepayError::ProtocolPaused);
+    }
+    if crate::oracle::is_repay_frozen(env, &get_debt_position(env, user).asset) {
+        return Err(RepayError::PriceStale);
+    }
+    Ok(())
+}
+
+/// Reject a payment that would leave a debt below the minimum borrow amount
+///
+/// Such dust positions cost more to liquidate than they are worth. The check
+/// covers principal and accrued interest; full repayment is always allowed.
+pub(crate) fn ensure_no_dust(env: &Env, user: &Address, amount: i128) -> Result<(), RepayError> {
+    check_no_dust(env, &get_user_debt(env, user), amount)
+}
+
+/// `ensure_no_dust` against a given debt position, with interest accrued
+pub(crate) fn check_no_dust(
+    env: &Env,
+    debt: &DebtPosition,
+    amount: i128,
+) -> Result<(), RepayError> {
+    let owed = debt.borrowed_amount.saturating_add(debt.interest_accrued);
+    let remaining = owed.saturating_sub(amount.max(0)).max(0);
+    if remaining > 0 && remaining < get_min_borrow_amount(env, &debt.asset) {
+        return Err(RepayError::DustRemaining);
+    }
+    Ok(())
+}
+
+/// Apply a payment to a user's debt without requiring the user's authorization
+///
+/// Used where the protocol repays on the user's behalf, e.g. from liquidation
+/// proceeds. Such payments do not count towards the user's repayment record.
+pub(crate) fn apply_repayment(
+    env: &Env,
+    user: Address,
+    amount: i128,
+) -> Result<(i128, i128, i128), RepayError> {
+    if amount <= 0 {
+        return Err(RepayError::InvalidAmount);
+    }
+
+    let mut position = get_debt_position(env, &user);
+    let accrued_interest = calculate_interest(env, &user, &position);
+    position.interest_accrued = position
+        .interest_accrued
+        .checked_add(accrued_interest)
+        .ok_or(RepayError::Overflow)?;
+
+    let total_owed = position
+        .borrowed_amount
+        .checked_add(position.interest_accrued)
+        .ok_or(RepayError::Overflow)?;
+    if total_owed == 0 {
+        return Err(RepayError::NoDebt);
+    }
+
+    let repay_amount = amount.min(total_owed);
+    let interest_paid = repay_amount.min(position.interest_accrued);
+    let principal_paid = repay_amount - interest_paid;
+
+    position.interest_accrued -= interest_paid;
+    position.borrowed_amount -= principal_paid;
+    position.last_update = env.ledger().timestamp();
+    position.borrow_index = crate::reserve::checkpoint_borrow_index(env, &position.asset);
+
+    crate::points::checkpoint(env, &user);
+    update_borrow_index(env);
+    save_debt_position(env, &user, &position);
+    set_total_debt(
+        env,
+        get_total_debt(env).saturating_sub(principal_paid).max(0),
+    );
+    adjust_asset_debt(env, &position.asset, -principal_paid);
+
+    crate::p2p::on_principal_repaid(env, &user, position.borrowed_amount, &position.asset);
+    crate::withdrawal_queue::process_withdrawal_queue(env, &position.asset);
+    crate::deleverage::track_borrower(env, &user);
+    crate::deleverage::update_crunch(env);
+
+    crate::history::record_action(
+        env,
+        &user,
+        PositionAction::Repay,
+        &position.asset,
+        repay_amount,
+    );
+    crate::position_hooks::notify(
+        env,
+        &user,
+        Some(crate::position_hooks::HookEvent::Repaid(repay_amount)),
+    );
+    let event = RepayEvent {
+        user,
+        asset: position.asset.clone(),
+        amount: repay_amount,
+        interest_paid,
+        principal_paid,
+        timestamp: env.ledger().timestamp(),
+    };
+    crate::events::publish(
+        env,
+        (
+            Symbol::new(env, "repay"),
+            event.user.clone(),
+            event.asset.clone(),
+        ),
+        event,
+    );
+
+    let remaining = position.borrowed_amount + position.interest_accrued;
+    Ok((remaining, interest_paid, principal_paid))
+}
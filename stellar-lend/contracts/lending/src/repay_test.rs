@@ -0,0 +1,111 @@
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+
+fn setup(env: &Env) -> (LendingContractClient<'_>, Address) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+
+    let contract_id = env.register(LendingContract, ());
+    let client = LendingContractClient::new(env, &contract_id);
+    client.initialize(
+        &Address::generate(env),
+        &InitConfig::new(env, 1_000_000_000, 1000),
+    );
+
+    let user = Address::generate(env);
+    let asset = Address::generate(env);
+    let collateral_asset = Address::generate(env);
+    client.borrow(&user, &asset, &100_000, &collateral_asset, &200_000);
+
+    (client, user)
+}
+
+#[test]
+fn test_repay_partial_principal() {
+    let env = Env::default();
+    let (client, user) = setup(&env);
+
+    let (remaining, interest_paid, principal_paid) = client.repay(&user, &40_000);
+    assert_eq!(remaining, 60_000);
+    assert_eq!(interest_paid, 0);
+    assert_eq!(principal_paid, 40_000);
+    assert_eq!(client.get_user_debt(&user).borrowed_amount, 60_000);
+}
+
+#[test]
+fn test_repay_interest_first() {
+    let env = Env::default();
+    let (client, user) = setup(&env);
+
+    env.ledger().with_mut(|li| li.timestamp = 1000 + 31536000);
+    let (remaining, interest_paid, principal_paid) = client.repay(&user, &10_000);
+
+    // 5% of 100,000 accrued over one year
+    assert_eq!(interest_paid, 5000);
+    assert_eq!(principal_paid, 5000);
+    assert_eq!(remaining, 95_000);
+}
+
+#[test]
+fn test_repay_capped_at_total_debt() {
+    let env = Env::default();
+    let (client, user) = setup(&env);
+
+    let (remaining, _, principal_paid) = client.repay(&user, &1_000_000);
+    assert_eq!(remaining, 0);
+    assert_eq!(principal_paid, 100_000);
+
+    let result = client.try_repay(&user, &1000);
+    assert_eq!(result, Err(Ok(RepayError::NoDebt)));
+}
+
+#[test]
+fn test_repay_invalid_amount() {
+    let env = Env::default();
+    let (client, user) = setup(&env);
+
+    let result = client.try_repay(&user, &0);
+    assert_eq!(result, Err(Ok(RepayError::InvalidAmount)));
+}
+
+#[test]
+fn test_repay_frees_debt_ceiling() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(LendingContract, ());
+    let client = LendingContractClient::new(&env, &contract_id);
+    client.initialize(
+        &Address::generate(&env),
+        &InitConfig::new(&env, 100_000, 1000),
+    );
+
+    let user = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let collateral_asset = Address::generate(&env);
+    client.borrow(&user, &asset, &100_000, &collateral_asset, &200_000);
+
+    let result = client.try_borrow(&user, &asset, &1000, &collateral_asset, &2000);
+    assert_eq!(result, Err(Ok(BorrowError::DebtCeilingReached)));
+
+    client.repay(&user, &10_000);
+    client.borrow(&user, &asset, &10_000, &collateral_asset, &20_000);
+}
+
+#[test]
+fn test_repay_rejects_dust_remainder() {
+    let env = Env::default();
+    let (client, user) = setup(&env);
+
+    // Minimum borrow amount is 1,000
+    let result = client.try_repay(&user, &99_001);
+    assert_eq!(result, Err(Ok(RepayError::DustRemaining)));
+
+    let (remaining, _, _) = client.repay(&user, &99_000);
+    assert_eq!(remaining, 1000);
+    let (remaining, _, _) = client.repay(&user, &1000);
+    assert_eq!(remaining, 0);
+}
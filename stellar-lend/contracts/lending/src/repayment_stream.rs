@@ -0,0 +1,181 @@
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol};
+
+use crate::repay::{apply_borrower_repayment, RepayError};
+
+/// Errors that can occur during streaming repayments
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum StreamError {
+    InvalidConfig = 1,
+    NoStream = 2,
+    NotDue = 3,
+    AllowanceExhausted = 4,
+    NoDebt = 5,
+    Overflow = 6,
+    DustRemaining = 7,
+    PriceStale = 8,
+    ProtocolPaused = 9,
+}
+
+/// Storage keys for repayment stream data
+#[contracttype]
+#[derive(Clone)]
+pub enum StreamDataKey {
+    RepaymentStream(Address),
+}
+
+/// A borrower's standing instruction to repay a fixed amount per interval
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RepaymentStream {
+    pub amount_per_interval: i128,
+    /// Interval length in seconds
+    pub interval: u64,
+    /// Remaining pre-approved amount the stream may pull
+    pub allowance: i128,
+    /// Time the next installment becomes due
+    pub next_due: u64,
+    /// Last ledger the stream can pull installments in
+    pub expiration_ledger: u32,
+}
+
+/// Repayment stream event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct StreamEvent {
+    pub user: Address,
+    pub asset: Address,
+    pub amount: i128,
+    pub allowance: i128,
+    pub timestamp: u64,
+}
+
+const MIN_STREAM_INTERVAL: u64 = 3600; // 1 hour
+
+/// Configure a repayment stream
+///
+/// The first installment is due one interval from now. `allowance` caps the
+/// total the stream may pull on the borrower's behalf.
+pub fn set_repayment_stream(
+    env: &Env,
+    user: Address,
+    amount_per_interval: i128,
+    interval: u64,
+    allowance: i128,
+    expiration_ledger: u32,
+) -> Result<(), StreamError> {
+    user.require_auth();
+
+    if amount_per_interval <= 0
+        || interval < MIN_STREAM_INTERVAL
+        || allowance <= 0
+        || !crate::approvals::is_valid_expiration(env, expiration_ledger)
+    {
+        return Err(StreamError::InvalidConfig);
+    }
+
+    let stream = RepaymentStream {
+        amount_per_interval,
+        interval,
+        allowance,
+        next_due: env.ledger().timestamp().saturating_add(interval),
+        expiration_ledger,
+    };
+    env.storage()
+        .persistent()
+        .set(&StreamDataKey::RepaymentStream(user.clone()), &stream);
+
+    emit_stream_event(env, "stream_set", user, 0, allowance);
+    Ok(())
+}
+
+/// Cancel a repayment stream
+pub fn cancel_repayment_stream(env: &Env, user: Address) -> Result<(), StreamError> {
+    user.require_auth();
+
+    get_repayment_stream(env, &user).ok_or(StreamError::NoStream)?;
+    remove_stream(env, &user);
+
+    emit_stream_event(env, "stream_cancelled", user, 0, 0);
+    Ok(())
+}
+
+/// Execute the installments of a stream that are due
+///
+/// Permissionless: any keeper may call this. Missed installments are caught
+/// up in a single payment, capped by the remaining allowance and the debt.
+///
+/// # Returns
+/// The amount repaid
+pub fn execute_repayment_stream(env: &Env, user: Address) -> Result<i128, StreamError> {
+    let mut stream = get_repayment_stream(env, &user).ok_or(StreamError::NoStream)?;
+    let now = env.ledger().timestamp();
+    if now < stream.next_due {
+        return Err(StreamError::NotDue);
+    }
+    if stream.allowance == 0 {
+        return Err(StreamError::AllowanceExhausted);
+    }
+
+    let installments = (now - stream.next_due) / stream.interval + 1;
+    let due = stream
+        .amount_per_interval
+        .checked_mul(installments as i128)
+        .ok_or(StreamError::Overflow)?
+        .min(stream.allowance);
+
+    let (_, interest_paid, principal_paid) = apply_borrower_repayment(env, user.clone(), due)
+        .map_err(|e| match e {
+            RepayError::NoDebt => StreamError::NoDebt,
+            RepayError::DustRemaining => StreamError::DustRemaining,
+            RepayError::PriceStale => StreamError::PriceStale,
+            RepayError::ProtocolPaused => StreamError::ProtocolPaused,
+            _ => StreamError::Overflow,
+        })?;
+    let paid = interest_paid + principal_paid;
+
+    stream.allowance -= paid;
+    stream.next_due = stream
+        .next_due
+        .saturating_add(installments.saturating_mul(stream.interval));
+    env.storage()
+        .persistent()
+        .set(&StreamDataKey::RepaymentStream(user.clone()), &stream);
+
+    emit_stream_event(env, "stream_executed", user, paid, stream.allowance);
+    Ok(paid)
+}
+
+/// Get a borrower's repayment stream, if any and not expired
+pub fn get_repayment_stream(env: &Env, user: &Address) -> Option<RepaymentStream> {
+    env.storage()
+        .persistent()
+        .get::<_, RepaymentStream>(&StreamDataKey::RepaymentStream(user.clone()))
+        .filter(|stream| crate::approvals::is_live(env, stream.expiration_ledger))
+}
+
+pub(crate) fn remove_stream(env: &Env, user: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&StreamDataKey::RepaymentStream(user.clone()));
+}
+
+fn emit_stream_event(env: &Env, name: &str, user: Address, amount: i128, allowance: i128) {
+    let event = StreamEvent {
+        asset: crate::borrow::get_debt_position(env, &user).asset,
+        user,
+        amount,
+        allowance,
+        timestamp: env.ledger().timestamp(),
+    };
+    crate::events::publish(
+        env,
+        (
+            Symbol::new(env, name),
+            event.user.clone(),
+            event.asset.clone(),
+        ),
+        event,
+    );
+}
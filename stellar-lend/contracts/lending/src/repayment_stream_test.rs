@@ -0,0 +1,92 @@
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+
+const DAY: u64 = 86400;
+
+fn setup(env: &Env) -> (LendingContractClient<'_>, Address) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 0);
+
+    let contract_id = env.register(LendingContract, ());
+    let client = LendingContractClient::new(env, &contract_id);
+    client.initialize(
+        &Address::generate(env),
+        &InitConfig::new(env, 1_000_000_000, 1000),
+    );
+
+    let user = Address::generate(env);
+    let asset = Address::generate(env);
+    let collateral_asset = Address::generate(env);
+    client.borrow(&user, &asset, &10_000, &collateral_asset, &20_000);
+    (client, user)
+}
+
+#[test]
+fn test_stream_repays_each_interval() {
+    let env = Env::default();
+    let (client, user) = setup(&env);
+
+    client.set_repayment_stream(&user, &1000, &DAY, &5000, &1_000);
+
+    let result = client.try_execute_repayment_stream(&user);
+    assert_eq!(result, Err(Ok(StreamError::NotDue)));
+
+    env.ledger().with_mut(|li| li.timestamp = DAY);
+    // One day of interest (2, rounded up) is paid before principal
+    assert_eq!(client.execute_repayment_stream(&user), 1000);
+    let debt = client.get_user_debt(&user);
+    assert_eq!(debt.borrowed_amount, 9002);
+
+    let stream = client.get_repayment_stream(&user).unwrap();
+    assert_eq!(stream.allowance, 4000);
+    assert_eq!(stream.next_due, 2 * DAY);
+}
+
+#[test]
+fn test_missed_installments_are_caught_up() {
+    let env = Env::default();
+    let (client, user) = setup(&env);
+
+    client.set_repayment_stream(&user, &1000, &DAY, &10_000, &1_000);
+
+    env.ledger().with_mut(|li| li.timestamp = 3 * DAY + 10);
+    assert_eq!(client.execute_repayment_stream(&user), 3000);
+    assert_eq!(
+        client.get_repayment_stream(&user).unwrap().next_due,
+        4 * DAY
+    );
+}
+
+#[test]
+fn test_allowance_caps_stream() {
+    let env = Env::default();
+    let (client, user) = setup(&env);
+
+    client.set_repayment_stream(&user, &1000, &DAY, &1500, &1_000);
+
+    env.ledger().with_mut(|li| li.timestamp = 2 * DAY);
+    assert_eq!(client.execute_repayment_stream(&user), 1500);
+
+    env.ledger().with_mut(|li| li.timestamp = 5 * DAY);
+    let result = client.try_execute_repayment_stream(&user);
+    assert_eq!(result, Err(Ok(StreamError::AllowanceExhausted)));
+}
+
+#[test]
+fn test_stream_config_and_cancel() {
+    let env = Env::default();
+    let (client, user) = setup(&env);
+
+    let result = client.try_set_repayment_stream(&user, &1000, &60, &5000, &1_000);
+    assert_eq!(result, Err(Ok(StreamError::InvalidConfig)));
+
+    client.set_repayment_stream(&user, &1000, &DAY, &5000, &1_000);
+    client.cancel_repayment_stream(&user);
+    assert_eq!(client.get_repayment_stream(&user), None);
+
+    let result = client.try_execute_repayment_stream(&user);
+    assert_eq!(result, Err(Ok(StreamError::NoStream)));
+}
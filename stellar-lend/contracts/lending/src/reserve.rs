@@ -0,0 +1,215 @@
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::borrow::{BorrowIndex, BORROW_INDEX_SCALE, SECONDS_PER_YEAR};
+use crate::math::mul_div_up;
+
+/// Storage keys for per-asset reserve state
+#[contracttype]
+#[derive(Clone)]
+pub enum ReserveDataKey {
+    ReserveData(Address),
+    /// Borrow index recorded at a reserve update, by asset and slot
+    IndexCheckpoint(Address, u32),
+    IndexCheckpointCount(Address),
+    /// Every asset with a reserve record
+    ReserveAssets,
+}
+
+/// Accounting state of one asset's market
+///
+/// Each asset accrues on its own record, so touching one market never reads
+/// or writes another's.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReserveData {
+    /// Net liquidity supplied
+    pub total_supplied: i128,
+    /// Outstanding pool principal
+    pub total_borrowed: i128,
+    /// Cumulative borrow index of the asset, scaled by `BORROW_INDEX_SCALE`;
+    /// pool interest on the asset accrues by its growth
+    pub borrow_index: i128,
+    /// Pool borrow rate the index accrues at until the next update, in basis
+    /// points per year
+    pub borrow_rate_bps: i128,
+    pub last_update: u64,
+}
+
+/// Get an asset's reserve state with its index accrued to now
+///
+/// The index accrues at the rate recorded at the reserve's last update, so a
+/// later rate change never applies to time that has already elapsed.
+pub fn get_reserve_data(env: &Env, asset: &Address) -> ReserveData {
+    let now = env.ledger().timestamp();
+    let mut reserve = env
+        .storage()
+        .persistent()
+        .get(&ReserveDataKey::ReserveData(asset.clone()))
+        .unwrap_or(ReserveData {
+            total_supplied: 0,
+            total_borrowed: 0,
+            borrow_index: BORROW_INDEX_SCALE,
+            borrow_rate_bps: crate::rate_model::get_borrow_rate(env),
+            last_update: now,
+        });
+
+    reserve.borrow_index = accrue_index(env, asset, &reserve, now);
+    reserve.last_update = now;
+    reserve
+}
+
+/// Borrow index of an asset at a past timestamp
+///
+/// Linearly interpolated between the surrounding checkpoints (interest
+/// accrues linearly between them) and projected from the latest checkpoint
+/// at the current rate. `None` before the asset's first checkpoint or in the
+/// future.
+pub fn get_borrow_index_at(env: &Env, asset: &Address, timestamp: u64) -> Option<i128> {
+    if timestamp > env.ledger().timestamp() {
+        return None;
+    }
+    let count = get_index_checkpoint_count(env, asset);
+    let first = get_index_checkpoint(env, asset, 0)?;
+    if timestamp < first.last_update {
+        return None;
+    }
+
+    // Last checkpoint at or before the timestamp
+    let (mut low, mut high) = (0u32, count - 1);
+    while low < high {
+        let mid = (low + high).div_ceil(2);
+        if get_index_checkpoint(env, asset, mid)?.last_update <= timestamp {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+    let before = get_index_checkpoint(env, asset, low)?;
+    if low == count - 1 {
+        // The latest checkpoint is the reserve's last update
+        let mut reserve = get_reserve_data(env, asset);
+        reserve.borrow_index = before.value;
+        reserve.last_update = before.last_update;
+        return Some(accrue_index(env, asset, &reserve, timestamp));
+    }
+    let after = get_index_checkpoint(env, asset, low + 1)?;
+    let span = (after.last_update - before.last_update) as i128;
+    Some(before.value.saturating_add(mul_div_up(
+        after.value - before.value,
+        (timestamp - before.last_update) as i128,
+        span,
+    )))
+}
+
+/// Grow a reserve's index from its last update to `to`
+///
+/// Accrues at the recorded pool rate plus the premium of an asset being
+/// delisted, which ramps with time and is integrated exactly.
+fn accrue_index(env: &Env, asset: &Address, reserve: &ReserveData, to: u64) -> i128 {
+    let from = reserve.last_update;
+    let rate_time = reserve
+        .borrow_rate_bps
+        .saturating_mul(to.saturating_sub(from) as i128)
+        .saturating_add(crate::listing::get_delist_premium_accrued(
+            env, asset, from, to,
+        ));
+    reserve.borrow_index.saturating_add(mul_div_up(
+        reserve.borrow_index,
+        rate_time,
+        10000 * SECONDS_PER_YEAR as i128,
+    ))
+}
+
+/// Accrue an asset's reserve and return its borrow index
+///
+/// Debt positions snapshot the index when their interest is settled, so it
+/// is stored to compound from the same point.
+pub(crate) fn checkpoint_borrow_index(env: &Env, asset: &Address) -> i128 {
+    update_reserve(env, asset, 0, 0);
+    get_reserve_data(env, asset).borrow_index
+}
+
+/// Accrue an asset's reserve and apply changes to its totals
+///
+/// The reserve then accrues at the current pool rate, and the pool borrow
+/// index is checkpointed with it.
+pub(crate) fn update_reserve(
+    env: &Env,
+    asset: &Address,
+    supplied_delta: i128,
+    borrowed_delta: i128,
+) {
+    let key = ReserveDataKey::ReserveData(asset.clone());
+    if !env.storage().persistent().has(&key) {
+        let mut assets = get_reserve_assets(env);
+        assets.push_back(asset.clone());
+        env.storage()
+            .persistent()
+            .set(&ReserveDataKey::ReserveAssets, &assets);
+    }
+    let mut reserve = get_reserve_data(env, asset);
+    reserve.total_supplied = reserve.total_supplied.saturating_add(supplied_delta).max(0);
+    reserve.total_borrowed = reserve.total_borrowed.saturating_add(borrowed_delta).max(0);
+    reserve.borrow_rate_bps = crate::rate_model::get_borrow_rate(env);
+    env.storage().persistent().set(&key, &reserve);
+    crate::borrow::update_borrow_index(env);
+    record_index_checkpoint(
+        env,
+        asset,
+        &BorrowIndex {
+            value: reserve.borrow_index,
+            last_update: reserve.last_update,
+        },
+    );
+}
+
+/// Accrue every reserve at its recorded rate and move it to the current rate
+///
+/// Called after a change to the pool rate configuration, so the new rate
+/// applies from now on.
+pub(crate) fn refresh_borrow_rates(env: &Env) {
+    crate::borrow::update_borrow_index(env);
+    for asset in get_reserve_assets(env).iter() {
+        update_reserve(env, &asset, 0, 0);
+    }
+}
+
+fn get_reserve_assets(env: &Env) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&ReserveDataKey::ReserveAssets)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Append a checkpoint, replacing one recorded earlier in the same ledger second
+fn record_index_checkpoint(env: &Env, asset: &Address, index: &BorrowIndex) {
+    let count = get_index_checkpoint_count(env, asset);
+    let slot = match count.checked_sub(1).and_then(|last| {
+        get_index_checkpoint(env, asset, last).filter(|c| c.last_update == index.last_update)
+    }) {
+        Some(_) => count - 1,
+        None => {
+            env.storage().persistent().set(
+                &ReserveDataKey::IndexCheckpointCount(asset.clone()),
+                &(count + 1),
+            );
+            count
+        }
+    };
+    env.storage()
+        .persistent()
+        .set(&ReserveDataKey::IndexCheckpoint(asset.clone(), slot), index);
+}
+
+fn get_index_checkpoint_count(env: &Env, asset: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&ReserveDataKey::IndexCheckpointCount(asset.clone()))
+        .unwrap_or(0)
+}
+
+fn get_index_checkpoint(env: &Env, asset: &Address, slot: u32) -> Option<BorrowIndex> {
+    env.storage()
+        .persistent()
+        .get(&ReserveDataKey::IndexCheckpoint(asset.clone(), slot))
+}
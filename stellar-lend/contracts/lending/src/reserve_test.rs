@@ -0,0 +1,141 @@
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+
+const YEAR: u64 = 31_536_000;
+
+#[test]
+fn test_reserves_accrue_independently() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    let client = LendingContractClient::new(&env, &env.register(LendingContract, ()));
+    client.initialize(
+        &Address::generate(&env),
+        &InitConfig::new(&env, 1_000_000_000, 1000),
+    );
+    let usdc = Address::generate(&env);
+    let xlm = Address::generate(&env);
+
+    let user = Address::generate(&env);
+    client.borrow(&user, &usdc, &10_000, &Address::generate(&env), &20_000);
+    let reserve = client.get_reserve_data(&usdc);
+    assert_eq!(reserve.total_borrowed, 10_000);
+    assert_eq!(reserve.borrow_index, 1_000_000_000);
+
+    // The XLM market is untouched by USDC activity
+    env.ledger().with_mut(|li| li.timestamp = 1000 + YEAR);
+    assert_eq!(client.get_reserve_data(&usdc).borrow_index, 1_050_000_000);
+    // Pool interest accrues by the reserve index growth
+    assert_eq!(client.get_user_debt(&user).interest_accrued, 500);
+    let untouched = client.get_reserve_data(&xlm);
+    assert_eq!(untouched.total_borrowed, 0);
+    assert_eq!(untouched.borrow_index, 1_000_000_000);
+
+    client.repay(&user, &5000);
+    let reserve = client.get_reserve_data(&usdc);
+    assert_eq!(reserve.last_update, 1000 + YEAR);
+    assert_eq!(reserve.total_borrowed, 5500);
+}
+
+#[test]
+fn test_historical_borrow_index_interpolates_checkpoints() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    let client = LendingContractClient::new(&env, &env.register(LendingContract, ()));
+    client.initialize(
+        &Address::generate(&env),
+        &InitConfig::new(&env, 1_000_000_000, 1000),
+    );
+    let user = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let collateral_asset = Address::generate(&env);
+    let year = 31_536_000u64;
+
+    assert_eq!(client.get_borrow_index_at(&asset, &1000), None);
+    client.borrow(&user, &asset, &10_000, &collateral_asset, &20_000);
+    env.ledger().with_mut(|li| li.timestamp = 1000 + year);
+    client.repay(&user, &1000);
+    let checkpoint = client.get_reserve_data(&asset).borrow_index;
+    assert_eq!(checkpoint, 1_050_000_000);
+
+    assert_eq!(
+        client.get_borrow_index_at(&asset, &1000),
+        Some(1_000_000_000)
+    );
+    // Half way between the checkpoints
+    assert_eq!(
+        client.get_borrow_index_at(&asset, &(1000 + year / 2)),
+        Some(1_025_000_000)
+    );
+    assert_eq!(
+        client.get_borrow_index_at(&asset, &(1000 + year)),
+        Some(checkpoint)
+    );
+    assert_eq!(client.get_borrow_index_at(&asset, &999), None);
+}
+
+#[test]
+fn test_historical_borrow_index_projects_latest_checkpoint() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 0);
+    let client = LendingContractClient::new(&env, &env.register(LendingContract, ()));
+    client.initialize(
+        &Address::generate(&env),
+        &InitConfig::new(&env, 1_000_000_000, 1000),
+    );
+    let year = 31_536_000u64;
+    let asset = Address::generate(&env);
+
+    client.borrow(
+        &Address::generate(&env),
+        &asset,
+        &10_000,
+        &Address::generate(&env),
+        &20_000,
+    );
+    env.ledger().with_mut(|li| li.timestamp = year);
+
+    // No checkpoint since the borrow: the index is projected forward
+    assert_eq!(
+        client.get_borrow_index_at(&asset, &year),
+        Some(client.get_reserve_data(&asset).borrow_index)
+    );
+    assert_eq!(client.get_borrow_index_at(&asset, &(year + 1)), None);
+    // Other markets have no history
+    assert_eq!(
+        client.get_borrow_index_at(&Address::generate(&env), &year),
+        None
+    );
+}
+
+#[test]
+fn test_rate_change_applies_only_from_now() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    let client = LendingContractClient::new(&env, &env.register(LendingContract, ()));
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &InitConfig::new(&env, 1_000_000_000, 1000));
+    let user = Address::generate(&env);
+    let asset = Address::generate(&env);
+    client.borrow(&user, &asset, &100_000, &Address::generate(&env), &200_000);
+
+    env.ledger().with_mut(|li| li.timestamp = 1000 + YEAR);
+    assert_eq!(client.get_user_debt(&user).interest_accrued, 5000);
+
+    // The year already elapsed keeps accruing at the old 5%
+    client.set_base_interest_rate(&admin, &10000);
+    assert_eq!(client.get_user_debt(&user).interest_accrued, 5000);
+    assert_eq!(client.get_reserve_data(&asset).borrow_rate_bps, 10000);
+    assert_eq!(client.get_borrow_index(), 1_050_000_000);
+
+    env.ledger().with_mut(|li| li.timestamp = 1000 + 2 * YEAR);
+    assert_eq!(client.get_reserve_data(&asset).borrow_index, 2_100_000_000);
+    assert_eq!(client.get_borrow_index(), 2_100_000_000);
+    assert_eq!(client.get_user_debt(&user).interest_accrued, 110_000);
+}
@@ -0,0 +1,508 @@
+use soroban_sdk::{contractclient, contracterror, contracttype, Address, Env, Symbol, Vec};
+
+use crate::admin::require_admin;
+use crate::borrow::{
+    emit_borrow_event, get_debt_position, get_min_borrow_amount, get_total_collateral_value,
+    get_total_user_debt, increase_debt, is_liquidatable, is_paused, validate_collateral_ratio,
+    BorrowError,
+};
+use crate::credit_score::record_liquidation;
+use crate::math::{div_down, mul_div_down};
+use crate::repay::{apply_repayment, ensure_no_dust};
+
+/// Compliance interface exposed by transfer-restricted (RWA) tokens
+#[contractclient(name = "RwaComplianceClient")]
+pub trait RwaCompliance {
+    /// Whether an account may hold the token
+    fn is_eligible(env: Env, account: Address) -> bool;
+    /// Whether a transfer would be accepted by the token
+    fn can_transfer(env: Env, from: Address, to: Address, amount: i128) -> bool;
+}
+
+/// Errors that can occur during RWA collateral operations
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum RwaError {
+    Unauthorized = 1,
+    InvalidConfig = 2,
+    AssetNotRegistered = 3,
+    TransferRestricted = 4,
+    NotEligible = 5,
+    InvalidAmount = 6,
+    InsufficientCollateral = 7,
+    PositionHealthy = 8,
+    NothingToClaim = 9,
+    ProtocolPaused = 10,
+    BelowMinimumBorrow = 11,
+    DebtCeilingReached = 12,
+    TooManyAssets = 13,
+    Overflow = 14,
+    DustRemaining = 15,
+    TooManyPositions = 16,
+    BorrowCapExceeded = 17,
+    PriceStale = 18,
+    MarketFrozen = 19,
+    AssetBorrowOnly = 20,
+    /// The borrow must go through `request_borrow`
+    LargeBorrowRequestRequired = 21,
+    /// The borrow is valued above the per-call maximum
+    BorrowSizeCapExceeded = 22,
+    /// The account is under a compliance freeze
+    AccountFrozen = 23,
+    AssetBorrowsPaused = 24,
+    AssetDelisted = 25,
+    BorrowFrozen = 26,
+    AssetCollateralOnly = 27,
+    LiquidityBufferReached = 28,
+    RehypothecationDisabled = 29,
+}
+
+/// Storage keys for RWA collateral data
+#[contracttype]
+#[derive(Clone)]
+pub enum RwaDataKey {
+    RestrictedAsset(Address),
+    RestrictedCollateral(Address, Address),
+    UserRestrictedAssets(Address),
+    PendingRwaSettlement(Address, Address),
+}
+
+/// Configuration of a registered restricted collateral asset
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RwaAssetConfig {
+    /// Contract answering compliance queries (usually the token itself)
+    pub compliance: Address,
+    /// Share of the deposited amount counted as collateral, in basis points
+    pub ltv_bps: i128,
+}
+
+/// Outcome of an RWA liquidation
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RwaLiquidation {
+    pub repaid: i128,
+    pub seized: i128,
+    /// False when the token refused the transfer and the seized amount was
+    /// held for later settlement
+    pub settled: bool,
+}
+
+/// RWA collateral event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct RwaEvent {
+    pub user: Address,
+    pub asset: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+const MAX_RWA_LTV_BPS: i128 = 8000; // 80%
+const MAX_RWA_ASSETS_PER_USER: u32 = 8;
+const RWA_CLOSE_FACTOR_BPS: i128 = 5000; // Up to 50% of the debt per liquidation
+const RWA_LIQUIDATION_BONUS_BPS: i128 = 500; // 5%
+
+/// Register a transfer-restricted collateral asset (admin only)
+pub fn register_rwa_asset(
+    env: &Env,
+    caller: Address,
+    asset: Address,
+    config: RwaAssetConfig,
+) -> Result<(), RwaError> {
+    require_admin(env, &caller).map_err(|_| RwaError::Unauthorized)?;
+
+    if config.ltv_bps <= 0 || config.ltv_bps > MAX_RWA_LTV_BPS {
+        return Err(RwaError::InvalidConfig);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&RwaDataKey::RestrictedAsset(asset), &config);
+    Ok(())
+}
+
+/// Get the configuration of a registered restricted asset
+pub fn get_rwa_asset(env: &Env, asset: &Address) -> Option<RwaAssetConfig> {
+    env.storage()
+        .persistent()
+        .get(&RwaDataKey::RestrictedAsset(asset.clone()))
+}
+
+/// Deposit restricted collateral
+///
+/// The token's compliance check must accept the transfer into the protocol.
+pub fn deposit_rwa_collateral(
+    env: &Env,
+    user: Address,
+    asset: Address,
+    amount: i128,
+) -> Result<(), RwaError> {
+    user.require_auth();
+
+    if amount <= 0 {
+        return Err(RwaError::InvalidAmount);
+    }
+    let config = get_rwa_asset(env, &asset).ok_or(RwaError::AssetNotRegistered)?;
+    if !crate::asset_mode::counts_as_collateral(env, &asset) {
+        return Err(RwaError::AssetBorrowOnly);
+    }
+    if !can_transfer(env, &config, &user, &env.current_contract_address(), amount) {
+        return Err(RwaError::TransferRestricted);
+    }
+
+    let mut assets = get_rwa_user_assets(env, &user);
+    if !assets.contains(&asset) {
+        if assets.len() >= MAX_RWA_ASSETS_PER_USER {
+            return Err(RwaError::TooManyAssets);
+        }
+        if !crate::position_limit::has_position_capacity(env, &user) {
+            return Err(RwaError::TooManyPositions);
+        }
+        assets.push_back(asset.clone());
+        env.storage()
+            .persistent()
+            .set(&RwaDataKey::UserRestrictedAssets(user.clone()), &assets);
+    }
+
+    let balance = get_rwa_collateral(env, &user, &asset)
+        .checked_add(amount)
+        .ok_or(RwaError::Overflow)?;
+    set_rwa_collateral(env, &user, &asset, balance);
+
+    emit_rwa_event(env, "rwa_deposit", user, asset, amount);
+    Ok(())
+}
+
+/// Withdraw restricted collateral if the position stays covered
+///
+/// The token's compliance check must accept the transfer back to the user.
+pub fn withdraw_rwa_collateral(
+    env: &Env,
+    user: Address,
+    asset: Address,
+    amount: i128,
+) -> Result<(), RwaError> {
+    user.require_auth();
+
+    if amount <= 0 {
+        return Err(RwaError::InvalidAmount);
+    }
+    let config = get_rwa_asset(env, &asset).ok_or(RwaError::AssetNotRegistered)?;
+    let balance = get_rwa_collateral(env, &user, &asset);
+    if amount > balance {
+        return Err(RwaError::InsufficientCollateral);
+    }
+    if !can_transfer(env, &config, &env.current_contract_address(), &user, amount) {
+        return Err(RwaError::TransferRestricted);
+    }
+    if crate::oracle::is_price_stale(env, &asset) {
+        return Err(RwaError::PriceStale);
+    }
+
+    set_rwa_collateral(env, &user, &asset, balance - amount);
+    let debt = get_total_user_debt(env, &user);
+    if debt > 0 {
+        validate_collateral_ratio(env, &user, get_total_collateral_value(env, &user), debt)
+            .map_err(|_| RwaError::InsufficientCollateral)?;
+    }
+
+    emit_rwa_event(env, "rwa_withdraw", user, asset, amount);
+    Ok(())
+}
+
+/// Borrow against restricted collateral and other deposited collateral
+pub fn rwa_borrow(env: &Env, user: Address, asset: Address, amount: i128) -> Result<(), RwaError> {
+    user.require_auth();
+
+    if is_paused(env) {
+        return Err(RwaError::ProtocolPaused);
+    }
+    if amount <= 0 {
+        return Err(RwaError::InvalidAmount);
+    }
+    if amount < get_min_borrow_amount(env, &asset) {
+        return Err(RwaError::BelowMinimumBorrow);
+    }
+
+    let debt = get_total_user_debt(env, &user)
+        .checked_add(amount)
+        .ok_or(RwaError::Overflow)?;
+    validate_collateral_ratio(env, &user, get_total_collateral_value(env, &user), debt)
+        .map_err(|_| RwaError::InsufficientCollateral)?;
+
+    increase_debt(env, &user, &asset, amount).map_err(debt_error)?;
+
+    emit_borrow_event(env, user, asset, amount, 0);
+    Ok(())
+}
+
+/// Liquidate restricted collateral of an undercollateralized position
+///
+/// Only addresses the token considers eligible holders may liquidate. The
+/// liquidator repays up to the close factor of the debt and seizes collateral
+/// plus a bonus. If the token refuses the transfer to the liquidator, the
+/// seized amount is held as a pending settlement the liquidator can claim
+/// later or direct to another eligible recipient.
+pub fn liquidate_rwa(
+    env: &Env,
+    liquidator: Address,
+    user: Address,
+    asset: Address,
+    repay_amount: i128,
+) -> Result<RwaLiquidation, RwaError> {
+    liquidator.require_auth();
+
+    let (config, repay, seized) =
+        check_rwa_liquidation(env, &liquidator, &user, &asset, repay_amount)?;
+    let balance = get_rwa_collateral(env, &user, &asset);
+
+    let (_, interest_paid, principal_paid) =
+        apply_repayment(env, user.clone(), repay).map_err(|_| RwaError::Overflow)?;
+    set_rwa_collateral(env, &user, &asset, balance - seized);
+    record_liquidation(env, &user);
+    crate::position_hooks::notify_liquidation(env, &user, interest_paid + principal_paid, seized);
+
+    let settled = can_transfer(
+        env,
+        &config,
+        &env.current_contract_address(),
+        &liquidator,
+        seized,
+    );
+    if !settled {
+        let key = RwaDataKey::PendingRwaSettlement(liquidator.clone(), asset.clone());
+        let pending = get_pending_rwa_settlement(env, &liquidator, &asset).saturating_add(seized);
+        env.storage().persistent().set(&key, &pending);
+    }
+
+    emit_rwa_event(env, "rwa_liquidation", user, asset, seized);
+    Ok(RwaLiquidation {
+        repaid: interest_paid + principal_paid,
+        seized,
+        settled,
+    })
+}
+
+/// Check an RWA liquidation without writing state
+///
+/// Returns the asset's configuration, the debt the liquidation would repay
+/// and the collateral it would seize.
+pub(crate) fn check_rwa_liquidation(
+    env: &Env,
+    liquidator: &Address,
+    user: &Address,
+    asset: &Address,
+    repay_amount: i128,
+) -> Result<(RwaAssetConfig, i128, i128), RwaError> {
+    if repay_amount <= 0 {
+        return Err(RwaError::InvalidAmount);
+    }
+    let config = get_rwa_asset(env, asset).ok_or(RwaError::AssetNotRegistered)?;
+    if !is_eligible(env, &config, liquidator) {
+        return Err(RwaError::NotEligible);
+    }
+
+    if crate::pause::is_operation_paused(env, crate::pause::PAUSE_LIQUIDATE) {
+        return Err(RwaError::ProtocolPaused);
+    }
+    if !is_liquidatable(env, user) {
+        return Err(RwaError::PositionHealthy);
+    }
+    let debt_asset = get_debt_position(env, user).asset;
+    if crate::oracle::is_liquidation_frozen(env, asset)
+        || crate::oracle::is_liquidation_frozen(env, &debt_asset)
+    {
+        return Err(RwaError::PriceStale);
+    }
+    if crate::oracle::is_market_frozen(env, asset)
+        || crate::oracle::is_market_frozen(env, &debt_asset)
+    {
+        return Err(RwaError::MarketFrozen);
+    }
+    let debt = get_total_user_debt(env, user);
+
+    let balance = get_rwa_collateral(env, user, asset);
+    if balance == 0 {
+        return Err(RwaError::InsufficientCollateral);
+    }
+    let max_repay = debt.saturating_mul(RWA_CLOSE_FACTOR_BPS) / 10000;
+    let mut repay = repay_amount.min(max_repay);
+    // The liquidator's collateral rounds down, as does the debt it retires
+    let mut seized = repay
+        .checked_mul(10000 + RWA_LIQUIDATION_BONUS_BPS)
+        .map(|seized| div_down(seized, 10000))
+        .ok_or(RwaError::Overflow)?;
+    if seized > balance {
+        // Collateral runs out first: scale the repayment down to what it buys
+        seized = balance;
+        repay = mul_div_down(balance, 10000, 10000 + RWA_LIQUIDATION_BONUS_BPS);
+    } else {
+        ensure_no_dust(env, user, repay).map_err(|_| RwaError::DustRemaining)?;
+    }
+    Ok((config, repay, seized))
+}
+
+/// Settle collateral held after a refused liquidation transfer
+///
+/// The seized amount is released to `recipient` (the liquidator or another
+/// address it designates) once the token accepts the transfer.
+///
+/// # Returns
+/// The amount settled
+pub fn claim_rwa_settlement(
+    env: &Env,
+    liquidator: Address,
+    asset: Address,
+    recipient: Address,
+) -> Result<i128, RwaError> {
+    liquidator.require_auth();
+
+    let config = get_rwa_asset(env, &asset).ok_or(RwaError::AssetNotRegistered)?;
+    let pending = get_pending_rwa_settlement(env, &liquidator, &asset);
+    if pending == 0 {
+        return Err(RwaError::NothingToClaim);
+    }
+    if !can_transfer(
+        env,
+        &config,
+        &env.current_contract_address(),
+        &recipient,
+        pending,
+    ) {
+        return Err(RwaError::TransferRestricted);
+    }
+
+    env.storage()
+        .persistent()
+        .remove(&RwaDataKey::PendingRwaSettlement(liquidator, asset.clone()));
+
+    emit_rwa_event(env, "rwa_settlement_claimed", recipient, asset, pending);
+    Ok(pending)
+}
+
+/// Get a user's deposited amount of a restricted asset
+pub fn get_rwa_collateral(env: &Env, user: &Address, asset: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&RwaDataKey::RestrictedCollateral(
+            user.clone(),
+            asset.clone(),
+        ))
+        .unwrap_or(0)
+}
+
+/// Get the restricted assets a user has deposited
+pub fn get_rwa_user_assets(env: &Env, user: &Address) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&RwaDataKey::UserRestrictedAssets(user.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Get collateral awaiting settlement to a liquidator
+pub fn get_pending_rwa_settlement(env: &Env, liquidator: &Address, asset: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&RwaDataKey::PendingRwaSettlement(
+            liquidator.clone(),
+            asset.clone(),
+        ))
+        .unwrap_or(0)
+}
+
+/// Collateral value of a user's restricted deposits (amount times the asset LTV)
+pub fn get_rwa_collateral_value(env: &Env, user: &Address) -> i128 {
+    get_rwa_user_assets(env, user)
+        .iter()
+        .filter(|asset| crate::asset_mode::counts_as_collateral(env, asset))
+        .filter_map(|asset| {
+            get_rwa_asset(env, &asset).map(|config| {
+                mul_div_down(get_rwa_collateral(env, user, &asset), config.ltv_bps, 10000)
+            })
+        })
+        .fold(0i128, |acc, value| acc.saturating_add(value))
+}
+
+/// A compliance query that fails or cannot be decoded counts as a refusal
+fn can_transfer(
+    env: &Env,
+    config: &RwaAssetConfig,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+) -> bool {
+    RwaComplianceClient::new(env, &config.compliance)
+        .try_can_transfer(from, to, &amount)
+        .map(|result| result.unwrap_or(false))
+        .unwrap_or(false)
+}
+
+fn is_eligible(env: &Env, config: &RwaAssetConfig, account: &Address) -> bool {
+    RwaComplianceClient::new(env, &config.compliance)
+        .try_is_eligible(account)
+        .map(|result| result.unwrap_or(false))
+        .unwrap_or(false)
+}
+
+fn set_rwa_collateral(env: &Env, user: &Address, asset: &Address, amount: i128) {
+    env.storage().persistent().set(
+        &RwaDataKey::RestrictedCollateral(user.clone(), asset.clone()),
+        &amount,
+    );
+}
+
+/// Map a failed debt increase onto the RWA error it surfaces as
+fn debt_error(error: BorrowError) -> RwaError {
+    match error {
+        BorrowError::ProtocolPaused => RwaError::ProtocolPaused,
+        BorrowError::TooManyPositions => RwaError::TooManyPositions,
+        BorrowError::AccountFrozen => RwaError::AccountFrozen,
+        BorrowError::BorrowCapExceeded => RwaError::BorrowCapExceeded,
+        BorrowError::PriceStale => RwaError::PriceStale,
+        BorrowError::MarketFrozen => RwaError::MarketFrozen,
+        BorrowError::AssetBorrowsPaused => RwaError::AssetBorrowsPaused,
+        BorrowError::AssetDelisted => RwaError::AssetDelisted,
+        BorrowError::BorrowFrozen => RwaError::BorrowFrozen,
+        BorrowError::AssetCollateralOnly => RwaError::AssetCollateralOnly,
+        BorrowError::DebtCeilingReached => RwaError::DebtCeilingReached,
+        BorrowError::LiquidityBufferReached => RwaError::LiquidityBufferReached,
+        BorrowError::RehypothecationDisabled => RwaError::RehypothecationDisabled,
+        BorrowError::LargeBorrowRequestRequired => RwaError::LargeBorrowRequestRequired,
+        BorrowError::BorrowSizeCapExceeded => RwaError::BorrowSizeCapExceeded,
+        BorrowError::Overflow => RwaError::Overflow,
+        BorrowError::InvalidAmount => RwaError::InvalidAmount,
+        BorrowError::BelowMinimumBorrow => RwaError::BelowMinimumBorrow,
+        BorrowError::Unauthorized => RwaError::Unauthorized,
+        BorrowError::AssetBorrowOnly => RwaError::AssetBorrowOnly,
+        // Collateral-side and request errors are never returned by the debt check
+        BorrowError::InsufficientCollateral
+        | BorrowError::AssetNotSupported
+        | BorrowError::ExposureCapReached
+        | BorrowError::BelowMinimumCollateral
+        | BorrowError::CollateralFrozen => RwaError::InsufficientCollateral,
+        BorrowError::InvalidConfig
+        | BorrowError::NoPendingBorrow
+        | BorrowError::BorrowDelayActive
+        | BorrowError::PendingBorrowExists => RwaError::InvalidConfig,
+    }
+}
+
+fn emit_rwa_event(env: &Env, name: &str, user: Address, asset: Address, amount: i128) {
+    let event = RwaEvent {
+        user,
+        asset,
+        amount,
+        timestamp: env.ledger().timestamp(),
+    };
+    crate::events::publish(
+        env,
+        (
+            Symbol::new(env, name),
+            event.user.clone(),
+            event.asset.clone(),
+        ),
+        event,
+    );
+}
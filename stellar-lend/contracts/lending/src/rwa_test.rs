@@ -0,0 +1,205 @@
+use super::*;
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, Address, Env, Symbol};
+
+/// Minimal restricted token compliance module: a blocklist plus a global halt
+#[contract]
+pub struct MockCompliance;
+
+#[contractimpl]
+impl MockCompliance {
+    pub fn set_blocked(env: Env, account: Address, blocked: bool) {
+        env.storage().instance().set(&account, &blocked);
+    }
+
+    pub fn set_halted(env: Env, halted: bool) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "halted"), &halted);
+    }
+
+    pub fn is_eligible(env: Env, account: Address) -> bool {
+        !env.storage().instance().get(&account).unwrap_or(false)
+    }
+
+    pub fn can_transfer(env: Env, from: Address, to: Address, _amount: i128) -> bool {
+        let halted: bool = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "halted"))
+            .unwrap_or(false);
+        !halted && Self::is_eligible(env.clone(), from) && Self::is_eligible(env, to)
+    }
+}
+
+fn setup(
+    env: &Env,
+) -> (
+    LendingContractClient<'_>,
+    MockComplianceClient<'_>,
+    Address,
+    Address,
+) {
+    env.mock_all_auths();
+
+    let contract_id = env.register(LendingContract, ());
+    let client = LendingContractClient::new(env, &contract_id);
+    client.initialize(
+        &Address::generate(env),
+        &InitConfig::new(env, 1_000_000_000, 1000),
+    );
+
+    let admin = Address::generate(env);
+    client.set_admin(&admin);
+
+    let compliance_id = env.register(MockCompliance, ());
+    let compliance = MockComplianceClient::new(env, &compliance_id);
+    let asset = Address::generate(env);
+    client.register_rwa_asset(&admin, &asset, &rwa_config(&compliance_id, 8000));
+    (client, compliance, admin, asset)
+}
+
+fn rwa_config(compliance: &Address, ltv_bps: i128) -> RwaAssetConfig {
+    RwaAssetConfig {
+        compliance: compliance.clone(),
+        ltv_bps,
+    }
+}
+
+/// Borrow against RWA collateral, then cut the LTV so the position is liquidatable
+fn open_unhealthy_position(
+    env: &Env,
+    client: &LendingContractClient<'_>,
+    compliance: &MockComplianceClient<'_>,
+    admin: &Address,
+    asset: &Address,
+) -> Address {
+    let user = Address::generate(env);
+    let debt_asset = Address::generate(env);
+    client.deposit_rwa_collateral(&user, asset, &100_000);
+    client.rwa_borrow(&user, &debt_asset, &50_000);
+    client.register_rwa_asset(admin, asset, &rwa_config(&compliance.address, 5000));
+    user
+}
+
+#[test]
+fn test_deposit_and_borrow_against_rwa() {
+    let env = Env::default();
+    let (client, _compliance, _admin, asset) = setup(&env);
+    let user = Address::generate(&env);
+    let debt_asset = Address::generate(&env);
+
+    client.deposit_rwa_collateral(&user, &asset, &100_000);
+    assert_eq!(client.get_rwa_collateral_value(&user), 80_000);
+
+    let result = client.try_rwa_borrow(&user, &debt_asset, &53_334);
+    assert_eq!(result, Err(Ok(RwaError::InsufficientCollateral)));
+    client.rwa_borrow(&user, &debt_asset, &53_333);
+
+    let result = client.try_withdraw_rwa_collateral(&user, &asset, &1000);
+    assert_eq!(result, Err(Ok(RwaError::InsufficientCollateral)));
+}
+
+#[test]
+fn test_compliance_blocks_restricted_transfers() {
+    let env = Env::default();
+    let (client, compliance, _admin, asset) = setup(&env);
+    let user = Address::generate(&env);
+
+    compliance.set_blocked(&user, &true);
+    let result = client.try_deposit_rwa_collateral(&user, &asset, &1000);
+    assert_eq!(result, Err(Ok(RwaError::TransferRestricted)));
+
+    compliance.set_blocked(&user, &false);
+    client.deposit_rwa_collateral(&user, &asset, &1000);
+
+    compliance.set_halted(&true);
+    let result = client.try_withdraw_rwa_collateral(&user, &asset, &1000);
+    assert_eq!(result, Err(Ok(RwaError::TransferRestricted)));
+}
+
+#[test]
+fn test_only_eligible_liquidators() {
+    let env = Env::default();
+    let (client, compliance, admin, asset) = setup(&env);
+    let user = open_unhealthy_position(&env, &client, &compliance, &admin, &asset);
+    let liquidator = Address::generate(&env);
+
+    compliance.set_blocked(&liquidator, &true);
+    let result = client.try_liquidate_rwa(&liquidator, &user, &asset, &10_000);
+    assert_eq!(result, Err(Ok(RwaError::NotEligible)));
+
+    compliance.set_blocked(&liquidator, &false);
+    let outcome = client.liquidate_rwa(&liquidator, &user, &asset, &10_000);
+    assert_eq!(
+        outcome,
+        RwaLiquidation {
+            repaid: 10_000,
+            seized: 10_500,
+            settled: true,
+        }
+    );
+    assert_eq!(client.get_rwa_collateral(&user, &asset), 89_500);
+    assert_eq!(client.get_user_debt(&user).borrowed_amount, 40_000);
+}
+
+#[test]
+fn test_refused_settlement_is_held_for_claim() {
+    let env = Env::default();
+    let (client, compliance, admin, asset) = setup(&env);
+    let user = open_unhealthy_position(&env, &client, &compliance, &admin, &asset);
+    let liquidator = Address::generate(&env);
+    let custodian = Address::generate(&env);
+
+    compliance.set_halted(&true);
+    let outcome = client.liquidate_rwa(&liquidator, &user, &asset, &10_000);
+    assert!(!outcome.settled);
+    assert_eq!(
+        client.get_pending_rwa_settlement(&liquidator, &asset),
+        10_500
+    );
+
+    let result = client.try_claim_rwa_settlement(&liquidator, &asset, &custodian);
+    assert_eq!(result, Err(Ok(RwaError::TransferRestricted)));
+
+    compliance.set_halted(&false);
+    assert_eq!(
+        client.claim_rwa_settlement(&liquidator, &asset, &custodian),
+        10_500
+    );
+    assert_eq!(client.get_pending_rwa_settlement(&liquidator, &asset), 0);
+}
+
+#[test]
+fn test_healthy_position_cannot_be_liquidated() {
+    let env = Env::default();
+    let (client, _compliance, _admin, asset) = setup(&env);
+    let user = Address::generate(&env);
+    let liquidator = Address::generate(&env);
+    let debt_asset = Address::generate(&env);
+
+    client.deposit_rwa_collateral(&user, &asset, &100_000);
+    client.rwa_borrow(&user, &debt_asset, &50_000);
+
+    let result = client.try_liquidate_rwa(&liquidator, &user, &asset, &10_000);
+    assert_eq!(result, Err(Ok(RwaError::PositionHealthy)));
+}
+
+#[test]
+fn test_liquidation_cannot_leave_dust_debt() {
+    let env = Env::default();
+    let (client, compliance, admin, asset) = setup(&env);
+    let user = open_unhealthy_position(&env, &client, &compliance, &admin, &asset);
+    let liquidator = Address::generate(&env);
+
+    // Raise the minimum borrow so a half close would leave dust behind
+    client.set_config(
+        &admin,
+        &Vec::from_array(&env, [ConfigChange::MinBorrowAmount(30_000)]),
+    );
+    let result = client.try_liquidate_rwa(&liquidator, &user, &asset, &25_000);
+    assert_eq!(result, Err(Ok(RwaError::DustRemaining)));
+
+    let liquidation = client.liquidate_rwa(&liquidator, &user, &asset, &20_000);
+    assert_eq!(liquidation.repaid, 20_000);
+    assert_eq!(client.get_user_debt(&user).borrowed_amount, 30_000);
+}
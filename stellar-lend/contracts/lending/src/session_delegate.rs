@@ -0,0 +1,363 @@
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol, Vec};
+
+use crate::repay::{apply_borrower_repayment, RepayError};
+use crate::withdraw::{apply_withdrawal, WithdrawError};
+
+/// Errors that can occur when acting through a session delegate
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum DelegateError {
+    Unauthorized = 1,
+    InvalidConfig = 2,
+    TooManyDelegates = 3,
+    /// The delegate is not scoped for the action
+    ActionNotAllowed = 4,
+    /// The action would exceed the delegate's daily cap
+    DailyLimitExceeded = 5,
+    InvalidAmount = 6,
+    /// The underlying repayment or withdrawal was rejected
+    ExecutionFailed = 7,
+}
+
+/// Storage keys for session delegate data
+#[contracttype]
+#[derive(Clone)]
+pub enum DelegateDataKey {
+    SessionDelegate(Address, Address),
+    /// Delegates an owner has authorized
+    OwnerDelegates(Address),
+    /// Amount a delegate has used of an action's cap on its latest day
+    DelegateUsage(Address, Address, DelegateAction),
+}
+
+/// An operation a session delegate may perform on the owner's position
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DelegateAction {
+    Repay,
+    WithdrawCollateral,
+}
+
+/// A delegate's daily allowance for one action
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DelegateLimit {
+    pub action: DelegateAction,
+    /// Most the delegate may move per UTC day
+    pub daily_cap: i128,
+}
+
+/// A delegate key's scope
+///
+/// Actions without a limit are not allowed.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SessionDelegate {
+    pub limits: Vec<DelegateLimit>,
+    /// Last ledger the delegate can act in
+    pub expiration_ledger: u32,
+}
+
+/// Part of an action's daily cap used on one day
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+struct DailyUsage {
+    day: u64,
+    used: i128,
+}
+
+/// Session delegate event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct DelegateEvent {
+    pub owner: Address,
+    pub delegate: Address,
+    pub timestamp: u64,
+}
+
+/// Delegated action event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct DelegateActionEvent {
+    pub owner: Address,
+    pub delegate: Address,
+    pub action: DelegateAction,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+const MAX_DELEGATES_PER_OWNER: u32 = 4;
+const SECONDS_PER_DAY: u64 = 86400;
+
+/// Authorize a delegate key to act on the owner's position within limits
+///
+/// Each action the delegate may perform is listed with a daily cap; anything
+/// else is refused. Authorizing an existing delegate replaces its scope but
+/// keeps the day's usage. The delegate is void after `expiration_ledger`;
+/// expired delegates are revoked here and no longer count toward the limit.
+pub fn authorize_delegate(
+    env: &Env,
+    owner: Address,
+    delegate: Address,
+    limits: Vec<DelegateLimit>,
+    expiration_ledger: u32,
+) -> Result<(), DelegateError> {
+    owner.require_auth();
+
+    if delegate == owner
+        || limits.is_empty()
+        || !crate::approvals::is_valid_expiration(env, expiration_ledger)
+    {
+        return Err(DelegateError::InvalidConfig);
+    }
+    for (i, limit) in limits.iter().enumerate() {
+        if limit.daily_cap <= 0
+            || limits
+                .iter()
+                .skip(i + 1)
+                .any(|other| other.action == limit.action)
+        {
+            return Err(DelegateError::InvalidConfig);
+        }
+    }
+
+    for other in get_owner_delegates(env, &owner).iter() {
+        if get_session_delegate(env, &owner, &other).is_none() {
+            remove_delegate(env, &owner, &other);
+        }
+    }
+    let mut delegates = get_owner_delegates(env, &owner);
+    if !delegates.contains(&delegate) {
+        if delegates.len() >= MAX_DELEGATES_PER_OWNER {
+            return Err(DelegateError::TooManyDelegates);
+        }
+        delegates.push_back(delegate.clone());
+        save_owner_delegates(env, &owner, &delegates);
+    }
+    env.storage().persistent().set(
+        &DelegateDataKey::SessionDelegate(owner.clone(), delegate.clone()),
+        &SessionDelegate {
+            limits,
+            expiration_ledger,
+        },
+    );
+    emit_delegate_event(env, "delegate_authorized", owner, delegate);
+    Ok(())
+}
+
+/// Revoke a session delegate
+pub fn revoke_delegate(env: &Env, owner: Address, delegate: Address) {
+    owner.require_auth();
+    remove_delegate(env, &owner, &delegate);
+}
+
+/// Get a delegate's scope, if authorized and not expired
+pub fn get_session_delegate(
+    env: &Env,
+    owner: &Address,
+    delegate: &Address,
+) -> Option<SessionDelegate> {
+    env.storage()
+        .persistent()
+        .get::<_, SessionDelegate>(&DelegateDataKey::SessionDelegate(
+            owner.clone(),
+            delegate.clone(),
+        ))
+        .filter(|scope| crate::approvals::is_live(env, scope.expiration_ledger))
+}
+
+/// Get the delegates an owner has authorized
+pub fn get_owner_delegates(env: &Env, owner: &Address) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DelegateDataKey::OwnerDelegates(owner.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Amount of an action's daily cap a delegate has left today
+pub fn get_delegate_remaining(
+    env: &Env,
+    owner: &Address,
+    delegate: &Address,
+    action: DelegateAction,
+) -> i128 {
+    match find_limit(env, owner, delegate, action) {
+        Some(limit) => limit
+            .daily_cap
+            .saturating_sub(get_usage(env, owner, delegate, action))
+            .max(0),
+        None => 0,
+    }
+}
+
+/// Repay the owner's debt as their delegate
+///
+/// Counts the amount actually paid against the delegate's daily cap.
+///
+/// # Returns
+/// The remaining debt, interest paid and principal paid
+pub fn delegate_repay(
+    env: &Env,
+    delegate: Address,
+    owner: Address,
+    amount: i128,
+) -> Result<(i128, i128, i128), DelegateError> {
+    delegate.require_auth();
+    if amount <= 0 {
+        return Err(DelegateError::InvalidAmount);
+    }
+
+    let result = apply_borrower_repayment(env, owner.clone(), amount).map_err(|e| match e {
+        RepayError::InvalidAmount => DelegateError::InvalidAmount,
+        _ => DelegateError::ExecutionFailed,
+    })?;
+    consume(
+        env,
+        &owner,
+        &delegate,
+        DelegateAction::Repay,
+        result.1 + result.2,
+    )?;
+    Ok(result)
+}
+
+/// Withdraw the owner's collateral as their delegate
+///
+/// # Returns
+/// The remaining collateral balance
+pub fn delegate_withdraw_collateral(
+    env: &Env,
+    delegate: Address,
+    owner: Address,
+    amount: i128,
+) -> Result<i128, DelegateError> {
+    delegate.require_auth();
+    if amount <= 0 {
+        return Err(DelegateError::InvalidAmount);
+    }
+
+    consume(
+        env,
+        &owner,
+        &delegate,
+        DelegateAction::WithdrawCollateral,
+        amount,
+    )?;
+    apply_withdrawal(env, owner, amount).map_err(|e| match e {
+        WithdrawError::InvalidAmount => DelegateError::InvalidAmount,
+        _ => DelegateError::ExecutionFailed,
+    })
+}
+
+/// Revoke all of an owner's delegates
+pub(crate) fn remove_all_delegates(env: &Env, owner: &Address) {
+    for delegate in get_owner_delegates(env, owner).iter() {
+        remove_delegate(env, owner, &delegate);
+    }
+}
+
+/// Count `amount` of an action against a delegate's cap for today
+fn consume(
+    env: &Env,
+    owner: &Address,
+    delegate: &Address,
+    action: DelegateAction,
+    amount: i128,
+) -> Result<(), DelegateError> {
+    let limit = find_limit(env, owner, delegate, action).ok_or(DelegateError::ActionNotAllowed)?;
+    let used = get_usage(env, owner, delegate, action).saturating_add(amount);
+    if used > limit.daily_cap {
+        return Err(DelegateError::DailyLimitExceeded);
+    }
+    env.storage().persistent().set(
+        &DelegateDataKey::DelegateUsage(owner.clone(), delegate.clone(), action),
+        &DailyUsage {
+            day: today(env),
+            used,
+        },
+    );
+    let event = DelegateActionEvent {
+        owner: owner.clone(),
+        delegate: delegate.clone(),
+        action,
+        amount,
+        timestamp: env.ledger().timestamp(),
+    };
+    crate::events::publish(
+        env,
+        (
+            Symbol::new(env, "delegate_action"),
+            event.owner.clone(),
+            event.delegate.clone(),
+        ),
+        event,
+    );
+    Ok(())
+}
+
+fn find_limit(
+    env: &Env,
+    owner: &Address,
+    delegate: &Address,
+    action: DelegateAction,
+) -> Option<DelegateLimit> {
+    get_session_delegate(env, owner, delegate)?
+        .limits
+        .iter()
+        .find(|limit| limit.action == action)
+}
+
+/// Part of an action's cap used today; usage resets at each UTC day
+fn get_usage(env: &Env, owner: &Address, delegate: &Address, action: DelegateAction) -> i128 {
+    env.storage()
+        .persistent()
+        .get::<_, DailyUsage>(&DelegateDataKey::DelegateUsage(
+            owner.clone(),
+            delegate.clone(),
+            action,
+        ))
+        .filter(|usage| usage.day == today(env))
+        .map_or(0, |usage| usage.used)
+}
+
+fn today(env: &Env) -> u64 {
+    env.ledger().timestamp() / SECONDS_PER_DAY
+}
+
+fn remove_delegate(env: &Env, owner: &Address, delegate: &Address) {
+    let key = DelegateDataKey::SessionDelegate(owner.clone(), delegate.clone());
+    if !env.storage().persistent().has(&key) {
+        return;
+    }
+    env.storage().persistent().remove(&key);
+    let mut delegates = get_owner_delegates(env, owner);
+    if let Some(index) = delegates.first_index_of(delegate) {
+        delegates.remove(index);
+    }
+    save_owner_delegates(env, owner, &delegates);
+    emit_delegate_event(env, "delegate_revoked", owner.clone(), delegate.clone());
+}
+
+fn save_owner_delegates(env: &Env, owner: &Address, delegates: &Vec<Address>) {
+    env.storage()
+        .persistent()
+        .set(&DelegateDataKey::OwnerDelegates(owner.clone()), delegates);
+}
+
+fn emit_delegate_event(env: &Env, name: &str, owner: Address, delegate: Address) {
+    let event = DelegateEvent {
+        owner,
+        delegate,
+        timestamp: env.ledger().timestamp(),
+    };
+    crate::events::publish(
+        env,
+        (
+            Symbol::new(env, name),
+            event.owner.clone(),
+            event.delegate.clone(),
+        ),
+        event,
+    );
+}
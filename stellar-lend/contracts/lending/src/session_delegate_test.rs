@@ -0,0 +1,146 @@
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, Env,
+};
+
+const DAY: u64 = 86400;
+
+fn setup(env: &Env) -> (LendingContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    let client = LendingContractClient::new(env, &env.register(LendingContract, ()));
+    client.initialize(
+        &Address::generate(env),
+        &InitConfig::new(env, 1_000_000_000, 1000),
+    );
+    let owner = Address::generate(env);
+    client.borrow(
+        &owner,
+        &Address::generate(env),
+        &10_000,
+        &Address::generate(env),
+        &30_000,
+    );
+    let delegate = Address::generate(env);
+    client.authorize_delegate(
+        &owner,
+        &delegate,
+        &vec![
+            env,
+            DelegateLimit {
+                action: DelegateAction::Repay,
+                daily_cap: 1_000,
+            },
+        ],
+        &(env.ledger().sequence() + 100),
+    );
+    (client, owner, delegate)
+}
+
+#[test]
+fn test_delegate_limited_to_scoped_actions() {
+    let env = Env::default();
+    let (client, owner, delegate) = setup(&env);
+
+    client.delegate_repay(&delegate, &owner, &600);
+    assert_eq!(
+        client.get_delegate_remaining(&owner, &delegate, &DelegateAction::Repay),
+        400
+    );
+    assert_eq!(
+        client.try_delegate_repay(&delegate, &owner, &401),
+        Err(Ok(DelegateError::DailyLimitExceeded))
+    );
+    assert_eq!(
+        client.try_delegate_withdraw_collateral(&delegate, &owner, &100),
+        Err(Ok(DelegateError::ActionNotAllowed))
+    );
+    assert_eq!(
+        client.try_delegate_repay(&Address::generate(&env), &owner, &100),
+        Err(Ok(DelegateError::ActionNotAllowed))
+    );
+
+    // The cap resets each day
+    env.ledger().with_mut(|li| li.timestamp += DAY);
+    client.delegate_repay(&delegate, &owner, &1_000);
+    assert_eq!(
+        client.get_delegate_remaining(&owner, &delegate, &DelegateAction::Repay),
+        0
+    );
+}
+
+#[test]
+fn test_delegate_expires_and_is_revocable() {
+    let env = Env::default();
+    let (client, owner, delegate) = setup(&env);
+    assert_eq!(
+        client.get_owner_delegates(&owner),
+        vec![&env, delegate.clone()]
+    );
+
+    env.ledger().with_mut(|li| li.sequence_number += 101);
+    assert_eq!(client.get_session_delegate(&owner, &delegate), None);
+    assert_eq!(
+        client.try_delegate_repay(&delegate, &owner, &100),
+        Err(Ok(DelegateError::ActionNotAllowed))
+    );
+
+    client.authorize_delegate(
+        &owner,
+        &delegate,
+        &vec![
+            &env,
+            DelegateLimit {
+                action: DelegateAction::WithdrawCollateral,
+                daily_cap: 5_000,
+            },
+        ],
+        &(env.ledger().sequence() + 100),
+    );
+    assert_eq!(
+        client.delegate_withdraw_collateral(&delegate, &owner, &5_000),
+        25_000
+    );
+    client.revoke_all(&owner);
+    assert_eq!(client.get_owner_delegates(&owner).len(), 0);
+    assert_eq!(
+        client.try_delegate_withdraw_collateral(&delegate, &owner, &100),
+        Err(Ok(DelegateError::ActionNotAllowed))
+    );
+}
+
+#[test]
+fn test_expired_delegates_free_their_slots() {
+    let env = Env::default();
+    let (client, owner, delegate) = setup(&env);
+    let limits = vec![
+        &env,
+        DelegateLimit {
+            action: DelegateAction::Repay,
+            daily_cap: 1_000,
+        },
+    ];
+    for _ in 0..3 {
+        client.authorize_delegate(
+            &owner,
+            &Address::generate(&env),
+            &limits,
+            &(env.ledger().sequence() + 100),
+        );
+    }
+    assert_eq!(
+        client.try_authorize_delegate(
+            &owner,
+            &Address::generate(&env),
+            &limits,
+            &(env.ledger().sequence() + 100),
+        ),
+        Err(Ok(DelegateError::TooManyDelegates))
+    );
+
+    env.ledger().with_mut(|li| li.sequence_number += 101);
+    let fresh = Address::generate(&env);
+    client.authorize_delegate(&owner, &fresh, &limits, &(env.ledger().sequence() + 100));
+    assert_eq!(client.get_owner_delegates(&owner), vec![&env, fresh]);
+    assert_eq!(client.get_session_delegate(&owner, &delegate), None);
+}
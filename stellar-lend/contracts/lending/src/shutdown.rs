@@ -0,0 +1,108 @@
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol};
+
+use crate::admin::require_admin;
+use crate::oracle::{get_effective_price, get_priced_assets, PriceData};
+
+/// Errors that can occur when shutting the protocol down
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ShutdownError {
+    Unauthorized = 1,
+    AlreadyShutdown = 2,
+}
+
+/// Storage keys for emergency shutdown data
+#[contracttype]
+#[derive(Clone)]
+pub enum ShutdownDataKey {
+    ShutdownInfo,
+    /// Price an asset settles at during wind-down
+    SettlementPrice(Address),
+}
+
+/// Emergency shutdown record
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShutdownInfo {
+    pub actor: Address,
+    pub timestamp: u64,
+    /// Number of asset prices snapshotted for settlement
+    pub assets_snapshotted: u32,
+}
+
+/// Settlement price snapshot event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct SettlementPriceEvent {
+    pub asset: Address,
+    pub price: i128,
+    pub observed_at: u64,
+}
+
+/// Shut the protocol down for wind-down (admin only, irreversible)
+///
+/// Snapshots the effective price of every priced asset in the same call.
+/// From then on the protocol values assets only at these settlement prices,
+/// so redemptions and repayments do not depend on later oracle movement. New
+/// borrows stop; repayments and withdrawals stay open.
+pub fn emergency_shutdown(env: &Env, caller: Address) -> Result<ShutdownInfo, ShutdownError> {
+    require_admin(env, &caller).map_err(|_| ShutdownError::Unauthorized)?;
+
+    if is_shutdown(env) {
+        return Err(ShutdownError::AlreadyShutdown);
+    }
+
+    let mut assets_snapshotted = 0u32;
+    for asset in get_priced_assets(env).iter() {
+        if let Some(data) = get_effective_price(env, &asset) {
+            env.storage()
+                .persistent()
+                .set(&ShutdownDataKey::SettlementPrice(asset.clone()), &data);
+            assets_snapshotted += 1;
+
+            let event = SettlementPriceEvent {
+                asset,
+                price: data.price,
+                observed_at: data.timestamp,
+            };
+            crate::events::publish(
+                env,
+                (Symbol::new(env, "settlement_price"), event.asset.clone()),
+                event,
+            );
+        }
+    }
+
+    let info = ShutdownInfo {
+        actor: caller,
+        timestamp: env.ledger().timestamp(),
+        assets_snapshotted,
+    };
+    env.storage()
+        .persistent()
+        .set(&ShutdownDataKey::ShutdownInfo, &info);
+    crate::events::publish(env, (Symbol::new(env, "emergency_shutdown"),), info.clone());
+    Ok(info)
+}
+
+/// Get the emergency shutdown record, if the protocol has been shut down
+pub fn get_shutdown_info(env: &Env) -> Option<ShutdownInfo> {
+    env.storage()
+        .persistent()
+        .get(&ShutdownDataKey::ShutdownInfo)
+}
+
+/// Whether the protocol has been shut down
+pub fn is_shutdown(env: &Env) -> bool {
+    env.storage()
+        .persistent()
+        .has(&ShutdownDataKey::ShutdownInfo)
+}
+
+/// Get the settlement price snapshotted for an asset at shutdown
+pub fn get_settlement_price(env: &Env, asset: &Address) -> Option<PriceData> {
+    env.storage()
+        .persistent()
+        .get(&ShutdownDataKey::SettlementPrice(asset.clone()))
+}
@@ -0,0 +1,70 @@
+use super::*;
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+fn setup(env: &Env) -> (LendingContractClient<'_>, Address) {
+    env.mock_all_auths();
+    let client = LendingContractClient::new(env, &env.register(LendingContract, ()));
+    client.initialize(
+        &Address::generate(env),
+        &InitConfig::new(env, 1_000_000_000, 1000),
+    );
+
+    let admin = Address::generate(env);
+    client.set_admin(&admin);
+    (client, admin)
+}
+
+#[test]
+fn test_shutdown_snapshots_settlement_prices() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let asset_a = Address::generate(&env);
+    let asset_b = Address::generate(&env);
+    client.set_asset_price(&admin, &asset_a, &10_000_000);
+    client.set_asset_price(&admin, &asset_b, &25_000_000);
+
+    let info = client.emergency_shutdown(&admin);
+    assert_eq!(info.assets_snapshotted, 2);
+    assert!(client.is_shutdown());
+
+    // Later oracle movement does not change settlement valuations
+    client.set_asset_price(&admin, &asset_a, &5_000_000);
+    assert_eq!(client.get_asset_price(&asset_a).unwrap().price, 5_000_000);
+    assert_eq!(
+        client.get_effective_price(&asset_a).unwrap().price,
+        10_000_000
+    );
+    assert_eq!(
+        client.get_settlement_price(&asset_b).unwrap().price,
+        25_000_000
+    );
+
+    assert_eq!(
+        client.try_emergency_shutdown(&admin),
+        Err(Ok(ShutdownError::AlreadyShutdown))
+    );
+}
+
+#[test]
+fn test_wind_down_stops_borrows_but_allows_exit() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let user = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let collateral = Address::generate(&env);
+    client.borrow(&user, &asset, &10_000, &collateral, &30_000);
+
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_emergency_shutdown(&stranger),
+        Err(Ok(ShutdownError::Unauthorized))
+    );
+    client.emergency_shutdown(&admin);
+
+    assert_eq!(
+        client.try_borrow(&user, &asset, &1000, &collateral, &3000),
+        Err(Ok(BorrowError::ProtocolPaused))
+    );
+    client.repay(&user, &10_000);
+    client.withdraw_collateral(&user, &30_000);
+}
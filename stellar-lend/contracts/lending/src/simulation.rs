@@ -0,0 +1,171 @@
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::borrow::{
+    check_debt_increase, collateral_value, get_collateral_position, get_total_collateral_value,
+    get_total_user_debt, get_user_debt, projected_health_factor, validate_borrow, BorrowError,
+};
+use crate::math::mul_div_down;
+use crate::repay::{check_no_dust, check_repay_open, RepayError};
+use crate::rwa::{check_rwa_liquidation, get_rwa_collateral, RwaError};
+
+/// Would-be outcome of a borrow
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BorrowSimulation {
+    /// Pool debt after the borrow, including accrued interest
+    pub debt: i128,
+    /// Fungible collateral after the deposit
+    pub collateral: i128,
+    pub health_factor: i128,
+}
+
+/// Would-be outcome of a repayment
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RepaySimulation {
+    /// Payment applied, after capping at the outstanding debt
+    pub amount: i128,
+    pub interest_paid: i128,
+    pub principal_paid: i128,
+    /// Interest forgiven by the early-repayment rebate
+    pub rebate: i128,
+    /// Pool debt left, including accrued interest
+    pub remaining: i128,
+    pub health_factor: i128,
+}
+
+/// Would-be outcome of an RWA liquidation
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LiquidationSimulation {
+    pub repaid: i128,
+    /// Collateral seized, including the liquidation bonus
+    pub seized: i128,
+    pub health_factor: i128,
+}
+
+/// Preview `borrow` without writing state
+///
+/// Runs the same checks as `borrow`, failing with the error it would return,
+/// and reports the resulting position. No authorization is required.
+pub fn simulate_borrow(
+    env: &Env,
+    user: Address,
+    asset: Address,
+    amount: i128,
+    collateral_asset: Address,
+    collateral_amount: i128,
+) -> Result<BorrowSimulation, BorrowError> {
+    let collateral = validate_borrow(
+        env,
+        &user,
+        &asset,
+        amount,
+        &collateral_asset,
+        collateral_amount,
+    )?;
+    check_debt_increase(env, &user, &asset, amount)?;
+
+    let position = get_user_debt(env, &user);
+    let debt = position
+        .borrowed_amount
+        .saturating_add(position.interest_accrued)
+        .saturating_add(amount);
+    let health_factor = projected_health_factor(
+        env,
+        &user,
+        &collateral.asset,
+        collateral_value(env, &user, &collateral),
+        get_total_user_debt(env, &user).saturating_add(amount),
+    );
+    Ok(BorrowSimulation {
+        debt,
+        collateral: collateral.amount,
+        health_factor,
+    })
+}
+
+/// Preview `repay` without writing state
+///
+/// Runs the same checks as `repay`, including the early-repayment rebate a
+/// closing payment would earn. No authorization is required.
+pub fn simulate_repay(
+    env: &Env,
+    user: Address,
+    amount: i128,
+) -> Result<RepaySimulation, RepayError> {
+    check_repay_open(env, &user)?;
+    let rebate = crate::early_repay::closing_rebate(env, &user, amount);
+    let mut position = get_user_debt(env, &user);
+    position.interest_accrued -= rebate;
+    check_no_dust(env, &position, amount)?;
+    if amount <= 0 {
+        return Err(RepayError::InvalidAmount);
+    }
+    let owed = position
+        .borrowed_amount
+        .checked_add(position.interest_accrued)
+        .ok_or(RepayError::Overflow)?;
+    if owed == 0 {
+        return Err(RepayError::NoDebt);
+    }
+
+    let paid = amount.min(owed);
+    let interest_paid = paid.min(position.interest_accrued);
+    let health_factor = projected_health_factor(
+        env,
+        &user,
+        &get_collateral_position(env, &user).asset,
+        get_total_collateral_value(env, &user),
+        get_total_user_debt(env, &user) - rebate - paid,
+    );
+    Ok(RepaySimulation {
+        amount: paid,
+        interest_paid,
+        principal_paid: paid - interest_paid,
+        rebate,
+        remaining: owed - paid,
+        health_factor,
+    })
+}
+
+/// Preview `liquidate_rwa` without writing state
+///
+/// Runs the same checks as `liquidate_rwa`, including the liquidator's
+/// eligibility, and reports the position it would leave. No authorization is
+/// required.
+pub fn simulate_liquidate(
+    env: &Env,
+    liquidator: Address,
+    user: Address,
+    asset: Address,
+    repay_amount: i128,
+) -> Result<LiquidationSimulation, RwaError> {
+    let (config, repay, seized) =
+        check_rwa_liquidation(env, &liquidator, &user, &asset, repay_amount)?;
+    let debt = get_user_debt(env, &user);
+    let owed = debt.borrowed_amount.saturating_add(debt.interest_accrued);
+    if repay <= 0 || owed == 0 {
+        return Err(RwaError::Overflow);
+    }
+    let repaid = repay.min(owed);
+
+    let mut value = get_total_collateral_value(env, &user);
+    if crate::asset_mode::counts_as_collateral(env, &asset) {
+        let balance = get_rwa_collateral(env, &user, &asset);
+        value -= mul_div_down(balance, config.ltv_bps, 10000)
+            - mul_div_down(balance - seized, config.ltv_bps, 10000);
+    }
+    let health_factor = projected_health_factor(
+        env,
+        &user,
+        &get_collateral_position(env, &user).asset,
+        value,
+        get_total_user_debt(env, &user) - repaid,
+    );
+    Ok(LiquidationSimulation {
+        repaid,
+        seized,
+        health_factor,
+    })
+}
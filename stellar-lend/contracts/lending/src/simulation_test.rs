@@ -0,0 +1,133 @@
+use super::*;
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, Address, Env};
+
+/// Compliance module accepting every holder and transfer
+#[contract]
+pub struct MockCompliance;
+
+#[contractimpl]
+impl MockCompliance {
+    pub fn is_eligible(_env: Env, _account: Address) -> bool {
+        true
+    }
+
+    pub fn can_transfer(_env: Env, _from: Address, _to: Address, _amount: i128) -> bool {
+        true
+    }
+}
+
+fn setup(env: &Env) -> (LendingContractClient<'_>, Address) {
+    env.mock_all_auths();
+    let client = LendingContractClient::new(env, &env.register(LendingContract, ()));
+    let admin = Address::generate(env);
+    client.initialize(&admin, &InitConfig::new(env, 1_000_000_000, 1000));
+    (client, admin)
+}
+
+#[test]
+fn test_simulated_borrow_matches_borrow() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    let user = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let collateral = Address::generate(&env);
+
+    assert_eq!(
+        client.try_simulate_borrow(&user, &asset, &10_000, &collateral, &14_999),
+        Err(Ok(BorrowError::InsufficientCollateral))
+    );
+    let simulation = client.simulate_borrow(&user, &asset, &10_000, &collateral, &20_000);
+    assert_eq!(client.get_user_debt(&user).borrowed_amount, 0);
+    assert_eq!(client.get_user_collateral(&user).amount, 0);
+
+    client.borrow(&user, &asset, &10_000, &collateral, &20_000);
+    assert_eq!(
+        simulation,
+        BorrowSimulation {
+            debt: 10_000,
+            collateral: 20_000,
+            health_factor: client.get_health_factor(&user),
+        }
+    );
+}
+
+#[test]
+fn test_simulated_repay_matches_repay() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    let user = Address::generate(&env);
+    client.borrow(
+        &user,
+        &Address::generate(&env),
+        &10_000,
+        &Address::generate(&env),
+        &20_000,
+    );
+
+    assert_eq!(
+        client.try_simulate_repay(&user, &9_500),
+        Err(Ok(RepayError::DustRemaining))
+    );
+    let simulation = client.simulate_repay(&user, &4_000);
+    assert_eq!(client.get_user_debt(&user).borrowed_amount, 10_000);
+
+    let (remaining, interest_paid, principal_paid) = client.repay(&user, &4_000);
+    assert_eq!(
+        simulation,
+        RepaySimulation {
+            amount: 4_000,
+            interest_paid,
+            principal_paid,
+            rebate: 0,
+            remaining,
+            health_factor: client.get_health_factor(&user),
+        }
+    );
+}
+
+#[test]
+fn test_simulated_liquidation_matches_liquidation() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let compliance = env.register(MockCompliance, ());
+    let asset = Address::generate(&env);
+    client.register_rwa_asset(
+        &admin,
+        &asset,
+        &RwaAssetConfig {
+            compliance: compliance.clone(),
+            ltv_bps: 8000,
+        },
+    );
+    let user = Address::generate(&env);
+    client.deposit_rwa_collateral(&user, &asset, &100_000);
+    client.rwa_borrow(&user, &Address::generate(&env), &50_000);
+
+    let liquidator = Address::generate(&env);
+    assert_eq!(
+        client.try_simulate_liquidate(&liquidator, &user, &asset, &10_000),
+        Err(Ok(RwaError::PositionHealthy))
+    );
+
+    // Cutting the LTV makes the position liquidatable
+    client.register_rwa_asset(
+        &admin,
+        &asset,
+        &RwaAssetConfig {
+            compliance,
+            ltv_bps: 5000,
+        },
+    );
+    let simulation = client.simulate_liquidate(&liquidator, &user, &asset, &10_000);
+    assert_eq!(client.get_rwa_collateral(&user, &asset), 100_000);
+
+    let outcome = client.liquidate_rwa(&liquidator, &user, &asset, &10_000);
+    assert_eq!(
+        simulation,
+        LiquidationSimulation {
+            repaid: outcome.repaid,
+            seized: outcome.seized,
+            health_factor: client.get_health_factor(&user),
+        }
+    );
+}
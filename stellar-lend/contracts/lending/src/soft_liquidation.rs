@@ -0,0 +1,294 @@
+use soroban_sdk::{contractclient, contracterror, contracttype, Address, Env, Symbol};
+
+use crate::admin::require_admin;
+use crate::borrow::{get_collateral_position, get_user_debt, save_collateral_position};
+use crate::history::PositionAction;
+use crate::math::{mul_div_down, mul_div_up};
+use crate::oracle::PRICE_SCALE;
+use crate::repay::apply_repayment;
+
+/// Interface exposed by the DEX adapter used to sell collateral
+#[contractclient(name = "DexAdapterClient")]
+pub trait DexAdapter {
+    /// Sell `amount_in` of `token_in` for at least `min_amount_out` of `token_out`
+    ///
+    /// Returns the amount of `token_out` received.
+    fn swap(
+        env: Env,
+        token_in: Address,
+        token_out: Address,
+        amount_in: i128,
+        min_amount_out: i128,
+    ) -> i128;
+}
+
+/// Errors that can occur during soft liquidation
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum SoftLiquidationError {
+    Unauthorized = 1,
+    InvalidConfig = 2,
+    AdapterNotSet = 3,
+    NotEnabled = 4,
+    NoDebt = 5,
+    /// The price has not fallen far enough into the band to convert more
+    NothingToConvert = 6,
+    PriceStale = 7,
+    SlippageExceeded = 8,
+    ProtocolPaused = 9,
+}
+
+/// Storage keys for soft liquidation data
+#[contracttype]
+#[derive(Clone)]
+pub enum SoftLiquidationDataKey {
+    DexAdapter,
+    SoftLiquidationBand(Address),
+}
+
+/// DEX adapter and the slippage tolerated against the oracle
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DexAdapterConfig {
+    pub adapter: Address,
+    /// Maximum shortfall of the swap output against the oracle value, in basis points
+    pub max_slippage_bps: i128,
+}
+
+/// A user's soft liquidation band
+///
+/// Prices are the collateral price in units of the debt asset, scaled by
+/// `PRICE_SCALE`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SoftLiquidationBand {
+    /// Price at which conversion starts
+    pub upper_price: i128,
+    /// Price at which all collateral is converted
+    pub lower_price: i128,
+    /// Collateral converted since the band was set
+    pub converted: i128,
+    /// Last ledger keepers may convert collateral in
+    pub expiration_ledger: u32,
+}
+
+/// Soft liquidation event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct SoftLiquidationEvent {
+    pub user: Address,
+    pub keeper: Address,
+    pub debt_asset: Address,
+    pub collateral_asset: Address,
+    pub collateral_sold: i128,
+    pub debt_repaid: i128,
+    pub price: i128,
+    pub timestamp: u64,
+}
+
+const MAX_SWAP_SLIPPAGE_BPS: i128 = 500; // 5%
+
+/// Set the DEX adapter used for soft liquidations (admin only)
+pub fn set_dex_adapter(
+    env: &Env,
+    caller: Address,
+    config: DexAdapterConfig,
+) -> Result<(), SoftLiquidationError> {
+    require_admin(env, &caller).map_err(|_| SoftLiquidationError::Unauthorized)?;
+    if !(0..=MAX_SWAP_SLIPPAGE_BPS).contains(&config.max_slippage_bps) {
+        return Err(SoftLiquidationError::InvalidConfig);
+    }
+    env.storage()
+        .persistent()
+        .set(&SoftLiquidationDataKey::DexAdapter, &config);
+    Ok(())
+}
+
+/// Get the DEX adapter used for soft liquidations, if set
+pub fn get_dex_adapter(env: &Env) -> Option<DexAdapterConfig> {
+    env.storage()
+        .persistent()
+        .get(&SoftLiquidationDataKey::DexAdapter)
+}
+
+/// Opt a position into soft liquidation across a price band
+///
+/// While the price sits inside the band, keepers gradually sell collateral
+/// for the debt asset in proportion to how far the price has fallen through
+/// it, so a brief dip costs a slice of the position instead of all of it.
+/// Setting a band resets its conversion progress.
+pub fn enable_soft_liquidation(
+    env: &Env,
+    user: Address,
+    upper_price: i128,
+    lower_price: i128,
+    expiration_ledger: u32,
+) -> Result<SoftLiquidationBand, SoftLiquidationError> {
+    user.require_auth();
+    if lower_price <= 0
+        || upper_price <= lower_price
+        || !crate::approvals::is_valid_expiration(env, expiration_ledger)
+    {
+        return Err(SoftLiquidationError::InvalidConfig);
+    }
+
+    let band = SoftLiquidationBand {
+        upper_price,
+        lower_price,
+        converted: 0,
+        expiration_ledger,
+    };
+    save_band(env, &user, &band);
+    Ok(band)
+}
+
+/// Opt a position out of soft liquidation
+pub fn disable_soft_liquidation(env: &Env, user: Address) {
+    user.require_auth();
+    remove_band(env, &user);
+}
+
+/// Get a user's soft liquidation band, if enabled and not expired
+pub fn get_soft_liquidation_band(env: &Env, user: &Address) -> Option<SoftLiquidationBand> {
+    env.storage()
+        .persistent()
+        .get::<_, SoftLiquidationBand>(&SoftLiquidationDataKey::SoftLiquidationBand(user.clone()))
+        .filter(|band| crate::approvals::is_live(env, band.expiration_ledger))
+}
+
+pub(crate) fn remove_band(env: &Env, user: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&SoftLiquidationDataKey::SoftLiquidationBand(user.clone()));
+}
+
+/// Convert the share of a position's collateral its band calls for (permissionless)
+///
+/// The target share rises linearly from nothing at the band's upper price to
+/// all collateral at its lower price. The sale goes through the DEX adapter,
+/// must return at least the oracle value less the configured slippage, and is
+/// capped at the collateral needed to repay the whole debt.
+///
+/// # Returns
+/// The collateral sold and the debt repaid
+pub fn soft_liquidate(
+    env: &Env,
+    keeper: Address,
+    user: Address,
+) -> Result<(i128, i128), SoftLiquidationError> {
+    keeper.require_auth();
+
+    let mut band = get_soft_liquidation_band(env, &user).ok_or(SoftLiquidationError::NotEnabled)?;
+    let adapter = get_dex_adapter(env).ok_or(SoftLiquidationError::AdapterNotSet)?;
+    if crate::pause::is_operation_paused(env, crate::pause::PAUSE_LIQUIDATE) {
+        return Err(SoftLiquidationError::ProtocolPaused);
+    }
+    let debt = get_user_debt(env, &user);
+    let owed = debt.borrowed_amount.saturating_add(debt.interest_accrued);
+    if owed == 0 {
+        return Err(SoftLiquidationError::NoDebt);
+    }
+    let mut collateral = get_collateral_position(env, &user);
+    let debt_price = effective_price(env, &debt.asset)?;
+    let collateral_price = effective_price(env, &collateral.asset)?;
+
+    let price = mul_div_down(collateral_price, PRICE_SCALE, debt_price);
+    if price >= band.upper_price {
+        return Err(SoftLiquidationError::NothingToConvert);
+    }
+    let share_bps = if price <= band.lower_price {
+        10000
+    } else {
+        mul_div_down(
+            band.upper_price - price,
+            10000,
+            band.upper_price - band.lower_price,
+        )
+    };
+    let target = mul_div_down(
+        collateral.amount.saturating_add(band.converted),
+        share_bps,
+        10000,
+    );
+    let needed = mul_div_up(owed, debt_price, collateral_price);
+    let sold = target
+        .saturating_sub(band.converted)
+        .min(needed)
+        .min(collateral.amount);
+    if sold <= 0 {
+        return Err(SoftLiquidationError::NothingToConvert);
+    }
+
+    let min_out = mul_div_down(
+        mul_div_down(sold, collateral_price, debt_price),
+        10000 - adapter.max_slippage_bps,
+        10000,
+    );
+    let received = DexAdapterClient::new(env, &adapter.adapter).swap(
+        &collateral.asset,
+        &debt.asset,
+        &sold,
+        &min_out,
+    );
+    if received < min_out {
+        return Err(SoftLiquidationError::SlippageExceeded);
+    }
+    let repaid = received.min(owed);
+    if repaid > 0 {
+        apply_repayment(env, user.clone(), repaid).map_err(|_| SoftLiquidationError::NoDebt)?;
+    }
+
+    crate::points::checkpoint(env, &user);
+    collateral.amount -= sold;
+    save_collateral_position(env, &user, &collateral);
+    band.converted = band.converted.saturating_add(sold);
+    save_band(env, &user, &band);
+    crate::history::record_action(
+        env,
+        &user,
+        PositionAction::CollateralSeized,
+        &collateral.asset,
+        sold,
+    );
+    crate::position_hooks::notify_liquidation(env, &user, repaid, sold);
+
+    let event = SoftLiquidationEvent {
+        user,
+        keeper,
+        debt_asset: debt.asset,
+        collateral_asset: collateral.asset,
+        collateral_sold: sold,
+        debt_repaid: repaid,
+        price,
+        timestamp: env.ledger().timestamp(),
+    };
+    crate::events::publish(
+        env,
+        (
+            Symbol::new(env, "soft_liquidation"),
+            event.user.clone(),
+            event.debt_asset.clone(),
+            event.collateral_asset.clone(),
+        ),
+        event,
+    );
+    Ok((sold, repaid))
+}
+
+fn effective_price(env: &Env, asset: &Address) -> Result<i128, SoftLiquidationError> {
+    if crate::oracle::is_liquidation_frozen(env, asset) {
+        return Err(SoftLiquidationError::PriceStale);
+    }
+    crate::oracle::get_effective_price(env, asset)
+        .map(|price| price.price)
+        .filter(|price| *price > 0)
+        .ok_or(SoftLiquidationError::PriceStale)
+}
+
+fn save_band(env: &Env, user: &Address, band: &SoftLiquidationBand) {
+    env.storage().persistent().set(
+        &SoftLiquidationDataKey::SoftLiquidationBand(user.clone()),
+        band,
+    );
+}
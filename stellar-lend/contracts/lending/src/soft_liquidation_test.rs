@@ -0,0 +1,138 @@
+use super::*;
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, Address, Env, Symbol};
+
+/// DEX adapter paying a settable share of the input amount
+#[contract]
+pub struct MockDexAdapter;
+
+#[contractimpl]
+impl MockDexAdapter {
+    pub fn set_rate(env: Env, rate_bps: i128) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "rate"), &rate_bps);
+    }
+
+    pub fn swap(
+        env: Env,
+        _token_in: Address,
+        _token_out: Address,
+        amount_in: i128,
+        _min_amount_out: i128,
+    ) -> i128 {
+        let rate: i128 = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "rate"))
+            .unwrap_or(10000);
+        amount_in * rate / 10000
+    }
+}
+
+struct Setup<'a> {
+    client: LendingContractClient<'a>,
+    admin: Address,
+    dex: MockDexAdapterClient<'a>,
+    user: Address,
+    collateral: Address,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    let client = LendingContractClient::new(env, &env.register(LendingContract, ()));
+    let admin = Address::generate(env);
+    client.initialize(&admin, &InitConfig::new(env, 1_000_000_000, 1000));
+
+    let dex = MockDexAdapterClient::new(env, &env.register(MockDexAdapter, ()));
+    client.set_dex_adapter(
+        &admin,
+        &DexAdapterConfig {
+            adapter: dex.address.clone(),
+            max_slippage_bps: 100,
+        },
+    );
+
+    let debt = Address::generate(env);
+    let collateral = Address::generate(env);
+    client.set_asset_price(&admin, &debt, &10_000_000);
+    client.set_asset_price(&admin, &collateral, &10_000_000);
+    let user = Address::generate(env);
+    client.borrow(&user, &debt, &10_000, &collateral, &20_000);
+    Setup {
+        client,
+        admin,
+        dex,
+        user,
+        collateral,
+    }
+}
+
+#[test]
+fn test_soft_liquidation_converts_gradually() {
+    let env = Env::default();
+    let s = setup(&env);
+    assert_eq!(
+        s.client.try_soft_liquidate(&s.admin, &s.user),
+        Err(Ok(SoftLiquidationError::NotEnabled))
+    );
+    s.client
+        .enable_soft_liquidation(&s.user, &8_000_000, &4_000_000, &1_000);
+
+    // Above the band nothing is sold
+    s.client
+        .set_asset_price(&s.admin, &s.collateral, &9_000_000);
+    assert_eq!(
+        s.client.try_soft_liquidate(&s.admin, &s.user),
+        Err(Ok(SoftLiquidationError::NothingToConvert))
+    );
+
+    // Halfway through the band, half the collateral is converted
+    s.client
+        .set_asset_price(&s.admin, &s.collateral, &6_000_000);
+    s.dex.set_rate(&6000);
+    assert_eq!(s.client.soft_liquidate(&s.admin, &s.user), (10_000, 6_000));
+    assert_eq!(s.client.get_user_collateral(&s.user).amount, 10_000);
+    assert_eq!(s.client.get_user_debt(&s.user).borrowed_amount, 4_000);
+    assert_eq!(
+        s.client
+            .get_soft_liquidation_band(&s.user)
+            .unwrap()
+            .converted,
+        10_000
+    );
+    assert_eq!(
+        s.client.try_soft_liquidate(&s.admin, &s.user),
+        Err(Ok(SoftLiquidationError::NothingToConvert))
+    );
+
+    // Further down, conversion tracks the band
+    s.client
+        .set_asset_price(&s.admin, &s.collateral, &5_000_000);
+    s.dex.set_rate(&5000);
+    assert_eq!(s.client.soft_liquidate(&s.admin, &s.user), (5_000, 2_500));
+    assert_eq!(s.client.get_user_debt(&s.user).borrowed_amount, 1_500);
+}
+
+#[test]
+fn test_soft_liquidation_rejects_slippage() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client
+        .enable_soft_liquidation(&s.user, &8_000_000, &4_000_000, &1_000);
+    s.client
+        .set_asset_price(&s.admin, &s.collateral, &6_000_000);
+
+    // The adapter pays below the oracle value less 1% slippage
+    s.dex.set_rate(&5000);
+    assert_eq!(
+        s.client.try_soft_liquidate(&s.admin, &s.user),
+        Err(Ok(SoftLiquidationError::SlippageExceeded))
+    );
+    assert_eq!(s.client.get_user_collateral(&s.user).amount, 20_000);
+
+    assert_eq!(
+        s.client
+            .try_enable_soft_liquidation(&s.user, &4_000_000, &8_000_000, &1_000),
+        Err(Ok(SoftLiquidationError::InvalidConfig))
+    );
+}
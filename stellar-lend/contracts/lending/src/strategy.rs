@@ -0,0 +1,659 @@
+use soroban_sdk::{contractclient, contracterror, contracttype, Address, Env, Symbol};
+
+use crate::admin::{get_guardian, require_admin, require_treasurer};
+use crate::math::mul_div_down;
+
+/// Interface exposed by external yield strategies
+#[contractclient(name = "YieldStrategyClient")]
+pub trait YieldStrategy {
+    /// Accept `amount` of `asset` from the protocol
+    fn deposit(env: Env, asset: Address, amount: i128);
+    /// Return `amount` of `asset` to the protocol
+    ///
+    /// Returns the amount actually returned.
+    fn withdraw(env: Env, asset: Address, amount: i128) -> i128;
+    /// Current value of the protocol's holdings of `asset`
+    fn balance(env: Env, asset: Address) -> i128;
+}
+
+/// Errors that can occur when managing yield strategies
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum StrategyError {
+    Unauthorized = 1,
+    InvalidConfig = 2,
+    InvalidAmount = 3,
+    NotConfigured = 4,
+    /// The strategy still holds funds and cannot be replaced
+    StrategyInUse = 5,
+    /// The deployment exceeds the asset's share cap or its spare cash
+    DeploymentCapExceeded = 6,
+    InsufficientDeployed = 7,
+    /// The strategy returned less than requested
+    StrategyShortfall = 8,
+    /// Deployments were stopped by an emergency divestment
+    StrategyBlocked = 9,
+    /// The strategy is not on the allowlist
+    StrategyNotAllowed = 10,
+    TimelockActive = 11,
+    NoPendingApproval = 12,
+    /// The asset has no strategy loss to write down
+    NoStrategyLoss = 13,
+}
+
+/// Storage keys for strategy data
+#[contracttype]
+#[derive(Clone)]
+pub enum StrategyDataKey {
+    StrategyConfig(Address),
+    /// Principal of an asset deployed to its strategy
+    StrategyDeployed(Address),
+    /// Present once an emergency divestment has stopped deployments
+    DeploymentsBlocked(Address),
+    /// Principal a strategy failed to return, not yet written down
+    StrategyLoss(Address),
+    /// Proposed allowlist entry of a strategy awaiting its timelock
+    PendingStrategyApproval(Address),
+    AllowedStrategy(Address),
+    /// Principal deployed to a strategy across all assets
+    StrategyDeposits(Address),
+    /// Harvested gains of an asset held back to absorb strategy losses
+    StrategyReserve(Address),
+}
+
+/// External strategy of an asset and how much idle cash it may hold
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct StrategyConfig {
+    pub strategy: Address,
+    /// Most of the market's un-borrowed liquidity that may be deployed, in basis points
+    pub max_deploy_bps: i128,
+    /// Share of harvested gains credited to suppliers, in basis points; the
+    /// rest goes to the asset's strategy reserve
+    pub supplier_share_bps: i128,
+}
+
+/// Strategy harvest event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct HarvestEvent {
+    pub asset: Address,
+    pub strategy: Address,
+    /// Gain realized over the deployed principal
+    pub profit: i128,
+    /// Shortfall of the strategy's balance against the deployed principal
+    pub loss: i128,
+    pub to_suppliers: i128,
+    pub to_reserve: i128,
+    pub timestamp: u64,
+}
+
+/// Strategy loss write-down event data
+///
+/// Each tranche is drawn in field order before the next is touched.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LossWriteDown {
+    pub asset: Address,
+    /// Loss outstanding before the write-down
+    pub loss: i128,
+    pub from_insurance: i128,
+    pub from_reserve: i128,
+    /// Loss borne by suppliers as a pro-rata cut to their balances
+    pub haircut: i128,
+    /// Loss left outstanding because the asset has no supply
+    pub remaining: i128,
+    pub timestamp: u64,
+}
+
+/// Allowlist entry of a strategy
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AllowedStrategy {
+    /// Most principal the strategy may hold across all assets
+    pub deposit_cap: i128,
+    /// Earliest time the entry can be approved, or when it was approved
+    pub effective_at: u64,
+}
+
+/// Strategy allowlist event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct StrategyAllowlistEvent {
+    pub strategy: Address,
+    pub deposit_cap: i128,
+    pub actor: Address,
+    pub timestamp: u64,
+}
+
+/// Strategy deposit or withdrawal event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct StrategyEvent {
+    pub asset: Address,
+    pub strategy: Address,
+    pub amount: i128,
+    /// Principal deployed after the event
+    pub deployed: i128,
+    pub timestamp: u64,
+}
+
+/// Emergency divestment event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct StrategyDivestEvent {
+    pub asset: Address,
+    pub strategy: Address,
+    /// Principal recorded as deployed
+    pub principal: i128,
+    pub returned: i128,
+    pub loss: i128,
+    pub timestamp: u64,
+}
+
+const STRATEGY_APPROVAL_TIMELOCK: u64 = 2 * 86400; // 2 days
+
+/// Propose adding a strategy to the allowlist, or changing its deposit cap
+/// (admin only)
+///
+/// The entry takes effect once `approve_strategy` is called after the
+/// timelock, which gives monitoring time to react to a malicious strategy.
+///
+/// # Returns
+/// The earliest time the entry can be approved
+pub fn propose_strategy(
+    env: &Env,
+    caller: Address,
+    strategy: Address,
+    deposit_cap: i128,
+) -> Result<u64, StrategyError> {
+    require_admin(env, &caller).map_err(|_| StrategyError::Unauthorized)?;
+    if deposit_cap <= 0 {
+        return Err(StrategyError::InvalidConfig);
+    }
+    let executable_at = env
+        .ledger()
+        .timestamp()
+        .saturating_add(STRATEGY_APPROVAL_TIMELOCK);
+    env.storage().persistent().set(
+        &StrategyDataKey::PendingStrategyApproval(strategy.clone()),
+        &AllowedStrategy {
+            deposit_cap,
+            effective_at: executable_at,
+        },
+    );
+    emit_allowlist_event(env, "strategy_proposed", strategy, deposit_cap, caller);
+    Ok(executable_at)
+}
+
+/// Add a proposed strategy to the allowlist once its timelock has passed
+/// (admin only)
+pub fn approve_strategy(
+    env: &Env,
+    caller: Address,
+    strategy: Address,
+) -> Result<(), StrategyError> {
+    require_admin(env, &caller).map_err(|_| StrategyError::Unauthorized)?;
+    let pending_key = StrategyDataKey::PendingStrategyApproval(strategy.clone());
+    let mut entry: AllowedStrategy = env
+        .storage()
+        .persistent()
+        .get(&pending_key)
+        .ok_or(StrategyError::NoPendingApproval)?;
+    let now = env.ledger().timestamp();
+    if now < entry.effective_at {
+        return Err(StrategyError::TimelockActive);
+    }
+
+    entry.effective_at = now;
+    env.storage().persistent().remove(&pending_key);
+    env.storage()
+        .persistent()
+        .set(&StrategyDataKey::AllowedStrategy(strategy.clone()), &entry);
+    emit_allowlist_event(env, "strategy_added", strategy, entry.deposit_cap, caller);
+    Ok(())
+}
+
+/// Remove a strategy from the allowlist, or drop its pending proposal
+/// (admin only)
+///
+/// Takes effect immediately. Funds already deployed to the strategy can
+/// still be recalled, but nothing new can be deployed to it.
+pub fn remove_strategy(env: &Env, caller: Address, strategy: Address) -> Result<(), StrategyError> {
+    require_admin(env, &caller).map_err(|_| StrategyError::Unauthorized)?;
+    let allowed_key = StrategyDataKey::AllowedStrategy(strategy.clone());
+    let pending_key = StrategyDataKey::PendingStrategyApproval(strategy.clone());
+    if !env.storage().persistent().has(&allowed_key)
+        && !env.storage().persistent().has(&pending_key)
+    {
+        return Err(StrategyError::StrategyNotAllowed);
+    }
+    env.storage().persistent().remove(&allowed_key);
+    env.storage().persistent().remove(&pending_key);
+    emit_allowlist_event(env, "strategy_removed", strategy, 0, caller);
+    Ok(())
+}
+
+/// Get the allowlist entry of a strategy, if approved
+pub fn get_allowed_strategy(env: &Env, strategy: &Address) -> Option<AllowedStrategy> {
+    env.storage()
+        .persistent()
+        .get(&StrategyDataKey::AllowedStrategy(strategy.clone()))
+}
+
+/// Get the proposed allowlist entry of a strategy awaiting approval, if any
+pub fn get_pending_strategy(env: &Env, strategy: &Address) -> Option<AllowedStrategy> {
+    env.storage()
+        .persistent()
+        .get(&StrategyDataKey::PendingStrategyApproval(strategy.clone()))
+}
+
+/// Principal deployed to a strategy across all assets
+pub fn get_strategy_deposits(env: &Env, strategy: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&StrategyDataKey::StrategyDeposits(strategy.clone()))
+        .unwrap_or(0)
+}
+
+/// Register the yield strategy of an asset (treasurer or admin)
+///
+/// The strategy must be on the allowlist and can only be replaced once
+/// everything deployed to it has been recalled; lowering `max_deploy_bps`
+/// takes effect for new deployments.
+pub fn set_strategy(
+    env: &Env,
+    caller: Address,
+    asset: Address,
+    config: StrategyConfig,
+) -> Result<(), StrategyError> {
+    require_treasurer(env, &caller).map_err(|_| StrategyError::Unauthorized)?;
+    if !(0..=10000).contains(&config.max_deploy_bps)
+        || !(0..=10000).contains(&config.supplier_share_bps)
+        || config.strategy == asset
+    {
+        return Err(StrategyError::InvalidConfig);
+    }
+    if let Some(current) = get_strategy_config(env, &asset) {
+        if current.strategy != config.strategy && get_strategy_deployed(env, &asset) > 0 {
+            return Err(StrategyError::StrategyInUse);
+        }
+    }
+    if get_allowed_strategy(env, &config.strategy).is_none() {
+        return Err(StrategyError::StrategyNotAllowed);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&StrategyDataKey::StrategyConfig(asset.clone()), &config);
+    crate::events::publish(env, (Symbol::new(env, "strategy_set"), asset), config);
+    Ok(())
+}
+
+/// Get the yield strategy of an asset, if registered
+pub fn get_strategy_config(env: &Env, asset: &Address) -> Option<StrategyConfig> {
+    env.storage()
+        .persistent()
+        .get(&StrategyDataKey::StrategyConfig(asset.clone()))
+}
+
+/// Principal of an asset currently deployed to its strategy
+pub fn get_strategy_deployed(env: &Env, asset: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&StrategyDataKey::StrategyDeployed(asset.clone()))
+        .unwrap_or(0)
+}
+
+/// Deploy idle cash of an asset to its strategy (treasurer or admin)
+///
+/// Total deployments are capped at `max_deploy_bps` of the market's
+/// un-borrowed liquidity, and only cash that is withdrawable and outside the
+/// liquidity buffer can be deployed. Deployed cash is not available for
+/// withdrawals until recalled.
+///
+/// # Returns
+/// The principal deployed after the deposit
+pub fn deploy_to_strategy(
+    env: &Env,
+    caller: Address,
+    asset: Address,
+    amount: i128,
+) -> Result<i128, StrategyError> {
+    require_treasurer(env, &caller).map_err(|_| StrategyError::Unauthorized)?;
+    if amount <= 0 {
+        return Err(StrategyError::InvalidAmount);
+    }
+    let config = get_strategy_config(env, &asset).ok_or(StrategyError::NotConfigured)?;
+    if is_strategy_blocked(env, &asset) {
+        return Err(StrategyError::StrategyBlocked);
+    }
+    let allowed =
+        get_allowed_strategy(env, &config.strategy).ok_or(StrategyError::StrategyNotAllowed)?;
+
+    let deployed = get_strategy_deployed(env, &asset);
+    let liquidity = crate::market::get_liquidity(env, &asset);
+    let un_borrowed = liquidity.available_cash.saturating_add(deployed);
+    let cap = mul_div_down(un_borrowed, config.max_deploy_bps, 10000);
+    let spare = liquidity
+        .withdrawable
+        .saturating_sub(liquidity.buffer_locked);
+    let strategy_deposits = get_strategy_deposits(env, &config.strategy).saturating_add(amount);
+    if amount > spare
+        || deployed.saturating_add(amount) > cap
+        || strategy_deposits > allowed.deposit_cap
+    {
+        return Err(StrategyError::DeploymentCapExceeded);
+    }
+
+    YieldStrategyClient::new(env, &config.strategy).deposit(&asset, &amount);
+    save_strategy_deposits(env, &config.strategy, strategy_deposits);
+    let deployed = deployed + amount;
+    save_deployed(env, &asset, deployed);
+    emit_strategy_event(
+        env,
+        "strategy_deposit",
+        asset,
+        config.strategy,
+        amount,
+        deployed,
+    );
+    Ok(deployed)
+}
+
+/// Recall deployed principal of an asset from its strategy (treasurer or admin)
+///
+/// The recalled cash becomes available to withdrawals and the withdrawal
+/// queue again.
+///
+/// # Returns
+/// The principal still deployed
+pub fn recall_from_strategy(
+    env: &Env,
+    caller: Address,
+    asset: Address,
+    amount: i128,
+) -> Result<i128, StrategyError> {
+    require_treasurer(env, &caller).map_err(|_| StrategyError::Unauthorized)?;
+    if amount <= 0 {
+        return Err(StrategyError::InvalidAmount);
+    }
+    let config = get_strategy_config(env, &asset).ok_or(StrategyError::NotConfigured)?;
+    let deployed = get_strategy_deployed(env, &asset);
+    if amount > deployed {
+        return Err(StrategyError::InsufficientDeployed);
+    }
+
+    let returned = YieldStrategyClient::new(env, &config.strategy).withdraw(&asset, &amount);
+    if returned < amount {
+        return Err(StrategyError::StrategyShortfall);
+    }
+    let deployed = deployed - amount;
+    save_deployed(env, &asset, deployed);
+    save_strategy_deposits(
+        env,
+        &config.strategy,
+        get_strategy_deposits(env, &config.strategy) - amount,
+    );
+    emit_strategy_event(
+        env,
+        "strategy_withdraw",
+        asset.clone(),
+        config.strategy,
+        amount,
+        deployed,
+    );
+    crate::withdrawal_queue::process_withdrawal_queue(env, &asset);
+    Ok(deployed)
+}
+
+/// Realize an asset's strategy gains and split them (permissionless)
+///
+/// Withdraws whatever the strategy holds above the deployed principal. The
+/// configured share is credited to suppliers, growing every supplier
+/// balance pro rata, and the rest to the asset's strategy reserve. A balance below the
+/// principal is reported as a loss and left deployed.
+///
+/// # Returns
+/// The realized profit
+pub fn harvest_strategy(env: &Env, keeper: Address, asset: Address) -> Result<i128, StrategyError> {
+    keeper.require_auth();
+    let config = get_strategy_config(env, &asset).ok_or(StrategyError::NotConfigured)?;
+    let deployed = get_strategy_deployed(env, &asset);
+    let client = YieldStrategyClient::new(env, &config.strategy);
+
+    let balance = client.balance(&asset);
+    let loss = deployed.saturating_sub(balance).max(0);
+    let gain = balance.saturating_sub(deployed);
+    let profit = if gain > 0 {
+        client.withdraw(&asset, &gain).clamp(0, gain)
+    } else {
+        0
+    };
+
+    let mut to_suppliers = mul_div_down(profit, config.supplier_share_bps, 10000);
+    if !crate::p2p::credit_suppliers(env, &asset, to_suppliers) {
+        to_suppliers = 0;
+    }
+    let to_reserve = profit - to_suppliers;
+    save_strategy_reserve(
+        env,
+        &asset,
+        get_strategy_reserve(env, &asset).saturating_add(to_reserve),
+    );
+
+    let event = HarvestEvent {
+        asset: asset.clone(),
+        strategy: config.strategy,
+        profit,
+        loss,
+        to_suppliers,
+        to_reserve,
+        timestamp: env.ledger().timestamp(),
+    };
+    crate::events::publish(
+        env,
+        (Symbol::new(env, "strategy_harvested"), event.asset.clone()),
+        event,
+    );
+    if to_suppliers > 0 {
+        crate::withdrawal_queue::process_withdrawal_queue(env, &asset);
+    }
+    Ok(profit)
+}
+
+/// Recall everything deployed to an asset's strategy and stop further
+/// deployments (guardian only)
+///
+/// Whatever the strategy fails to return is recorded as the asset's
+/// strategy loss, which keeps the missing cash out of withdrawable
+/// liquidity; a strategy whose withdrawal fails outright returns nothing.
+/// Deployments stay blocked until the admin resumes them.
+///
+/// # Returns
+/// The amount the strategy returned
+pub fn emergency_divest(env: &Env, asset: Address) -> Result<i128, StrategyError> {
+    let guardian = get_guardian(env).ok_or(StrategyError::Unauthorized)?;
+    guardian.require_auth();
+    let config = get_strategy_config(env, &asset).ok_or(StrategyError::NotConfigured)?;
+
+    let principal = get_strategy_deployed(env, &asset);
+    let returned = if principal > 0 {
+        YieldStrategyClient::new(env, &config.strategy)
+            .try_withdraw(&asset, &principal)
+            .map(|result| result.unwrap_or(0))
+            .unwrap_or(0)
+            .clamp(0, principal)
+    } else {
+        0
+    };
+    let loss = principal - returned;
+    save_deployed(env, &asset, 0);
+    save_strategy_deposits(
+        env,
+        &config.strategy,
+        get_strategy_deposits(env, &config.strategy) - principal,
+    );
+    if loss > 0 {
+        env.storage().persistent().set(
+            &StrategyDataKey::StrategyLoss(asset.clone()),
+            &get_strategy_loss(env, &asset).saturating_add(loss),
+        );
+    }
+    env.storage()
+        .persistent()
+        .set(&StrategyDataKey::DeploymentsBlocked(asset.clone()), &true);
+
+    let event = StrategyDivestEvent {
+        asset: asset.clone(),
+        strategy: config.strategy,
+        principal,
+        returned,
+        loss,
+        timestamp: env.ledger().timestamp(),
+    };
+    crate::events::publish(
+        env,
+        (Symbol::new(env, "strategy_divested"), event.asset.clone()),
+        event,
+    );
+    crate::withdrawal_queue::process_withdrawal_queue(env, &asset);
+    Ok(returned)
+}
+
+/// Write an asset's strategy loss down (permissionless)
+///
+/// The loss is covered by a fixed waterfall: the asset's insurance fund
+/// first, then its strategy reserve, then a pro-rata haircut to supplier
+/// balances. Covered cash returns to withdrawable liquidity.
+pub fn write_down_strategy_loss(
+    env: &Env,
+    keeper: Address,
+    asset: Address,
+) -> Result<LossWriteDown, StrategyError> {
+    keeper.require_auth();
+    let loss = get_strategy_loss(env, &asset);
+    if loss <= 0 {
+        return Err(StrategyError::NoStrategyLoss);
+    }
+
+    let from_insurance = crate::insurance::debit_insurance_fund(env, &asset, loss);
+    let reserve = get_strategy_reserve(env, &asset);
+    let from_reserve = (loss - from_insurance).min(reserve);
+    save_strategy_reserve(env, &asset, reserve - from_reserve);
+    let haircut = crate::p2p::haircut_suppliers(env, &asset, loss - from_insurance - from_reserve);
+    let remaining = loss - from_insurance - from_reserve - haircut;
+
+    let key = StrategyDataKey::StrategyLoss(asset.clone());
+    if remaining > 0 {
+        env.storage().persistent().set(&key, &remaining);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+
+    let write_down = LossWriteDown {
+        asset: asset.clone(),
+        loss,
+        from_insurance,
+        from_reserve,
+        haircut,
+        remaining,
+        timestamp: env.ledger().timestamp(),
+    };
+    crate::events::publish(
+        env,
+        (
+            Symbol::new(env, "strategy_loss_written_down"),
+            write_down.asset.clone(),
+        ),
+        write_down.clone(),
+    );
+    crate::withdrawal_queue::process_withdrawal_queue(env, &asset);
+    Ok(write_down)
+}
+
+/// Allow deployments to an asset's strategy again after a divestment (admin only)
+pub fn resume_strategy(env: &Env, caller: Address, asset: Address) -> Result<(), StrategyError> {
+    require_admin(env, &caller).map_err(|_| StrategyError::Unauthorized)?;
+    env.storage()
+        .persistent()
+        .remove(&StrategyDataKey::DeploymentsBlocked(asset));
+    Ok(())
+}
+
+/// Whether deployments to an asset's strategy are blocked by a divestment
+pub fn is_strategy_blocked(env: &Env, asset: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&StrategyDataKey::DeploymentsBlocked(asset.clone()))
+}
+
+/// Principal of an asset lost in its strategy and not yet written down
+pub fn get_strategy_loss(env: &Env, asset: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&StrategyDataKey::StrategyLoss(asset.clone()))
+        .unwrap_or(0)
+}
+
+/// Harvested gains of an asset held back to absorb strategy losses
+pub fn get_strategy_reserve(env: &Env, asset: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&StrategyDataKey::StrategyReserve(asset.clone()))
+        .unwrap_or(0)
+}
+
+fn save_strategy_reserve(env: &Env, asset: &Address, reserve: i128) {
+    env.storage()
+        .persistent()
+        .set(&StrategyDataKey::StrategyReserve(asset.clone()), &reserve);
+}
+
+fn save_deployed(env: &Env, asset: &Address, deployed: i128) {
+    env.storage()
+        .persistent()
+        .set(&StrategyDataKey::StrategyDeployed(asset.clone()), &deployed);
+}
+
+fn save_strategy_deposits(env: &Env, strategy: &Address, deposits: i128) {
+    env.storage().persistent().set(
+        &StrategyDataKey::StrategyDeposits(strategy.clone()),
+        &deposits.max(0),
+    );
+}
+
+fn emit_allowlist_event(
+    env: &Env,
+    name: &str,
+    strategy: Address,
+    deposit_cap: i128,
+    actor: Address,
+) {
+    let event = StrategyAllowlistEvent {
+        strategy,
+        deposit_cap,
+        actor,
+        timestamp: env.ledger().timestamp(),
+    };
+    crate::events::publish(env, (Symbol::new(env, name),), event);
+}
+
+fn emit_strategy_event(
+    env: &Env,
+    name: &str,
+    asset: Address,
+    strategy: Address,
+    amount: i128,
+    deployed: i128,
+) {
+    let event = StrategyEvent {
+        asset,
+        strategy,
+        amount,
+        deployed,
+        timestamp: env.ledger().timestamp(),
+    };
+    crate::events::publish(env, (Symbol::new(env, name), event.asset.clone()), event);
+}
@@ -0,0 +1,311 @@
+use super::*;
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Ledger},
+    Address, Env, Symbol,
+};
+
+const DAY: u64 = 86400;
+
+/// Strategy holding deposits per asset, with a settable balance
+#[contract]
+pub struct MockStrategy;
+
+#[contractimpl]
+impl MockStrategy {
+    pub fn deposit(env: Env, asset: Address, amount: i128) {
+        let balance = Self::balance(env.clone(), asset.clone());
+        env.storage().instance().set(&asset, &(balance + amount));
+    }
+
+    pub fn withdraw(env: Env, asset: Address, amount: i128) -> i128 {
+        if env.storage().instance().has(&Symbol::new(&env, "failing")) {
+            panic!("strategy withdrawals halted");
+        }
+        let balance = Self::balance(env.clone(), asset.clone());
+        let returned = amount.min(balance);
+        env.storage().instance().set(&asset, &(balance - returned));
+        returned
+    }
+
+    pub fn balance(env: Env, asset: Address) -> i128 {
+        env.storage().instance().get(&asset).unwrap_or(0)
+    }
+
+    pub fn set_balance(env: Env, asset: Address, balance: i128) {
+        env.storage().instance().set(&asset, &balance);
+    }
+
+    pub fn halt_withdrawals(env: Env) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "failing"), &true);
+    }
+}
+
+fn setup(
+    env: &Env,
+) -> (
+    LendingContractClient<'_>,
+    MockStrategyClient<'_>,
+    Address,
+    Address,
+    Address,
+) {
+    env.mock_all_auths();
+    let client = LendingContractClient::new(env, &env.register(LendingContract, ()));
+    let admin = Address::generate(env);
+    client.initialize(&admin, &InitConfig::new(env, 1_000_000_000, 1000));
+    let treasurer = Address::generate(env);
+    client.set_treasurer(&admin, &treasurer);
+
+    let usdc = Address::generate(env);
+    client.set_p2p_market(
+        &admin,
+        &usdc,
+        &P2PMarket {
+            pool_supply_rate_bps: 200,
+            p2p_cursor_bps: 5000,
+        },
+    );
+    let supplier = Address::generate(env);
+    client.p2p_supply(&supplier, &usdc, &10_000);
+    client.borrow(
+        &Address::generate(env),
+        &usdc,
+        &4_000,
+        &Address::generate(env),
+        &8_000,
+    );
+
+    let strategy = MockStrategyClient::new(env, &env.register(MockStrategy, ()));
+    client.propose_strategy(&admin, &strategy.address, &10_000);
+    env.ledger().with_mut(|li| li.timestamp += 2 * DAY);
+    client.approve_strategy(&admin, &strategy.address);
+    client.set_strategy(
+        &treasurer,
+        &usdc,
+        &StrategyConfig {
+            strategy: strategy.address.clone(),
+            max_deploy_bps: 5000,
+            supplier_share_bps: 8000,
+        },
+    );
+    (client, strategy, treasurer, usdc, supplier)
+}
+
+#[test]
+fn test_deployed_cash_leaves_withdrawable_liquidity() {
+    let env = Env::default();
+    let (client, strategy, treasurer, usdc, supplier) = setup(&env);
+
+    // Half of the 6,000 un-borrowed may be deployed
+    assert_eq!(
+        client.try_deploy_to_strategy(&treasurer, &usdc, &3_001),
+        Err(Ok(StrategyError::DeploymentCapExceeded))
+    );
+    assert_eq!(client.deploy_to_strategy(&treasurer, &usdc, &3_000), 3_000);
+    assert_eq!(strategy.balance(&usdc), 3_000);
+
+    let liquidity = client.get_liquidity(&usdc);
+    assert_eq!(liquidity.deployed, 3_000);
+    assert_eq!(liquidity.available_cash, 3_000);
+    assert_eq!(liquidity.withdrawable, 3_000);
+    assert_eq!(
+        client.try_p2p_withdraw(&supplier, &usdc, &3_500),
+        Err(Ok(P2PError::InsufficientLiquidity))
+    );
+
+    assert_eq!(client.recall_from_strategy(&treasurer, &usdc, &3_000), 0);
+    assert_eq!(client.get_liquidity(&usdc).withdrawable, 6_000);
+    client.p2p_withdraw(&supplier, &usdc, &3_500);
+}
+
+#[test]
+fn test_strategy_management_rules() {
+    let env = Env::default();
+    let (client, strategy, treasurer, usdc, _) = setup(&env);
+    client.deploy_to_strategy(&treasurer, &usdc, &1_000);
+
+    let replacement = StrategyConfig {
+        strategy: Address::generate(&env),
+        max_deploy_bps: 5000,
+        supplier_share_bps: 8000,
+    };
+    assert_eq!(
+        client.try_set_strategy(&treasurer, &usdc, &replacement),
+        Err(Ok(StrategyError::StrategyInUse))
+    );
+    assert_eq!(
+        client.try_recall_from_strategy(&treasurer, &usdc, &1_001),
+        Err(Ok(StrategyError::InsufficientDeployed))
+    );
+
+    // A strategy that cannot return the principal fails the recall
+    strategy.set_balance(&usdc, &900);
+    assert_eq!(
+        client.try_recall_from_strategy(&treasurer, &usdc, &1_000),
+        Err(Ok(StrategyError::StrategyShortfall))
+    );
+    assert_eq!(
+        client.try_deploy_to_strategy(&Address::generate(&env), &usdc, &100),
+        Err(Ok(StrategyError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_emergency_divest_records_loss_and_blocks() {
+    let env = Env::default();
+    let (client, strategy, treasurer, usdc, _) = setup(&env);
+    let admin = client.get_admin().unwrap();
+    assert_eq!(
+        client.try_emergency_divest(&usdc),
+        Err(Ok(StrategyError::Unauthorized))
+    );
+    client.set_guardian(&admin, &Address::generate(&env));
+
+    client.deploy_to_strategy(&treasurer, &usdc, &3_000);
+    strategy.set_balance(&usdc, &2_400);
+    assert_eq!(client.emergency_divest(&usdc), 2_400);
+    assert_eq!(client.get_strategy_deployed(&usdc), 0);
+    assert_eq!(client.get_strategy_loss(&usdc), 600);
+
+    // The lost cash stays out of withdrawable liquidity
+    let liquidity = client.get_liquidity(&usdc);
+    assert_eq!(liquidity.strategy_loss, 600);
+    assert_eq!(liquidity.withdrawable, 5_400);
+
+    assert!(client.is_strategy_blocked(&usdc));
+    assert_eq!(
+        client.try_deploy_to_strategy(&treasurer, &usdc, &100),
+        Err(Ok(StrategyError::StrategyBlocked))
+    );
+    client.resume_strategy(&admin, &usdc);
+    client.deploy_to_strategy(&treasurer, &usdc, &100);
+}
+
+#[test]
+fn test_emergency_divest_survives_failing_strategy() {
+    let env = Env::default();
+    let (client, strategy, treasurer, usdc, _) = setup(&env);
+    let admin = client.get_admin().unwrap();
+    client.set_guardian(&admin, &Address::generate(&env));
+
+    client.deploy_to_strategy(&treasurer, &usdc, &3_000);
+    strategy.halt_withdrawals();
+    assert_eq!(client.emergency_divest(&usdc), 0);
+    assert_eq!(client.get_strategy_deployed(&usdc), 0);
+    assert_eq!(client.get_strategy_loss(&usdc), 3_000);
+    assert!(client.is_strategy_blocked(&usdc));
+}
+
+#[test]
+fn test_strategy_allowlist_timelock_and_cap() {
+    let env = Env::default();
+    let (client, strategy, treasurer, usdc, _) = setup(&env);
+    let admin = client.get_admin().unwrap();
+
+    let unlisted = StrategyConfig {
+        strategy: Address::generate(&env),
+        max_deploy_bps: 5000,
+        supplier_share_bps: 8000,
+    };
+    let eurc = Address::generate(&env);
+    assert_eq!(
+        client.try_set_strategy(&treasurer, &eurc, &unlisted),
+        Err(Ok(StrategyError::StrategyNotAllowed))
+    );
+    client.propose_strategy(&admin, &unlisted.strategy, &1_000);
+    env.ledger().with_mut(|li| li.timestamp += DAY);
+    assert_eq!(
+        client.try_approve_strategy(&admin, &unlisted.strategy),
+        Err(Ok(StrategyError::TimelockActive))
+    );
+
+    // Lowering the cap goes through the timelock too
+    client.propose_strategy(&admin, &strategy.address, &1_000);
+    env.ledger().with_mut(|li| li.timestamp += 2 * DAY);
+    client.approve_strategy(&admin, &strategy.address);
+    assert_eq!(
+        client
+            .get_allowed_strategy(&strategy.address)
+            .unwrap()
+            .deposit_cap,
+        1_000
+    );
+    assert_eq!(
+        client.try_deploy_to_strategy(&treasurer, &usdc, &1_001),
+        Err(Ok(StrategyError::DeploymentCapExceeded))
+    );
+    client.deploy_to_strategy(&treasurer, &usdc, &1_000);
+    assert_eq!(client.get_strategy_deposits(&strategy.address), 1_000);
+
+    // A removed strategy can be recalled from but not deployed to
+    client.remove_strategy(&admin, &strategy.address);
+    client.recall_from_strategy(&treasurer, &usdc, &500);
+    assert_eq!(
+        client.try_deploy_to_strategy(&treasurer, &usdc, &100),
+        Err(Ok(StrategyError::StrategyNotAllowed))
+    );
+}
+
+#[test]
+fn test_harvest_splits_gains_between_suppliers_and_reserve() {
+    let env = Env::default();
+    let (client, strategy, treasurer, usdc, supplier) = setup(&env);
+    client.deploy_to_strategy(&treasurer, &usdc, &3_000);
+    let keeper = Address::generate(&env);
+
+    strategy.set_balance(&usdc, &4_000);
+    let balance = client.get_p2p_supply(&supplier, &usdc).on_pool;
+    assert_eq!(client.harvest_strategy(&keeper, &usdc), 1_000);
+    assert_eq!(strategy.balance(&usdc), 3_000);
+    assert_eq!(client.get_strategy_deployed(&usdc), 3_000);
+
+    // 80% grows the sole supplier's balance, the rest goes to the reserve
+    assert_eq!(
+        client.get_p2p_supply(&supplier, &usdc).on_pool - balance,
+        800
+    );
+    assert_eq!(client.get_strategy_reserve(&usdc), 200);
+
+    // A loss is reported but leaves the principal deployed
+    strategy.set_balance(&usdc, &2_500);
+    assert_eq!(client.harvest_strategy(&keeper, &usdc), 0);
+    assert_eq!(client.get_strategy_deployed(&usdc), 3_000);
+}
+
+#[test]
+fn test_strategy_loss_write_down_waterfall() {
+    let env = Env::default();
+    let (client, strategy, treasurer, usdc, supplier) = setup(&env);
+    let admin = client.get_admin().unwrap();
+    client.set_guardian(&admin, &Address::generate(&env));
+    let keeper = Address::generate(&env);
+    assert_eq!(
+        client.try_write_down_strategy_loss(&keeper, &usdc),
+        Err(Ok(StrategyError::NoStrategyLoss))
+    );
+
+    // 200 of reserve from a harvest, then a 1,000 loss on divestment
+    client.deploy_to_strategy(&treasurer, &usdc, &3_000);
+    strategy.set_balance(&usdc, &4_000);
+    client.harvest_strategy(&keeper, &usdc);
+    strategy.set_balance(&usdc, &2_000);
+    client.emergency_divest(&usdc);
+    let balance = client.get_p2p_supply(&supplier, &usdc).on_pool;
+    let supplied = client.get_reserve_data(&usdc).total_supplied;
+
+    let write_down = client.write_down_strategy_loss(&keeper, &usdc);
+    assert_eq!(write_down.loss, 1_000);
+    assert_eq!(write_down.from_insurance, 0);
+    assert_eq!(write_down.from_reserve, 200);
+    assert_eq!(write_down.haircut, 800);
+    assert_eq!(client.get_strategy_loss(&usdc), 0);
+    assert_eq!(client.get_strategy_reserve(&usdc), 0);
+    assert_eq!(
+        supplied - client.get_reserve_data(&usdc).total_supplied,
+        800
+    );
+    assert!(client.get_p2p_supply(&supplier, &usdc).on_pool < balance);
+}
@@ -0,0 +1,75 @@
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::asset_mode::{get_asset_mode, AssetMode};
+use crate::borrow::{
+    get_health_factor, get_total_collateral_value, get_total_user_debt, get_user_collateral,
+    get_user_debt, CollateralPosition, DebtPosition,
+};
+use crate::collateral_tier::{get_asset_tier, CollateralTier};
+use crate::p2p::{get_p2p_supply, get_supplier_assets};
+
+/// A user's supplied balance of one asset
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SupplyBalance {
+    pub asset: Address,
+    /// Supplied liquidity including accrued interest
+    pub amount: i128,
+}
+
+/// Everything a wallet needs to render a user's account
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct UserSummary {
+    /// Non-zero supplied balances, in order of first supply
+    pub supplies: Vec<SupplyBalance>,
+    /// Pool collateral
+    pub collateral: CollateralPosition,
+    /// Pool debt including accrued interest
+    pub debt: DebtPosition,
+    /// Usage mode of the pool collateral asset; `BorrowOnly` collateral is
+    /// not counted
+    pub collateral_mode: AssetMode,
+    /// Collateral value across pool, NFT and RWA collateral
+    pub total_collateral_value: i128,
+    /// Debt across pool, fixed-term and amortizing loans
+    pub total_debt: i128,
+    /// Health factor in basis points (`i128::MAX` without debt)
+    pub health_factor: i128,
+    /// Vested rewards claimable now
+    pub claimable_rewards: i128,
+    pub points: i128,
+    /// Risk tier of the pool collateral asset; empty when none is assigned
+    pub collateral_tier: Vec<CollateralTier>,
+}
+
+/// Get a user's supplies, debt, health, rewards and collateral tier
+pub fn get_user_summary(env: &Env, user: &Address) -> UserSummary {
+    let mut supplies = Vec::new(env);
+    for asset in get_supplier_assets(env, user).iter() {
+        let position = get_p2p_supply(env, user, &asset);
+        let amount = position.in_p2p.saturating_add(position.on_pool);
+        if amount > 0 {
+            supplies.push_back(SupplyBalance { asset, amount });
+        }
+    }
+    let collateral = get_user_collateral(env, user);
+    let collateral_mode = get_asset_mode(env, &collateral.asset);
+    let mut collateral_tier = Vec::new(env);
+    if let Some(tier) = get_asset_tier(env, &collateral.asset) {
+        collateral_tier.push_back(tier);
+    }
+
+    UserSummary {
+        supplies,
+        collateral,
+        debt: get_user_debt(env, user),
+        collateral_mode,
+        total_collateral_value: get_total_collateral_value(env, user),
+        total_debt: get_total_user_debt(env, user),
+        health_factor: get_health_factor(env, user),
+        claimable_rewards: crate::vesting::get_claimable_vested(env, user),
+        points: crate::points::get_points(env, user),
+        collateral_tier,
+    }
+}
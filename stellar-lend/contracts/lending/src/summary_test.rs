@@ -0,0 +1,77 @@
+use super::*;
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+#[test]
+fn test_user_summary_combines_positions() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = LendingContractClient::new(&env, &env.register(LendingContract, ()));
+    client.initialize(
+        &Address::generate(&env),
+        &InitConfig::new(&env, 1_000_000_000, 1000),
+    );
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let market = P2PMarket {
+        pool_supply_rate_bps: 200,
+        p2p_cursor_bps: 5000,
+    };
+    let usdc = Address::generate(&env);
+    let xlm = Address::generate(&env);
+    client.set_p2p_market(&admin, &usdc, &market);
+    client.set_p2p_market(&admin, &xlm, &market);
+    let collateral_asset = Address::generate(&env);
+    client.set_tier_config(
+        &admin,
+        &CollateralTier::BlueChip,
+        &TierConfig {
+            params: CollateralParams {
+                ltv_bps: 6667,
+                liquidation_threshold_bps: 8000,
+            },
+            exposure_cap: 1_000_000,
+        },
+    );
+    client.assign_asset_tier(&admin, &collateral_asset, &CollateralTier::BlueChip);
+
+    let user = Address::generate(&env);
+    client.p2p_supply(&user, &usdc, &5000);
+    client.p2p_supply(&user, &xlm, &3000);
+    client.p2p_withdraw(&user, &xlm, &3000);
+    client.borrow(&user, &usdc, &10_000, &collateral_asset, &20_000);
+
+    let summary = client.get_user_summary(&user);
+    assert_eq!(
+        summary.supplies,
+        Vec::from_array(
+            &env,
+            [SupplyBalance {
+                asset: usdc.clone(),
+                amount: 5000,
+            }]
+        )
+    );
+    assert_eq!(summary.collateral.amount, 20_000);
+    assert_eq!(summary.debt.borrowed_amount, 10_000);
+    assert_eq!(summary.total_debt, 10_000);
+    assert_eq!(summary.total_collateral_value, 20_000);
+    assert_eq!(summary.health_factor, client.get_health_factor(&user));
+    assert_eq!(summary.claimable_rewards, 0);
+    assert_eq!(
+        summary.collateral_tier,
+        Vec::from_array(&env, [CollateralTier::BlueChip])
+    );
+}
+
+#[test]
+fn test_user_summary_of_new_account() {
+    let env = Env::default();
+    let client = LendingContractClient::new(&env, &env.register(LendingContract, ()));
+
+    let summary = client.get_user_summary(&Address::generate(&env));
+    assert!(summary.supplies.is_empty());
+    assert_eq!(summary.total_debt, 0);
+    assert_eq!(summary.health_factor, i128::MAX);
+    assert!(summary.collateral_tier.is_empty());
+}
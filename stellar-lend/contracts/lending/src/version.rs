@@ -0,0 +1,57 @@
+use soroban_sdk::{contracttype, Env, String};
+
+/// Semantic version of this build
+pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+/// Version of the storage layout; bumped whenever stored types change
+pub const SCHEMA_VERSION: u32 = 1;
+/// Build identifier, set through `STELLARLEND_BUILD_ID` at compile time
+const BUILD_ID: &str = match option_env!("STELLARLEND_BUILD_ID") {
+    Some(id) => id,
+    None => "dev",
+};
+
+/// Storage keys for contract metadata
+#[contracttype]
+#[derive(Clone)]
+pub enum VersionDataKey {
+    ContractVersion,
+}
+
+/// Version metadata recorded when the contract was set up
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContractVersion {
+    pub version: String,
+    pub schema_version: u32,
+    pub build: String,
+    /// Ledger time the metadata was recorded (0 if never recorded)
+    pub recorded_at: u64,
+}
+
+/// Record the running build's version metadata
+pub(crate) fn record_version(env: &Env) {
+    let version = ContractVersion {
+        recorded_at: env.ledger().timestamp(),
+        ..current_version(env)
+    };
+    env.storage()
+        .persistent()
+        .set(&VersionDataKey::ContractVersion, &version);
+}
+
+/// Get the recorded version metadata, or the running build's before it is recorded
+pub fn get_version(env: &Env) -> ContractVersion {
+    env.storage()
+        .persistent()
+        .get(&VersionDataKey::ContractVersion)
+        .unwrap_or_else(|| current_version(env))
+}
+
+fn current_version(env: &Env) -> ContractVersion {
+    ContractVersion {
+        version: String::from_str(env, CONTRACT_VERSION),
+        schema_version: SCHEMA_VERSION,
+        build: String::from_str(env, BUILD_ID),
+        recorded_at: 0,
+    }
+}
@@ -0,0 +1,30 @@
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env, String,
+};
+
+#[test]
+fn test_version_recorded_at_initialization() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 5000);
+    let client = LendingContractClient::new(&env, &env.register(LendingContract, ()));
+
+    let before = client.get_version();
+    assert_eq!(
+        before.version,
+        String::from_str(&env, env!("CARGO_PKG_VERSION"))
+    );
+    assert_eq!(before.schema_version, SCHEMA_VERSION);
+    assert_eq!(before.recorded_at, 0);
+
+    client.initialize(
+        &Address::generate(&env),
+        &InitConfig::new(&env, 1_000_000_000, 1000),
+    );
+    let recorded = client.get_version();
+    assert_eq!(recorded.recorded_at, 5000);
+    assert_eq!(recorded.version, before.version);
+    assert_eq!(recorded.build, before.build);
+}
@@ -0,0 +1,252 @@
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol, Vec};
+
+use crate::admin::require_admin;
+use crate::insurance::credit_protocol_fee;
+use crate::math::div_up;
+
+/// Errors that can occur during vesting operations
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum VestingError {
+    Unauthorized = 1,
+    InvalidConfig = 2,
+    InvalidAmount = 3,
+    NothingToClaim = 4,
+    TooManySchedules = 5,
+    Overflow = 6,
+}
+
+/// Storage keys for vesting data
+#[contracttype]
+#[derive(Clone)]
+pub enum VestingDataKey {
+    VestingConfig,
+    VestingSchedules(Address),
+}
+
+/// Vesting configuration for reward payouts
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct VestingConfig {
+    pub enabled: bool,
+    pub reward_asset: Address,
+    pub duration: u64,
+    pub early_exit_penalty_bps: i128,
+}
+
+/// A single linear vesting schedule
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct VestingSchedule {
+    pub total: i128,
+    pub released: i128,
+    pub start: u64,
+    pub duration: u64,
+}
+
+/// Vesting event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct VestingEvent {
+    pub user: Address,
+    pub amount: i128,
+    pub penalty: i128,
+    pub timestamp: u64,
+}
+
+const MAX_SCHEDULES_PER_USER: u32 = 16;
+const MAX_EARLY_EXIT_PENALTY_BPS: i128 = 5000; // 50% in basis points
+
+/// Configure reward vesting (admin only)
+pub fn set_vesting_config(
+    env: &Env,
+    caller: Address,
+    config: VestingConfig,
+) -> Result<(), VestingError> {
+    require_admin(env, &caller).map_err(|_| VestingError::Unauthorized)?;
+
+    if config.enabled && config.duration == 0 {
+        return Err(VestingError::InvalidConfig);
+    }
+    if config.early_exit_penalty_bps < 0
+        || config.early_exit_penalty_bps > MAX_EARLY_EXIT_PENALTY_BPS
+    {
+        return Err(VestingError::InvalidConfig);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&VestingDataKey::VestingConfig, &config);
+    Ok(())
+}
+
+/// Get the vesting configuration, if set
+pub fn get_vesting_config(env: &Env) -> Option<VestingConfig> {
+    env.storage()
+        .persistent()
+        .get(&VestingDataKey::VestingConfig)
+}
+
+/// Pay out a reward, wrapping it in a vesting schedule when vesting is enabled
+///
+/// Returns the amount paid out immediately (zero when the reward was vested).
+pub fn pay_reward(env: &Env, user: &Address, amount: i128) -> Result<i128, VestingError> {
+    if amount <= 0 {
+        return Err(VestingError::InvalidAmount);
+    }
+
+    let config = match get_vesting_config(env) {
+        Some(config) if config.enabled => config,
+        _ => {
+            emit_vesting_event(env, "reward_paid", user.clone(), amount, 0);
+            return Ok(amount);
+        }
+    };
+
+    let mut schedules = get_vesting_schedules(env, user);
+    if schedules.len() >= MAX_SCHEDULES_PER_USER {
+        return Err(VestingError::TooManySchedules);
+    }
+    schedules.push_back(VestingSchedule {
+        total: amount,
+        released: 0,
+        start: env.ledger().timestamp(),
+        duration: config.duration,
+    });
+    save_vesting_schedules(env, user, &schedules);
+
+    emit_vesting_event(env, "vesting_created", user.clone(), amount, 0);
+    Ok(0)
+}
+
+/// Distribute a reward to a user through the vesting wrapper (admin only)
+pub fn distribute_reward(
+    env: &Env,
+    caller: Address,
+    user: Address,
+    amount: i128,
+) -> Result<i128, VestingError> {
+    require_admin(env, &caller).map_err(|_| VestingError::Unauthorized)?;
+    pay_reward(env, &user, amount)
+}
+
+/// Release all currently unlocked rewards for a user
+pub fn claim_vested(env: &Env, user: Address) -> Result<i128, VestingError> {
+    user.require_auth();
+
+    let now = env.ledger().timestamp();
+    let schedules = get_vesting_schedules(env, &user);
+    let mut remaining = Vec::new(env);
+    let mut claimed: i128 = 0;
+
+    for mut schedule in schedules.iter() {
+        let unlocked = vested_amount(&schedule, now)?;
+        let claimable = unlocked - schedule.released;
+        claimed = claimed
+            .checked_add(claimable)
+            .ok_or(VestingError::Overflow)?;
+        schedule.released = unlocked;
+        if schedule.released < schedule.total {
+            remaining.push_back(schedule);
+        }
+    }
+
+    if claimed == 0 {
+        return Err(VestingError::NothingToClaim);
+    }
+
+    save_vesting_schedules(env, &user, &remaining);
+    emit_vesting_event(env, "vesting_claimed", user, claimed, 0);
+    Ok(claimed)
+}
+
+/// Exit all vesting schedules early, forfeiting a penalty on the locked portion
+///
+/// The penalty is routed to the insurance fund of the reward asset.
+pub fn exit_vesting(env: &Env, user: Address) -> Result<i128, VestingError> {
+    user.require_auth();
+
+    let config = get_vesting_config(env).ok_or(VestingError::InvalidConfig)?;
+    let now = env.ledger().timestamp();
+    let schedules = get_vesting_schedules(env, &user);
+
+    let mut unlocked_total: i128 = 0;
+    let mut locked_total: i128 = 0;
+    for schedule in schedules.iter() {
+        let unlocked = vested_amount(&schedule, now)?;
+        unlocked_total = unlocked_total
+            .checked_add(unlocked - schedule.released)
+            .ok_or(VestingError::Overflow)?;
+        locked_total = locked_total
+            .checked_add(schedule.total - unlocked)
+            .ok_or(VestingError::Overflow)?;
+    }
+
+    if unlocked_total == 0 && locked_total == 0 {
+        return Err(VestingError::NothingToClaim);
+    }
+
+    // Round the penalty up so the protocol never under-collects
+    let penalty = locked_total
+        .checked_mul(config.early_exit_penalty_bps)
+        .map(|penalty| div_up(penalty, 10000))
+        .ok_or(VestingError::Overflow)?;
+    let payout = unlocked_total + locked_total - penalty;
+
+    save_vesting_schedules(env, &user, &Vec::new(env));
+    credit_protocol_fee(env, &config.reward_asset, penalty);
+
+    emit_vesting_event(env, "vesting_exited", user, payout, penalty);
+    Ok(payout)
+}
+
+/// Get all active vesting schedules for a user
+pub fn get_vesting_schedules(env: &Env, user: &Address) -> Vec<VestingSchedule> {
+    env.storage()
+        .persistent()
+        .get(&VestingDataKey::VestingSchedules(user.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Get the amount a user could claim right now
+pub fn get_claimable_vested(env: &Env, user: &Address) -> i128 {
+    let now = env.ledger().timestamp();
+    get_vesting_schedules(env, user)
+        .iter()
+        .map(|s| vested_amount(&s, now).unwrap_or(s.released) - s.released)
+        .fold(0i128, |acc, v| acc.saturating_add(v))
+}
+
+/// Linearly vested amount of a schedule at `now`, rounded down
+fn vested_amount(schedule: &VestingSchedule, now: u64) -> Result<i128, VestingError> {
+    let elapsed = now.saturating_sub(schedule.start);
+    if elapsed >= schedule.duration {
+        return Ok(schedule.total);
+    }
+    schedule
+        .total
+        .checked_mul(elapsed as i128)
+        .ok_or(VestingError::Overflow)?
+        .checked_div(schedule.duration as i128)
+        .ok_or(VestingError::Overflow)
+}
+
+fn save_vesting_schedules(env: &Env, user: &Address, schedules: &Vec<VestingSchedule>) {
+    let key = VestingDataKey::VestingSchedules(user.clone());
+    if schedules.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, schedules);
+    }
+}
+
+fn emit_vesting_event(env: &Env, name: &str, user: Address, amount: i128, penalty: i128) {
+    let event = VestingEvent {
+        user,
+        amount,
+        penalty,
+        timestamp: env.ledger().timestamp(),
+    };
+    crate::events::publish(env, (Symbol::new(env, name), event.user.clone()), event);
+}
@@ -0,0 +1,118 @@
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+
+fn setup(env: &Env) -> (LendingContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+
+    let contract_id = env.register(LendingContract, ());
+    let client = LendingContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let reward_asset = Address::generate(env);
+    client.initialize(&admin, &InitConfig::new(env, 1_000_000_000, 1000));
+    client.set_vesting_config(
+        &admin,
+        &VestingConfig {
+            enabled: true,
+            reward_asset: reward_asset.clone(),
+            duration: 1000,
+            early_exit_penalty_bps: 2000,
+        },
+    );
+
+    (client, admin, reward_asset)
+}
+
+#[test]
+fn test_reward_paid_immediately_without_vesting() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(LendingContract, ());
+    let client = LendingContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    client.initialize(&admin, &InitConfig::new(&env, 1_000_000_000, 1000));
+
+    assert_eq!(client.distribute_reward(&admin, &user, &500), 500);
+    assert_eq!(client.get_vesting_schedules(&user).len(), 0);
+}
+
+#[test]
+fn test_reward_vests_linearly() {
+    let env = Env::default();
+    let (client, admin, _) = setup(&env);
+    let user = Address::generate(&env);
+
+    assert_eq!(client.distribute_reward(&admin, &user, &1000), 0);
+    assert_eq!(client.get_claimable_vested(&user), 0);
+
+    env.ledger().with_mut(|li| li.timestamp = 1250);
+    assert_eq!(client.get_claimable_vested(&user), 250);
+    assert_eq!(client.claim_vested(&user), 250);
+
+    let result = client.try_claim_vested(&user);
+    assert_eq!(result, Err(Ok(VestingError::NothingToClaim)));
+
+    env.ledger().with_mut(|li| li.timestamp = 5000);
+    assert_eq!(client.claim_vested(&user), 750);
+    assert_eq!(client.get_vesting_schedules(&user).len(), 0);
+}
+
+#[test]
+fn test_early_exit_penalty_goes_to_insurance_fund() {
+    let env = Env::default();
+    let (client, admin, reward_asset) = setup(&env);
+    let user = Address::generate(&env);
+
+    client.distribute_reward(&admin, &user, &1000);
+    env.ledger().with_mut(|li| li.timestamp = 1500);
+
+    // 500 unlocked, 500 locked; 20% penalty on the locked half
+    assert_eq!(client.exit_vesting(&user), 900);
+    assert_eq!(client.get_insurance_fund(&reward_asset), 100);
+    assert_eq!(client.get_vesting_schedules(&user).len(), 0);
+}
+
+#[test]
+fn test_vesting_config_validation() {
+    let env = Env::default();
+    let (client, admin, reward_asset) = setup(&env);
+
+    let result = client.try_set_vesting_config(
+        &admin,
+        &VestingConfig {
+            enabled: true,
+            reward_asset: reward_asset.clone(),
+            duration: 1000,
+            early_exit_penalty_bps: 6000,
+        },
+    );
+    assert_eq!(result, Err(Ok(VestingError::InvalidConfig)));
+
+    let result = client.try_set_vesting_config(
+        &admin,
+        &VestingConfig {
+            enabled: true,
+            reward_asset,
+            duration: 0,
+            early_exit_penalty_bps: 0,
+        },
+    );
+    assert_eq!(result, Err(Ok(VestingError::InvalidConfig)));
+}
+
+#[test]
+fn test_vesting_admin_only() {
+    let env = Env::default();
+    let (client, _, _) = setup(&env);
+    let user = Address::generate(&env);
+
+    let result = client.try_distribute_reward(&user, &user, &1000);
+    assert_eq!(result, Err(Ok(VestingError::Unauthorized)));
+}
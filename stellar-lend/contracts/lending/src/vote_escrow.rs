@@ -0,0 +1,473 @@
+use soroban_sdk::{contracterror, contracttype, Address, Env, Map, Symbol, Vec};
+
+use crate::admin::require_admin;
+
+/// Errors that can occur during vote-escrow operations
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum VoteEscrowError {
+    Unauthorized = 1,
+    InvalidAmount = 2,
+    InvalidLockDuration = 3,
+    LockExists = 4,
+    NoLock = 5,
+    LockNotExpired = 6,
+    LockExpired = 7,
+    GaugeNotFound = 8,
+    GaugeExists = 9,
+    InvalidWeights = 10,
+    Overflow = 11,
+}
+
+/// Storage keys for vote-escrow data
+#[contracttype]
+#[derive(Clone)]
+pub enum VoteEscrowDataKey {
+    VeLock(Address),
+    GaugeVotes(Address),
+    Gauges,
+    GaugePoint(Address),
+    /// Vote slope that expires from a gauge at a week boundary
+    GaugeSlopeChange(Address, u64),
+    FullBoostPower,
+}
+
+/// A user's governance token lock
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct VeLock {
+    pub amount: i128,
+    pub unlock_time: u64,
+}
+
+/// A vote directing part of a lock's decaying power to a gauge
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct GaugeVote {
+    /// Share of the locked amount voted, by which the weight decays per second
+    pub slope: i128,
+    /// Unlock time of the backing lock, when the vote's weight reaches zero
+    pub unlock_time: u64,
+}
+
+/// Decaying vote weight of a gauge as of `timestamp`
+///
+/// `bias` is the weight scaled by `MAX_LOCK_DURATION`; it falls by `slope`
+/// every second, and slopes drop out at the unlock weeks of their locks.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct GaugePoint {
+    pub bias: i128,
+    pub slope: i128,
+    pub timestamp: u64,
+}
+
+/// Vote-escrow event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct VeLockEvent {
+    pub user: Address,
+    pub amount: i128,
+    pub unlock_time: u64,
+    pub timestamp: u64,
+}
+
+const WEEK: u64 = 7 * 86400;
+const MAX_LOCK_DURATION: u64 = 4 * 365 * 86400; // 4 years
+const BASE_BOOST_BPS: i128 = 10000; // 1x
+const MAX_EXTRA_BOOST_BPS: i128 = 15000; // up to 2.5x total
+
+/// Lock governance tokens until `unlock_time` (rounded down to a whole week)
+pub fn create_lock(
+    env: &Env,
+    user: Address,
+    amount: i128,
+    unlock_time: u64,
+) -> Result<(), VoteEscrowError> {
+    user.require_auth();
+
+    if amount <= 0 {
+        return Err(VoteEscrowError::InvalidAmount);
+    }
+    if get_lock(env, &user).is_some() {
+        return Err(VoteEscrowError::LockExists);
+    }
+
+    let unlock_time = validate_unlock_time(env, unlock_time)?;
+    let lock = VeLock {
+        amount,
+        unlock_time,
+    };
+    save_lock(env, &user, &lock);
+
+    emit_lock_event(env, "ve_lock_created", user, &lock);
+    Ok(())
+}
+
+/// Add tokens to an existing, unexpired lock
+pub fn increase_lock_amount(env: &Env, user: Address, amount: i128) -> Result<(), VoteEscrowError> {
+    user.require_auth();
+
+    if amount <= 0 {
+        return Err(VoteEscrowError::InvalidAmount);
+    }
+    let mut lock = get_lock(env, &user).ok_or(VoteEscrowError::NoLock)?;
+    if lock.unlock_time <= env.ledger().timestamp() {
+        return Err(VoteEscrowError::LockExpired);
+    }
+
+    lock.amount = lock
+        .amount
+        .checked_add(amount)
+        .ok_or(VoteEscrowError::Overflow)?;
+    save_lock(env, &user, &lock);
+
+    emit_lock_event(env, "ve_lock_increased", user, &lock);
+    Ok(())
+}
+
+/// Extend an unexpired lock to a later unlock time
+pub fn extend_lock(env: &Env, user: Address, unlock_time: u64) -> Result<(), VoteEscrowError> {
+    user.require_auth();
+
+    let mut lock = get_lock(env, &user).ok_or(VoteEscrowError::NoLock)?;
+    if lock.unlock_time <= env.ledger().timestamp() {
+        return Err(VoteEscrowError::LockExpired);
+    }
+
+    let unlock_time = validate_unlock_time(env, unlock_time)?;
+    if unlock_time <= lock.unlock_time {
+        return Err(VoteEscrowError::InvalidLockDuration);
+    }
+
+    lock.unlock_time = unlock_time;
+    save_lock(env, &user, &lock);
+
+    emit_lock_event(env, "ve_lock_extended", user, &lock);
+    Ok(())
+}
+
+/// Release an expired lock, clearing any gauge votes it backed
+pub fn withdraw_lock(env: &Env, user: Address) -> Result<i128, VoteEscrowError> {
+    user.require_auth();
+
+    let lock = get_lock(env, &user).ok_or(VoteEscrowError::NoLock)?;
+    if lock.unlock_time > env.ledger().timestamp() {
+        return Err(VoteEscrowError::LockNotExpired);
+    }
+
+    clear_votes(env, &user)?;
+    env.storage()
+        .persistent()
+        .remove(&VoteEscrowDataKey::VeLock(user.clone()));
+
+    emit_lock_event(env, "ve_lock_withdrawn", user, &lock);
+    Ok(lock.amount)
+}
+
+/// Get a user's lock, if any
+pub fn get_lock(env: &Env, user: &Address) -> Option<VeLock> {
+    env.storage()
+        .persistent()
+        .get(&VoteEscrowDataKey::VeLock(user.clone()))
+}
+
+/// Current voting power: the locked amount scaled by the remaining lock time
+pub fn get_voting_power(env: &Env, user: &Address) -> i128 {
+    let lock = match get_lock(env, user) {
+        Some(lock) => lock,
+        None => return 0,
+    };
+    let remaining = lock.unlock_time.saturating_sub(env.ledger().timestamp());
+    lock.amount
+        .saturating_mul(remaining as i128)
+        .saturating_div(MAX_LOCK_DURATION as i128)
+}
+
+/// Reward boost in basis points (10000 = 1x, 25000 = 2.5x)
+///
+/// The boost scales with voting power up to the admin-configured full-boost power.
+pub fn get_reward_boost(env: &Env, user: &Address) -> i128 {
+    let full_boost_power: i128 = env
+        .storage()
+        .persistent()
+        .get(&VoteEscrowDataKey::FullBoostPower)
+        .unwrap_or(0);
+    if full_boost_power <= 0 {
+        return BASE_BOOST_BPS;
+    }
+
+    let power = get_voting_power(env, user).min(full_boost_power);
+    BASE_BOOST_BPS
+        + MAX_EXTRA_BOOST_BPS
+            .saturating_mul(power)
+            .saturating_div(full_boost_power)
+}
+
+/// Set the voting power that earns the maximum reward boost (admin only)
+pub fn set_full_boost_power(
+    env: &Env,
+    caller: Address,
+    power: i128,
+) -> Result<(), VoteEscrowError> {
+    require_admin(env, &caller).map_err(|_| VoteEscrowError::Unauthorized)?;
+    if power <= 0 {
+        return Err(VoteEscrowError::InvalidAmount);
+    }
+    env.storage()
+        .persistent()
+        .set(&VoteEscrowDataKey::FullBoostPower, &power);
+    Ok(())
+}
+
+/// Register a market as an emissions gauge (admin only)
+pub fn add_gauge(env: &Env, caller: Address, market: Address) -> Result<(), VoteEscrowError> {
+    require_admin(env, &caller).map_err(|_| VoteEscrowError::Unauthorized)?;
+
+    let mut gauges = get_gauges(env);
+    if gauges.contains(&market) {
+        return Err(VoteEscrowError::GaugeExists);
+    }
+    gauges.push_back(market);
+    env.storage()
+        .persistent()
+        .set(&VoteEscrowDataKey::Gauges, &gauges);
+    Ok(())
+}
+
+/// Get all registered gauges
+pub fn get_gauges(env: &Env) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&VoteEscrowDataKey::Gauges)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Allocate voting power across gauges
+///
+/// `weights` maps each gauge to a share of the user's current voting power in
+/// basis points; shares must not exceed 10000 in total. Votes decay with the
+/// remaining lock time and stop counting once the lock expires. Previous
+/// votes are replaced, so re-voting after extending a lock refreshes them.
+pub fn vote_for_gauges(
+    env: &Env,
+    user: Address,
+    weights: Map<Address, u32>,
+) -> Result<(), VoteEscrowError> {
+    user.require_auth();
+
+    if get_voting_power(env, &user) == 0 {
+        return Err(VoteEscrowError::NoLock);
+    }
+    let lock = get_lock(env, &user).ok_or(VoteEscrowError::NoLock)?;
+
+    let gauges = get_gauges(env);
+    let mut total_bps: u32 = 0;
+    for (gauge, bps) in weights.iter() {
+        if !gauges.contains(&gauge) {
+            return Err(VoteEscrowError::GaugeNotFound);
+        }
+        total_bps = total_bps
+            .checked_add(bps)
+            .ok_or(VoteEscrowError::InvalidWeights)?;
+    }
+    if total_bps > 10000 {
+        return Err(VoteEscrowError::InvalidWeights);
+    }
+
+    clear_votes(env, &user)?;
+
+    let now = env.ledger().timestamp();
+    let mut votes = Map::new(env);
+    for (gauge, bps) in weights.iter() {
+        let slope = lock.amount.saturating_mul(bps as i128) / 10000;
+        if slope == 0 {
+            continue;
+        }
+        let mut point = get_gauge_point(env, &gauge);
+        point.bias = slope
+            .checked_mul((lock.unlock_time - now) as i128)
+            .and_then(|bias| point.bias.checked_add(bias))
+            .ok_or(VoteEscrowError::Overflow)?;
+        point.slope = point
+            .slope
+            .checked_add(slope)
+            .ok_or(VoteEscrowError::Overflow)?;
+        save_gauge_point(env, &gauge, &point);
+        adjust_slope_change(env, &gauge, lock.unlock_time, slope);
+        votes.set(
+            gauge,
+            GaugeVote {
+                slope,
+                unlock_time: lock.unlock_time,
+            },
+        );
+    }
+
+    env.storage()
+        .persistent()
+        .set(&VoteEscrowDataKey::GaugeVotes(user.clone()), &votes);
+
+    crate::events::publish(env, (Symbol::new(env, "gauge_vote"), user), votes);
+    Ok(())
+}
+
+/// Get the weight currently voted to a gauge
+///
+/// Votes backed by expired locks count for nothing.
+pub fn get_gauge_weight(env: &Env, market: &Address) -> i128 {
+    get_gauge_point(env, market).bias / MAX_LOCK_DURATION as i128
+}
+
+/// Get a gauge's share of total emissions weight in basis points
+pub fn get_gauge_relative_weight(env: &Env, market: &Address) -> i128 {
+    let total = total_gauge_weight(env, &get_gauges(env));
+    if total == 0 {
+        return 0;
+    }
+    get_gauge_weight(env, market).saturating_mul(10000) / total
+}
+
+/// Emissions multiplier of a market in basis points (10000 = 1x)
+///
+/// Once votes are cast, gauges share emissions by weight: a gauge holding an
+/// even share of the votes earns 1x and one without votes earns nothing.
+/// Markets that are not gauges, and every market before any votes, earn 1x.
+pub fn get_gauge_multiplier(env: &Env, market: &Address) -> i128 {
+    let gauges = get_gauges(env);
+    if !gauges.contains(market) {
+        return BASE_BOOST_BPS;
+    }
+    let total = total_gauge_weight(env, &gauges);
+    if total == 0 {
+        return BASE_BOOST_BPS;
+    }
+    get_gauge_weight(env, market)
+        .saturating_mul(BASE_BOOST_BPS)
+        .saturating_mul(gauges.len() as i128)
+        / total
+}
+
+fn total_gauge_weight(env: &Env, gauges: &Vec<Address>) -> i128 {
+    gauges.iter().fold(0i128, |total, gauge| {
+        total.saturating_add(get_gauge_weight(env, &gauge))
+    })
+}
+
+/// A gauge's point decayed to now, dropping the slopes of locks unlocked since
+fn get_gauge_point(env: &Env, market: &Address) -> GaugePoint {
+    let now = env.ledger().timestamp();
+    let mut point = env
+        .storage()
+        .persistent()
+        .get(&VoteEscrowDataKey::GaugePoint(market.clone()))
+        .unwrap_or(GaugePoint {
+            bias: 0,
+            slope: 0,
+            timestamp: now,
+        });
+
+    // Lock unlock times fall on week boundaries
+    while point.slope > 0 {
+        let week = (point.timestamp / WEEK + 1) * WEEK;
+        if week > now {
+            break;
+        }
+        point.bias = point
+            .bias
+            .saturating_sub(point.slope.saturating_mul((week - point.timestamp) as i128))
+            .max(0);
+        point.timestamp = week;
+        point.slope = point
+            .slope
+            .saturating_sub(get_slope_change(env, market, week))
+            .max(0);
+    }
+    point.bias = point
+        .bias
+        .saturating_sub(
+            point
+                .slope
+                .saturating_mul(now.saturating_sub(point.timestamp) as i128),
+        )
+        .max(0);
+    point.timestamp = now;
+    point
+}
+
+fn save_gauge_point(env: &Env, market: &Address, point: &GaugePoint) {
+    env.storage()
+        .persistent()
+        .set(&VoteEscrowDataKey::GaugePoint(market.clone()), point);
+}
+
+fn get_slope_change(env: &Env, market: &Address, week: u64) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&VoteEscrowDataKey::GaugeSlopeChange(market.clone(), week))
+        .unwrap_or(0)
+}
+
+fn adjust_slope_change(env: &Env, market: &Address, week: u64, delta: i128) {
+    let change = get_slope_change(env, market, week).saturating_add(delta);
+    env.storage().persistent().set(
+        &VoteEscrowDataKey::GaugeSlopeChange(market.clone(), week),
+        &change,
+    );
+}
+
+/// Remove a user's previous votes from gauge weights
+///
+/// Votes whose lock has expired have already decayed out.
+fn clear_votes(env: &Env, user: &Address) -> Result<(), VoteEscrowError> {
+    let key = VoteEscrowDataKey::GaugeVotes(user.clone());
+    let votes: Map<Address, GaugeVote> = match env.storage().persistent().get(&key) {
+        Some(votes) => votes,
+        None => return Ok(()),
+    };
+
+    let now = env.ledger().timestamp();
+    for (gauge, vote) in votes.iter() {
+        if vote.unlock_time <= now {
+            continue;
+        }
+        let mut point = get_gauge_point(env, &gauge);
+        point.bias = point
+            .bias
+            .saturating_sub(vote.slope.saturating_mul((vote.unlock_time - now) as i128))
+            .max(0);
+        point.slope = point.slope.saturating_sub(vote.slope).max(0);
+        save_gauge_point(env, &gauge, &point);
+        adjust_slope_change(env, &gauge, vote.unlock_time, -vote.slope);
+    }
+    env.storage().persistent().remove(&key);
+    Ok(())
+}
+
+/// Round `unlock_time` down to a whole week and check the lock duration bounds
+fn validate_unlock_time(env: &Env, unlock_time: u64) -> Result<u64, VoteEscrowError> {
+    let now = env.ledger().timestamp();
+    let rounded = (unlock_time / WEEK) * WEEK;
+    let duration = rounded.saturating_sub(now);
+    if rounded <= now || !(WEEK..=MAX_LOCK_DURATION).contains(&duration) {
+        return Err(VoteEscrowError::InvalidLockDuration);
+    }
+    Ok(rounded)
+}
+
+fn save_lock(env: &Env, user: &Address, lock: &VeLock) {
+    env.storage()
+        .persistent()
+        .set(&VoteEscrowDataKey::VeLock(user.clone()), lock);
+}
+
+fn emit_lock_event(env: &Env, name: &str, user: Address, lock: &VeLock) {
+    let event = VeLockEvent {
+        user,
+        amount: lock.amount,
+        unlock_time: lock.unlock_time,
+        timestamp: env.ledger().timestamp(),
+    };
+    crate::events::publish(env, (Symbol::new(env, name), event.user.clone()), event);
+}
@@ -0,0 +1,186 @@
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env, Map,
+};
+
+const WEEK: u64 = 7 * 86400;
+const FOUR_YEARS: u64 = 4 * 365 * 86400;
+
+fn setup(env: &Env) -> (LendingContractClient<'_>, Address) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 0);
+
+    let contract_id = env.register(LendingContract, ());
+    let client = LendingContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    client.initialize(&admin, &InitConfig::new(env, 1_000_000_000, 1000));
+    (client, admin)
+}
+
+#[test]
+fn test_voting_power_decays_linearly() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    let user = Address::generate(&env);
+
+    client.create_lock(&user, &1_000_000, &FOUR_YEARS);
+    let full = client.get_voting_power(&user);
+    // Unlock rounds down to a whole week, so power is just under the locked amount
+    assert!(full > 990_000 && full <= 1_000_000);
+
+    let unlock_time = client.get_ve_lock(&user).unwrap().unlock_time;
+    env.ledger().with_mut(|li| li.timestamp = FOUR_YEARS / 2);
+    let expected = 1_000_000 * (unlock_time - FOUR_YEARS / 2) as i128 / FOUR_YEARS as i128;
+    assert_eq!(client.get_voting_power(&user), expected);
+
+    env.ledger().with_mut(|li| li.timestamp = FOUR_YEARS);
+    assert_eq!(client.get_voting_power(&user), 0);
+}
+
+#[test]
+fn test_lock_duration_bounds() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    let user = Address::generate(&env);
+
+    let result = client.try_create_lock(&user, &1000, &(WEEK - 1));
+    assert_eq!(result, Err(Ok(VoteEscrowError::InvalidLockDuration)));
+
+    let result = client.try_create_lock(&user, &1000, &(FOUR_YEARS + 2 * WEEK));
+    assert_eq!(result, Err(Ok(VoteEscrowError::InvalidLockDuration)));
+
+    client.create_lock(&user, &1000, &(2 * WEEK));
+    let result = client.try_create_lock(&user, &1000, &(2 * WEEK));
+    assert_eq!(result, Err(Ok(VoteEscrowError::LockExists)));
+}
+
+#[test]
+fn test_withdraw_only_after_expiry() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    let user = Address::generate(&env);
+
+    client.create_lock(&user, &1000, &(2 * WEEK));
+    client.increase_lock_amount(&user, &500);
+    client.extend_lock(&user, &(3 * WEEK));
+
+    let result = client.try_withdraw_lock(&user);
+    assert_eq!(result, Err(Ok(VoteEscrowError::LockNotExpired)));
+
+    env.ledger().with_mut(|li| li.timestamp = 3 * WEEK);
+    assert_eq!(client.withdraw_lock(&user), 1500);
+    assert_eq!(client.get_ve_lock(&user), None);
+}
+
+#[test]
+fn test_gauge_votes_direct_weight() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let market_a = Address::generate(&env);
+    let market_b = Address::generate(&env);
+
+    client.add_gauge(&admin, &market_a);
+    client.add_gauge(&admin, &market_b);
+
+    client.create_lock(&alice, &1_000_000, &FOUR_YEARS);
+    client.create_lock(&bob, &1_000_000, &FOUR_YEARS);
+
+    let mut alice_weights = Map::new(&env);
+    alice_weights.set(market_a.clone(), 10000u32);
+    client.vote_for_gauges(&alice, &alice_weights);
+
+    let mut bob_weights = Map::new(&env);
+    bob_weights.set(market_a.clone(), 5000u32);
+    bob_weights.set(market_b.clone(), 5000u32);
+    client.vote_for_gauges(&bob, &bob_weights);
+
+    assert_eq!(client.get_gauge_relative_weight(&market_a), 7500);
+    assert_eq!(client.get_gauge_relative_weight(&market_b), 2500);
+
+    // Re-voting replaces previous votes
+    client.vote_for_gauges(&alice, &bob_weights);
+    assert_eq!(client.get_gauge_relative_weight(&market_a), 5000);
+}
+
+#[test]
+fn test_gauge_weight_expires_with_lock() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let market_a = Address::generate(&env);
+    let market_b = Address::generate(&env);
+    client.add_gauge(&admin, &market_a);
+    client.add_gauge(&admin, &market_b);
+
+    client.create_lock(&alice, &1_000_000, &(10 * WEEK));
+    client.create_lock(&bob, &1_000_000, &FOUR_YEARS);
+
+    let mut alice_weights = Map::new(&env);
+    alice_weights.set(market_a.clone(), 10000u32);
+    client.vote_for_gauges(&alice, &alice_weights);
+    let mut bob_weights = Map::new(&env);
+    bob_weights.set(market_b.clone(), 10000u32);
+    client.vote_for_gauges(&bob, &bob_weights);
+
+    let alice_weight = client.get_gauge_weight(&market_a);
+    assert_eq!(alice_weight, client.get_voting_power(&alice));
+
+    // Weight decays with the remaining lock time
+    env.ledger().with_mut(|li| li.timestamp = 5 * WEEK);
+    assert_eq!(client.get_gauge_weight(&market_a), alice_weight / 2);
+    assert_eq!(
+        client.get_gauge_weight(&market_b),
+        client.get_voting_power(&bob)
+    );
+
+    // An expired lock no longer directs emissions
+    env.ledger().with_mut(|li| li.timestamp = 11 * WEEK);
+    assert_eq!(client.get_gauge_weight(&market_a), 0);
+    assert_eq!(client.get_gauge_relative_weight(&market_a), 0);
+    assert_eq!(client.get_gauge_relative_weight(&market_b), 10000);
+}
+
+#[test]
+fn test_gauge_vote_validation() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let user = Address::generate(&env);
+    let market = Address::generate(&env);
+    let unknown = Address::generate(&env);
+
+    client.add_gauge(&admin, &market);
+    client.create_lock(&user, &1000, &FOUR_YEARS);
+
+    let mut weights = Map::new(&env);
+    weights.set(market.clone(), 10001u32);
+    let result = client.try_vote_for_gauges(&user, &weights);
+    assert_eq!(result, Err(Ok(VoteEscrowError::InvalidWeights)));
+
+    let mut weights = Map::new(&env);
+    weights.set(unknown, 100u32);
+    let result = client.try_vote_for_gauges(&user, &weights);
+    assert_eq!(result, Err(Ok(VoteEscrowError::GaugeNotFound)));
+}
+
+#[test]
+fn test_reward_boost_scales_with_power() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let user = Address::generate(&env);
+
+    assert_eq!(client.get_reward_boost(&user), 10000);
+
+    client.set_full_boost_power(&admin, &500_000);
+    client.create_lock(&user, &1_000_000, &FOUR_YEARS);
+    assert_eq!(client.get_reward_boost(&user), 25000);
+
+    env.ledger()
+        .with_mut(|li| li.timestamp = FOUR_YEARS - FOUR_YEARS / 8);
+    let boost = client.get_reward_boost(&user);
+    assert!(boost > 10000 && boost < 25000);
+}
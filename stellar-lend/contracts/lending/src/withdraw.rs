@@ -0,0 +1,106 @@
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol};
+
+use crate::borrow::{
+    get_collateral_position, get_total_collateral_value, get_total_user_debt,
+    save_collateral_position, validate_collateral_ratio,
+};
+use crate::history::PositionAction;
+
+/// Errors that can occur during collateral withdrawal
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum WithdrawError {
+    InvalidAmount = 1,
+    InsufficientBalance = 2,
+    InsufficientCollateral = 3,
+    PriceStale = 4,
+    ProtocolPaused = 5,
+    /// The collateral was posted with a borrow too recently
+    CooldownActive = 6,
+}
+
+/// Withdraw event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct WithdrawEvent {
+    pub user: Address,
+    pub asset: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+/// Withdraw deposited collateral
+///
+/// The remaining collateral must still cover all of the user's debt at the
+/// minimum collateral ratio.
+///
+/// # Returns
+/// The remaining collateral balance
+pub fn withdraw_collateral(env: &Env, user: Address, amount: i128) -> Result<i128, WithdrawError> {
+    user.require_auth();
+    apply_withdrawal(env, user, amount)
+}
+
+/// Withdraw collateral without requiring the user's authorization
+///
+/// Used to execute withdrawals the user authorized in advance.
+pub(crate) fn apply_withdrawal(
+    env: &Env,
+    user: Address,
+    amount: i128,
+) -> Result<i128, WithdrawError> {
+    if amount <= 0 {
+        return Err(WithdrawError::InvalidAmount);
+    }
+    if crate::pause::is_operation_paused(env, crate::pause::PAUSE_WITHDRAW) {
+        return Err(WithdrawError::ProtocolPaused);
+    }
+
+    let mut position = get_collateral_position(env, &user);
+    if amount > position.amount {
+        return Err(WithdrawError::InsufficientBalance);
+    }
+    if crate::oracle::is_price_stale(env, &position.asset) {
+        return Err(WithdrawError::PriceStale);
+    }
+    if crate::withdraw_cooldown::is_cooling_down(env, &user, &position.asset) {
+        return Err(WithdrawError::CooldownActive);
+    }
+
+    crate::points::checkpoint(env, &user);
+    position.amount -= amount;
+    save_collateral_position(env, &user, &position);
+
+    let debt = get_total_user_debt(env, &user);
+    if debt > 0 {
+        validate_collateral_ratio(env, &user, get_total_collateral_value(env, &user), debt)
+            .map_err(|_| WithdrawError::InsufficientCollateral)?;
+    }
+
+    crate::history::record_action(
+        env,
+        &user,
+        PositionAction::WithdrawCollateral,
+        &position.asset,
+        amount,
+    );
+    crate::position_hooks::notify(env, &user, None);
+    let event = WithdrawEvent {
+        user,
+        asset: position.asset.clone(),
+        amount,
+        timestamp: env.ledger().timestamp(),
+    };
+    crate::events::publish(
+        env,
+        (
+            Symbol::new(env, "withdraw_collateral"),
+            event.user.clone(),
+            event.asset.clone(),
+        ),
+        event,
+    );
+
+    Ok(position.amount)
+}
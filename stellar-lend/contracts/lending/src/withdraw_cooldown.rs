@@ -0,0 +1,96 @@
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol};
+
+use crate::admin::require_risk_manager;
+
+/// Errors that can occur when configuring withdrawal cooldowns
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum WithdrawCooldownError {
+    Unauthorized = 1,
+}
+
+/// Storage keys for withdrawal cooldown data
+#[contracttype]
+#[derive(Clone)]
+pub enum WithdrawCooldownDataKey {
+    /// Ledgers collateral of an asset stays locked after a borrow against it
+    WithdrawCooldown(Address),
+    /// Ledger of a user's latest borrow against newly deposited collateral
+    LastBorrowLedger(Address),
+}
+
+/// Withdrawal cooldown event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct WithdrawCooldownEvent {
+    pub asset: Address,
+    pub ledgers: u32,
+    pub actor: Address,
+    pub timestamp: u64,
+}
+
+/// Lock collateral of an asset for a number of ledgers after a borrow
+/// (risk manager or admin)
+///
+/// Collateral posted with a borrow cannot be withdrawn until `ledgers`
+/// ledgers have closed, so one ledger blocks same-ledger round trips. Zero
+/// removes the cooldown.
+pub fn set_withdraw_cooldown(
+    env: &Env,
+    caller: Address,
+    asset: Address,
+    ledgers: u32,
+) -> Result<(), WithdrawCooldownError> {
+    require_risk_manager(env, &caller).map_err(|_| WithdrawCooldownError::Unauthorized)?;
+
+    let key = WithdrawCooldownDataKey::WithdrawCooldown(asset.clone());
+    if ledgers == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &ledgers);
+    }
+    let event = WithdrawCooldownEvent {
+        asset,
+        ledgers,
+        actor: caller,
+        timestamp: env.ledger().timestamp(),
+    };
+    crate::events::publish(
+        env,
+        (
+            Symbol::new(env, "withdraw_cooldown_updated"),
+            event.asset.clone(),
+        ),
+        event,
+    );
+    Ok(())
+}
+
+/// Get the ledgers collateral of an asset stays locked after a borrow
+pub fn get_withdraw_cooldown(env: &Env, asset: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&WithdrawCooldownDataKey::WithdrawCooldown(asset.clone()))
+        .unwrap_or(0)
+}
+
+/// Record that a user borrowed against newly deposited collateral
+pub(crate) fn record_borrow(env: &Env, user: &Address) {
+    env.storage().persistent().set(
+        &WithdrawCooldownDataKey::LastBorrowLedger(user.clone()),
+        &env.ledger().sequence(),
+    );
+}
+
+/// Whether a user's collateral of `asset` is still locked by a recent borrow
+pub(crate) fn is_cooling_down(env: &Env, user: &Address, asset: &Address) -> bool {
+    let cooldown = get_withdraw_cooldown(env, asset);
+    if cooldown == 0 {
+        return false;
+    }
+    env.storage()
+        .persistent()
+        .get::<_, u32>(&WithdrawCooldownDataKey::LastBorrowLedger(user.clone()))
+        .is_some_and(|ledger| env.ledger().sequence() < ledger.saturating_add(cooldown))
+}
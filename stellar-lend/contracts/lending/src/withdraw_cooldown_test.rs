@@ -0,0 +1,46 @@
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+
+#[test]
+fn test_collateral_locked_after_borrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = LendingContractClient::new(&env, &env.register(LendingContract, ()));
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &InitConfig::new(&env, 1_000_000_000, 1000));
+    let collateral = Address::generate(&env);
+    assert_eq!(
+        client.try_set_withdraw_cooldown(&Address::generate(&env), &collateral, &3),
+        Err(Ok(WithdrawCooldownError::Unauthorized))
+    );
+    client.set_withdraw_cooldown(&admin, &collateral, &3);
+
+    let user = Address::generate(&env);
+    client.borrow(
+        &user,
+        &Address::generate(&env),
+        &10_000,
+        &collateral,
+        &30_000,
+    );
+    assert_eq!(
+        client.try_withdraw_collateral(&user, &1_000),
+        Err(Ok(WithdrawError::CooldownActive))
+    );
+    env.ledger().with_mut(|li| li.sequence_number += 2);
+    assert_eq!(
+        client.try_withdraw_collateral(&user, &1_000),
+        Err(Ok(WithdrawError::CooldownActive))
+    );
+
+    env.ledger().with_mut(|li| li.sequence_number += 1);
+    client.withdraw_collateral(&user, &1_000);
+
+    // Without a cooldown collateral can leave in the borrow's ledger
+    client.set_withdraw_cooldown(&admin, &collateral, &0);
+    client.borrow(&user, &Address::generate(&env), &1_000, &collateral, &2_000);
+    client.withdraw_collateral(&user, &1_000);
+}
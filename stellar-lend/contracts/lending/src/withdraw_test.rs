@@ -0,0 +1,49 @@
+use super::*;
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+fn setup(env: &Env) -> (LendingContractClient<'_>, Address) {
+    env.mock_all_auths();
+
+    let contract_id = env.register(LendingContract, ());
+    let client = LendingContractClient::new(env, &contract_id);
+    client.initialize(
+        &Address::generate(env),
+        &InitConfig::new(env, 1_000_000_000, 1000),
+    );
+
+    let user = Address::generate(env);
+    let asset = Address::generate(env);
+    let collateral_asset = Address::generate(env);
+    client.borrow(&user, &asset, &10_000, &collateral_asset, &20_000);
+    (client, user)
+}
+
+#[test]
+fn test_withdraw_collateral_keeps_ratio() {
+    let env = Env::default();
+    let (client, user) = setup(&env);
+
+    assert_eq!(client.get_health_factor(&user), 13_333);
+
+    assert_eq!(client.withdraw_collateral(&user, &5000), 15_000);
+    assert_eq!(client.get_health_factor(&user), 10_000);
+
+    let result = client.try_withdraw_collateral(&user, &1);
+    assert_eq!(result, Err(Ok(WithdrawError::InsufficientCollateral)));
+}
+
+#[test]
+fn test_withdraw_collateral_validation() {
+    let env = Env::default();
+    let (client, user) = setup(&env);
+
+    let result = client.try_withdraw_collateral(&user, &0);
+    assert_eq!(result, Err(Ok(WithdrawError::InvalidAmount)));
+
+    let result = client.try_withdraw_collateral(&user, &20_001);
+    assert_eq!(result, Err(Ok(WithdrawError::InsufficientBalance)));
+
+    client.repay(&user, &10_000);
+    assert_eq!(client.withdraw_collateral(&user, &20_000), 0);
+    assert_eq!(client.get_health_factor(&user), i128::MAX);
+}
@@ -0,0 +1,253 @@
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol, Vec};
+
+use crate::p2p::{apply_withdrawal, get_p2p_market, get_p2p_supply};
+
+/// Errors that can occur in the withdrawal queue
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum WithdrawalQueueError {
+    InvalidAmount = 1,
+    MarketNotFound = 2,
+    InsufficientBalance = 3,
+    /// The withdrawal can be served now through `p2p_withdraw`
+    LiquidityAvailable = 4,
+    AlreadyQueued = 5,
+    QueueFull = 6,
+    NotQueued = 7,
+}
+
+/// Storage keys for withdrawal queue data
+#[contracttype]
+#[derive(Clone)]
+pub enum WithdrawalQueueDataKey {
+    /// Queued withdrawals of an asset, in arrival order
+    WithdrawalQueue(Address),
+}
+
+/// A supplier's withdrawal waiting for liquidity
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueuedWithdrawal {
+    pub supplier: Address,
+    /// Amount still to be filled
+    pub amount: i128,
+    pub queued_at: u64,
+}
+
+/// A supplier's place in an asset's withdrawal queue
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueuePosition {
+    /// Zero-based position; 0 is filled next
+    pub position: u32,
+    pub amount: i128,
+    /// Amount queued ahead of the supplier
+    pub ahead: i128,
+}
+
+/// Withdrawal queue event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct WithdrawalQueueEvent {
+    pub supplier: Address,
+    pub asset: Address,
+    pub amount: i128,
+    /// Amount still queued after the event
+    pub remaining: i128,
+    pub timestamp: u64,
+}
+
+const MAX_QUEUED_WITHDRAWALS: u32 = 32;
+
+/// Queue a withdrawal that available liquidity cannot serve
+///
+/// The queue is filled first in, first out as repayments and new supply bring
+/// cash into the market. The queued amount keeps earning supply interest
+/// until it is filled.
+///
+/// # Returns
+/// The supplier's position in the queue
+pub fn queue_withdrawal(
+    env: &Env,
+    supplier: Address,
+    asset: Address,
+    amount: i128,
+) -> Result<QueuePosition, WithdrawalQueueError> {
+    supplier.require_auth();
+
+    if amount <= 0 {
+        return Err(WithdrawalQueueError::InvalidAmount);
+    }
+    if get_p2p_market(env, &asset).is_none() {
+        return Err(WithdrawalQueueError::MarketNotFound);
+    }
+    let position = get_p2p_supply(env, &supplier, &asset);
+    if amount > position.on_pool.saturating_add(position.in_p2p) {
+        return Err(WithdrawalQueueError::InsufficientBalance);
+    }
+    if amount <= crate::market::get_liquidity(env, &asset).withdrawable {
+        return Err(WithdrawalQueueError::LiquidityAvailable);
+    }
+    let mut queue = get_withdrawal_queue(env, &asset);
+    if queue.iter().any(|entry| entry.supplier == supplier) {
+        return Err(WithdrawalQueueError::AlreadyQueued);
+    }
+    if queue.len() >= MAX_QUEUED_WITHDRAWALS {
+        return Err(WithdrawalQueueError::QueueFull);
+    }
+
+    queue.push_back(QueuedWithdrawal {
+        supplier: supplier.clone(),
+        amount,
+        queued_at: env.ledger().timestamp(),
+    });
+    save_queue(env, &asset, &queue);
+    emit_queue_event(
+        env,
+        "withdrawal_queued",
+        supplier.clone(),
+        asset.clone(),
+        0,
+        amount,
+    );
+
+    process_withdrawal_queue(env, &asset);
+    get_queue_position(env, &supplier, &asset).ok_or(WithdrawalQueueError::NotQueued)
+}
+
+/// Leave an asset's withdrawal queue
+///
+/// # Returns
+/// The unfilled amount that was queued
+pub fn cancel_withdrawal(
+    env: &Env,
+    supplier: Address,
+    asset: Address,
+) -> Result<i128, WithdrawalQueueError> {
+    supplier.require_auth();
+
+    let mut queue = get_withdrawal_queue(env, &asset);
+    let index = queue
+        .iter()
+        .position(|entry| entry.supplier == supplier)
+        .ok_or(WithdrawalQueueError::NotQueued)? as u32;
+    let entry = queue.get_unchecked(index);
+    queue.remove(index);
+    save_queue(env, &asset, &queue);
+
+    emit_queue_event(
+        env,
+        "withdrawal_cancelled",
+        supplier,
+        asset,
+        entry.amount,
+        0,
+    );
+    Ok(entry.amount)
+}
+
+/// Get a supplier's position in an asset's withdrawal queue, if queued
+pub fn get_queue_position(env: &Env, supplier: &Address, asset: &Address) -> Option<QueuePosition> {
+    let mut ahead = 0i128;
+    for (position, entry) in get_withdrawal_queue(env, asset).iter().enumerate() {
+        if entry.supplier == *supplier {
+            return Some(QueuePosition {
+                position: position as u32,
+                amount: entry.amount,
+                ahead,
+            });
+        }
+        ahead = ahead.saturating_add(entry.amount);
+    }
+    None
+}
+
+/// Queued withdrawals of an asset, in arrival order
+pub fn get_withdrawal_queue(env: &Env, asset: &Address) -> Vec<QueuedWithdrawal> {
+    env.storage()
+        .persistent()
+        .get(&WithdrawalQueueDataKey::WithdrawalQueue(asset.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Total amount waiting in an asset's withdrawal queue
+pub(crate) fn get_pending_withdrawals(env: &Env, asset: &Address) -> i128 {
+    get_withdrawal_queue(env, asset)
+        .iter()
+        .fold(0i128, |acc, entry| acc.saturating_add(entry.amount))
+}
+
+/// Fill queued withdrawals from the market's available cash, oldest first
+///
+/// Entries whose supplier no longer holds the queued balance are filled up
+/// to what remains and dropped.
+pub(crate) fn process_withdrawal_queue(env: &Env, asset: &Address) {
+    let mut queue = get_withdrawal_queue(env, asset);
+    if queue.is_empty() {
+        return;
+    }
+    let mut cash = crate::market::get_liquidity(env, asset).available_cash;
+
+    while cash > 0 {
+        let Some(mut entry) = queue.first() else {
+            break;
+        };
+        let position = get_p2p_supply(env, &entry.supplier, asset);
+        let balance = position.on_pool.saturating_add(position.in_p2p);
+        let fill = entry.amount.min(balance).min(cash);
+        if fill > 0 && apply_withdrawal(env, entry.supplier.clone(), asset.clone(), fill).is_err() {
+            break;
+        }
+        cash -= fill;
+        entry.amount -= fill;
+        if entry.amount > 0 && fill < balance {
+            queue.set(0, entry.clone());
+        } else {
+            queue.pop_front();
+            entry.amount = 0;
+        }
+        emit_queue_event(
+            env,
+            "withdrawal_filled",
+            entry.supplier,
+            asset.clone(),
+            fill,
+            entry.amount,
+        );
+    }
+    save_queue(env, asset, &queue);
+}
+
+fn save_queue(env: &Env, asset: &Address, queue: &Vec<QueuedWithdrawal>) {
+    env.storage().persistent().set(
+        &WithdrawalQueueDataKey::WithdrawalQueue(asset.clone()),
+        queue,
+    );
+}
+
+fn emit_queue_event(
+    env: &Env,
+    name: &str,
+    supplier: Address,
+    asset: Address,
+    amount: i128,
+    remaining: i128,
+) {
+    let event = WithdrawalQueueEvent {
+        supplier,
+        asset,
+        amount,
+        remaining,
+        timestamp: env.ledger().timestamp(),
+    };
+    crate::events::publish(
+        env,
+        (
+            Symbol::new(env, name),
+            event.supplier.clone(),
+            event.asset.clone(),
+        ),
+        event,
+    );
+}
@@ -0,0 +1,68 @@
+use super::*;
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+fn setup(env: &Env) -> (LendingContractClient<'_>, Address) {
+    env.mock_all_auths();
+    let client = LendingContractClient::new(env, &env.register(LendingContract, ()));
+    let admin = Address::generate(env);
+    client.initialize(&admin, &InitConfig::new(env, 1_000_000_000, 1000));
+    let asset = Address::generate(env);
+    client.set_p2p_market(
+        &admin,
+        &asset,
+        &P2PMarket {
+            pool_supply_rate_bps: 200,
+            p2p_cursor_bps: 5000,
+        },
+    );
+    (client, asset)
+}
+
+#[test]
+fn test_queue_fills_in_order_as_repayments_arrive() {
+    let env = Env::default();
+    let (client, asset) = setup(&env);
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    client.p2p_supply(&first, &asset, &10_000);
+    client.p2p_supply(&second, &asset, &10_000);
+    let borrower = Address::generate(&env);
+    client.borrow(
+        &borrower,
+        &asset,
+        &18_000,
+        &Address::generate(&env),
+        &40_000,
+    );
+
+    assert_eq!(
+        client.try_p2p_withdraw(&first, &asset, &5000),
+        Err(Ok(P2PError::InsufficientLiquidity))
+    );
+    assert_eq!(
+        client.try_queue_withdrawal(&first, &asset, &2000),
+        Err(Ok(WithdrawalQueueError::LiquidityAvailable))
+    );
+
+    // The 2,000 of cash is filled straight away
+    let position = client.queue_withdrawal(&first, &asset, &5000);
+    assert_eq!(position.amount, 3000);
+    let position = client.queue_withdrawal(&second, &asset, &4000);
+    assert_eq!((position.position, position.ahead), (1, 3000));
+    assert_eq!(client.get_liquidity(&asset).pending_withdrawals, 7000);
+    assert_eq!(client.get_p2p_supply(&first, &asset).on_pool, 8000);
+
+    client.repay(&borrower, &5000);
+    assert_eq!(client.get_queue_position(&first, &asset), None);
+    assert_eq!(client.get_p2p_supply(&first, &asset).on_pool, 5000);
+    let position = client.get_queue_position(&second, &asset).unwrap();
+    assert_eq!((position.position, position.amount), (0, 2000));
+    assert_eq!(client.get_p2p_supply(&second, &asset).on_pool, 8000);
+
+    assert_eq!(client.cancel_withdrawal(&second, &asset), 2000);
+    assert_eq!(client.get_withdrawal_queue(&asset).len(), 0);
+    assert_eq!(
+        client.try_cancel_withdrawal(&second, &asset),
+        Err(Ok(WithdrawalQueueError::NotQueued))
+    );
+}
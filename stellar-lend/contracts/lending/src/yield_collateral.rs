@@ -0,0 +1,132 @@
+use soroban_sdk::{contractclient, contracterror, contracttype, Address, Env, Symbol};
+
+use crate::admin::require_risk_manager;
+use crate::math::mul_div_down;
+use crate::oracle::{get_effective_price, is_price_stale};
+
+/// Interface exposed by the rate oracle of a yield-bearing token
+#[contractclient(name = "ExchangeRateOracleClient")]
+pub trait ExchangeRateOracle {
+    /// Underlying tokens one yield-bearing token redeems for, scaled by 1e9
+    fn exchange_rate(env: Env) -> i128;
+}
+
+/// Errors that can occur when registering yield-bearing collateral
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum YieldCollateralError {
+    Unauthorized = 1,
+    InvalidConfig = 2,
+    NotRegistered = 3,
+}
+
+/// Storage keys for yield-bearing collateral data
+#[contracttype]
+#[derive(Clone)]
+pub enum YieldCollateralDataKey {
+    YieldToken(Address),
+}
+
+/// Rate oracle and underlying asset of a yield-bearing token
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct YieldTokenConfig {
+    pub rate_oracle: Address,
+    pub underlying: Address,
+}
+
+/// Scale of exchange rates reported by rate oracles
+pub const EXCHANGE_RATE_SCALE: i128 = 1_000_000_000;
+
+/// Register a yield-bearing collateral token (risk manager or admin)
+///
+/// Collateral of a registered token is valued at its exchange rate into the
+/// underlying instead of 1:1, so positions grow with the accrued yield. The
+/// token's price is the underlying's price times the rate.
+pub fn register_yield_token(
+    env: &Env,
+    caller: Address,
+    token: Address,
+    config: YieldTokenConfig,
+) -> Result<(), YieldCollateralError> {
+    require_risk_manager(env, &caller).map_err(|_| YieldCollateralError::Unauthorized)?;
+
+    if config.underlying == token || config.rate_oracle == token {
+        return Err(YieldCollateralError::InvalidConfig);
+    }
+    env.storage()
+        .persistent()
+        .set(&YieldCollateralDataKey::YieldToken(token.clone()), &config);
+    crate::oracle::track_priced_asset(env, &token);
+
+    crate::events::publish(
+        env,
+        (Symbol::new(env, "yield_token_registered"), token),
+        config,
+    );
+    Ok(())
+}
+
+/// Stop valuing a token at its exchange rate (risk manager or admin)
+pub fn deregister_yield_token(
+    env: &Env,
+    caller: Address,
+    token: Address,
+) -> Result<(), YieldCollateralError> {
+    require_risk_manager(env, &caller).map_err(|_| YieldCollateralError::Unauthorized)?;
+
+    let key = YieldCollateralDataKey::YieldToken(token);
+    if !env.storage().persistent().has(&key) {
+        return Err(YieldCollateralError::NotRegistered);
+    }
+    env.storage().persistent().remove(&key);
+    Ok(())
+}
+
+/// Get the rate oracle and underlying of a registered yield-bearing token
+pub fn get_yield_token_config(env: &Env, token: &Address) -> Option<YieldTokenConfig> {
+    env.storage()
+        .persistent()
+        .get(&YieldCollateralDataKey::YieldToken(token.clone()))
+}
+
+/// Current exchange rate of a registered yield-bearing token, scaled by 1e9
+///
+/// `None` if the token is not registered or its oracle reports a
+/// non-positive rate.
+pub fn get_exchange_rate(env: &Env, token: &Address) -> Option<i128> {
+    let config = get_yield_token_config(env, token)?;
+    let rate = ExchangeRateOracleClient::new(env, &config.rate_oracle).exchange_rate();
+    (rate > 0).then_some(rate)
+}
+
+/// Price of a registered yield-bearing token, scaled by `PRICE_SCALE`
+pub(crate) fn get_yield_token_price(
+    env: &Env,
+    config: &YieldTokenConfig,
+    token: &Address,
+) -> Option<i128> {
+    let price = get_effective_price(env, &config.underlying)?.price;
+    let rate = get_exchange_rate(env, token)?;
+    Some(mul_div_down(price, rate, EXCHANGE_RATE_SCALE))
+}
+
+/// Whether the underlying price of a registered yield-bearing token is stale
+pub(crate) fn is_yield_price_stale(env: &Env, config: &YieldTokenConfig) -> bool {
+    is_price_stale(env, &config.underlying)
+}
+
+/// Value of a collateral amount in units of its underlying
+///
+/// Unregistered assets are valued 1:1; a registered token whose rate is
+/// unavailable is valued at nothing.
+pub(crate) fn to_underlying(env: &Env, asset: &Address, amount: i128) -> i128 {
+    if get_yield_token_config(env, asset).is_none() {
+        return amount;
+    }
+    match get_exchange_rate(env, asset) {
+        Some(rate) => mul_div_down(amount, rate, EXCHANGE_RATE_SCALE),
+        None => 0,
+    }
+}
@@ -0,0 +1,138 @@
+use super::*;
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, Address, Env, Symbol};
+
+/// Rate oracle with a settable exchange rate
+#[contract]
+pub struct MockRateOracle;
+
+#[contractimpl]
+impl MockRateOracle {
+    pub fn set_rate(env: Env, rate: i128) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "rate"), &rate);
+    }
+
+    pub fn exchange_rate(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "rate"))
+            .unwrap_or(0)
+    }
+}
+
+fn setup(
+    env: &Env,
+) -> (
+    LendingContractClient<'_>,
+    MockRateOracleClient<'_>,
+    Address,
+    Address,
+) {
+    env.mock_all_auths();
+    let client = LendingContractClient::new(env, &env.register(LendingContract, ()));
+    let admin = Address::generate(env);
+    client.initialize(&admin, &InitConfig::new(env, 1_000_000_000, 1000));
+
+    let oracle = MockRateOracleClient::new(env, &env.register(MockRateOracle, ()));
+    oracle.set_rate(&(EXCHANGE_RATE_SCALE * 3 / 2));
+    let staked = Address::generate(env);
+    client.register_yield_token(
+        &admin,
+        &staked,
+        &YieldTokenConfig {
+            rate_oracle: oracle.address.clone(),
+            underlying: Address::generate(env),
+        },
+    );
+    (client, oracle, admin, staked)
+}
+
+#[test]
+fn test_yield_collateral_valued_at_exchange_rate() {
+    let env = Env::default();
+    let (client, oracle, _, staked) = setup(&env);
+    let user = Address::generate(&env);
+    let debt_asset = Address::generate(&env);
+
+    // 10,000 tokens redeem for 15,000 of the underlying, enough for 10,000 at 150%
+    client.borrow(&user, &debt_asset, &10_000, &staked, &10_000);
+    assert_eq!(
+        client.get_user_summary(&user).total_collateral_value,
+        15_000
+    );
+    let health = client.get_health_factor(&user);
+
+    // Accrued yield raises the position's value
+    oracle.set_rate(&(EXCHANGE_RATE_SCALE * 9 / 5));
+    assert_eq!(
+        client.get_exchange_rate(&staked),
+        Some(EXCHANGE_RATE_SCALE * 9 / 5)
+    );
+    assert_eq!(
+        client.get_user_summary(&user).total_collateral_value,
+        18_000
+    );
+    assert!(client.get_health_factor(&user) > health);
+
+    // Unregistered tokens are valued 1:1
+    let plain = Address::generate(&env);
+    assert_eq!(
+        client.try_borrow(
+            &Address::generate(&env),
+            &debt_asset,
+            &10_000,
+            &plain,
+            &10_000
+        ),
+        Err(Ok(BorrowError::InsufficientCollateral))
+    );
+}
+
+#[test]
+fn test_yield_token_priced_from_underlying() {
+    let env = Env::default();
+    let (client, oracle, admin, staked) = setup(&env);
+    let underlying = client.get_yield_token_config(&staked).unwrap().underlying;
+
+    assert_eq!(client.get_effective_price(&staked), None);
+    client.set_asset_price(&admin, &underlying, &20_000_000);
+    assert_eq!(
+        client.get_effective_price(&staked).unwrap().price,
+        30_000_000
+    );
+
+    oracle.set_rate(&0);
+    assert_eq!(client.get_exchange_rate(&staked), None);
+    assert_eq!(client.get_effective_price(&staked), None);
+
+    client.deregister_yield_token(&admin, &staked);
+    assert_eq!(
+        client.try_deregister_yield_token(&admin, &staked),
+        Err(Ok(YieldCollateralError::NotRegistered))
+    );
+}
+
+#[test]
+fn test_committed_liquidation_check_uses_exchange_rate() {
+    let env = Env::default();
+    let (client, oracle, admin, staked) = setup(&env);
+    let user = Address::generate(&env);
+    let collection = Address::generate(&env);
+    client.approve_nft_collection(&admin, &collection, &5000);
+    client.set_nft_floor(&admin, &collection, &2);
+    client.escrow_nft(&user, &collection, &1);
+
+    // 30,000 tokens redeem for 15,000, just enough for 10,000 at 150%
+    oracle.set_rate(&(EXCHANGE_RATE_SCALE / 2));
+    client.borrow(&user, &Address::generate(&env), &10_000, &staked, &30_000);
+    client.set_committed_price_mode(&admin, &true);
+    assert_eq!(
+        client.try_start_nft_auction(&collection, &1),
+        Err(Ok(NftError::PositionHealthy))
+    );
+
+    // Valued at the lower rate rather than the token count, it is liquidatable
+    oracle.set_rate(&(EXCHANGE_RATE_SCALE * 2 / 5));
+    client.start_nft_auction(&collection, &1);
+}
@@ -0,0 +1,102 @@
+#![no_std]
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+mod vault;
+use vault::{
+    convert_to_assets, deposit, get_config, get_event_sequence, get_shares, get_total_assets,
+    get_total_shares, harvest, initialize, withdraw, VaultConfig, VaultError,
+};
+pub use vault::{DexAdapter, LendingPool};
+
+#[contract]
+pub struct VaultContract;
+
+#[contractimpl]
+impl VaultContract {
+    /// Initialize the vault
+    ///
+    /// # Arguments
+    /// * `config` - Pool, AMM adapter, assets and harvest settings; `config.admin` must authorize
+    ///
+    /// # Returns
+    /// Returns Ok(()) on success
+    pub fn initialize(env: Env, config: VaultConfig) -> Result<(), VaultError> {
+        initialize(&env, config)
+    }
+
+    /// Deposit assets into the vault
+    ///
+    /// The vault supplies the assets to the lending pool and mints shares.
+    ///
+    /// # Arguments
+    /// * `user` - The depositor (must authorize)
+    /// * `amount` - The amount of the vault asset to deposit
+    ///
+    /// # Returns
+    /// Returns the number of shares minted
+    pub fn deposit(env: Env, user: Address, amount: i128) -> Result<i128, VaultError> {
+        deposit(&env, user, amount)
+    }
+
+    /// Redeem vault shares
+    ///
+    /// # Arguments
+    /// * `user` - The share holder (must authorize)
+    /// * `shares` - The number of shares to redeem
+    ///
+    /// # Returns
+    /// Returns the amount of assets withdrawn
+    pub fn withdraw(env: Env, user: Address, shares: i128) -> Result<i128, VaultError> {
+        withdraw(&env, user, shares)
+    }
+
+    /// Compound the vault's incentives (permissionless)
+    ///
+    /// Claims rewards from the pool, swaps them to the vault asset through the
+    /// AMM adapter and re-supplies the proceeds.
+    ///
+    /// # Arguments
+    /// * `caller` - The account triggering the harvest
+    /// * `min_amount_out` - Minimum assets the swap must return
+    ///
+    /// # Returns
+    /// Returns the amount of assets compounded
+    pub fn harvest(env: Env, caller: Address, min_amount_out: i128) -> Result<i128, VaultError> {
+        harvest(&env, caller, min_amount_out)
+    }
+
+    /// Get the vault configuration
+    pub fn get_config(env: Env) -> Option<VaultConfig> {
+        get_config(&env).ok()
+    }
+
+    /// Get the shares held by an account
+    pub fn get_shares(env: Env, account: Address) -> i128 {
+        get_shares(&env, &account)
+    }
+
+    /// Get the total shares outstanding
+    pub fn get_total_shares(env: Env) -> i128 {
+        get_total_shares(&env)
+    }
+
+    /// Get the assets the vault has supplied to the pool
+    pub fn get_total_assets(env: Env) -> i128 {
+        get_total_assets(&env)
+    }
+
+    /// Convert a share amount to assets at the current share price
+    pub fn convert_to_assets(env: Env, shares: i128) -> i128 {
+        convert_to_assets(&env, shares)
+    }
+
+    /// Get the sequence number of the last event the vault published
+    ///
+    /// Every vault event carries its sequence number as the last topic.
+    pub fn get_event_sequence(env: Env) -> u64 {
+        get_event_sequence(&env)
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,384 @@
+use soroban_sdk::{contractclient, contracterror, contracttype, Address, Env, Symbol};
+
+/// Supply and incentive interface of the StellarLend lending pool
+#[contractclient(name = "LendingPoolClient")]
+pub trait LendingPool {
+    /// Supply liquidity on behalf of `supplier`
+    fn p2p_supply(env: Env, supplier: Address, asset: Address, amount: i128) -> i128;
+    /// Withdraw supplied liquidity on behalf of `supplier`
+    fn p2p_withdraw(env: Env, supplier: Address, asset: Address, amount: i128) -> i128;
+    /// Claim unlocked incentive rewards
+    fn claim_vested(env: Env, user: Address) -> i128;
+}
+
+/// Swap interface of the StellarLend AMM adapter
+#[contractclient(name = "DexAdapterClient")]
+pub trait DexAdapter {
+    /// Swap `params.amount_in` of `params.token_in` for `params.token_out`
+    fn execute_swap(env: Env, user: Address, params: SwapParams) -> i128;
+}
+
+/// Swap parameters accepted by the AMM adapter's `execute_swap`
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SwapParams {
+    pub protocol: Address,
+    pub token_in: Option<Address>,
+    pub token_out: Option<Address>,
+    pub amount_in: i128,
+    pub min_amount_out: i128,
+    pub slippage_tolerance: i128,
+    pub deadline: u64,
+}
+
+/// Errors that can occur during vault operations
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum VaultError {
+    /// Vault has already been initialized
+    AlreadyInitialized = 1,
+    /// Vault has not been initialized
+    NotInitialized = 2,
+    /// Invalid vault configuration
+    InvalidConfig = 3,
+    /// Amount must be positive
+    InvalidAmount = 4,
+    /// Caller holds fewer shares than requested
+    InsufficientShares = 5,
+    /// The harvest interval has not elapsed
+    HarvestTooSoon = 6,
+    /// No rewards were available to compound
+    NothingToHarvest = 7,
+    /// The lending pool rejected the call
+    PoolCallFailed = 8,
+    /// The swap failed or returned less than the minimum output
+    SwapFailed = 9,
+    /// Overflow occurred during calculation
+    Overflow = 10,
+}
+
+/// Storage keys for vault data
+#[contracttype]
+#[derive(Clone)]
+pub enum VaultDataKey {
+    /// Vault configuration
+    Config,
+    /// Shares held by an account
+    Shares(Address),
+    /// Total shares outstanding
+    TotalShares,
+    /// Assets supplied to the pool on behalf of the vault
+    TotalAssets,
+    /// Timestamp of the last harvest
+    LastHarvest,
+    /// Sequence number of the last event published by the vault
+    EventSequence,
+}
+
+/// Vault configuration
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct VaultConfig {
+    /// Vault admin
+    pub admin: Address,
+    /// Lending pool the vault supplies into
+    pub pool: Address,
+    /// AMM adapter used to swap incentives
+    pub dex: Address,
+    /// AMM protocol the adapter routes swaps through
+    pub dex_protocol: Address,
+    /// Asset supplied to the pool
+    pub asset: Address,
+    /// Incentive token paid by the pool
+    pub reward_asset: Address,
+    /// Minimum time between harvests (seconds)
+    pub harvest_interval: u64,
+    /// Slippage tolerance passed to the adapter (basis points)
+    pub slippage_bps: i128,
+}
+
+/// Vault event data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct VaultEvent {
+    pub account: Address,
+    pub assets: i128,
+    pub shares: i128,
+    pub timestamp: u64,
+}
+
+const MAX_SLIPPAGE_BPS: i128 = 1000; // 10%
+
+/// Virtual shares and assets added to the share price, as if a dead deposit
+/// of one asset unit had been made at 1000 shares per unit. Inflating the
+/// share price of a near-empty vault then mostly accrues to the virtual
+/// shares, so first-depositor attacks cost more than they can take.
+const VIRTUAL_SHARES: i128 = 1000;
+const VIRTUAL_ASSETS: i128 = 1;
+
+/// Initialize the vault
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `config` - Vault configuration; `config.admin` must authorize
+///
+/// # Returns
+/// Returns Ok(()) on success
+pub fn initialize(env: &Env, config: VaultConfig) -> Result<(), VaultError> {
+    if env.storage().persistent().has(&VaultDataKey::Config) {
+        return Err(VaultError::AlreadyInitialized);
+    }
+    config.admin.require_auth();
+
+    if !(0..=MAX_SLIPPAGE_BPS).contains(&config.slippage_bps) {
+        return Err(VaultError::InvalidConfig);
+    }
+    env.storage()
+        .persistent()
+        .set(&VaultDataKey::Config, &config);
+    Ok(())
+}
+
+/// Deposit assets, which the vault supplies to the pool, in exchange for shares
+///
+/// Shares are minted at the current share price including the virtual
+/// offset, rounded down in favor of existing holders.
+///
+/// # Returns
+/// Returns the number of shares minted
+pub fn deposit(env: &Env, user: Address, amount: i128) -> Result<i128, VaultError> {
+    user.require_auth();
+
+    if amount <= 0 {
+        return Err(VaultError::InvalidAmount);
+    }
+    let config = get_config(env)?;
+
+    let total_shares = get_total_shares(env);
+    let total_assets = get_total_assets(env);
+    let shares = mul_div_down(
+        amount,
+        total_shares + VIRTUAL_SHARES,
+        total_assets + VIRTUAL_ASSETS,
+    )
+    .ok_or(VaultError::Overflow)?;
+    if shares == 0 {
+        return Err(VaultError::InvalidAmount);
+    }
+
+    LendingPoolClient::new(env, &config.pool)
+        .try_p2p_supply(&env.current_contract_address(), &config.asset, &amount)
+        .map_err(|_| VaultError::PoolCallFailed)?
+        .map_err(|_| VaultError::PoolCallFailed)?;
+
+    set_total_assets(env, total_assets + amount);
+    set_total_shares(env, total_shares + shares);
+    set_shares(env, &user, get_shares(env, &user) + shares);
+
+    emit_vault_event(env, "vault_deposit", user, amount, shares);
+    Ok(shares)
+}
+
+/// Redeem shares for their share of the vault's assets
+///
+/// # Returns
+/// Returns the amount of assets withdrawn from the pool
+pub fn withdraw(env: &Env, user: Address, shares: i128) -> Result<i128, VaultError> {
+    user.require_auth();
+
+    if shares <= 0 {
+        return Err(VaultError::InvalidAmount);
+    }
+    let config = get_config(env)?;
+    let balance = get_shares(env, &user);
+    if shares > balance {
+        return Err(VaultError::InsufficientShares);
+    }
+
+    let total_shares = get_total_shares(env);
+    let total_assets = get_total_assets(env);
+    let assets = mul_div_down(
+        shares,
+        total_assets + VIRTUAL_ASSETS,
+        total_shares + VIRTUAL_SHARES,
+    )
+    .ok_or(VaultError::Overflow)?
+    .min(total_assets);
+
+    if assets > 0 {
+        LendingPoolClient::new(env, &config.pool)
+            .try_p2p_withdraw(&env.current_contract_address(), &config.asset, &assets)
+            .map_err(|_| VaultError::PoolCallFailed)?
+            .map_err(|_| VaultError::PoolCallFailed)?;
+    }
+
+    set_total_assets(env, total_assets - assets);
+    set_total_shares(env, total_shares - shares);
+    set_shares(env, &user, balance - shares);
+
+    emit_vault_event(env, "vault_withdraw", user, assets, shares);
+    Ok(assets)
+}
+
+/// Claim the vault's incentives, swap them to the supplied asset and re-supply
+///
+/// Permissionless, at most once per harvest interval. Compounded assets accrue
+/// to all share holders through the share price.
+///
+/// # Arguments
+/// * `caller` - The account triggering the harvest
+/// * `min_amount_out` - Minimum assets the swap must return
+///
+/// # Returns
+/// Returns the amount of assets compounded
+pub fn harvest(env: &Env, caller: Address, min_amount_out: i128) -> Result<i128, VaultError> {
+    let config = get_config(env)?;
+    let now = env.ledger().timestamp();
+    if let Some(last) = env
+        .storage()
+        .persistent()
+        .get::<_, u64>(&VaultDataKey::LastHarvest)
+    {
+        if now < last.saturating_add(config.harvest_interval) {
+            return Err(VaultError::HarvestTooSoon);
+        }
+    }
+
+    let vault = env.current_contract_address();
+    let pool = LendingPoolClient::new(env, &config.pool);
+    let claimed = pool
+        .try_claim_vested(&vault)
+        .map_err(|_| VaultError::PoolCallFailed)?
+        .map_err(|_| VaultError::PoolCallFailed)?;
+    if claimed <= 0 {
+        return Err(VaultError::NothingToHarvest);
+    }
+
+    let compounded = if config.reward_asset == config.asset {
+        claimed
+    } else {
+        let params = SwapParams {
+            protocol: config.dex_protocol.clone(),
+            token_in: Some(config.reward_asset.clone()),
+            token_out: Some(config.asset.clone()),
+            amount_in: claimed,
+            min_amount_out,
+            slippage_tolerance: config.slippage_bps,
+            deadline: now,
+        };
+        DexAdapterClient::new(env, &config.dex)
+            .try_execute_swap(&vault, &params)
+            .map_err(|_| VaultError::SwapFailed)?
+            .map_err(|_| VaultError::SwapFailed)?
+    };
+    if compounded < min_amount_out || compounded <= 0 {
+        return Err(VaultError::SwapFailed);
+    }
+
+    pool.try_p2p_supply(&vault, &config.asset, &compounded)
+        .map_err(|_| VaultError::PoolCallFailed)?
+        .map_err(|_| VaultError::PoolCallFailed)?;
+
+    let total_assets = get_total_assets(env)
+        .checked_add(compounded)
+        .ok_or(VaultError::Overflow)?;
+    set_total_assets(env, total_assets);
+    env.storage()
+        .persistent()
+        .set(&VaultDataKey::LastHarvest, &now);
+
+    emit_vault_event(env, "vault_harvest", caller, compounded, 0);
+    Ok(compounded)
+}
+
+/// Get the vault configuration
+pub fn get_config(env: &Env) -> Result<VaultConfig, VaultError> {
+    env.storage()
+        .persistent()
+        .get(&VaultDataKey::Config)
+        .ok_or(VaultError::NotInitialized)
+}
+
+/// Get the shares held by an account
+pub fn get_shares(env: &Env, account: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&VaultDataKey::Shares(account.clone()))
+        .unwrap_or(0)
+}
+
+/// Get the total shares outstanding
+pub fn get_total_shares(env: &Env) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&VaultDataKey::TotalShares)
+        .unwrap_or(0)
+}
+
+/// Get the assets supplied to the pool on behalf of the vault
+pub fn get_total_assets(env: &Env) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&VaultDataKey::TotalAssets)
+        .unwrap_or(0)
+}
+
+/// Convert a share amount to assets at the current share price
+pub fn convert_to_assets(env: &Env, shares: i128) -> i128 {
+    mul_div_down(
+        shares,
+        get_total_assets(env) + VIRTUAL_ASSETS,
+        get_total_shares(env) + VIRTUAL_SHARES,
+    )
+    .unwrap_or(i128::MAX)
+}
+
+/// Sequence number of the last published event (0 before any event)
+pub fn get_event_sequence(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&VaultDataKey::EventSequence)
+        .unwrap_or(0)
+}
+
+fn mul_div_down(a: i128, b: i128, c: i128) -> Option<i128> {
+    a.checked_mul(b).map(|product| product / c)
+}
+
+fn set_shares(env: &Env, account: &Address, shares: i128) {
+    env.storage()
+        .persistent()
+        .set(&VaultDataKey::Shares(account.clone()), &shares);
+}
+
+fn set_total_shares(env: &Env, shares: i128) {
+    env.storage()
+        .persistent()
+        .set(&VaultDataKey::TotalShares, &shares);
+}
+
+fn set_total_assets(env: &Env, assets: i128) {
+    env.storage()
+        .persistent()
+        .set(&VaultDataKey::TotalAssets, &assets);
+}
+
+fn emit_vault_event(env: &Env, name: &str, account: Address, assets: i128, shares: i128) {
+    let event = VaultEvent {
+        account,
+        assets,
+        shares,
+        timestamp: env.ledger().timestamp(),
+    };
+    // Sequenced like the lending pool's events: the last topic increases by
+    // one per event so indexers can detect gaps and drop duplicates
+    let sequence = get_event_sequence(env) + 1;
+    env.storage()
+        .instance()
+        .set(&VaultDataKey::EventSequence, &sequence);
+    env.events().publish(
+        (Symbol::new(env, name), event.account.clone(), sequence),
+        event,
+    );
+}